@@ -25,17 +25,11 @@ fn extract_opcode_attr(attrs: &[Attribute]) -> u128 {
 fn extract_returns_attr(attrs: &[Attribute]) -> Option<String> {
     for attr in attrs {
         if attr.path.is_ident("returns") {
-            // Just get the raw tokens as a string
-            let tokens = attr.tokens.clone().to_string();
-            
-            // Remove the parentheses and any whitespace
-            let type_str = tokens.trim_start_matches('(')
-                                .trim_end_matches(')')
-                                .trim();
-            
-            if !type_str.is_empty() {
-                return Some(type_str.to_string());
-            }
+            // Parse the type so the ABI spells it the same way as parameter types
+            let ty: Type = attr
+                .parse_args()
+                .expect("Invalid #[returns(Type)] attribute");
+            return Some(get_type_string(&ty));
         }
     }
     None
@@ -107,6 +101,66 @@ fn is_u128_type(ty: &Type) -> bool {
     false
 }
 
+/// Check if a type is a bool
+fn is_bool_type(ty: &Type) -> bool {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        if let Some(segment) = path.segments.last() {
+            return segment.ident == "bool";
+        }
+    }
+    false
+}
+
+/// Check if a type is one of the unsigned integers narrower than u128
+fn is_small_uint_type(ty: &Type) -> bool {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        if let Some(segment) = path.segments.last() {
+            return ["u8", "u16", "u32", "u64"]
+                .iter()
+                .any(|name| segment.ident == name);
+        }
+    }
+    false
+}
+
+/// Get the length of a fixed byte array type such as [u8; 32]
+fn get_byte_array_len(ty: &Type) -> Option<&syn::Expr> {
+    if let Type::Array(array) = ty {
+        if let Type::Path(TypePath { path, .. }) = &*array.elem {
+            if path.is_ident("u8") {
+                return Some(&array.len);
+            }
+        }
+    }
+    None
+}
+
+/// Check if a type is an Option
+fn is_option_type(ty: &Type) -> bool {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        if let Some(segment) = path.segments.last() {
+            return segment.ident == "Option";
+        }
+    }
+    false
+}
+
+/// Get the inner type of an Option
+fn get_option_inner_type(ty: &Type) -> Option<&Type> {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        if let Some(segment) = path.segments.last() {
+            if segment.ident == "Option" {
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    if let Some(syn::GenericArgument::Type(inner_type)) = args.args.first() {
+                        return Some(inner_type);
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
 /// Check if a type is a Vec
 fn is_vec_type(ty: &Type) -> bool {
     if let Type::Path(TypePath { path, .. }) = ty {
@@ -205,6 +259,111 @@ fn generate_u128_extraction(field_name: &Ident) -> proc_macro2::TokenStream {
     }
 }
 
+/// Generate code to extract a bool parameter, given as 0 or 1, from __macro_inputs
+fn generate_bool_extraction(field_name: &Ident) -> proc_macro2::TokenStream {
+    quote! {
+        let #field_name = {
+            if input_index >= __macro_inputs.len() {
+                return Err(anyhow::anyhow!("Missing bool parameter"));
+            }
+            let value = __macro_inputs[input_index];
+            input_index += 1;
+            match value {
+                0 => false,
+                1 => true,
+                _ => return Err(anyhow::anyhow!("Invalid bool parameter: {}", value)),
+            }
+        };
+    }
+}
+
+/// Generate code to extract a u8, u16, u32 or u64 parameter from __macro_inputs
+fn generate_small_uint_extraction(field_name: &Ident, ty: &Type) -> proc_macro2::TokenStream {
+    quote! {
+        let #field_name: #ty = {
+            if input_index >= __macro_inputs.len() {
+                return Err(anyhow::anyhow!("Missing {} parameter", stringify!(#ty)));
+            }
+            let value = __macro_inputs[input_index];
+            input_index += 1;
+            <#ty as core::convert::TryFrom<u128>>::try_from(value).map_err(|_| anyhow::anyhow!("{} does not fit in {}", value, stringify!(#ty)))?
+        };
+    }
+}
+
+/// Generate code to extract a fixed byte array from __macro_inputs, where the
+/// bytes are packed little endian sixteen to an input
+fn generate_byte_array_extraction(field_name: &Ident, len: &syn::Expr) -> proc_macro2::TokenStream {
+    quote! {
+        let #field_name = {
+            const LENGTH: usize = #len;
+            let words = (LENGTH + 15) / 16;
+            if input_index + words > __macro_inputs.len() {
+                return Err(anyhow::anyhow!("Not enough parameters provided for [u8; {}]", LENGTH));
+            }
+            let bytes: Vec<u8> = __macro_inputs[input_index..input_index + words]
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .take(LENGTH)
+                .collect();
+            input_index += words;
+            let mut array = [0u8; LENGTH];
+            array.copy_from_slice(&bytes);
+            array
+        };
+    }
+}
+
+/// Generate code to extract an Option from __macro_inputs: a 0 for None, or a 1
+/// followed by the value
+fn generate_option_extraction(field_name: &Ident, inner_type: &Type) -> proc_macro2::TokenStream {
+    let element_name = format_ident!("element");
+    let element_extraction = generate_element_extraction(inner_type, &element_name);
+
+    quote! {
+        let #field_name = {
+            if input_index >= __macro_inputs.len() {
+                return Err(anyhow::anyhow!("Missing tag parameter for Option"));
+            }
+            let tag = __macro_inputs[input_index];
+            input_index += 1;
+            match tag {
+                0 => None,
+                1 => {
+                    #element_extraction
+                    Some(#element_name)
+                }
+                _ => return Err(anyhow::anyhow!("Invalid Option tag: {}", tag)),
+            }
+        };
+    }
+}
+
+/// Generate code to extract a struct deriving StorageCodec from __macro_inputs:
+/// the length of its encoding, then the encoding packed as for byte arrays
+fn generate_codec_extraction(field_name: &Ident, ty: &Type) -> proc_macro2::TokenStream {
+    quote! {
+        let #field_name = {
+            if input_index >= __macro_inputs.len() {
+                return Err(anyhow::anyhow!("Missing length parameter for {}", stringify!(#ty)));
+            }
+            let length = __macro_inputs[input_index] as usize;
+            input_index += 1;
+            let words = length / 16 + (length % 16 != 0) as usize;
+            if words > __macro_inputs.len() - input_index {
+                return Err(anyhow::anyhow!("Not enough parameters provided for {}", stringify!(#ty)));
+            }
+            let bytes: Vec<u8> = __macro_inputs[input_index..input_index + words]
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .take(length)
+                .collect();
+            input_index += words;
+            <#ty as alkanes_runtime::storage::StorageCodec>::decode(&bytes)?
+        };
+    }
+}
+
 /// Generate code to extract a single element based on its type
 fn generate_element_extraction(ty: &Type, element_name: &Ident) -> proc_macro2::TokenStream {
     if is_string_type(ty) {
@@ -213,6 +372,12 @@ fn generate_element_extraction(ty: &Type, element_name: &Ident) -> proc_macro2::
         generate_alkane_id_extraction(element_name)
     } else if is_u128_type(ty) {
         generate_u128_extraction(element_name)
+    } else if is_bool_type(ty) {
+        generate_bool_extraction(element_name)
+    } else if is_small_uint_type(ty) {
+        generate_small_uint_extraction(element_name, ty)
+    } else if let Some(len) = get_byte_array_len(ty) {
+        generate_byte_array_extraction(element_name, len)
     } else if is_vec_type(ty) {
         // For Vec types, get the inner type and generate Vec extraction
         if let Some(inner_type) = get_vec_inner_type(ty) {
@@ -220,9 +385,17 @@ fn generate_element_extraction(ty: &Type, element_name: &Ident) -> proc_macro2::
         } else {
             panic!("Failed to get inner type for Vec");
         }
+    } else if is_option_type(ty) {
+        if let Some(inner_type) = get_option_inner_type(ty) {
+            generate_option_extraction(element_name, inner_type)
+        } else {
+            panic!("Failed to get inner type for Option");
+        }
+    } else if let Type::Path(_) = ty {
+        // Any other named type is read through its StorageCodec
+        generate_codec_extraction(element_name, ty)
     } else {
-        // For other types, panic
-        panic!("Unsupported type. Only String, AlkaneId, u128, and Vec are supported.");
+        panic!("Unsupported type. Only integers, bool, String, AlkaneId, byte arrays, Vec, Option and StorageCodec structs are supported.");
     }
 }
 
@@ -269,17 +442,33 @@ fn get_type_string(ty: &Type) -> String {
                         return format!("Vec<{}>", get_type_string(inner_type));
                     }
                 }
+                if let Some(inner_type) = get_option_inner_type(ty) {
+                    return format!("Option<{}>", get_type_string(inner_type));
+                }
                 segment.ident.to_string()
             } else {
                 "unknown".to_string()
             }
         }
+        Type::Array(array) if get_byte_array_len(ty).is_some() => {
+            let len = &array.len;
+            format!("[u8; {}]", quote!(#len))
+        }
         _ => "unknown".to_string(),
     }
 }
 
 /// Derive macro for MessageDispatch trait
-#[proc_macro_derive(MessageDispatch, attributes(opcode, returns))]
+///
+/// Fields are read from the inputs in order. Integers and bool take one input,
+/// AlkaneId two, a String its bytes up to a zero byte, [u8; N] its bytes packed
+/// sixteen to an input, a Vec its length then its elements, an Option a 0 or a
+/// 1 followed by the value, and any other type the length of its StorageCodec
+/// encoding followed by that encoding packed like a byte array. Methods return
+/// either a CallResponse or a value encoded into `data` with its StorageCodec,
+/// whose type `#[returns]` should name for the ABI. An `#[errors(Type)]` on the
+/// enum names an `AlkaneError` enum methods revert with as typed errors.
+#[proc_macro_derive(MessageDispatch, attributes(opcode, returns, view, errors))]
 pub fn derive_message_dispatch(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
@@ -386,7 +575,7 @@ pub fn derive_message_dispatch(input: TokenStream) -> TokenStream {
                 quote! {
                     Self::#variant_name #pattern => {
                         // Call the method directly on the responder
-                        responder.#method_name(#param_pass)
+                        alkanes_runtime::message::MessageReturn::into_response(responder.#method_name(#param_pass)?, responder)
                     }
                 }
             },
@@ -399,7 +588,7 @@ pub fn derive_message_dispatch(input: TokenStream) -> TokenStream {
                 quote! {
                     Self::#variant_name => {
                         // Call the method directly on the responder
-                        responder.#method_name()
+                        alkanes_runtime::message::MessageReturn::into_response(responder.#method_name()?, responder)
                     }
                 }
             },
@@ -479,6 +668,24 @@ pub fn derive_message_dispatch(input: TokenStream) -> TokenStream {
 
     let method_json_str = format!("{}", method_json_entries);
 
+    // Errors named by #[errors(Type)] revert as typed errors and join the ABI
+    let errors_type = extract_errors_type(&input.attrs);
+    let (revert_data, errors_json) = match &errors_type {
+        Some(errors_type) => (
+            quote! {
+                fn revert_data(error: &anyhow::Error) -> Option<Vec<u8>> {
+                    error
+                        .downcast_ref::<#errors_type>()
+                        .map(alkanes_runtime::revert::AlkaneError::revert_data)
+                }
+            },
+            quote! {
+                format!(", \"errors\": {}", <#errors_type as alkanes_runtime::revert::AlkaneError>::abi())
+            },
+        ),
+        None => (quote! {}, quote! { String::new() }),
+    };
+
     let expanded = quote! {
         impl alkanes_runtime::message::MessageDispatch<#concrete_type_name> for #name {
             fn from_opcode(opcode: u128, __macro_inputs: Vec<u128>) -> Result<Self, anyhow::Error> {
@@ -497,15 +704,488 @@ pub fn derive_message_dispatch(input: TokenStream) -> TokenStream {
             fn export_abi() -> Vec<u8> {
                 // Generate a JSON representation of the ABI with methods
                 let abi_string = format!(
-                    "{{ \"contract\": \"{}\", \"methods\": [{}] }}",
+                    "{{ \"contract\": \"{}\", \"methods\": [{}]{} }}",
                     #concrete_type_name_string,
-                    #method_json_str
+                    #method_json_str,
+                    #errors_json
                 );
 
                 abi_string.into_bytes()
             }
+
+            #revert_data
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Derives `alkanes_runtime::storage::StorageCodec` for a struct whose fields
+/// all implement it, so the struct can be stored as a composite key or value.
+/// Fields are written in declaration order, those without a fixed width behind
+/// their length.
+#[proc_macro_derive(StorageCodec)]
+pub fn derive_storage_codec(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let name_string = name.to_string();
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => panic!("StorageCodec can only be derived for structs"),
+    };
+
+    let (encode_fields, init) = match fields {
+        Fields::Named(fields_named) => {
+            let field_names: Vec<_> = fields_named
+                .named
+                .iter()
+                .map(|field| field.ident.as_ref().unwrap())
+                .collect();
+            (
+                quote! {
+                    #(alkanes_runtime::storage::StorageCodec::encode_field(&self.#field_names, &mut out);)*
+                },
+                quote! {
+                    Self {
+                        #(#field_names: alkanes_runtime::storage::StorageCodec::decode_field(&mut cursor)?),*
+                    }
+                },
+            )
+        }
+        Fields::Unnamed(fields_unnamed) => {
+            let indices: Vec<_> = (0..fields_unnamed.unnamed.len())
+                .map(syn::Index::from)
+                .collect();
+            let decodes = indices.iter().map(|_| {
+                quote! { alkanes_runtime::storage::StorageCodec::decode_field(&mut cursor)? }
+            });
+            (
+                quote! {
+                    #(alkanes_runtime::storage::StorageCodec::encode_field(&self.#indices, &mut out);)*
+                },
+                quote! {
+                    Self(#(#decodes),*)
+                },
+            )
+        }
+        Fields::Unit => panic!("StorageCodec can only be derived for structs with fields"),
+    };
+
+    let expanded = quote! {
+        impl alkanes_runtime::storage::StorageCodec for #name {
+            fn encode(&self) -> Vec<u8> {
+                let mut out = Vec::new();
+                #encode_fields
+                out
+            }
+
+            fn decode(bytes: &[u8]) -> Result<Self, alkanes_runtime::anyhow::Error> {
+                let mut cursor = alkanes_runtime::storage::Cursor::new(bytes.to_vec());
+                let result = #init;
+                if (cursor.position() as usize) < bytes.len() {
+                    return Err(alkanes_runtime::anyhow::anyhow!("trailing bytes after {}", #name_string));
+                }
+                Ok(result)
+            }
         }
     };
 
     TokenStream::from(expanded)
 }
+
+/// Parses the type named by a variant's #[returns(Type)] attribute
+fn extract_returns_type(attrs: &[Attribute]) -> Option<Type> {
+    attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("returns"))
+        .map(|attr| {
+            attr.parse_args()
+                .expect("Invalid #[returns(Type)] attribute")
+        })
+}
+
+/// Generate code appending `value`, a reference to a `ty`, to `__inputs` the
+/// way the MessageDispatch derive reads it back
+fn generate_element_encoding(
+    ty: &Type,
+    value: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let element_name = format_ident!("element");
+    if is_u128_type(ty) {
+        quote! { __inputs.push(*#value); }
+    } else if is_bool_type(ty) || is_small_uint_type(ty) {
+        quote! { __inputs.push(*#value as u128); }
+    } else if is_alkane_id_type(ty) {
+        quote! {
+            __inputs.push(#value.block);
+            __inputs.push(#value.tx);
+        }
+    } else if is_string_type(ty) {
+        quote! {
+            let mut __bytes = #value.as_bytes().to_vec();
+            __bytes.push(0);
+            __inputs.extend(alkanes_support::codec::pack_words(&__bytes));
+        }
+    } else if get_byte_array_len(ty).is_some() {
+        quote! { __inputs.extend(alkanes_support::codec::pack_words(#value)); }
+    } else if let Some(inner_type) = get_vec_inner_type(ty) {
+        let element_encoding = generate_element_encoding(inner_type, quote! { #element_name });
+        quote! {
+            __inputs.push(#value.len() as u128);
+            for #element_name in #value.iter() {
+                #element_encoding
+            }
+        }
+    } else if let Some(inner_type) = get_option_inner_type(ty) {
+        let element_encoding = generate_element_encoding(inner_type, quote! { #element_name });
+        quote! {
+            match #value {
+                Some(#element_name) => {
+                    __inputs.push(1);
+                    #element_encoding
+                }
+                None => __inputs.push(0),
+            }
+        }
+    } else if let Type::Path(_) = ty {
+        quote! {
+            let __bytes = alkanes_support::codec::StorageCodec::encode(#value);
+            __inputs.push(__bytes.len() as u128);
+            __inputs.extend(alkanes_support::codec::pack_words(&__bytes));
+        }
+    } else {
+        panic!("Unsupported type. Only integers, bool, String, AlkaneId, byte arrays, Vec, Option and StorageCodec structs are supported.");
+    }
+}
+
+/// Derives a typed client for the alkane a message enum describes, named after
+/// the enum with "Message" replaced by "Client". It can be derived next to
+/// MessageDispatch or on a copy of another contract's enum, which only needs
+/// the variants to be called.
+///
+/// For each variant the client gets a `<method>_cellpack` function building
+/// the cellpack and a `<method>` function making the call from another alkane.
+/// Variants marked `#[view]` are sent as a staticcall. A `#[returns(Type)]`
+/// variant decodes the returned data with its StorageCodec; any other returns
+/// the CallResponse.
+///
+/// The cellpack functions are also generated on their own, on a struct named
+/// with "Cellpacks" in place of "Client" that needs only alkanes-support. A
+/// wallet copying the enum can mark it `#[cellpacks_only]` to get just that
+/// struct and build calldata without the runtime or the contract.
+#[proc_macro_derive(MessageClient, attributes(opcode, returns, view, cellpacks_only))]
+pub fn derive_message_client(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let vis = &input.vis;
+    let name_string = input.ident.to_string();
+    let base_name = name_string.trim_end_matches("Message");
+    let client_name = format_ident!("{}Client", base_name);
+    let cellpacks_name = format_ident!("{}Cellpacks", base_name);
+    let cellpacks_only = input
+        .attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("cellpacks_only"));
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => panic!("MessageClient can only be derived for enums"),
+    };
+
+    let (builders, methods): (Vec<_>, Vec<_>) = variants.iter().map(|variant| {
+        let method_name = format_ident!("{}", variant_to_method_name(&variant.ident));
+        let cellpack_name = format_ident!("{}_cellpack", method_name);
+        let opcode = extract_opcode_attr(&variant.attrs);
+        let view = variant.attrs.iter().any(|attr| attr.path.is_ident("view"));
+
+        let fields: Vec<(&Ident, &Type)> = match &variant.fields {
+            Fields::Named(fields_named) => fields_named
+                .named
+                .iter()
+                .map(|field| (field.ident.as_ref().unwrap(), &field.ty))
+                .collect(),
+            Fields::Unnamed(_) => panic!(
+                "Tuple variants are not supported for MessageClient. Use named fields (struct variants) instead for variant {}",
+                variant.ident
+            ),
+            Fields::Unit => Vec::new(),
+        };
+        for (field_name, _) in fields.iter() {
+            if *field_name == "responder" {
+                panic!("Field name 'responder' is reserved and cannot be used");
+            }
+        }
+        let params: Vec<_> = fields
+            .iter()
+            .map(|(field_name, ty)| quote! { #field_name: #ty })
+            .collect();
+        let field_names: Vec<_> = fields.iter().map(|(field_name, _)| field_name).collect();
+        let encodings = fields.iter().map(|(field_name, ty)| {
+            let encoding = generate_element_encoding(ty, quote! { __value });
+            quote! {
+                {
+                    let __value = &#field_name;
+                    #encoding
+                }
+            }
+        });
+
+        let call = if view {
+            quote! { staticcall }
+        } else {
+            quote! { call }
+        };
+        let (returns, decode) = match extract_returns_type(&variant.attrs) {
+            Some(ty) => (
+                quote! { #ty },
+                quote! { <#ty as alkanes_runtime::storage::StorageCodec>::decode(&__response.data) },
+            ),
+            None => (
+                quote! { alkanes_support::response::CallResponse },
+                quote! { Ok(__response) },
+            ),
+        };
+
+        let builder = quote! {
+            pub fn #cellpack_name(&self, #(#params),*) -> alkanes_support::cellpack::Cellpack {
+                let mut __inputs: Vec<u128> = vec![#opcode];
+                #(#encodings)*
+                alkanes_support::cellpack::Cellpack {
+                    target: self.id.clone(),
+                    inputs: __inputs,
+                }
+            }
+        };
+        let method = quote! {
+            pub fn #cellpack_name(&self, #(#params),*) -> alkanes_support::cellpack::Cellpack {
+                #cellpacks_name::new(self.id.clone()).#cellpack_name(#(#field_names),*)
+            }
+
+            pub fn #method_name<R: alkanes_runtime::runtime::AlkaneResponder>(
+                &self,
+                responder: &R,
+                #(#params),*
+            ) -> Result<#returns, alkanes_runtime::anyhow::Error> {
+                let __cellpack = self.#cellpack_name(#(#field_names),*);
+                let __fuel = self.fuel.unwrap_or_else(|| responder.fuel());
+                let __response = responder.#call(&__cellpack, &self.alkanes, __fuel)?;
+                #decode
+            }
+        };
+        (builder, method)
+    }).unzip();
+
+    let cellpacks = quote! {
+        #[allow(dead_code)]
+        #[derive(Clone, Debug, Default)]
+        #vis struct #cellpacks_name {
+            pub id: alkanes_support::id::AlkaneId,
+        }
+
+        #[allow(dead_code)]
+        impl #cellpacks_name {
+            pub fn new(id: alkanes_support::id::AlkaneId) -> Self {
+                Self { id }
+            }
+
+            #(#builders)*
+        }
+    };
+    if cellpacks_only {
+        return TokenStream::from(cellpacks);
+    }
+
+    let expanded = quote! {
+        #cellpacks
+
+        #[allow(dead_code)]
+        #[derive(Clone, Debug, Default)]
+        #vis struct #client_name {
+            pub id: alkanes_support::id::AlkaneId,
+            /// Sent along with every call
+            pub alkanes: alkanes_support::parcel::AlkaneTransferParcel,
+            /// The fuel each call may use, all that is left if unset
+            pub fuel: Option<u64>,
+        }
+
+        #[allow(dead_code)]
+        impl #client_name {
+            pub fn new(id: alkanes_support::id::AlkaneId) -> Self {
+                Self {
+                    id,
+                    ..Default::default()
+                }
+            }
+
+            pub fn with_alkanes(mut self, alkanes: alkanes_support::parcel::AlkaneTransferParcel) -> Self {
+                self.alkanes = alkanes;
+                self
+            }
+
+            pub fn with_fuel(mut self, fuel: u64) -> Self {
+                self.fuel = Some(fuel);
+                self
+            }
+
+            #(#methods)*
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Extracts the code attribute from an error variant's attributes
+fn extract_code_attr(attrs: &[Attribute]) -> u32 {
+    for attr in attrs {
+        if attr.path.is_ident("code") {
+            if let Ok(Meta::List(meta_list)) = attr.parse_meta() {
+                if let Some(NestedMeta::Lit(Lit::Int(lit_int))) = meta_list.nested.first() {
+                    if let Ok(value) = lit_int.base10_parse::<u32>() {
+                        return value;
+                    }
+                }
+            }
+        }
+    }
+    panic!("Missing or invalid #[code(n)] attribute");
+}
+
+/// Parses the error enum named by the message enum's #[errors(Type)] attribute
+fn extract_errors_type(attrs: &[Attribute]) -> Option<Type> {
+    attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("errors"))
+        .map(|attr| {
+            attr.parse_args()
+                .expect("Invalid #[errors(Type)] attribute")
+        })
+}
+
+/// Derives `alkanes_runtime::revert::AlkaneError` for an enum whose variants
+/// each carry a `#[code(n)]`, along with `Display` and `std::error::Error` so
+/// methods can return the errors with `?`. Fields are written as
+/// `StorageCodec::encode_field` writes them. The enum must also derive `Debug`.
+#[proc_macro_derive(AlkaneError, attributes(code))]
+pub fn derive_alkane_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let name_string = name.to_string();
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => panic!("AlkaneError can only be derived for enums"),
+    };
+
+    let mut codes = std::collections::HashSet::new();
+    let mut code_arms = Vec::new();
+    let mut encode_arms = Vec::new();
+    let mut decode_arms = Vec::new();
+    let mut display_arms = Vec::new();
+    let mut error_json_entries = Vec::new();
+
+    for variant in variants.iter() {
+        let variant_name = &variant.ident;
+        let variant_string = variant_name.to_string();
+        let code = extract_code_attr(&variant.attrs);
+        if code == 0x08c379a0 {
+            panic!(
+                "Error code {} of {} is the string revert selector",
+                code, variant_name
+            );
+        }
+        if !codes.insert(code) {
+            panic!("Duplicate error code {} on {}", code, variant_name);
+        }
+
+        let fields: Vec<_> = match &variant.fields {
+            Fields::Named(fields_named) => fields_named.named.iter().collect(),
+            Fields::Unnamed(_) => {
+                panic!("Tuple variants are not supported for AlkaneError. Use named fields (struct variants) instead for variant {}", variant_name);
+            }
+            Fields::Unit => Vec::new(),
+        };
+        let field_names: Vec<_> = fields
+            .iter()
+            .map(|field| field.ident.as_ref().unwrap())
+            .collect();
+
+        code_arms.push(quote! { Self::#variant_name { .. } => #code });
+        encode_arms.push(quote! {
+            Self::#variant_name { #(#field_names),* } => {
+                #(alkanes_runtime::storage::StorageCodec::encode_field(#field_names, &mut out);)*
+            }
+        });
+        decode_arms.push(quote! {
+            #code => Self::#variant_name {
+                #(#field_names: alkanes_runtime::storage::StorageCodec::decode_field(&mut cursor)?),*
+            }
+        });
+        display_arms
+            .push(quote! { Self::#variant_name { .. } => write!(f, "{}", #variant_string) });
+
+        let params_json = fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "{{ \"type\": \"{}\", \"name\": \"{}\" }}",
+                    get_type_string(&field.ty),
+                    field.ident.as_ref().unwrap()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        error_json_entries.push(format!(
+            "{{ \"name\": \"{}\", \"code\": {}, \"params\": [{}] }}",
+            variant_string, code, params_json
+        ));
+    }
+
+    let errors_json = format!("[{}]", error_json_entries.join(", "));
+
+    let expanded = quote! {
+        impl alkanes_runtime::revert::AlkaneError for #name {
+            fn code(&self) -> u32 {
+                match self {
+                    #(#code_arms),*
+                }
+            }
+
+            fn encode_fields(&self) -> Vec<u8> {
+                let mut out = Vec::new();
+                match self {
+                    #(#encode_arms),*
+                }
+                out
+            }
+
+            fn decode_fields(code: u32, fields: &[u8]) -> Result<Self, alkanes_runtime::anyhow::Error> {
+                #[allow(unused_mut)]
+                let mut cursor = alkanes_runtime::storage::Cursor::new(fields.to_vec());
+                let result = match code {
+                    #(#decode_arms,)*
+                    _ => return Err(alkanes_runtime::anyhow::anyhow!("Unknown {} code: {}", #name_string, code)),
+                };
+                if (cursor.position() as usize) < fields.len() {
+                    return Err(alkanes_runtime::anyhow::anyhow!("trailing bytes after {}", #name_string));
+                }
+                Ok(result)
+            }
+
+            fn abi() -> String {
+                #errors_json.to_string()
+            }
+        }
+
+        impl std::fmt::Display for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    #(#display_arms),*
+                }
+            }
+        }
+
+        impl std::error::Error for #name {}
+    };
+
+    TokenStream::from(expanded)
+}
@@ -3,13 +3,19 @@ use alkanes_support::context::Context;
 #[cfg(feature = "test-utils")]
 use wasm_bindgen::prelude::*;
 
-#[cfg(not(feature = "test-utils"))]
+#[cfg(all(feature = "test-utils", feature = "test"))]
+compile_error!("the test-utils and test features both replace the host imports");
+
+#[cfg(not(any(feature = "test-utils", feature = "test")))]
 #[link(wasm_import_module = "env")]
 extern "C" {
     pub fn abort(a: i32, b: i32, c: i32, d: i32);
     pub fn __load_storage(k: i32, v: i32) -> i32;
     pub fn __request_storage(k: i32) -> i32;
+    pub fn __load_foreign_storage(who: i32, k: i32, v: i32) -> i32;
+    pub fn __request_foreign_storage(who: i32, k: i32) -> i32;
     pub fn __log(v: i32);
+    pub fn __emit(event: i32);
     pub fn __balance(who: i32, what: i32, output: i32);
     pub fn __request_context() -> i32;
     pub fn __load_context(output: i32) -> i32;
@@ -21,6 +27,9 @@ extern "C" {
     pub fn __load_transaction(output: i32);
     pub fn __request_block() -> i32;
     pub fn __load_block(output: i32);
+    pub fn __load_block_header(height: u64, output: i32);
+    pub fn __load_median_time_past(height: u64, output: i32);
+    pub fn __load_blockhash(height: u64, output: i32);
     pub fn __call(cellpack: i32, incoming_alkanes: i32, checkpoint: i32, start_fuel: u64) -> i32;
     pub fn __staticcall(
         cellpack: i32,
@@ -36,6 +45,9 @@ extern "C" {
     ) -> i32;
 }
 
+#[cfg(feature = "test")]
+pub use crate::mock::imports::*;
+
 #[cfg(feature = "test-utils")]
 pub mod externs {
     pub use wasm_bindgen::prelude::*;
@@ -70,9 +82,16 @@ mod exports {
     pub fn __request_storage(k: i32) -> i32 {
         0
     }
+    pub fn __load_foreign_storage(who: i32, k: i32, v: i32) -> i32 {
+        v
+    }
+    pub fn __request_foreign_storage(who: i32, k: i32) -> i32 {
+        0
+    }
     pub fn __log(ptr: i32) -> () {
         externs::write(format!("{}", String::from_utf8(ptr_to_vec(ptr)).unwrap()).as_str());
     }
+    pub fn __emit(event: i32) {}
     pub fn __balance(who: i32, what: i32, output: i32) -> i32 {
         0
     }
@@ -110,6 +129,9 @@ mod exports {
         0
     }
     pub fn __load_block(output: i32) {}
+    pub fn __load_block_header(height: u64, output: i32) {}
+    pub fn __load_median_time_past(height: u64, output: i32) {}
+    pub fn __load_blockhash(height: u64, output: i32) {}
     pub fn __call(cellpack: i32, incoming_alkanes: i32, checkpoint: i32, start_fuel: u64) -> i32 {
         0
     }
@@ -3,11 +3,18 @@ pub mod auth;
 pub mod compat;
 pub mod imports;
 pub mod message;
+pub mod meta;
+#[cfg(feature = "test")]
+pub mod mock;
+pub mod revert;
 pub mod runtime;
 pub mod stdio;
 pub mod storage;
 pub mod token;
 pub use crate::stdio::stdout;
+// the derives in alkanes-macros expand to `alkanes_runtime::anyhow` paths, so
+// contracts using them need no anyhow dependency of their own
+pub use anyhow;
 
 #[macro_export]
 macro_rules! declare_alkane {
@@ -39,8 +46,10 @@ macro_rules! declare_alkane {
             let extended = match result {
                 Ok(res) => handle_success(res),
                 Err(err) => {
-                    let error_msg = format!("Error: {}", err);
-                    let extended = handle_error(&error_msg);
+                    let extended = match $message_type::revert_data(&err) {
+                        Some(data) => alkanes_runtime::runtime::handle_revert(data),
+                        None => handle_error(&format!("Error: {}", err)),
+                    };
                     return alkanes_runtime::runtime::response_to_i32(extended);
                 }
             };
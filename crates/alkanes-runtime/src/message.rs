@@ -1,8 +1,10 @@
+use crate::runtime::AlkaneResponder;
+use crate::storage::StorageCodec;
 use alkanes_support::response::CallResponse;
 use anyhow::Result;
 
-// Re-export the MessageDispatch derive macro
-pub use alkanes_macros::MessageDispatch;
+// Re-export the MessageDispatch and MessageClient derive macros
+pub use alkanes_macros::{MessageClient, MessageDispatch};
 
 /// Trait for dispatching messages based on opcodes
 pub trait MessageDispatch<T>: Sized {
@@ -14,4 +16,43 @@ pub trait MessageDispatch<T>: Sized {
 
     /// Export ABI metadata for the message enum
     fn export_abi() -> Vec<u8>;
+
+    /// The revert data for `error` when it is one of the errors named by
+    /// `#[errors(..)]`, which the alkane then reverts with in place of a message
+    fn revert_data(_error: &anyhow::Error) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+/// What a method dispatched to may return. A `CallResponse` is sent back as
+/// the method built it. Any other value forwards the incoming alkanes and is
+/// written to `data` with its `StorageCodec`, which is the encoding the ABI
+/// names for the `#[returns]` type.
+pub trait MessageReturn {
+    fn into_response<R: AlkaneResponder>(self, responder: &R) -> Result<CallResponse>;
+}
+
+impl MessageReturn for CallResponse {
+    fn into_response<R: AlkaneResponder>(self, _responder: &R) -> Result<CallResponse> {
+        Ok(self)
+    }
 }
+
+impl MessageReturn for () {
+    fn into_response<R: AlkaneResponder>(self, responder: &R) -> Result<CallResponse> {
+        Ok(CallResponse::forward(
+            &responder.context()?.incoming_alkanes,
+        ))
+    }
+}
+
+impl<T: StorageCodec> MessageReturn for T {
+    fn into_response<R: AlkaneResponder>(self, responder: &R) -> Result<CallResponse> {
+        let mut response = CallResponse::forward(&responder.context()?.incoming_alkanes);
+        response.data = self.encode();
+        Ok(response)
+    }
+}
+
+// kept under its old name for contracts packing inputs by hand
+pub use alkanes_support::codec::pack_words as pack_bytes;
@@ -0,0 +1,411 @@
+//! An in-process stand-in for the indexer, so contract code can run natively
+//! under `cargo test` with the `test` feature. `imports` takes the place of the
+//! wasm externs and answers from the `MockHost` installed on the test.
+//!
+//! Pointers handed to the host are truncated to `i32` on a 64 bit target, so
+//! under this feature the runtime passes handles from `to_ptr` instead and the
+//! host resolves them back to the buffers they name.
+use crate::message::MessageDispatch;
+use crate::revert::{Revert, ERROR_STRING_SELECTOR};
+use crate::runtime::{clear_cache, get_cache, handle_success, AlkaneResponder};
+use alkanes_support::{
+    cellpack::Cellpack,
+    context::Context,
+    event::AlkaneEvent,
+    id::AlkaneId,
+    parcel::AlkaneTransferParcel,
+    response::{CallResponse, ExtendedCallResponse},
+    storage::StorageMap,
+};
+use anyhow::{anyhow, Result};
+use bitcoin::block::Header;
+use bitcoin::consensus::serialize;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Mutex, MutexGuard};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallKind {
+    Call,
+    Delegatecall,
+    Staticcall,
+}
+
+/// An extcall the contract made, as the host received it.
+#[derive(Clone, Debug)]
+pub struct MockCall {
+    pub kind: CallKind,
+    pub cellpack: Cellpack,
+    pub incoming_alkanes: AlkaneTransferParcel,
+    /// The storage the contract had written when it made the call.
+    pub storage: StorageMap,
+    pub fuel: u64,
+}
+
+type CallHandler = Box<dyn FnMut(&MockCall) -> Result<CallResponse> + Send>;
+
+/// The chain state a contract under test sees. `storage` is the storage of
+/// `context.myself` before the call; what the contract writes stays in the
+/// runtime cache until `commit`.
+#[derive(Default)]
+pub struct MockHost {
+    pub context: Context,
+    pub storage: HashMap<Vec<u8>, Vec<u8>>,
+    pub foreign_storage: HashMap<AlkaneId, HashMap<Vec<u8>, Vec<u8>>>,
+    /// Balances by holder, then alkane.
+    pub balances: HashMap<(AlkaneId, AlkaneId), u128>,
+    pub block: Vec<u8>,
+    pub transaction: Vec<u8>,
+    pub headers: HashMap<u64, Header>,
+    pub height: u64,
+    pub sequence: u128,
+    pub fuel: u64,
+    /// Filled by the host as the contract runs.
+    pub calls: Vec<MockCall>,
+    pub events: Vec<AlkaneEvent>,
+    pub logs: String,
+    handler: Option<CallHandler>,
+    returndata: Vec<u8>,
+}
+
+static LOCK: Mutex<()> = Mutex::new(());
+static HOST: Mutex<Option<MockHost>> = Mutex::new(None);
+static POINTERS: Mutex<Vec<usize>> = Mutex::new(vec![]);
+
+/// Keeps a `MockHost` installed. Tests installing a host run one at a time,
+/// since the runtime keeps the storage a contract writes in a global.
+pub struct MockGuard {
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Drop for MockGuard {
+    fn drop(&mut self) {
+        *HOST.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        POINTERS.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        clear_cache();
+    }
+}
+
+impl MockHost {
+    /// Answers extcalls with `handler`. Without one every extcall reverts. A
+    /// handler failing with a `Revert` reverts with its data as a typed error.
+    pub fn on_call<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(&MockCall) -> Result<CallResponse> + Send + 'static,
+    {
+        self.handler = Some(Box::new(handler));
+        self
+    }
+    pub fn install(self) -> MockGuard {
+        let lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        clear_cache();
+        *HOST.lock().unwrap_or_else(|e| e.into_inner()) = Some(self);
+        MockGuard { _lock: lock }
+    }
+}
+
+pub fn with_host<R>(f: impl FnOnce(&mut MockHost) -> R) -> R {
+    f(HOST
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_mut()
+        .expect("no MockHost installed"))
+}
+
+/// The unwind `imports::abort` starts, standing in for the trap that ends a
+/// wasm call.
+struct Aborted;
+
+/// Runs the message the inputs of the host context encode against `T`, the
+/// way `declare_alkane!` does, and returns the response with the storage the
+/// contract wrote. A call that aborts or panics reverts, as it would on chain,
+/// and like any revert leaves nothing written.
+pub fn execute<T, M>() -> Result<ExtendedCallResponse>
+where
+    T: AlkaneResponder + Default,
+    M: MessageDispatch<T>,
+{
+    let inputs = with_host(|host| host.context.inputs.clone());
+    let (opcode, inputs) = inputs
+        .split_first()
+        .ok_or_else(|| anyhow!("No opcode provided"))?;
+    let response = panic::catch_unwind(AssertUnwindSafe(|| {
+        M::from_opcode(*opcode, inputs.to_vec())?.dispatch(&T::default())
+    }))
+    .unwrap_or_else(|payload| {
+        let reason = if payload.is::<Aborted>() {
+            "aborted".to_string()
+        } else if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "panicked".to_string()
+        };
+        Err(anyhow!("ALKANES: revert: {}", reason))
+    })
+    .inspect_err(|_| clear_cache())?;
+    Ok(handle_success(response))
+}
+
+/// Moves the storage the contract wrote into the host, as the indexer does
+/// once a call returns, so the next call starts from it.
+pub fn commit() {
+    let cache = get_cache();
+    with_host(|host| host.storage.extend(cache.0.into_iter()));
+    clear_cache();
+}
+
+pub fn to_ptr(v: &mut Vec<u8>) -> i32 {
+    let mut pointers = POINTERS.lock().unwrap_or_else(|e| e.into_inner());
+    pointers.push(v.as_mut_ptr() as usize);
+    // the low bits carry the offset callers add to reach the data
+    (pointers.len() << 4) as i32
+}
+
+pub fn to_passback_ptr(v: &mut Vec<u8>) -> i32 {
+    to_ptr(v) + 4
+}
+
+fn resolve(ptr: i32) -> *mut u8 {
+    let pointers = POINTERS.lock().unwrap_or_else(|e| e.into_inner());
+    let base = pointers[((ptr >> 4) - 1) as usize];
+    (base + (ptr & 0xf) as usize) as *mut u8
+}
+
+unsafe fn arraybuffer<'a>(ptr: i32) -> &'a mut [u8] {
+    let data = resolve(ptr);
+    let mut length = [0u8; 4];
+    length.copy_from_slice(std::slice::from_raw_parts(data.sub(4), 4));
+    std::slice::from_raw_parts_mut(data, u32::from_le_bytes(length) as usize)
+}
+
+unsafe fn read_arraybuffer(ptr: i32) -> Vec<u8> {
+    arraybuffer(ptr).to_vec()
+}
+
+unsafe fn send_to_arraybuffer(ptr: i32, v: &[u8]) -> i32 {
+    let buffer = arraybuffer(ptr);
+    let length = std::cmp::min(buffer.len(), v.len());
+    buffer[..length].copy_from_slice(&v[..length]);
+    ptr
+}
+
+unsafe fn read_id(ptr: i32) -> AlkaneId {
+    AlkaneId::parse(&mut Cursor::new(read_arraybuffer(ptr))).unwrap()
+}
+
+fn serialize_context(context: &Context) -> Vec<u8> {
+    let mut result: Vec<u8> = context.myself.clone().into();
+    result.extend(Into::<Vec<u8>>::into(context.caller.clone()));
+    result.extend((context.vout as u128).to_le_bytes());
+    result.extend(context.incoming_alkanes.serialize());
+    for input in &context.inputs {
+        result.extend(input.to_le_bytes());
+    }
+    result
+}
+
+fn storage_of(host: &MockHost, who: &AlkaneId, key: &Vec<u8>) -> Vec<u8> {
+    // an alkane reading itself this way sees its storage from before the call
+    let storage = if who == &host.context.myself {
+        Some(&host.storage)
+    } else {
+        host.foreign_storage.get(who)
+    };
+    storage
+        .and_then(|v| v.get(key))
+        .cloned()
+        .unwrap_or_default()
+}
+
+unsafe fn extcall(
+    kind: CallKind,
+    cellpack: i32,
+    incoming_alkanes: i32,
+    checkpoint: i32,
+    fuel: u64,
+) -> i32 {
+    let call = MockCall {
+        kind,
+        cellpack: Cellpack::parse(&mut Cursor::new(read_arraybuffer(cellpack))).unwrap(),
+        incoming_alkanes: AlkaneTransferParcel::parse(&mut Cursor::new(read_arraybuffer(
+            incoming_alkanes,
+        )))
+        .unwrap(),
+        storage: StorageMap::parse(&mut Cursor::new(read_arraybuffer(checkpoint))).unwrap(),
+        fuel,
+    };
+    // the handler runs without the host locked, so it may use `with_host`
+    let mut handler = with_host(|host| host.handler.take());
+    let result = match handler.as_mut() {
+        Some(handler) => handler(&call),
+        None => Err(anyhow!(
+            "no extcall stub for {}:{}",
+            call.cellpack.target.block,
+            call.cellpack.target.tx
+        )),
+    };
+    with_host(|host| {
+        host.handler = handler;
+        host.calls.push(call);
+        match result {
+            Ok(response) => {
+                host.returndata = response.serialize();
+                host.returndata.len() as i32
+            }
+            Err(e) => {
+                let mut response = CallResponse::default();
+                response.data = match e.downcast_ref::<Revert>() {
+                    Some(revert) => revert.data.clone(),
+                    None => {
+                        let mut data = ERROR_STRING_SELECTOR.to_vec();
+                        data.extend(e.to_string().as_bytes());
+                        data
+                    }
+                };
+                host.returndata = response.serialize();
+                -(host.returndata.len() as i32)
+            }
+        }
+    })
+}
+
+/// The host functions of `imports.rs`, answered from the installed `MockHost`.
+#[allow(unused_variables)]
+pub mod imports {
+    use super::*;
+
+    pub unsafe fn abort(a: i32, b: i32, c: i32, d: i32) {
+        // unwinds without the panic hook, so an expected revert prints nothing
+        panic::resume_unwind(Box::new(Aborted));
+    }
+    pub unsafe fn __load_storage(k: i32, v: i32) -> i32 {
+        let key = read_arraybuffer(k);
+        let value = with_host(|host| host.storage.get(&key).cloned().unwrap_or_default());
+        send_to_arraybuffer(v, &value)
+    }
+    pub unsafe fn __request_storage(k: i32) -> i32 {
+        let key = read_arraybuffer(k);
+        with_host(|host| host.storage.get(&key).map(|v| v.len()).unwrap_or(0) as i32)
+    }
+    pub unsafe fn __load_foreign_storage(who: i32, k: i32, v: i32) -> i32 {
+        let (who, key) = (read_id(who), read_arraybuffer(k));
+        let value = with_host(|host| storage_of(host, &who, &key));
+        send_to_arraybuffer(v, &value)
+    }
+    pub unsafe fn __request_foreign_storage(who: i32, k: i32) -> i32 {
+        let (who, key) = (read_id(who), read_arraybuffer(k));
+        with_host(|host| storage_of(host, &who, &key).len() as i32)
+    }
+    pub unsafe fn __log(v: i32) {
+        let message = String::from_utf8_lossy(&read_arraybuffer(v)).to_string();
+        print!("{}", message);
+        with_host(|host| host.logs.push_str(&message));
+    }
+    pub unsafe fn __emit(event: i32) {
+        let event = AlkaneEvent::parse(&mut Cursor::new(read_arraybuffer(event))).unwrap();
+        with_host(|host| host.events.push(event));
+    }
+    pub unsafe fn __balance(who: i32, what: i32, output: i32) {
+        let key = (read_id(who), read_id(what));
+        let balance = with_host(|host| host.balances.get(&key).cloned().unwrap_or(0));
+        send_to_arraybuffer(output, &balance.to_le_bytes());
+    }
+    pub unsafe fn __request_context() -> i32 {
+        with_host(|host| serialize_context(&host.context).len() as i32)
+    }
+    pub unsafe fn __load_context(output: i32) -> i32 {
+        let context = with_host(|host| serialize_context(&host.context));
+        send_to_arraybuffer(output, &context)
+    }
+    pub unsafe fn __sequence(output: i32) {
+        send_to_arraybuffer(output, &with_host(|host| host.sequence).to_le_bytes());
+    }
+    pub unsafe fn __fuel(output: i32) {
+        send_to_arraybuffer(output, &with_host(|host| host.fuel).to_le_bytes());
+    }
+    pub unsafe fn __height(output: i32) {
+        send_to_arraybuffer(output, &with_host(|host| host.height).to_le_bytes());
+    }
+    pub unsafe fn __returndatacopy(output: i32) {
+        send_to_arraybuffer(output, &with_host(|host| host.returndata.clone()));
+    }
+    pub unsafe fn __request_transaction() -> i32 {
+        with_host(|host| host.transaction.len() as i32)
+    }
+    pub unsafe fn __load_transaction(output: i32) {
+        send_to_arraybuffer(output, &with_host(|host| host.transaction.clone()));
+    }
+    pub unsafe fn __request_block() -> i32 {
+        with_host(|host| host.block.len() as i32)
+    }
+    pub unsafe fn __load_block(output: i32) {
+        send_to_arraybuffer(output, &with_host(|host| host.block.clone()));
+    }
+    pub unsafe fn __load_block_header(height: u64, output: i32) {
+        let header = with_host(|host| host.headers.get(&height).cloned())
+            .unwrap_or_else(|| panic!("no header at height {} in the MockHost", height));
+        send_to_arraybuffer(output, &serialize(&header));
+    }
+    pub unsafe fn __load_median_time_past(height: u64, output: i32) {
+        let mut times = with_host(|host| {
+            (height.saturating_sub(10)..=height)
+                .filter_map(|h| host.headers.get(&h).map(|header| header.time))
+                .collect::<Vec<u32>>()
+        });
+        if times.is_empty() {
+            panic!("no header at height {} in the MockHost", height);
+        }
+        times.sort_unstable();
+        send_to_arraybuffer(output, &times[times.len() / 2].to_le_bytes());
+    }
+    pub unsafe fn __load_blockhash(height: u64, output: i32) {
+        let header = with_host(|host| host.headers.get(&height).cloned())
+            .unwrap_or_else(|| panic!("no header at height {} in the MockHost", height));
+        send_to_arraybuffer(output, &serialize(&header.block_hash()));
+    }
+    pub unsafe fn __call(
+        cellpack: i32,
+        incoming_alkanes: i32,
+        checkpoint: i32,
+        start_fuel: u64,
+    ) -> i32 {
+        extcall(
+            CallKind::Call,
+            cellpack,
+            incoming_alkanes,
+            checkpoint,
+            start_fuel,
+        )
+    }
+    pub unsafe fn __staticcall(
+        cellpack: i32,
+        incoming_alkanes: i32,
+        checkpoint: i32,
+        start_fuel: u64,
+    ) -> i32 {
+        extcall(
+            CallKind::Staticcall,
+            cellpack,
+            incoming_alkanes,
+            checkpoint,
+            start_fuel,
+        )
+    }
+    pub unsafe fn __delegatecall(
+        cellpack: i32,
+        incoming_alkanes: i32,
+        checkpoint: i32,
+        start_fuel: u64,
+    ) -> i32 {
+        extcall(
+            CallKind::Delegatecall,
+            cellpack,
+            incoming_alkanes,
+            checkpoint,
+            start_fuel,
+        )
+    }
+}
@@ -0,0 +1,57 @@
+//! Typed revert errors. Revert data starts with a four byte selector: the
+//! string selector for a message, as `handle_error` writes it, or otherwise the
+//! big endian code of an error declared with `#[derive(AlkaneError)]`, followed
+//! by its fields as `StorageCodec::encode_field` writes them.
+pub use alkanes_macros::AlkaneError;
+pub use alkanes_support::abi::ERROR_STRING_SELECTOR;
+use anyhow::Result;
+use std::fmt;
+
+/// Implemented by `#[derive(AlkaneError)]` for an enum whose variants each
+/// carry a `#[code(n)]`. Naming the enum in `#[errors(..)]` on the message enum
+/// reverts with it when a method returns one, and lists it in the ABI.
+pub trait AlkaneError: Sized {
+    fn code(&self) -> u32;
+    fn encode_fields(&self) -> Vec<u8>;
+    fn decode_fields(code: u32, fields: &[u8]) -> Result<Self>;
+    /// The ABI JSON of the errors, as a list.
+    fn abi() -> String;
+
+    fn revert_data(&self) -> Vec<u8> {
+        let mut data = self.code().to_be_bytes().to_vec();
+        data.extend(self.encode_fields());
+        data
+    }
+    fn from_revert_data(data: &[u8]) -> Option<Self> {
+        if data.len() < 4 || data[0..4] == ERROR_STRING_SELECTOR {
+            return None;
+        }
+        let code = u32::from_be_bytes(data[0..4].try_into().unwrap());
+        Self::decode_fields(code, &data[4..]).ok()
+    }
+    /// The error a call made through `AlkaneResponder::call` or its siblings
+    /// failed with, if the callee reverted with one of these.
+    fn from_extcall(error: &anyhow::Error) -> Option<Self> {
+        error
+            .downcast_ref::<Revert>()
+            .and_then(|v| Self::from_revert_data(&v.data))
+    }
+}
+
+/// The revert data of an extcall that failed with a typed error.
+#[derive(Clone, Debug)]
+pub struct Revert {
+    pub data: Vec<u8>,
+}
+
+impl fmt::Display for Revert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Extcall failed: 0x")?;
+        for byte in &self.data {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Revert {}
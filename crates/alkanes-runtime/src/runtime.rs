@@ -1,10 +1,14 @@
 #[allow(unused_imports)]
 use crate::imports::{
-    __balance, __call, __delegatecall, __fuel, __height, __load_block, __load_context,
+    __balance, __call, __delegatecall, __emit, __fuel, __height, __load_block, __load_block_header,
+    __load_blockhash, __load_context, __load_foreign_storage, __load_median_time_past,
     __load_storage, __load_transaction, __log, __request_block, __request_context,
-    __request_storage, __request_transaction, __returndatacopy, __sequence, __staticcall,
-    abort, /*, __load_output, __request_output */
+    __request_foreign_storage, __request_storage, __request_transaction, __returndatacopy,
+    __sequence, __staticcall, abort, /*, __load_output, __request_output */
 };
+#[cfg(feature = "test")]
+use crate::mock::{to_passback_ptr, to_ptr};
+use crate::revert::{Revert, ERROR_STRING_SELECTOR};
 use crate::storage::StoragePointer;
 #[allow(unused_imports)]
 use crate::{
@@ -12,8 +16,14 @@ use crate::{
     stdio::{stdout, Write},
 };
 use anyhow::{anyhow, Result};
+use bitcoin::block::Header;
+use bitcoin::consensus::deserialize;
+use bitcoin::BlockHash;
 #[allow(unused_imports)]
-use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr, to_ptr};
+use metashrew_support::compat::to_arraybuffer_layout;
+#[cfg(not(feature = "test"))]
+#[allow(unused_imports)]
+use metashrew_support::compat::{to_passback_ptr, to_ptr};
 use metashrew_support::index_pointer::KeyValuePointer;
 use std::io::Cursor;
 
@@ -24,6 +34,7 @@ use crate::compat::panic_hook;
 use alkanes_support::{
     cellpack::Cellpack,
     context::Context,
+    event::AlkaneEvent,
     id::AlkaneId,
     parcel::{AlkaneTransfer, AlkaneTransferParcel},
     response::{CallResponse, ExtendedCallResponse},
@@ -56,6 +67,14 @@ pub fn get_cache() -> StorageMap {
     }
 }
 
+/// Drops what the contract has written, so the mock host can start a call afresh.
+#[cfg(feature = "test")]
+pub(crate) fn clear_cache() {
+    unsafe {
+        _CACHE = None;
+    }
+}
+
 #[allow(static_mut_refs)]
 pub fn handle_success(response: CallResponse) -> ExtendedCallResponse {
     let mut extended: ExtendedCallResponse = response.into();
@@ -73,6 +92,14 @@ pub fn handle_error(error: &str) -> ExtendedCallResponse {
     response.into()
 }
 
+/// Reverts with the data of a typed error.
+pub fn handle_revert(data: Vec<u8>) -> ExtendedCallResponse {
+    let mut response = CallResponse::default();
+    response.data = data;
+    _abort();
+    response.into()
+}
+
 pub fn prepare_response(response: ExtendedCallResponse) -> Vec<u8> {
     response.serialize()
 }
@@ -114,7 +141,13 @@ pub trait Extcall {
                 )));
             }
             let response = CallResponse::parse(&mut Cursor::new((&returndata[4..]).to_vec()))?;
-            if response.data.len() <= 4 || &response.data[0..4] != &[0x08, 0xc3, 0x79, 0xa0] {
+            if response.data.len() >= 4 && &response.data[0..4] != &ERROR_STRING_SELECTOR {
+                return Err(Revert {
+                    data: response.data,
+                }
+                .into());
+            }
+            if response.data.len() <= 4 {
                 return Err(anyhow!("Extcall failed (no details available)"));
             }
             let error_message = String::from_utf8_lossy(&response.data[4..]).to_string();
@@ -244,6 +277,22 @@ pub trait AlkaneResponder: 'static {
             }
         }
     }
+    /// Reads `k` from the storage of another alkane without calling into it.
+    /// Writes are visible once the frame making them returns or calls out, so
+    /// a caller's writes up to its call are seen, but writes a running frame
+    /// has made since its last call out, including this alkane's, are not.
+    fn load_from(&self, who: &AlkaneId, k: Vec<u8>) -> Vec<u8> {
+        unsafe {
+            let mut who_bytes: Vec<u8> = to_arraybuffer_layout::<Vec<u8>>(who.clone().into());
+            let who_ptr = to_passback_ptr(&mut who_bytes);
+            let mut key_bytes = to_arraybuffer_layout(&k);
+            let key = to_passback_ptr(&mut key_bytes);
+            let buf_size = __request_foreign_storage(who_ptr, key) as usize;
+            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; buf_size]);
+            __load_foreign_storage(who_ptr, key, to_passback_ptr(&mut buffer));
+            (&buffer[4..]).to_vec()
+        }
+    }
     #[allow(static_mut_refs)]
     fn store(&self, k: Vec<u8>, v: Vec<u8>) {
         unsafe {
@@ -251,6 +300,13 @@ pub trait AlkaneResponder: 'static {
             _CACHE.as_mut().unwrap().set(&k, &v);
         }
     }
+    /// Emits an event that is persisted with the transaction unless this call reverts.
+    fn emit(&self, topics: Vec<Vec<u8>>, data: Vec<u8>) {
+        unsafe {
+            let mut buffer = to_arraybuffer_layout(&AlkaneEvent::new(topics, data).serialize());
+            __emit(to_passback_ptr(&mut buffer));
+        }
+    }
     fn balance(&self, who: &AlkaneId, what: &AlkaneId) -> u128 {
         unsafe {
             let mut who_bytes: Vec<u8> = to_arraybuffer_layout::<Vec<u8>>(who.clone().into());
@@ -283,6 +339,31 @@ pub trait AlkaneResponder: 'static {
             u64::from_le_bytes((&buffer[4..]).try_into().unwrap())
         }
     }
+    /// Header of the block at `height`, which may be any height up to the one
+    /// being indexed. Later heights abort the call.
+    fn block_header(&self, height: u64) -> Result<Header> {
+        unsafe {
+            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; 80]);
+            __load_block_header(height, to_ptr(&mut buffer) + 4);
+            Ok(deserialize(&buffer[4..])?)
+        }
+    }
+    fn blockhash(&self, height: u64) -> Result<BlockHash> {
+        unsafe {
+            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; 32]);
+            __load_blockhash(height, to_ptr(&mut buffer) + 4);
+            Ok(deserialize(&buffer[4..])?)
+        }
+    }
+    /// Median timestamp of the eleven blocks ending at `height`, as BIP113
+    /// defines it, or of the blocks indexed so far when there are fewer.
+    fn median_time_past(&self, height: u64) -> Result<u32> {
+        unsafe {
+            let mut buffer: Vec<u8> = to_arraybuffer_layout(vec![0; 4]);
+            __load_median_time_past(height, to_ptr(&mut buffer) + 4);
+            Ok(u32::from_le_bytes((&buffer[4..]).try_into()?))
+        }
+    }
     fn extcall<T: Extcall>(
         &self,
         cellpack: &Cellpack,
@@ -1,5 +1,9 @@
 use crate::imports::__log;
-use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr};
+#[cfg(feature = "test")]
+use crate::mock::to_passback_ptr;
+use metashrew_support::compat::to_arraybuffer_layout;
+#[cfg(not(feature = "test"))]
+use metashrew_support::compat::to_passback_ptr;
 pub use std::fmt::{Error, Write};
 
 pub struct Stdout(());
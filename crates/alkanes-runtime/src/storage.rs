@@ -1,8 +1,10 @@
 use crate::runtime::AlkaneResponder;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
 use alkanes_support::response::CallResponse;
+use alkanes_support::utils::overflow_error;
 use metashrew_support::index_pointer::KeyValuePointer;
+use std::marker::PhantomData;
 use std::sync::Arc;
 
 struct StorageHandle(());
@@ -30,3 +32,265 @@ impl KeyValuePointer for StoragePointer {
         Arc::new(RUNTIME_STORAGE.load(self.unwrap().as_ref().clone()))
     }
 }
+
+pub use alkanes_macros::StorageCodec;
+pub use alkanes_support::codec::StorageCodec;
+// named by the impls the derives expand to
+#[doc(hidden)]
+pub use std::io::Cursor;
+
+/// A single value stored under one key, encoded as `Option<T>` so a value
+/// that encodes to no bytes is still told apart from one never set.
+pub struct StorageValue<T> {
+    pointer: StoragePointer,
+    _value: PhantomData<T>,
+}
+
+impl<T: StorageCodec> StorageValue<T> {
+    pub fn new(pointer: StoragePointer) -> Self {
+        Self {
+            pointer,
+            _value: PhantomData,
+        }
+    }
+    pub fn from_keyword(keyword: &str) -> Self {
+        Self::new(StoragePointer::from_keyword(keyword))
+    }
+    pub fn exists(&self) -> bool {
+        self.pointer.get().len() > 0
+    }
+    pub fn get(&self) -> Result<Option<T>> {
+        let bytes = self.pointer.get();
+        if bytes.len() == 0 {
+            return Ok(None);
+        }
+        Option::<T>::decode(&bytes)
+    }
+    pub fn get_or_default(&self) -> Result<T>
+    where
+        T: Default,
+    {
+        Ok(self.get()?.unwrap_or_default())
+    }
+    pub fn set(&mut self, value: &T) {
+        let mut bytes = vec![1];
+        bytes.extend(value.encode());
+        self.pointer.set(Arc::new(bytes));
+    }
+    pub fn remove(&mut self) {
+        self.pointer.set(Arc::new(vec![]));
+    }
+}
+
+impl StorageValue<u128> {
+    /// Adds `by` to the stored count and returns the new count.
+    pub fn increment(&mut self, by: u128) -> Result<u128> {
+        let value = overflow_error(self.get_or_default()?.checked_add(by))?;
+        self.set(&value);
+        Ok(value)
+    }
+    pub fn decrement(&mut self, by: u128) -> Result<u128> {
+        let value = overflow_error(self.get_or_default()?.checked_sub(by))?;
+        self.set(&value);
+        Ok(value)
+    }
+}
+
+/// A list kept as `/length` and one key per index under the pointer.
+pub struct StorageVec<T> {
+    pointer: StoragePointer,
+    _value: PhantomData<T>,
+}
+
+impl<T: StorageCodec> StorageVec<T> {
+    pub fn new(pointer: StoragePointer) -> Self {
+        Self {
+            pointer,
+            _value: PhantomData,
+        }
+    }
+    pub fn from_keyword(keyword: &str) -> Self {
+        Self::new(StoragePointer::from_keyword(keyword))
+    }
+    fn length_pointer(&self) -> StoragePointer {
+        self.pointer.keyword("/length")
+    }
+    fn element(&self, index: u128) -> StoragePointer {
+        self.pointer.keyword("/").select_value::<u128>(index)
+    }
+    pub fn len(&self) -> u128 {
+        self.length_pointer().get_value::<u128>()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, index: u128) -> Result<Option<T>> {
+        if index >= self.len() {
+            return Ok(None);
+        }
+        T::decode(&self.element(index).get()).map(Some)
+    }
+    pub fn set(&mut self, index: u128, value: &T) -> Result<()> {
+        let length = self.len();
+        if index >= length {
+            return Err(anyhow!(
+                "index {} out of bounds for length {}",
+                index,
+                length
+            ));
+        }
+        self.element(index).set(Arc::new(value.encode()));
+        Ok(())
+    }
+    pub fn push(&mut self, value: &T) {
+        let length = self.len();
+        self.element(length).set(Arc::new(value.encode()));
+        self.length_pointer().set_value::<u128>(length + 1);
+    }
+    pub fn pop(&mut self) -> Result<Option<T>> {
+        let length = self.len();
+        if length == 0 {
+            return Ok(None);
+        }
+        let value = self.get(length - 1)?;
+        self.element(length - 1).set(Arc::new(vec![]));
+        self.length_pointer().set_value::<u128>(length - 1);
+        Ok(value)
+    }
+    /// Removes the value at `index`, moving the last value into its place.
+    pub fn swap_remove(&mut self, index: u128) -> Result<Option<T>> {
+        let length = self.len();
+        if index >= length {
+            return Ok(None);
+        }
+        let value = self.get(index)?;
+        if index != length - 1 {
+            let last = self.element(length - 1).get();
+            self.element(index).set(last);
+        }
+        self.pop()?;
+        Ok(value)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = Result<T>> + '_ {
+        (0..self.len()).map(move |index| T::decode(&self.element(index).get()))
+    }
+}
+
+/// A set of values, iterated in insertion order until a value is removed and
+/// the last one takes its place.
+pub struct StorageSet<T> {
+    items: StorageVec<T>,
+    positions: StoragePointer,
+}
+
+impl<T: StorageCodec> StorageSet<T> {
+    pub fn new(pointer: StoragePointer) -> Self {
+        Self {
+            items: StorageVec::new(pointer.keyword("/items")),
+            positions: pointer.keyword("/positions/"),
+        }
+    }
+    pub fn from_keyword(keyword: &str) -> Self {
+        Self::new(StoragePointer::from_keyword(keyword))
+    }
+    // the index of the value in `items` plus one, so zero means absent
+    fn position(&self, value: &T) -> StoragePointer {
+        self.positions.select(&value.encode())
+    }
+    pub fn len(&self) -> u128 {
+        self.items.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    pub fn contains(&self, value: &T) -> bool {
+        self.position(value).get_value::<u128>() != 0
+    }
+    /// Returns whether the value was not already in the set.
+    pub fn insert(&mut self, value: &T) -> bool {
+        if self.contains(value) {
+            return false;
+        }
+        self.items.push(value);
+        self.position(value).set_value::<u128>(self.items.len());
+        true
+    }
+    /// Returns whether the value was in the set.
+    pub fn remove(&mut self, value: &T) -> Result<bool> {
+        let position = self.position(value).get_value::<u128>();
+        if position == 0 {
+            return Ok(false);
+        }
+        self.items.swap_remove(position - 1)?;
+        if let Some(moved) = self.items.get(position - 1)? {
+            self.position(&moved).set_value::<u128>(position);
+        }
+        self.position(value).set(Arc::new(vec![]));
+        Ok(true)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = Result<T>> + '_ {
+        self.items.iter()
+    }
+}
+
+/// Values stored by key, with the keys kept in a `StorageSet` so the map can
+/// be iterated.
+pub struct StorageMap<K, V> {
+    keys: StorageSet<K>,
+    entries: StoragePointer,
+    _value: PhantomData<V>,
+}
+
+impl<K: StorageCodec, V: StorageCodec> StorageMap<K, V> {
+    pub fn new(pointer: StoragePointer) -> Self {
+        Self {
+            keys: StorageSet::new(pointer.keyword("/keys")),
+            entries: pointer.keyword("/entries/"),
+            _value: PhantomData,
+        }
+    }
+    pub fn from_keyword(keyword: &str) -> Self {
+        Self::new(StoragePointer::from_keyword(keyword))
+    }
+    fn entry(&self, key: &K) -> StoragePointer {
+        self.entries.select(&key.encode())
+    }
+    pub fn len(&self) -> u128 {
+        self.keys.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.keys.contains(key)
+    }
+    pub fn get(&self, key: &K) -> Result<Option<V>> {
+        let bytes = self.entry(key).get();
+        if bytes.len() == 0 && !self.contains_key(key) {
+            return Ok(None);
+        }
+        V::decode(&bytes).map(Some)
+    }
+    pub fn insert(&mut self, key: &K, value: &V) {
+        self.keys.insert(key);
+        self.entry(key).set(Arc::new(value.encode()));
+    }
+    pub fn remove(&mut self, key: &K) -> Result<Option<V>> {
+        let value = self.get(key)?;
+        if value.is_some() {
+            self.keys.remove(key)?;
+            self.entry(key).set(Arc::new(vec![]));
+        }
+        Ok(value)
+    }
+    pub fn keys(&self) -> impl Iterator<Item = Result<K>> + '_ {
+        self.keys.iter()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = Result<(K, V)>> + '_ {
+        self.keys.iter().map(move |key| {
+            let key = key?;
+            let value = V::decode(&self.entry(&key).get())?;
+            Ok((key, value))
+        })
+    }
+}
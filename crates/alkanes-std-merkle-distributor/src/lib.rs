@@ -174,3 +174,62 @@ declare_alkane! {
         type Message = MerkleDistributorMessage;
     }
 }
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use super::*;
+    use alkanes_runtime::mock::{self, MockHost};
+    use alkanes_support::parcel::AlkaneTransferParcel;
+
+    const MYSELF: AlkaneId = AlkaneId { block: 2, tx: 3 };
+    const TOKEN: AlkaneId = AlkaneId { block: 2, tx: 1 };
+
+    fn host(incoming_alkanes: Vec<AlkaneTransfer>) -> MockHost {
+        let mut host = MockHost::default();
+        host.context.myself = MYSELF;
+        host.context.inputs = vec![0, 4, 1, 2];
+        host.context.incoming_alkanes = AlkaneTransferParcel(incoming_alkanes);
+        host
+    }
+
+    #[test]
+    fn test_initialize_locks_incoming_alkane() -> Result<()> {
+        let _host = host(vec![AlkaneTransfer {
+            id: TOKEN,
+            value: 1000,
+        }])
+        .install();
+        let response = mock::execute::<MerkleDistributor, MerkleDistributorMessage>()?;
+        assert!(response.alkanes.0.is_empty());
+        assert_eq!(response.storage.get(b"/alkane"), Some(&TOKEN.into()));
+
+        // a second initialize is refused once the first is committed
+        mock::commit();
+        let err = mock::execute::<MerkleDistributor, MerkleDistributorMessage>().unwrap_err();
+        assert_eq!(err.to_string(), "already initialized");
+        Ok(())
+    }
+
+    #[test]
+    fn test_initialize_without_alkane_reverts() -> Result<()> {
+        let _host = host(vec![]).install();
+        // the contract panics, which reverts the call like a trap would
+        let err = mock::execute::<MerkleDistributor, MerkleDistributorMessage>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "ALKANES: revert: must send 1 alkane to lock for distribution"
+        );
+        mock::commit();
+        mock::with_host(|host| assert!(host.storage.is_empty()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_claim_without_transaction_fails() -> Result<()> {
+        let mut host = host(vec![]);
+        host.context.inputs = vec![1];
+        let _host = host.install();
+        assert!(mock::execute::<MerkleDistributor, MerkleDistributorMessage>().is_err());
+        Ok(())
+    }
+}
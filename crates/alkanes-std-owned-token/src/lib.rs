@@ -1,5 +1,9 @@
 use alkanes_runtime::runtime::AlkaneResponder;
-use alkanes_runtime::{auth::AuthenticatedResponder, declare_alkane, message::MessageDispatch};
+use alkanes_runtime::{
+    auth::AuthenticatedResponder,
+    declare_alkane,
+    message::{MessageClient, MessageDispatch},
+};
 #[allow(unused_imports)]
 use alkanes_runtime::{
     println,
@@ -17,7 +21,7 @@ impl MintableToken for OwnedToken {}
 
 impl AuthenticatedResponder for OwnedToken {}
 
-#[derive(MessageDispatch)]
+#[derive(MessageDispatch, MessageClient)]
 enum OwnedTokenMessage {
     #[opcode(0)]
     Initialize {
@@ -37,18 +41,22 @@ enum OwnedTokenMessage {
     Mint { token_units: u128 },
 
     #[opcode(99)]
+    #[view]
     #[returns(String)]
     GetName,
 
     #[opcode(100)]
+    #[view]
     #[returns(String)]
     GetSymbol,
 
     #[opcode(101)]
+    #[view]
     #[returns(u128)]
     GetTotalSupply,
 
     #[opcode(1000)]
+    #[view]
     #[returns(Vec<u8>)]
     GetData,
 }
@@ -147,3 +155,131 @@ declare_alkane! {
         type Message = OwnedTokenMessage;
     }
 }
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use super::*;
+    use alkanes_runtime::mock::{self, CallKind, MockHost};
+    use alkanes_support::{id::AlkaneId, parcel::AlkaneTransferParcel};
+
+    const MYSELF: AlkaneId = AlkaneId { block: 2, tx: 1 };
+    const AUTH: AlkaneId = AlkaneId { block: 2, tx: 2 };
+
+    fn host(incoming_alkanes: Vec<AlkaneTransfer>) -> MockHost {
+        let mut host = MockHost::default().on_call(|call| {
+            let mut response = CallResponse::forward(&call.incoming_alkanes);
+            if call.cellpack.target == AUTH && call.cellpack.inputs == vec![1] {
+                response.data = vec![1];
+            }
+            Ok(response)
+        });
+        host.context.myself = MYSELF;
+        host.context.inputs = vec![77, 500];
+        host.context.incoming_alkanes = AlkaneTransferParcel(incoming_alkanes);
+        host.storage.insert(b"/auth".to_vec(), AUTH.into());
+        host.storage
+            .insert(b"/totalsupply".to_vec(), 1000u128.to_le_bytes().to_vec());
+        host
+    }
+
+    #[test]
+    fn test_mint_with_auth_token() -> Result<()> {
+        let _host = host(vec![AlkaneTransfer { id: AUTH, value: 1 }]).install();
+        let response = mock::execute::<OwnedToken, OwnedTokenMessage>()?;
+        assert_eq!(
+            response.alkanes.0,
+            vec![
+                AlkaneTransfer { id: AUTH, value: 1 },
+                AlkaneTransfer {
+                    id: MYSELF,
+                    value: 500
+                }
+            ]
+        );
+        assert_eq!(
+            response.storage.get(b"/totalsupply"),
+            Some(&1500u128.to_le_bytes().to_vec())
+        );
+        mock::with_host(|host| {
+            assert_eq!(host.calls.len(), 1);
+            assert_eq!(host.calls[0].cellpack.target, AUTH);
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_client() -> Result<()> {
+        let client = OwnedTokenClient::new(AUTH);
+        assert_eq!(client.mint_cellpack(500).inputs, vec![77, 500]);
+        let mut name = [0u8; 16];
+        name[..5].copy_from_slice(b"OWNED");
+        assert_eq!(
+            client
+                .initialize_with_name_symbol_cellpack(1, 1000, "OWNED".into(), "OWNED".into())
+                .inputs,
+            vec![
+                1,
+                1,
+                1000,
+                u128::from_le_bytes(name),
+                u128::from_le_bytes(name)
+            ]
+        );
+
+        let _host = MockHost::default()
+            .on_call(|_| {
+                let mut response = CallResponse::default();
+                response.data = 1000u128.to_le_bytes().to_vec();
+                Ok(response)
+            })
+            .install();
+        assert_eq!(client.get_total_supply(&OwnedToken::default())?, 1000);
+        mock::with_host(|host| {
+            assert_eq!(host.calls[0].kind, CallKind::Staticcall);
+            assert_eq!(host.calls[0].cellpack.inputs, vec![101]);
+        });
+        Ok(())
+    }
+
+    // a copy of the enum as a wallet would keep it, with no runtime behind it
+    #[derive(MessageClient)]
+    #[cellpacks_only]
+    #[allow(dead_code)]
+    enum WalletMessage {
+        #[opcode(1)]
+        InitializeWithNameSymbol {
+            auth_token_units: u128,
+            token_units: u128,
+            name: String,
+            symbol: String,
+        },
+
+        #[opcode(77)]
+        Mint { token_units: u128 },
+    }
+
+    #[test]
+    fn test_standalone_cellpacks() {
+        let client = OwnedTokenClient::new(AUTH);
+        let cellpacks = WalletCellpacks::new(AUTH);
+        assert_eq!(cellpacks.mint_cellpack(500), client.mint_cellpack(500));
+        assert_eq!(
+            cellpacks.initialize_with_name_symbol_cellpack(1, 1000, "OWNED".into(), "OWNED".into()),
+            OwnedTokenCellpacks::new(AUTH).initialize_with_name_symbol_cellpack(
+                1,
+                1000,
+                "OWNED".into(),
+                "OWNED".into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_mint_without_auth_token() -> Result<()> {
+        let _host = host(vec![]).install();
+        let err = mock::execute::<OwnedToken, OwnedTokenMessage>().unwrap_err();
+        assert_eq!(err.to_string(), "Auth token is not in incoming alkanes");
+        mock::with_host(|host| assert!(host.calls.is_empty()));
+        Ok(())
+    }
+}
@@ -1,4 +1,10 @@
-use alkanes_runtime::{declare_alkane, message::MessageDispatch, runtime::AlkaneResponder};
+use alkanes_runtime::storage::{StorageCodec, StorageMap, StorageSet, StorageValue, StorageVec};
+use alkanes_runtime::{
+    declare_alkane,
+    message::{MessageClient, MessageDispatch},
+    revert::AlkaneError,
+    runtime::AlkaneResponder,
+};
 use alkanes_support::{
     cellpack::Cellpack,
     id::AlkaneId,
@@ -6,6 +12,7 @@ use alkanes_support::{
     response::CallResponse,
 };
 use anyhow::{anyhow, Result};
+use bitcoin::consensus::serialize;
 use metashrew_support::compat::{to_arraybuffer_layout, to_passback_ptr};
 use sha2::{Digest, Sha256};
 #[allow(unused_imports)]
@@ -17,7 +24,23 @@ use {
 #[derive(Default)]
 pub struct LoggerAlkane(());
 
-#[derive(MessageDispatch)]
+#[derive(StorageCodec)]
+struct Deposit {
+    amount: u128,
+    note: String,
+}
+
+#[derive(Debug, AlkaneError)]
+enum LoggerAlkaneError {
+    #[code(1)]
+    Unauthorized { caller: AlkaneId },
+
+    #[code(2)]
+    InsufficientBalance { needed: u128, available: u128 },
+}
+
+#[derive(MessageDispatch, MessageClient)]
+#[errors(LoggerAlkaneError)]
 enum LoggerAlkaneMessage {
     #[opcode(2)]
     SelfCall,
@@ -76,9 +99,77 @@ enum LoggerAlkaneMessage {
     #[opcode(50)]
     GetTransaction,
 
+    #[opcode(60)]
+    TestEmit { value: u128 },
+
+    #[opcode(61)]
+    TestEmitRevert,
+
+    #[opcode(70)]
+    #[returns(Vec<u8>)]
+    TestBlockHeader { height: u128 },
+
+    #[opcode(71)]
+    #[returns(u128)]
+    TestMedianTimePast { height: u128 },
+
+    #[opcode(72)]
+    TestStore { value: u128 },
+
+    #[opcode(73)]
+    #[returns(u128)]
+    TestLoadFrom { target: AlkaneId },
+
+    #[opcode(74)]
+    TestCollectionsInsert {
+        depositor: AlkaneId,
+        amount: u128,
+        note: String,
+    },
+
+    #[opcode(75)]
+    TestCollectionsRemove { depositor: AlkaneId },
+
+    #[opcode(76)]
+    #[returns(Vec<u8>)]
+    TestCollectionsRead,
+
+    #[opcode(77)]
+    #[returns(Option<String>)]
+    TestEmptyValue,
+
     #[opcode(78)]
     HashLoop,
 
+    #[opcode(79)]
+    #[view]
+    #[returns(Option<u64>)]
+    TestEchoFlag { flag: bool, small: u64 },
+
+    #[opcode(80)]
+    #[returns([u8; 32])]
+    TestEchoHash { hash: [u8; 32] },
+
+    #[opcode(81)]
+    #[view]
+    #[returns(Vec<AlkaneId>)]
+    TestEchoIds { ids: Vec<AlkaneId> },
+
+    #[opcode(82)]
+    #[returns(Deposit)]
+    TestEchoDeposit { deposit: Option<Deposit> },
+
+    #[opcode(83)]
+    #[returns(Vec<u8>)]
+    TestClientCall { target: AlkaneId },
+
+    #[opcode(84)]
+    TestTypedRevert { needed: u128, available: u128 },
+
+    #[opcode(85)]
+    #[returns(u128)]
+    TestCatchTypedRevert { target: AlkaneId },
+
     #[opcode(99)]
     #[returns(Vec<u8>)]
     ReturnDefaultData,
@@ -193,6 +284,164 @@ impl LoggerAlkane {
         Ok(response)
     }
 
+    fn test_emit(&self, value: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        self.emit(vec![b"test".to_vec()], value.to_le_bytes().to_vec());
+        Ok(CallResponse::forward(&context.incoming_alkanes))
+    }
+
+    fn test_emit_revert(&self) -> Result<CallResponse> {
+        self.emit(vec![b"test".to_vec()], vec![]);
+        Err(anyhow!("reverting after emit"))
+    }
+
+    fn test_block_header(&self, height: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let height: u64 = height.try_into()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        response.data = serialize(&self.block_header(height)?);
+        response.data.extend(serialize(&self.blockhash(height)?));
+        Ok(response)
+    }
+
+    fn test_median_time_past(&self, height: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        response.data = (self.median_time_past(height.try_into()?)? as u128)
+            .to_le_bytes()
+            .to_vec();
+        Ok(response)
+    }
+
+    fn test_store(&self, value: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        self.store(b"/value".to_vec(), value.to_le_bytes().to_vec());
+        Ok(CallResponse::forward(&context.incoming_alkanes))
+    }
+
+    fn test_load_from(&self, target: AlkaneId) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        response.data = self.load_from(&target, b"/value".to_vec());
+        Ok(response)
+    }
+
+    fn deposits(&self) -> StorageMap<AlkaneId, Deposit> {
+        StorageMap::from_keyword("/deposits")
+    }
+
+    fn depositors(&self) -> StorageSet<AlkaneId> {
+        StorageSet::from_keyword("/depositors")
+    }
+
+    fn test_collections_insert(
+        &self,
+        depositor: AlkaneId,
+        amount: u128,
+        note: String,
+    ) -> Result<CallResponse> {
+        let context = self.context()?;
+        self.deposits()
+            .insert(&depositor, &Deposit { amount, note });
+        self.depositors().insert(&depositor);
+        StorageVec::<u128>::from_keyword("/amounts").push(&amount);
+        StorageValue::<u128>::from_keyword("/inserts").increment(1)?;
+        Ok(CallResponse::forward(&context.incoming_alkanes))
+    }
+
+    fn test_collections_remove(&self, depositor: AlkaneId) -> Result<CallResponse> {
+        let context = self.context()?;
+        self.deposits().remove(&depositor)?;
+        self.depositors().remove(&depositor)?;
+        Ok(CallResponse::forward(&context.incoming_alkanes))
+    }
+
+    /// Inserts, amounts pushed, their sum and depositors, then each deposit as
+    /// its depositor followed by the encoded `Deposit`.
+    fn test_collections_read(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        let amounts = StorageVec::<u128>::from_keyword("/amounts");
+        let sum = amounts.iter().sum::<Result<u128>>()?;
+        let mut data = StorageValue::<u128>::from_keyword("/inserts")
+            .get_or_default()?
+            .encode();
+        data.extend(amounts.len().encode());
+        data.extend(sum.encode());
+        data.extend(self.depositors().len().encode());
+        for entry in self.deposits().iter() {
+            let (depositor, deposit) = entry?;
+            data.extend(depositor.encode());
+            data.extend(deposit.encode());
+        }
+        response.data = data;
+        Ok(response)
+    }
+
+    /// Returns the note stored by an earlier call, then stores an empty one.
+    fn test_empty_value(&self) -> Result<Option<String>> {
+        let mut note = StorageValue::<String>::from_keyword("/note");
+        let previous = note.get()?;
+        note.set(&String::new());
+        Ok(previous)
+    }
+
+    fn test_echo_flag(&self, flag: bool, small: u64) -> Result<Option<u64>> {
+        Ok(if flag { Some(small) } else { None })
+    }
+
+    fn test_echo_hash(&self, hash: [u8; 32]) -> Result<[u8; 32]> {
+        Ok(hash)
+    }
+
+    fn test_echo_ids(&self, ids: Vec<AlkaneId>) -> Result<Vec<AlkaneId>> {
+        Ok(ids.into_iter().rev().collect())
+    }
+
+    fn test_echo_deposit(&self, deposit: Option<Deposit>) -> Result<Deposit> {
+        deposit.ok_or_else(|| anyhow!("no deposit given"))
+    }
+
+    /// Calls the echo methods of `target` through its generated client and
+    /// returns what they decode to, encoded again one after the other.
+    fn test_client_call(&self, target: AlkaneId) -> Result<Vec<u8>> {
+        let client = LoggerAlkaneClient::new(target);
+        let mut data = client.test_echo_flag(self, true, 9)?.encode();
+        data.extend(
+            client
+                .test_echo_ids(self, vec![AlkaneId::new(4, 5), AlkaneId::new(6, 7)])?
+                .encode(),
+        );
+        let deposit = Deposit {
+            amount: 300,
+            note: String::from("sixteen bytes!!!"),
+        };
+        data.extend(client.test_echo_deposit(self, Some(deposit))?.encode());
+        let strings = vec![String::from("exactly sixteen!"), String::from("hi")];
+        data.extend(client.process_strings(self, strings)?.data);
+        Ok(data)
+    }
+
+    fn test_typed_revert(&self, needed: u128, available: u128) -> Result<()> {
+        if needed > available {
+            return Err(LoggerAlkaneError::InsufficientBalance { needed, available }.into());
+        }
+        Ok(())
+    }
+
+    fn test_catch_typed_revert(&self, target: AlkaneId) -> Result<u128> {
+        match LoggerAlkaneClient::new(target).test_typed_revert(self, 5, 3) {
+            Ok(_) => Err(anyhow!("expected test_typed_revert to revert")),
+            Err(e) => match LoggerAlkaneError::from_extcall(&e) {
+                Some(LoggerAlkaneError::InsufficientBalance { needed, available }) => {
+                    Ok(needed - available)
+                }
+                Some(error) => Err(error.into()),
+                None => Err(e),
+            },
+        }
+    }
+
     fn test_ext_call(&self, target: AlkaneId, inputs: Vec<u128>) -> Result<CallResponse> {
         let context = self.context()?;
         let cellpack = Cellpack {
@@ -0,0 +1,265 @@
+use crate::id::AlkaneId;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+/// The selector of revert data carrying a message rather than a typed error.
+pub const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// The JSON an alkane returns from `__meta`, as generated by the
+/// `MessageDispatch` derive.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContractAbi {
+    pub contract: String,
+    pub methods: Vec<MethodAbi>,
+    /// The errors named by `#[errors(..)]` on the message enum.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ErrorAbi>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MethodAbi {
+    pub name: String,
+    pub opcode: u128,
+    pub params: Vec<ParamAbi>,
+    pub returns: String,
+}
+
+/// A typed error an alkane may revert with. Its revert data is the big endian
+/// `code` followed by the fields, those without a fixed width behind a little
+/// endian u32 length, as `StorageCodec::encode_field` writes them.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ErrorAbi {
+    pub name: String,
+    pub code: u32,
+    pub params: Vec<ParamAbi>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParamAbi {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub name: String,
+}
+
+/// A cellpack argument or return value read back through its ABI type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AbiValue {
+    U128(u128),
+    Bool(bool),
+    String(String),
+    AlkaneId(AlkaneId),
+    Bytes(Vec<u8>),
+    List(Vec<AbiValue>),
+    Option(Option<Box<AbiValue>>),
+}
+
+impl ContractAbi {
+    pub fn method(&self, opcode: u128) -> Option<&MethodAbi> {
+        self.methods.iter().find(|v| v.opcode == opcode)
+    }
+    /// Reads revert data as one of the declared errors. Reverts with a message,
+    /// or with a code this alkane does not declare, give `None`.
+    pub fn decode_error(&self, data: &[u8]) -> Option<(&ErrorAbi, Vec<AbiValue>)> {
+        if data.len() < 4 || data[0..4] == ERROR_STRING_SELECTOR {
+            return None;
+        }
+        let code = u32::from_be_bytes(data[0..4].try_into().unwrap());
+        let error = self.errors.iter().find(|v| v.code == code)?;
+        let mut fields = &data[4..];
+        let values = error
+            .params
+            .iter()
+            .map(|param| decode_field(&param.kind, &mut fields))
+            .collect::<Result<Vec<AbiValue>>>()
+            .ok()?;
+        if !fields.is_empty() {
+            return None;
+        }
+        Some((error, values))
+    }
+}
+
+impl MethodAbi {
+    /// Reads the cellpack inputs following the opcode the same way the
+    /// generated `from_opcode` does.
+    pub fn decode_inputs(&self, inputs: &[u128]) -> Result<Vec<AbiValue>> {
+        let mut index = 0;
+        self.params
+            .iter()
+            .map(|param| decode_input(&param.kind, inputs, &mut index))
+            .collect()
+    }
+    /// Reads `CallResponse.data` as the declared return type, in the
+    /// `StorageCodec` encoding typed returns are written with. Data that does
+    /// not fit the type is left as bytes.
+    pub fn decode_data(&self, data: &[u8]) -> AbiValue {
+        decode_bytes(&self.returns, data).unwrap_or_else(|_| AbiValue::Bytes(data.to_vec()))
+    }
+}
+
+fn uint_width(kind: &str) -> Option<usize> {
+    match kind {
+        "u8" => Some(1),
+        "u16" => Some(2),
+        "u32" => Some(4),
+        "u64" => Some(8),
+        "u128" => Some(16),
+        _ => None,
+    }
+}
+
+fn byte_array_len(kind: &str) -> Option<usize> {
+    kind.strip_prefix("[u8; ")?.strip_suffix(']')?.parse().ok()
+}
+
+fn decode_bytes(kind: &str, data: &[u8]) -> Result<AbiValue> {
+    if let Some(inner) = kind
+        .strip_prefix("Option<")
+        .and_then(|v| v.strip_suffix('>'))
+    {
+        return match data.split_first() {
+            Some((0, [])) => Ok(AbiValue::Option(None)),
+            Some((1, rest)) => Ok(AbiValue::Option(Some(Box::new(decode_bytes(inner, rest)?)))),
+            _ => Err(anyhow!("invalid Option tag")),
+        };
+    }
+    if let Some(width) = uint_width(kind).or_else(|| byte_array_len(kind)) {
+        if data.len() != width {
+            return Err(anyhow!("expected {} bytes for {}", width, kind));
+        }
+    }
+    match kind {
+        "u8" | "u16" | "u32" | "u64" | "u128" => {
+            let mut bytes = [0u8; 16];
+            bytes[..data.len()].copy_from_slice(data);
+            Ok(AbiValue::U128(u128::from_le_bytes(bytes)))
+        }
+        "bool" => match data {
+            [0] => Ok(AbiValue::Bool(false)),
+            [1] => Ok(AbiValue::Bool(true)),
+            _ => Err(anyhow!("invalid bool")),
+        },
+        "AlkaneId" if data.len() == 32 => Ok(AbiValue::AlkaneId(AlkaneId::new(
+            u128::from_le_bytes(data[0..16].try_into().unwrap()),
+            u128::from_le_bytes(data[16..32].try_into().unwrap()),
+        ))),
+        "Vec<AlkaneId>" if data.len() % 32 == 0 => data
+            .chunks(32)
+            .map(|v| decode_bytes("AlkaneId", v))
+            .collect::<Result<Vec<AbiValue>>>()
+            .map(AbiValue::List),
+        "AlkaneId" | "Vec<AlkaneId>" => Err(anyhow!("invalid length for {}", kind)),
+        "String" => Ok(AbiValue::String(String::from_utf8(data.to_vec())?)),
+        // Vec<u8>, byte arrays and structs encoded with their StorageCodec
+        _ => Ok(AbiValue::Bytes(data.to_vec())),
+    }
+}
+
+fn take<'a>(fields: &mut &'a [u8], length: usize) -> Result<&'a [u8]> {
+    if fields.len() < length {
+        return Err(anyhow!("not enough bytes"));
+    }
+    let (value, rest) = fields.split_at(length);
+    *fields = rest;
+    Ok(value)
+}
+
+fn decode_field(kind: &str, fields: &mut &[u8]) -> Result<AbiValue> {
+    let width = match kind {
+        "bool" => Some(1),
+        "AlkaneId" => Some(32),
+        _ => uint_width(kind).or_else(|| byte_array_len(kind)),
+    };
+    let length = match width {
+        Some(width) => width,
+        None => u32::from_le_bytes(take(fields, 4)?.try_into().unwrap()) as usize,
+    };
+    decode_bytes(kind, take(fields, length)?)
+}
+
+/// Reads `length` bytes packed little endian sixteen to an input.
+fn next_bytes(inputs: &[u128], index: &mut usize, length: usize) -> Result<Vec<u8>> {
+    let words = length / 16 + (length % 16 != 0) as usize;
+    let packed = inputs
+        .get(*index..index.saturating_add(words))
+        .ok_or_else(|| anyhow!("not enough inputs"))?;
+    *index += words;
+    Ok(packed
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .take(length)
+        .collect())
+}
+
+fn next(inputs: &[u128], index: &mut usize) -> Result<u128> {
+    let value = *inputs
+        .get(*index)
+        .ok_or_else(|| anyhow!("not enough inputs"))?;
+    *index += 1;
+    Ok(value)
+}
+
+fn decode_input(kind: &str, inputs: &[u128], index: &mut usize) -> Result<AbiValue> {
+    if let Some(inner) = kind.strip_prefix("Vec<").and_then(|v| v.strip_suffix('>')) {
+        let length = next(inputs, index)?;
+        return (0..length)
+            .map(|_| decode_input(inner, inputs, index))
+            .collect::<Result<Vec<AbiValue>>>()
+            .map(AbiValue::List);
+    }
+    if let Some(inner) = kind
+        .strip_prefix("Option<")
+        .and_then(|v| v.strip_suffix('>'))
+    {
+        return match next(inputs, index)? {
+            0 => Ok(AbiValue::Option(None)),
+            1 => Ok(AbiValue::Option(Some(Box::new(decode_input(
+                inner, inputs, index,
+            )?)))),
+            tag => Err(anyhow!("invalid Option tag {}", tag)),
+        };
+    }
+    if let Some(length) = byte_array_len(kind) {
+        return Ok(AbiValue::Bytes(next_bytes(inputs, index, length)?));
+    }
+    if let Some(width) = uint_width(kind) {
+        let value = next(inputs, index)?;
+        if width < 16 && value >> (width * 8) != 0 {
+            return Err(anyhow!("{} does not fit in {}", value, kind));
+        }
+        return Ok(AbiValue::U128(value));
+    }
+    match kind {
+        "bool" => match next(inputs, index)? {
+            0 => Ok(AbiValue::Bool(false)),
+            1 => Ok(AbiValue::Bool(true)),
+            value => Err(anyhow!("invalid bool {}", value)),
+        },
+        "AlkaneId" => Ok(AbiValue::AlkaneId(AlkaneId::new(
+            next(inputs, index)?,
+            next(inputs, index)?,
+        ))),
+        "String" => {
+            // packed little endian into u128s up to the first zero byte
+            let mut bytes = vec![];
+            'outer: loop {
+                for byte in next(inputs, index)?.to_le_bytes() {
+                    if byte == 0 {
+                        break 'outer;
+                    }
+                    bytes.push(byte);
+                }
+                if *index >= inputs.len() {
+                    break;
+                }
+            }
+            Ok(AbiValue::String(String::from_utf8(bytes)?))
+        }
+        "unknown" => Err(anyhow!("unsupported parameter type {}", kind)),
+        // any other type is a struct passed as its StorageCodec encoding
+        _ => {
+            let length = next(inputs, index)? as usize;
+            Ok(AbiValue::Bytes(next_bytes(inputs, index, length)?))
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! How values are laid out in storage and in the inputs of a cellpack. The
+//! runtime builds its storage types on these, and the cellpack builders the
+//! `MessageClient` derive generates use them directly, so a wallet can encode
+//! calls without depending on the runtime.
+use crate::id::AlkaneId;
+use anyhow::{anyhow, Result};
+use metashrew_support::utils::{consume_exact, consume_sized_int, is_empty};
+use std::io::Cursor;
+
+/// How a value is laid out in storage. A `StorageValue` stores its value as
+/// `Some(value)` and a `StorageMap` keeps its keys in a set, so a `String` or
+/// `Vec<u8>` left empty still reads back as present.
+pub trait StorageCodec: Sized {
+    fn encode(&self) -> Vec<u8>;
+    fn decode(bytes: &[u8]) -> Result<Self>;
+    /// Appends the value as one field of a composite key or value. Values
+    /// without a fixed width are written behind their length.
+    fn encode_field(&self, out: &mut Vec<u8>) {
+        let bytes = self.encode();
+        out.extend((bytes.len() as u32).to_le_bytes());
+        out.extend(bytes);
+    }
+    fn decode_field(cursor: &mut Cursor<Vec<u8>>) -> Result<Self> {
+        let length: usize = consume_sized_int::<u32>(cursor)?.try_into()?;
+        Self::decode(&consume_exact(cursor, length)?)
+    }
+}
+
+macro_rules! int_codec {
+    ($($t:ty),*) => {
+        $(
+            impl StorageCodec for $t {
+                fn encode(&self) -> Vec<u8> {
+                    self.to_le_bytes().to_vec()
+                }
+                fn decode(bytes: &[u8]) -> Result<Self> {
+                    Ok(<$t>::from_le_bytes(bytes.try_into().map_err(|_| {
+                        anyhow!(
+                            "expected {} bytes for {}, got {}",
+                            std::mem::size_of::<$t>(),
+                            stringify!($t),
+                            bytes.len()
+                        )
+                    })?))
+                }
+                fn encode_field(&self, out: &mut Vec<u8>) {
+                    out.extend(self.to_le_bytes());
+                }
+                fn decode_field(cursor: &mut Cursor<Vec<u8>>) -> Result<Self> {
+                    consume_sized_int::<$t>(cursor)
+                }
+            }
+        )*
+    };
+}
+
+int_codec!(u8, u16, u32, u64, u128);
+
+impl StorageCodec for bool {
+    fn encode(&self) -> Vec<u8> {
+        vec![*self as u8]
+    }
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        match bytes {
+            [0] => Ok(false),
+            [1] => Ok(true),
+            _ => Err(anyhow!("expected a single 0 or 1 byte for bool")),
+        }
+    }
+    fn encode_field(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+    fn decode_field(cursor: &mut Cursor<Vec<u8>>) -> Result<Self> {
+        Self::decode(&consume_exact(cursor, 1)?)
+    }
+}
+
+impl StorageCodec for AlkaneId {
+    fn encode(&self) -> Vec<u8> {
+        self.into()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != 32 {
+            return Err(anyhow!(
+                "expected 32 bytes for AlkaneId, got {}",
+                bytes.len()
+            ));
+        }
+        AlkaneId::parse(&mut Cursor::new(bytes.to_vec()))
+    }
+    fn encode_field(&self, out: &mut Vec<u8>) {
+        out.extend(self.encode());
+    }
+    fn decode_field(cursor: &mut Cursor<Vec<u8>>) -> Result<Self> {
+        AlkaneId::parse(cursor)
+    }
+}
+
+impl StorageCodec for String {
+    fn encode(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        Ok(String::from_utf8(bytes.to_vec())?)
+    }
+}
+
+impl StorageCodec for Vec<u8> {
+    fn encode(&self) -> Vec<u8> {
+        self.clone()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        Ok(bytes.to_vec())
+    }
+}
+
+/// The ids back to back, each in its fixed 32 bytes.
+impl StorageCodec for Vec<AlkaneId> {
+    fn encode(&self) -> Vec<u8> {
+        self.iter().flat_map(|v| v.encode()).collect()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() % 32 != 0 {
+            return Err(anyhow!(
+                "expected a multiple of 32 bytes for Vec<AlkaneId>, got {}",
+                bytes.len()
+            ));
+        }
+        bytes.chunks(32).map(AlkaneId::decode).collect()
+    }
+}
+
+/// A 0 byte for `None`, or a 1 byte followed by the value.
+impl<T: StorageCodec> StorageCodec for Option<T> {
+    fn encode(&self) -> Vec<u8> {
+        match self {
+            Some(v) => {
+                let mut out = vec![1];
+                out.extend(v.encode());
+                out
+            }
+            None => vec![0],
+        }
+    }
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        match bytes.split_first() {
+            Some((0, [])) => Ok(None),
+            Some((1, rest)) => Ok(Some(T::decode(rest)?)),
+            _ => Err(anyhow!("expected a 0 or 1 tag for Option")),
+        }
+    }
+}
+
+impl<const N: usize> StorageCodec for [u8; N] {
+    fn encode(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        bytes
+            .try_into()
+            .map_err(|_| anyhow!("expected {} bytes, got {}", N, bytes.len()))
+    }
+    fn encode_field(&self, out: &mut Vec<u8>) {
+        out.extend(self);
+    }
+    fn decode_field(cursor: &mut Cursor<Vec<u8>>) -> Result<Self> {
+        Self::decode(&consume_exact(cursor, N)?)
+    }
+}
+
+impl<A: StorageCodec, B: StorageCodec> StorageCodec for (A, B) {
+    fn encode(&self) -> Vec<u8> {
+        let mut out = vec![];
+        self.0.encode_field(&mut out);
+        self.1.encode_field(&mut out);
+        out
+    }
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut cursor = Cursor::new(bytes.to_vec());
+        let result = (A::decode_field(&mut cursor)?, B::decode_field(&mut cursor)?);
+        if !is_empty(&mut cursor) {
+            return Err(anyhow!("trailing bytes after pair"));
+        }
+        Ok(result)
+    }
+}
+
+/// Packs bytes little endian sixteen to an input, the way the `MessageDispatch`
+/// derive reads strings, byte arrays and encoded structs from a cellpack.
+pub fn pack_words(bytes: &[u8]) -> Vec<u128> {
+    bytes
+        .chunks(16)
+        .map(|chunk| {
+            let mut word = [0u8; 16];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u128::from_le_bytes(word)
+        })
+        .collect()
+}
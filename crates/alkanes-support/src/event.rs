@@ -0,0 +1,70 @@
+use crate::id::AlkaneId;
+use crate::proto;
+use crate::utils::field_or_default;
+use anyhow::Result;
+use metashrew_support::utils::{consume_exact, consume_sized_int};
+use protobuf::MessageField;
+use std::io::Cursor;
+
+/// An event emitted by a contract through `__emit`.
+///
+/// Only `topics` and `data` cross the wasm boundary; `alkane` is filled in by the
+/// host with the id of the emitting contract.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct AlkaneEvent {
+    pub alkane: AlkaneId,
+    pub topics: Vec<Vec<u8>>,
+    pub data: Vec<u8>,
+}
+
+impl AlkaneEvent {
+    pub fn new(topics: Vec<Vec<u8>>, data: Vec<u8>) -> Self {
+        Self {
+            alkane: AlkaneId::default(),
+            topics,
+            data,
+        }
+    }
+    pub fn parse(cursor: &mut Cursor<Vec<u8>>) -> Result<AlkaneEvent> {
+        let mut result = AlkaneEvent::default();
+        let len = consume_sized_int::<u32>(cursor)?;
+        for _i in 0..len {
+            let topic_length: usize = consume_sized_int::<u32>(cursor)?.try_into()?;
+            result.topics.push(consume_exact(cursor, topic_length)?);
+        }
+        let data_length: usize = consume_sized_int::<u32>(cursor)?.try_into()?;
+        result.data = consume_exact(cursor, data_length)?;
+        Ok(result)
+    }
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut buffer = Vec::<u8>::new();
+        buffer.extend(&(self.topics.len() as u32).to_le_bytes());
+        for topic in self.topics.iter() {
+            buffer.extend(&(topic.len() as u32).to_le_bytes());
+            buffer.extend(topic);
+        }
+        buffer.extend(&(self.data.len() as u32).to_le_bytes());
+        buffer.extend(&self.data);
+        buffer
+    }
+}
+
+impl Into<proto::alkanes::AlkaneEvent> for AlkaneEvent {
+    fn into(self) -> proto::alkanes::AlkaneEvent {
+        let mut result = proto::alkanes::AlkaneEvent::new();
+        result.alkane = MessageField::some(self.alkane.into());
+        result.topics = self.topics;
+        result.data = self.data;
+        result
+    }
+}
+
+impl From<proto::alkanes::AlkaneEvent> for AlkaneEvent {
+    fn from(v: proto::alkanes::AlkaneEvent) -> AlkaneEvent {
+        AlkaneEvent {
+            alkane: field_or_default(v.alkane),
+            topics: v.topics,
+            data: v.data,
+        }
+    }
+}
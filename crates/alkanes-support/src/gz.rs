@@ -16,3 +16,16 @@ pub fn compress(binary: Vec<u8>) -> Result<Vec<u8>> {
     writer.write_all(&binary)?;
     Ok(writer.finish()?)
 }
+
+/// Decompresses at most `limit` bytes, returning `None` if the payload inflates
+/// to more than that.
+pub fn decompress_bounded(binary: &[u8], limit: usize) -> Result<Option<Vec<u8>>> {
+    let mut result = Vec::<u8>::new();
+    GzDecoder::new(binary)
+        .take((limit as u64).saturating_add(1))
+        .read_to_end(&mut result)?;
+    if result.len() > limit {
+        return Ok(None);
+    }
+    Ok(Some(result))
+}
@@ -1,3 +1,4 @@
+use crate::precompile::PRECOMPILE_BLOCK;
 use anyhow::Result;
 use metashrew_support::utils::consume_sized_int;
 use protorune_support::balance_sheet::ProtoruneRuneId;
@@ -74,6 +75,13 @@ impl AlkaneId {
             None
         }
     }
+    pub fn precompile(&self) -> Option<u128> {
+        if self.block == PRECOMPILE_BLOCK {
+            Some(self.tx)
+        } else {
+            None
+        }
+    }
     pub fn factory(&self) -> Option<AlkaneId> {
         if self.block == 5 {
             Some(AlkaneId {
@@ -1,10 +1,14 @@
+pub mod abi;
 pub mod cellpack;
+pub mod codec;
 pub mod constants;
 pub mod context;
 pub mod envelope;
+pub mod event;
 pub mod gz;
 pub mod id;
 pub mod parcel;
+pub mod precompile;
 pub mod proto;
 pub mod response;
 pub mod storage;
@@ -0,0 +1,81 @@
+use crate::cellpack::Cellpack;
+use crate::id::AlkaneId;
+use crate::utils::pack_bytes;
+
+/// Block of the reserved ids the VM answers natively instead of running wasm.
+/// Byte arguments are passed with `pack_bytes`; verifiers return a single byte,
+/// 1 when valid and 0 otherwise.
+pub const PRECOMPILE_BLOCK: u128 = 7;
+
+pub const SHA256: AlkaneId = AlkaneId {
+    block: PRECOMPILE_BLOCK,
+    tx: 1,
+};
+pub const RIPEMD160: AlkaneId = AlkaneId {
+    block: PRECOMPILE_BLOCK,
+    tx: 2,
+};
+pub const TAGGED_HASH: AlkaneId = AlkaneId {
+    block: PRECOMPILE_BLOCK,
+    tx: 3,
+};
+pub const SCHNORR_VERIFY: AlkaneId = AlkaneId {
+    block: PRECOMPILE_BLOCK,
+    tx: 4,
+};
+pub const ECDSA_VERIFY: AlkaneId = AlkaneId {
+    block: PRECOMPILE_BLOCK,
+    tx: 5,
+};
+pub const MERKLE_VERIFY: AlkaneId = AlkaneId {
+    block: PRECOMPILE_BLOCK,
+    tx: 6,
+};
+
+fn cellpack(target: AlkaneId, args: &[&[u8]]) -> Cellpack {
+    Cellpack {
+        target,
+        inputs: args.iter().flat_map(|v| pack_bytes(v)).collect(),
+    }
+}
+
+pub fn sha256(data: &[u8]) -> Cellpack {
+    cellpack(SHA256, &[data])
+}
+
+pub fn ripemd160(data: &[u8]) -> Cellpack {
+    cellpack(RIPEMD160, &[data])
+}
+
+/// BIP340 tagged hash, `sha256(sha256(tag) || sha256(tag) || data)`.
+pub fn tagged_hash(tag: &[u8], data: &[u8]) -> Cellpack {
+    cellpack(TAGGED_HASH, &[tag, data])
+}
+
+/// BIP340 signature over a 32-byte message by an x-only public key.
+pub fn schnorr_verify(pubkey: &[u8], message: &[u8], signature: &[u8]) -> Cellpack {
+    cellpack(SCHNORR_VERIFY, &[pubkey, message, signature])
+}
+
+/// Compact 64-byte ECDSA signature over a 32-byte message by a serialized
+/// public key.
+pub fn ecdsa_verify(pubkey: &[u8], message: &[u8], signature: &[u8]) -> Cellpack {
+    cellpack(ECDSA_VERIFY, &[pubkey, message, signature])
+}
+
+/// SHA-256 merkle proof of `leaf` at `index` in a tree of `leaves`, laid out as
+/// `rs_merkle` builds it: a node without a sibling moves up unchanged. `proof`
+/// is the concatenated 32-byte siblings from the leaf up.
+pub fn merkle_verify(
+    root: &[u8],
+    leaf: &[u8],
+    index: u128,
+    leaves: u128,
+    proof: &[u8],
+) -> Cellpack {
+    let mut result = cellpack(MERKLE_VERIFY, &[root, leaf]);
+    result.inputs.push(index);
+    result.inputs.push(leaves);
+    result.inputs.extend(pack_bytes(proof));
+    result
+}
@@ -593,6 +593,10 @@ pub struct MessageContextParcel {
     pub pointer: u32,
     // @@protoc_insertion_point(field:alkanes.MessageContextParcel.refund_pointer)
     pub refund_pointer: u32,
+    // @@protoc_insertion_point(field:alkanes.MessageContextParcel.overrides)
+    pub overrides: ::protobuf::MessageField<StateOverrides>,
+    // @@protoc_insertion_point(field:alkanes.MessageContextParcel.profile)
+    pub profile: bool,
     // special fields
     // @@protoc_insertion_point(special_field:alkanes.MessageContextParcel.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -610,7 +614,7 @@ impl MessageContextParcel {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut fields = ::std::vec::Vec::with_capacity(11);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "alkanes",
@@ -657,6 +661,16 @@ impl MessageContextParcel {
             |m: &MessageContextParcel| { &m.refund_pointer },
             |m: &mut MessageContextParcel| { &mut m.refund_pointer },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, StateOverrides>(
+            "overrides",
+            |m: &MessageContextParcel| { &m.overrides },
+            |m: &mut MessageContextParcel| { &mut m.overrides },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "profile",
+            |m: &MessageContextParcel| { &m.profile },
+            |m: &mut MessageContextParcel| { &mut m.profile },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MessageContextParcel>(
             "MessageContextParcel",
             fields,
@@ -702,6 +716,12 @@ impl ::protobuf::Message for MessageContextParcel {
                 72 => {
                     self.refund_pointer = is.read_uint32()?;
                 },
+                82 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.overrides)?;
+                },
+                88 => {
+                    self.profile = is.read_bool()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -742,6 +762,13 @@ impl ::protobuf::Message for MessageContextParcel {
         if self.refund_pointer != 0 {
             my_size += ::protobuf::rt::uint32_size(9, self.refund_pointer);
         }
+        if let Some(v) = self.overrides.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.profile != false {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -775,6 +802,12 @@ impl ::protobuf::Message for MessageContextParcel {
         if self.refund_pointer != 0 {
             os.write_uint32(9, self.refund_pointer)?;
         }
+        if let Some(v) = self.overrides.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
+        if self.profile != false {
+            os.write_bool(11, self.profile)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -801,6 +834,8 @@ impl ::protobuf::Message for MessageContextParcel {
         self.vout = 0;
         self.pointer = 0;
         self.refund_pointer = 0;
+        self.overrides.clear();
+        self.profile = false;
         self.special_fields.clear();
     }
 
@@ -815,6 +850,8 @@ impl ::protobuf::Message for MessageContextParcel {
             vout: 0,
             pointer: 0,
             refund_pointer: 0,
+            overrides: ::protobuf::MessageField::none(),
+            profile: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -978,6 +1015,308 @@ impl ::protobuf::reflect::ProtobufValue for KeyValuePair {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+// @@protoc_insertion_point(message:alkanes.AlkaneStateOverride)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneStateOverride {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneStateOverride.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneStateOverride.bytecode)
+    pub bytecode: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneStateOverride.storage)
+    pub storage: ::std::vec::Vec<KeyValuePair>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneStateOverride.balances)
+    pub balances: ::std::vec::Vec<AlkaneTransfer>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneStateOverride.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneStateOverride {
+    fn default() -> &'a AlkaneStateOverride {
+        <AlkaneStateOverride as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneStateOverride {
+    pub fn new() -> AlkaneStateOverride {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &AlkaneStateOverride| { &m.id },
+            |m: &mut AlkaneStateOverride| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bytecode",
+            |m: &AlkaneStateOverride| { &m.bytecode },
+            |m: &mut AlkaneStateOverride| { &mut m.bytecode },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "storage",
+            |m: &AlkaneStateOverride| { &m.storage },
+            |m: &mut AlkaneStateOverride| { &mut m.storage },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "balances",
+            |m: &AlkaneStateOverride| { &m.balances },
+            |m: &mut AlkaneStateOverride| { &mut m.balances },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneStateOverride>(
+            "AlkaneStateOverride",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneStateOverride {
+    const NAME: &'static str = "AlkaneStateOverride";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                },
+                18 => {
+                    self.bytecode = is.read_bytes()?;
+                },
+                26 => {
+                    self.storage.push(is.read_message()?);
+                },
+                34 => {
+                    self.balances.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.bytecode.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.bytecode);
+        }
+        for value in &self.storage {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.balances {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.id.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.bytecode.is_empty() {
+            os.write_bytes(2, &self.bytecode)?;
+        }
+        for v in &self.storage {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        for v in &self.balances {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkaneStateOverride {
+        AlkaneStateOverride::new()
+    }
+
+    fn clear(&mut self) {
+        self.id.clear();
+        self.bytecode.clear();
+        self.storage.clear();
+        self.balances.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkaneStateOverride {
+        static instance: AlkaneStateOverride = AlkaneStateOverride {
+            id: ::protobuf::MessageField::none(),
+            bytecode: ::std::vec::Vec::new(),
+            storage: ::std::vec::Vec::new(),
+            balances: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkaneStateOverride {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneStateOverride").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkaneStateOverride {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkaneStateOverride {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.StateOverrides)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct StateOverrides {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.StateOverrides.alkanes)
+    pub alkanes: ::std::vec::Vec<AlkaneStateOverride>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.StateOverrides.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a StateOverrides {
+    fn default() -> &'a StateOverrides {
+        <StateOverrides as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StateOverrides {
+    pub fn new() -> StateOverrides {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "alkanes",
+            |m: &StateOverrides| { &m.alkanes },
+            |m: &mut StateOverrides| { &mut m.alkanes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<StateOverrides>(
+            "StateOverrides",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for StateOverrides {
+    const NAME: &'static str = "StateOverrides";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.alkanes.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.alkanes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.alkanes {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> StateOverrides {
+        StateOverrides::new()
+    }
+
+    fn clear(&mut self) {
+        self.alkanes.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static StateOverrides {
+        static instance: StateOverrides = StateOverrides {
+            alkanes: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for StateOverrides {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("StateOverrides").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for StateOverrides {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StateOverrides {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 // @@protoc_insertion_point(message:alkanes.ExtendedCallResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
 pub struct ExtendedCallResponse {
@@ -1618,53 +1957,60 @@ impl ::protobuf::reflect::ProtobufValue for AlkanesEnterContext {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkanesExitContext)
+// @@protoc_insertion_point(message:alkanes.StorageRead)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkanesExitContext {
+pub struct StorageRead {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.status)
-    pub status: ::protobuf::EnumOrUnknown<AlkanesTraceStatusFlag>,
-    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.response)
-    pub response: ::protobuf::MessageField<ExtendedCallResponse>,
+    // @@protoc_insertion_point(field:alkanes.StorageRead.key)
+    pub key: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.StorageRead.value)
+    pub value: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.StorageRead.owner)
+    pub owner: ::protobuf::MessageField<AlkaneId>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkanesExitContext.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.StorageRead.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkanesExitContext {
-    fn default() -> &'a AlkanesExitContext {
-        <AlkanesExitContext as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a StorageRead {
+    fn default() -> &'a StorageRead {
+        <StorageRead as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkanesExitContext {
-    pub fn new() -> AlkanesExitContext {
+impl StorageRead {
+    pub fn new() -> StorageRead {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "status",
-            |m: &AlkanesExitContext| { &m.status },
-            |m: &mut AlkanesExitContext| { &mut m.status },
+            "key",
+            |m: &StorageRead| { &m.key },
+            |m: &mut StorageRead| { &mut m.key },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ExtendedCallResponse>(
-            "response",
-            |m: &AlkanesExitContext| { &m.response },
-            |m: &mut AlkanesExitContext| { &mut m.response },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "value",
+            |m: &StorageRead| { &m.value },
+            |m: &mut StorageRead| { &mut m.value },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesExitContext>(
-            "AlkanesExitContext",
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "owner",
+            |m: &StorageRead| { &m.owner },
+            |m: &mut StorageRead| { &mut m.owner },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<StorageRead>(
+            "StorageRead",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkanesExitContext {
-    const NAME: &'static str = "AlkanesExitContext";
+impl ::protobuf::Message for StorageRead {
+    const NAME: &'static str = "StorageRead";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1673,11 +2019,14 @@ impl ::protobuf::Message for AlkanesExitContext {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.status = is.read_enum_or_unknown()?;
+                10 => {
+                    self.key = is.read_bytes()?;
                 },
                 18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.response)?;
+                    self.value = is.read_bytes()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.owner)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1691,10 +2040,13 @@ impl ::protobuf::Message for AlkanesExitContext {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.status != ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS) {
-            my_size += ::protobuf::rt::int32_size(1, self.status.value());
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
         }
-        if let Some(v) = self.response.as_ref() {
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        if let Some(v) = self.owner.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
@@ -1704,11 +2056,14 @@ impl ::protobuf::Message for AlkanesExitContext {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.status != ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS) {
-            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
         }
-        if let Some(v) = self.response.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        if let Some(v) = self.owner.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1722,83 +2077,99 @@ impl ::protobuf::Message for AlkanesExitContext {
         &mut self.special_fields
     }
 
-    fn new() -> AlkanesExitContext {
-        AlkanesExitContext::new()
+    fn new() -> StorageRead {
+        StorageRead::new()
     }
 
     fn clear(&mut self) {
-        self.status = ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS);
-        self.response.clear();
+        self.key.clear();
+        self.value.clear();
+        self.owner.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkanesExitContext {
-        static instance: AlkanesExitContext = AlkanesExitContext {
-            status: ::protobuf::EnumOrUnknown::from_i32(0),
-            response: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static StorageRead {
+        static instance: StorageRead = StorageRead {
+            key: ::std::vec::Vec::new(),
+            value: ::std::vec::Vec::new(),
+            owner: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkanesExitContext {
+impl ::protobuf::MessageFull for StorageRead {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesExitContext").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("StorageRead").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkanesExitContext {
+impl ::std::fmt::Display for StorageRead {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkanesExitContext {
+impl ::protobuf::reflect::ProtobufValue for StorageRead {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkanesCreate)
+// @@protoc_insertion_point(message:alkanes.StorageWrite)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkanesCreate {
+pub struct StorageWrite {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkanesCreate.new_alkane)
-    pub new_alkane: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.StorageWrite.key)
+    pub key: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.StorageWrite.old_value)
+    pub old_value: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.StorageWrite.new_value)
+    pub new_value: ::std::vec::Vec<u8>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkanesCreate.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.StorageWrite.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkanesCreate {
-    fn default() -> &'a AlkanesCreate {
-        <AlkanesCreate as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a StorageWrite {
+    fn default() -> &'a StorageWrite {
+        <StorageWrite as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkanesCreate {
-    pub fn new() -> AlkanesCreate {
+impl StorageWrite {
+    pub fn new() -> StorageWrite {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
-            "new_alkane",
-            |m: &AlkanesCreate| { &m.new_alkane },
-            |m: &mut AlkanesCreate| { &mut m.new_alkane },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "key",
+            |m: &StorageWrite| { &m.key },
+            |m: &mut StorageWrite| { &mut m.key },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesCreate>(
-            "AlkanesCreate",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "old_value",
+            |m: &StorageWrite| { &m.old_value },
+            |m: &mut StorageWrite| { &mut m.old_value },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "new_value",
+            |m: &StorageWrite| { &m.new_value },
+            |m: &mut StorageWrite| { &mut m.new_value },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<StorageWrite>(
+            "StorageWrite",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkanesCreate {
-    const NAME: &'static str = "AlkanesCreate";
+impl ::protobuf::Message for StorageWrite {
+    const NAME: &'static str = "StorageWrite";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1808,7 +2179,13 @@ impl ::protobuf::Message for AlkanesCreate {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.new_alkane)?;
+                    self.key = is.read_bytes()?;
+                },
+                18 => {
+                    self.old_value = is.read_bytes()?;
+                },
+                26 => {
+                    self.new_value = is.read_bytes()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1822,9 +2199,14 @@ impl ::protobuf::Message for AlkanesCreate {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.new_alkane.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if !self.old_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.old_value);
+        }
+        if !self.new_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.new_value);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1832,8 +2214,14 @@ impl ::protobuf::Message for AlkanesCreate {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.new_alkane.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if !self.old_value.is_empty() {
+            os.write_bytes(2, &self.old_value)?;
+        }
+        if !self.new_value.is_empty() {
+            os.write_bytes(3, &self.new_value)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1847,150 +2235,813 @@ impl ::protobuf::Message for AlkanesCreate {
         &mut self.special_fields
     }
 
-    fn new() -> AlkanesCreate {
-        AlkanesCreate::new()
+    fn new() -> StorageWrite {
+        StorageWrite::new()
     }
 
     fn clear(&mut self) {
-        self.new_alkane.clear();
+        self.key.clear();
+        self.old_value.clear();
+        self.new_value.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkanesCreate {
-        static instance: AlkanesCreate = AlkanesCreate {
-            new_alkane: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static StorageWrite {
+        static instance: StorageWrite = StorageWrite {
+            key: ::std::vec::Vec::new(),
+            old_value: ::std::vec::Vec::new(),
+            new_value: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkanesCreate {
+impl ::protobuf::MessageFull for StorageWrite {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesCreate").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("StorageWrite").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkanesCreate {
+impl ::std::fmt::Display for StorageWrite {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkanesCreate {
+impl ::protobuf::reflect::ProtobufValue for StorageWrite {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkanesTraceEvent)
+// @@protoc_insertion_point(message:alkanes.TraceTransfer)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkanesTraceEvent {
-    // message oneof groups
-    pub event: ::std::option::Option<alkanes_trace_event::Event>,
+pub struct TraceTransfer {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.TraceTransfer.from)
+    pub from: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.TraceTransfer.to)
+    pub to: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.TraceTransfer.transfer)
+    pub transfer: ::protobuf::MessageField<AlkaneTransfer>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkanesTraceEvent.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.TraceTransfer.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkanesTraceEvent {
-    fn default() -> &'a AlkanesTraceEvent {
-        <AlkanesTraceEvent as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TraceTransfer {
+    fn default() -> &'a TraceTransfer {
+        <TraceTransfer as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkanesTraceEvent {
-    pub fn new() -> AlkanesTraceEvent {
+impl TraceTransfer {
+    pub fn new() -> TraceTransfer {
         ::std::default::Default::default()
     }
 
-    // .alkanes.AlkanesEnterContext enter_context = 1;
-
-    pub fn enter_context(&self) -> &AlkanesEnterContext {
-        match self.event {
-            ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(ref v)) => v,
-            _ => <AlkanesEnterContext as ::protobuf::Message>::default_instance(),
-        }
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "from",
+            |m: &TraceTransfer| { &m.from },
+            |m: &mut TraceTransfer| { &mut m.from },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "to",
+            |m: &TraceTransfer| { &m.to },
+            |m: &mut TraceTransfer| { &mut m.to },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneTransfer>(
+            "transfer",
+            |m: &TraceTransfer| { &m.transfer },
+            |m: &mut TraceTransfer| { &mut m.transfer },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TraceTransfer>(
+            "TraceTransfer",
+            fields,
+            oneofs,
+        )
     }
+}
 
-    pub fn clear_enter_context(&mut self) {
-        self.event = ::std::option::Option::None;
+impl ::protobuf::Message for TraceTransfer {
+    const NAME: &'static str = "TraceTransfer";
+
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    pub fn has_enter_context(&self) -> bool {
-        match self.event {
-            ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(..)) => true,
-            _ => false,
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.from)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.to)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.transfer)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_enter_context(&mut self, v: AlkanesEnterContext) {
-        self.event = ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(v))
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.from.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.to.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.transfer.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_enter_context(&mut self) -> &mut AlkanesEnterContext {
-        if let ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(_)) = self.event {
-        } else {
-            self.event = ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(AlkanesEnterContext::new()));
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.from.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         }
-        match self.event {
-            ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(ref mut v)) => v,
-            _ => panic!(),
+        if let Some(v) = self.to.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.transfer.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
         }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // Take field
-    pub fn take_enter_context(&mut self) -> AlkanesEnterContext {
-        if self.has_enter_context() {
-            match self.event.take() {
-                ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            AlkanesEnterContext::new()
-        }
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
     }
 
-    // .alkanes.AlkanesExitContext exit_context = 2;
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
 
-    pub fn exit_context(&self) -> &AlkanesExitContext {
-        match self.event {
-            ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(ref v)) => v,
-            _ => <AlkanesExitContext as ::protobuf::Message>::default_instance(),
-        }
+    fn new() -> TraceTransfer {
+        TraceTransfer::new()
     }
 
-    pub fn clear_exit_context(&mut self) {
-        self.event = ::std::option::Option::None;
+    fn clear(&mut self) {
+        self.from.clear();
+        self.to.clear();
+        self.transfer.clear();
+        self.special_fields.clear();
     }
 
-    pub fn has_exit_context(&self) -> bool {
-        match self.event {
-            ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(..)) => true,
-            _ => false,
-        }
+    fn default_instance() -> &'static TraceTransfer {
+        static instance: TraceTransfer = TraceTransfer {
+            from: ::protobuf::MessageField::none(),
+            to: ::protobuf::MessageField::none(),
+            transfer: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_exit_context(&mut self, v: AlkanesExitContext) {
-        self.event = ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(v))
+impl ::protobuf::MessageFull for TraceTransfer {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TraceTransfer").unwrap()).clone()
     }
+}
 
-    // Mutable pointer to the field.
-    pub fn mut_exit_context(&mut self) -> &mut AlkanesExitContext {
-        if let ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(_)) = self.event {
-        } else {
-            self.event = ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(AlkanesExitContext::new()));
-        }
-        match self.event {
-            ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(ref mut v)) => v,
-            _ => panic!(),
-        }
+impl ::std::fmt::Display for TraceTransfer {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Take field
-    pub fn take_exit_context(&mut self) -> AlkanesExitContext {
+impl ::protobuf::reflect::ProtobufValue for TraceTransfer {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkanesExitContext)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkanesExitContext {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.status)
+    pub status: ::protobuf::EnumOrUnknown<AlkanesTraceStatusFlag>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.response)
+    pub response: ::protobuf::MessageField<ExtendedCallResponse>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.fuel_used)
+    pub fuel_used: u64,
+    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.storage_reads)
+    pub storage_reads: ::std::vec::Vec<StorageRead>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.storage_writes)
+    pub storage_writes: ::std::vec::Vec<StorageWrite>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesExitContext.transfers)
+    pub transfers: ::std::vec::Vec<TraceTransfer>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkanesExitContext.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkanesExitContext {
+    fn default() -> &'a AlkanesExitContext {
+        <AlkanesExitContext as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkanesExitContext {
+    pub fn new() -> AlkanesExitContext {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &AlkanesExitContext| { &m.status },
+            |m: &mut AlkanesExitContext| { &mut m.status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ExtendedCallResponse>(
+            "response",
+            |m: &AlkanesExitContext| { &m.response },
+            |m: &mut AlkanesExitContext| { &mut m.response },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "fuel_used",
+            |m: &AlkanesExitContext| { &m.fuel_used },
+            |m: &mut AlkanesExitContext| { &mut m.fuel_used },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "storage_reads",
+            |m: &AlkanesExitContext| { &m.storage_reads },
+            |m: &mut AlkanesExitContext| { &mut m.storage_reads },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "storage_writes",
+            |m: &AlkanesExitContext| { &m.storage_writes },
+            |m: &mut AlkanesExitContext| { &mut m.storage_writes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "transfers",
+            |m: &AlkanesExitContext| { &m.transfers },
+            |m: &mut AlkanesExitContext| { &mut m.transfers },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesExitContext>(
+            "AlkanesExitContext",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkanesExitContext {
+    const NAME: &'static str = "AlkanesExitContext";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.response)?;
+                },
+                24 => {
+                    self.fuel_used = is.read_uint64()?;
+                },
+                34 => {
+                    self.storage_reads.push(is.read_message()?);
+                },
+                42 => {
+                    self.storage_writes.push(is.read_message()?);
+                },
+                50 => {
+                    self.transfers.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.status != ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS) {
+            my_size += ::protobuf::rt::int32_size(1, self.status.value());
+        }
+        if let Some(v) = self.response.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.fuel_used != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.fuel_used);
+        }
+        for value in &self.storage_reads {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.storage_writes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.transfers {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.status != ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        if let Some(v) = self.response.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.fuel_used != 0 {
+            os.write_uint64(3, self.fuel_used)?;
+        }
+        for v in &self.storage_reads {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        for v in &self.storage_writes {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        for v in &self.transfers {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkanesExitContext {
+        AlkanesExitContext::new()
+    }
+
+    fn clear(&mut self) {
+        self.status = ::protobuf::EnumOrUnknown::new(AlkanesTraceStatusFlag::SUCCESS);
+        self.response.clear();
+        self.fuel_used = 0;
+        self.storage_reads.clear();
+        self.storage_writes.clear();
+        self.transfers.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkanesExitContext {
+        static instance: AlkanesExitContext = AlkanesExitContext {
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            response: ::protobuf::MessageField::none(),
+            fuel_used: 0,
+            storage_reads: ::std::vec::Vec::new(),
+            storage_writes: ::std::vec::Vec::new(),
+            transfers: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkanesExitContext {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesExitContext").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkanesExitContext {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkanesExitContext {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkanesCreate)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkanesCreate {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkanesCreate.new_alkane)
+    pub new_alkane: ::protobuf::MessageField<AlkaneId>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkanesCreate.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkanesCreate {
+    fn default() -> &'a AlkanesCreate {
+        <AlkanesCreate as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkanesCreate {
+    pub fn new() -> AlkanesCreate {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "new_alkane",
+            |m: &AlkanesCreate| { &m.new_alkane },
+            |m: &mut AlkanesCreate| { &mut m.new_alkane },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesCreate>(
+            "AlkanesCreate",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkanesCreate {
+    const NAME: &'static str = "AlkanesCreate";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.new_alkane)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.new_alkane.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.new_alkane.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkanesCreate {
+        AlkanesCreate::new()
+    }
+
+    fn clear(&mut self) {
+        self.new_alkane.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkanesCreate {
+        static instance: AlkanesCreate = AlkanesCreate {
+            new_alkane: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkanesCreate {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesCreate").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkanesCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkanesCreate {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkaneEvent)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneEvent {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneEvent.alkane)
+    pub alkane: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneEvent.topics)
+    pub topics: ::std::vec::Vec<::std::vec::Vec<u8>>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneEvent.data)
+    pub data: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneEvent {
+    fn default() -> &'a AlkaneEvent {
+        <AlkaneEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneEvent {
+    pub fn new() -> AlkaneEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "alkane",
+            |m: &AlkaneEvent| { &m.alkane },
+            |m: &mut AlkaneEvent| { &mut m.alkane },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "topics",
+            |m: &AlkaneEvent| { &m.topics },
+            |m: &mut AlkaneEvent| { &mut m.topics },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data",
+            |m: &AlkaneEvent| { &m.data },
+            |m: &mut AlkaneEvent| { &mut m.data },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneEvent>(
+            "AlkaneEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneEvent {
+    const NAME: &'static str = "AlkaneEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.alkane)?;
+                },
+                18 => {
+                    self.topics.push(is.read_bytes()?);
+                },
+                26 => {
+                    self.data = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.alkane.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.topics {
+            my_size += ::protobuf::rt::bytes_size(2, &value);
+        };
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.alkane.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        for v in &self.topics {
+            os.write_bytes(2, &v)?;
+        };
+        if !self.data.is_empty() {
+            os.write_bytes(3, &self.data)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkaneEvent {
+        AlkaneEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.alkane.clear();
+        self.topics.clear();
+        self.data.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkaneEvent {
+        static instance: AlkaneEvent = AlkaneEvent {
+            alkane: ::protobuf::MessageField::none(),
+            topics: ::std::vec::Vec::new(),
+            data: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkaneEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkaneEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkaneEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkanesTraceEvent)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkanesTraceEvent {
+    // message oneof groups
+    pub event: ::std::option::Option<alkanes_trace_event::Event>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkanesTraceEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkanesTraceEvent {
+    fn default() -> &'a AlkanesTraceEvent {
+        <AlkanesTraceEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkanesTraceEvent {
+    pub fn new() -> AlkanesTraceEvent {
+        ::std::default::Default::default()
+    }
+
+    // .alkanes.AlkanesEnterContext enter_context = 1;
+
+    pub fn enter_context(&self) -> &AlkanesEnterContext {
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(ref v)) => v,
+            _ => <AlkanesEnterContext as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_enter_context(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_enter_context(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enter_context(&mut self, v: AlkanesEnterContext) {
+        self.event = ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_enter_context(&mut self) -> &mut AlkanesEnterContext {
+        if let ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(AlkanesEnterContext::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_enter_context(&mut self) -> AlkanesEnterContext {
+        if self.has_enter_context() {
+            match self.event.take() {
+                ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AlkanesEnterContext::new()
+        }
+    }
+
+    // .alkanes.AlkanesExitContext exit_context = 2;
+
+    pub fn exit_context(&self) -> &AlkanesExitContext {
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(ref v)) => v,
+            _ => <AlkanesExitContext as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_exit_context(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_exit_context(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exit_context(&mut self, v: AlkanesExitContext) {
+        self.event = ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_exit_context(&mut self) -> &mut AlkanesExitContext {
+        if let ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(AlkanesExitContext::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_exit_context(&mut self) -> AlkanesExitContext {
         if self.has_exit_context() {
             match self.event.take() {
                 ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(v)) => v,
@@ -2001,90 +3052,3328 @@ impl AlkanesTraceEvent {
         }
     }
 
-    // .alkanes.AlkanesCreate create_alkane = 3;
+    // .alkanes.AlkanesCreate create_alkane = 3;
+
+    pub fn create_alkane(&self) -> &AlkanesCreate {
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(ref v)) => v,
+            _ => <AlkanesCreate as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_create_alkane(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_create_alkane(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_alkane(&mut self, v: AlkanesCreate) {
+        self.event = ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_create_alkane(&mut self) -> &mut AlkanesCreate {
+        if let ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(AlkanesCreate::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_create_alkane(&mut self) -> AlkanesCreate {
+        if self.has_create_alkane() {
+            match self.event.take() {
+                ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AlkanesCreate::new()
+        }
+    }
+
+    // .alkanes.AlkaneEvent emit = 4;
+
+    pub fn emit(&self) -> &AlkaneEvent {
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::Emit(ref v)) => v,
+            _ => <AlkaneEvent as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_emit(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_emit(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::Emit(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_emit(&mut self, v: AlkaneEvent) {
+        self.event = ::std::option::Option::Some(alkanes_trace_event::Event::Emit(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_emit(&mut self) -> &mut AlkaneEvent {
+        if let ::std::option::Option::Some(alkanes_trace_event::Event::Emit(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(alkanes_trace_event::Event::Emit(AlkaneEvent::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(alkanes_trace_event::Event::Emit(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_emit(&mut self) -> AlkaneEvent {
+        if self.has_emit() {
+            match self.event.take() {
+                ::std::option::Option::Some(alkanes_trace_event::Event::Emit(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AlkaneEvent::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AlkanesEnterContext>(
+            "enter_context",
+            AlkanesTraceEvent::has_enter_context,
+            AlkanesTraceEvent::enter_context,
+            AlkanesTraceEvent::mut_enter_context,
+            AlkanesTraceEvent::set_enter_context,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AlkanesExitContext>(
+            "exit_context",
+            AlkanesTraceEvent::has_exit_context,
+            AlkanesTraceEvent::exit_context,
+            AlkanesTraceEvent::mut_exit_context,
+            AlkanesTraceEvent::set_exit_context,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AlkanesCreate>(
+            "create_alkane",
+            AlkanesTraceEvent::has_create_alkane,
+            AlkanesTraceEvent::create_alkane,
+            AlkanesTraceEvent::mut_create_alkane,
+            AlkanesTraceEvent::set_create_alkane,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AlkaneEvent>(
+            "emit",
+            AlkanesTraceEvent::has_emit,
+            AlkanesTraceEvent::emit,
+            AlkanesTraceEvent::mut_emit,
+            AlkanesTraceEvent::set_emit,
+        ));
+        oneofs.push(alkanes_trace_event::Event::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesTraceEvent>(
+            "AlkanesTraceEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkanesTraceEvent {
+    const NAME: &'static str = "AlkanesTraceEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.event = ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(is.read_message()?));
+                },
+                18 => {
+                    self.event = ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(is.read_message()?));
+                },
+                26 => {
+                    self.event = ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(is.read_message()?));
+                },
+                34 => {
+                    self.event = ::std::option::Option::Some(alkanes_trace_event::Event::Emit(is.read_message()?));
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.event {
+            match v {
+                &alkanes_trace_event::Event::EnterContext(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &alkanes_trace_event::Event::ExitContext(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &alkanes_trace_event::Event::CreateAlkane(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &alkanes_trace_event::Event::Emit(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let ::std::option::Option::Some(ref v) = self.event {
+            match v {
+                &alkanes_trace_event::Event::EnterContext(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &alkanes_trace_event::Event::ExitContext(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &alkanes_trace_event::Event::CreateAlkane(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+                },
+                &alkanes_trace_event::Event::Emit(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkanesTraceEvent {
+        AlkanesTraceEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkanesTraceEvent {
+        static instance: AlkanesTraceEvent = AlkanesTraceEvent {
+            event: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkanesTraceEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesTraceEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkanesTraceEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkanesTraceEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `AlkanesTraceEvent`
+pub mod alkanes_trace_event {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:alkanes.AlkanesTraceEvent.event)
+    pub enum Event {
+        // @@protoc_insertion_point(oneof_field:alkanes.AlkanesTraceEvent.enter_context)
+        EnterContext(super::AlkanesEnterContext),
+        // @@protoc_insertion_point(oneof_field:alkanes.AlkanesTraceEvent.exit_context)
+        ExitContext(super::AlkanesExitContext),
+        // @@protoc_insertion_point(oneof_field:alkanes.AlkanesTraceEvent.create_alkane)
+        CreateAlkane(super::AlkanesCreate),
+        // @@protoc_insertion_point(oneof_field:alkanes.AlkanesTraceEvent.emit)
+        Emit(super::AlkaneEvent),
+    }
+
+    impl ::protobuf::Oneof for Event {
+    }
+
+    impl ::protobuf::OneofFull for Event {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::AlkanesTraceEvent as ::protobuf::MessageFull>::descriptor().oneof_by_name("event").unwrap()).clone()
+        }
+    }
+
+    impl Event {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Event>("event")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkanesBlockEvent)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkanesBlockEvent {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkanesBlockEvent.traces)
+    pub traces: ::protobuf::MessageField<AlkanesTrace>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesBlockEvent.outpoint)
+    pub outpoint: ::protobuf::MessageField<Outpoint>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesBlockEvent.txindex)
+    pub txindex: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkanesBlockEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkanesBlockEvent {
+    fn default() -> &'a AlkanesBlockEvent {
+        <AlkanesBlockEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkanesBlockEvent {
+    pub fn new() -> AlkanesBlockEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkanesTrace>(
+            "traces",
+            |m: &AlkanesBlockEvent| { &m.traces },
+            |m: &mut AlkanesBlockEvent| { &mut m.traces },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
+            "outpoint",
+            |m: &AlkanesBlockEvent| { &m.outpoint },
+            |m: &mut AlkanesBlockEvent| { &mut m.outpoint },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "txindex",
+            |m: &AlkanesBlockEvent| { &m.txindex },
+            |m: &mut AlkanesBlockEvent| { &mut m.txindex },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesBlockEvent>(
+            "AlkanesBlockEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkanesBlockEvent {
+    const NAME: &'static str = "AlkanesBlockEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.traces)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
+                },
+                24 => {
+                    self.txindex = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.traces.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.outpoint.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.txindex != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.txindex);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.traces.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.outpoint.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.txindex != 0 {
+            os.write_uint64(3, self.txindex)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkanesBlockEvent {
+        AlkanesBlockEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.traces.clear();
+        self.outpoint.clear();
+        self.txindex = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkanesBlockEvent {
+        static instance: AlkanesBlockEvent = AlkanesBlockEvent {
+            traces: ::protobuf::MessageField::none(),
+            outpoint: ::protobuf::MessageField::none(),
+            txindex: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkanesBlockEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesBlockEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkanesBlockEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkanesBlockEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkanesBlockTraceEvent)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkanesBlockTraceEvent {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkanesBlockTraceEvent.events)
+    pub events: ::std::vec::Vec<AlkanesBlockEvent>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkanesBlockTraceEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkanesBlockTraceEvent {
+    fn default() -> &'a AlkanesBlockTraceEvent {
+        <AlkanesBlockTraceEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkanesBlockTraceEvent {
+    pub fn new() -> AlkanesBlockTraceEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &AlkanesBlockTraceEvent| { &m.events },
+            |m: &mut AlkanesBlockTraceEvent| { &mut m.events },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesBlockTraceEvent>(
+            "AlkanesBlockTraceEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkanesBlockTraceEvent {
+    const NAME: &'static str = "AlkanesBlockTraceEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.events.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkanesBlockTraceEvent {
+        AlkanesBlockTraceEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.events.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkanesBlockTraceEvent {
+        static instance: AlkanesBlockTraceEvent = AlkanesBlockTraceEvent {
+            events: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkanesBlockTraceEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesBlockTraceEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkanesBlockTraceEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkanesBlockTraceEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkanesTrace)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkanesTrace {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkanesTrace.events)
+    pub events: ::std::vec::Vec<AlkanesTraceEvent>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkanesTrace.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkanesTrace {
+    fn default() -> &'a AlkanesTrace {
+        <AlkanesTrace as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkanesTrace {
+    pub fn new() -> AlkanesTrace {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &AlkanesTrace| { &m.events },
+            |m: &mut AlkanesTrace| { &mut m.events },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesTrace>(
+            "AlkanesTrace",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkanesTrace {
+    const NAME: &'static str = "AlkanesTrace";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.events.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkanesTrace {
+        AlkanesTrace::new()
+    }
+
+    fn clear(&mut self) {
+        self.events.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkanesTrace {
+        static instance: AlkanesTrace = AlkanesTrace {
+            events: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkanesTrace {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesTrace").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkanesTrace {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkanesTrace {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.SimulateResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SimulateResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.SimulateResponse.execution)
+    pub execution: ::protobuf::MessageField<ExtendedCallResponse>,
+    // @@protoc_insertion_point(field:alkanes.SimulateResponse.gas_used)
+    pub gas_used: u64,
+    // @@protoc_insertion_point(field:alkanes.SimulateResponse.error)
+    pub error: ::std::string::String,
+    // @@protoc_insertion_point(field:alkanes.SimulateResponse.trace)
+    pub trace: ::protobuf::MessageField<AlkanesTrace>,
+    // @@protoc_insertion_point(field:alkanes.SimulateResponse.profile)
+    pub profile: ::protobuf::MessageField<FuelProfile>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.SimulateResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SimulateResponse {
+    fn default() -> &'a SimulateResponse {
+        <SimulateResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SimulateResponse {
+    pub fn new() -> SimulateResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ExtendedCallResponse>(
+            "execution",
+            |m: &SimulateResponse| { &m.execution },
+            |m: &mut SimulateResponse| { &mut m.execution },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "gas_used",
+            |m: &SimulateResponse| { &m.gas_used },
+            |m: &mut SimulateResponse| { &mut m.gas_used },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &SimulateResponse| { &m.error },
+            |m: &mut SimulateResponse| { &mut m.error },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkanesTrace>(
+            "trace",
+            |m: &SimulateResponse| { &m.trace },
+            |m: &mut SimulateResponse| { &mut m.trace },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, FuelProfile>(
+            "profile",
+            |m: &SimulateResponse| { &m.profile },
+            |m: &mut SimulateResponse| { &mut m.profile },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SimulateResponse>(
+            "SimulateResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SimulateResponse {
+    const NAME: &'static str = "SimulateResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.execution)?;
+                },
+                16 => {
+                    self.gas_used = is.read_uint64()?;
+                },
+                26 => {
+                    self.error = is.read_string()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.trace)?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.profile)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.execution.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.gas_used != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.gas_used);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.error);
+        }
+        if let Some(v) = self.trace.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.profile.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.execution.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if self.gas_used != 0 {
+            os.write_uint64(2, self.gas_used)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(3, &self.error)?;
+        }
+        if let Some(v) = self.trace.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.profile.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SimulateResponse {
+        SimulateResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.execution.clear();
+        self.gas_used = 0;
+        self.error.clear();
+        self.trace.clear();
+        self.profile.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SimulateResponse {
+        static instance: SimulateResponse = SimulateResponse {
+            execution: ::protobuf::MessageField::none(),
+            gas_used: 0,
+            error: ::std::string::String::new(),
+            trace: ::protobuf::MessageField::none(),
+            profile: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SimulateResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SimulateResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SimulateResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SimulateResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.HostFunctionFuel)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct HostFunctionFuel {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.HostFunctionFuel.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:alkanes.HostFunctionFuel.calls)
+    pub calls: u64,
+    // @@protoc_insertion_point(field:alkanes.HostFunctionFuel.bytes)
+    pub bytes: u64,
+    // @@protoc_insertion_point(field:alkanes.HostFunctionFuel.fuel)
+    pub fuel: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.HostFunctionFuel.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HostFunctionFuel {
+    fn default() -> &'a HostFunctionFuel {
+        <HostFunctionFuel as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HostFunctionFuel {
+    pub fn new() -> HostFunctionFuel {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &HostFunctionFuel| { &m.name },
+            |m: &mut HostFunctionFuel| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "calls",
+            |m: &HostFunctionFuel| { &m.calls },
+            |m: &mut HostFunctionFuel| { &mut m.calls },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bytes",
+            |m: &HostFunctionFuel| { &m.bytes },
+            |m: &mut HostFunctionFuel| { &mut m.bytes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "fuel",
+            |m: &HostFunctionFuel| { &m.fuel },
+            |m: &mut HostFunctionFuel| { &mut m.fuel },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HostFunctionFuel>(
+            "HostFunctionFuel",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HostFunctionFuel {
+    const NAME: &'static str = "HostFunctionFuel";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.calls = is.read_uint64()?;
+                },
+                24 => {
+                    self.bytes = is.read_uint64()?;
+                },
+                32 => {
+                    self.fuel = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.calls != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.calls);
+        }
+        if self.bytes != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.bytes);
+        }
+        if self.fuel != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.fuel);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.calls != 0 {
+            os.write_uint64(2, self.calls)?;
+        }
+        if self.bytes != 0 {
+            os.write_uint64(3, self.bytes)?;
+        }
+        if self.fuel != 0 {
+            os.write_uint64(4, self.fuel)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HostFunctionFuel {
+        HostFunctionFuel::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.calls = 0;
+        self.bytes = 0;
+        self.fuel = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HostFunctionFuel {
+        static instance: HostFunctionFuel = HostFunctionFuel {
+            name: ::std::string::String::new(),
+            calls: 0,
+            bytes: 0,
+            fuel: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HostFunctionFuel {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HostFunctionFuel").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HostFunctionFuel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HostFunctionFuel {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.FuelProfile)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct FuelProfile {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.FuelProfile.target)
+    pub target: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.FuelProfile.inputs)
+    pub inputs: ::std::vec::Vec<Uint128>,
+    // @@protoc_insertion_point(field:alkanes.FuelProfile.total)
+    pub total: u64,
+    // @@protoc_insertion_point(field:alkanes.FuelProfile.instructions)
+    pub instructions: u64,
+    // @@protoc_insertion_point(field:alkanes.FuelProfile.storage)
+    pub storage: u64,
+    // @@protoc_insertion_point(field:alkanes.FuelProfile.host_functions)
+    pub host_functions: ::std::vec::Vec<HostFunctionFuel>,
+    // @@protoc_insertion_point(field:alkanes.FuelProfile.children)
+    pub children: ::std::vec::Vec<FuelProfile>,
+    // @@protoc_insertion_point(field:alkanes.FuelProfile.reverted)
+    pub reverted: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.FuelProfile.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FuelProfile {
+    fn default() -> &'a FuelProfile {
+        <FuelProfile as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FuelProfile {
+    pub fn new() -> FuelProfile {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "target",
+            |m: &FuelProfile| { &m.target },
+            |m: &mut FuelProfile| { &mut m.target },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "inputs",
+            |m: &FuelProfile| { &m.inputs },
+            |m: &mut FuelProfile| { &mut m.inputs },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total",
+            |m: &FuelProfile| { &m.total },
+            |m: &mut FuelProfile| { &mut m.total },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "instructions",
+            |m: &FuelProfile| { &m.instructions },
+            |m: &mut FuelProfile| { &mut m.instructions },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "storage",
+            |m: &FuelProfile| { &m.storage },
+            |m: &mut FuelProfile| { &mut m.storage },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "host_functions",
+            |m: &FuelProfile| { &m.host_functions },
+            |m: &mut FuelProfile| { &mut m.host_functions },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "children",
+            |m: &FuelProfile| { &m.children },
+            |m: &mut FuelProfile| { &mut m.children },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "reverted",
+            |m: &FuelProfile| { &m.reverted },
+            |m: &mut FuelProfile| { &mut m.reverted },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FuelProfile>(
+            "FuelProfile",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for FuelProfile {
+    const NAME: &'static str = "FuelProfile";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.target)?;
+                },
+                18 => {
+                    self.inputs.push(is.read_message()?);
+                },
+                24 => {
+                    self.total = is.read_uint64()?;
+                },
+                32 => {
+                    self.instructions = is.read_uint64()?;
+                },
+                40 => {
+                    self.storage = is.read_uint64()?;
+                },
+                50 => {
+                    self.host_functions.push(is.read_message()?);
+                },
+                58 => {
+                    self.children.push(is.read_message()?);
+                },
+                64 => {
+                    self.reverted = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.inputs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.total != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.total);
+        }
+        if self.instructions != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.instructions);
+        }
+        if self.storage != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.storage);
+        }
+        for value in &self.host_functions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.children {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.reverted != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.target.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        for v in &self.inputs {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        if self.total != 0 {
+            os.write_uint64(3, self.total)?;
+        }
+        if self.instructions != 0 {
+            os.write_uint64(4, self.instructions)?;
+        }
+        if self.storage != 0 {
+            os.write_uint64(5, self.storage)?;
+        }
+        for v in &self.host_functions {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        };
+        for v in &self.children {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        };
+        if self.reverted != false {
+            os.write_bool(8, self.reverted)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FuelProfile {
+        FuelProfile::new()
+    }
+
+    fn clear(&mut self) {
+        self.target.clear();
+        self.inputs.clear();
+        self.total = 0;
+        self.instructions = 0;
+        self.storage = 0;
+        self.host_functions.clear();
+        self.children.clear();
+        self.reverted = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FuelProfile {
+        static instance: FuelProfile = FuelProfile {
+            target: ::protobuf::MessageField::none(),
+            inputs: ::std::vec::Vec::new(),
+            total: 0,
+            instructions: 0,
+            storage: 0,
+            host_functions: ::std::vec::Vec::new(),
+            children: ::std::vec::Vec::new(),
+            reverted: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for FuelProfile {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FuelProfile").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FuelProfile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FuelProfile {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.MultiSimulateResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct MultiSimulateResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.MultiSimulateResponse.responses)
+    pub responses: ::std::vec::Vec<SimulateResponse>,
+    // @@protoc_insertion_point(field:alkanes.MultiSimulateResponse.error)
+    pub error: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.MultiSimulateResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MultiSimulateResponse {
+    fn default() -> &'a MultiSimulateResponse {
+        <MultiSimulateResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MultiSimulateResponse {
+    pub fn new() -> MultiSimulateResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "responses",
+            |m: &MultiSimulateResponse| { &m.responses },
+            |m: &mut MultiSimulateResponse| { &mut m.responses },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &MultiSimulateResponse| { &m.error },
+            |m: &mut MultiSimulateResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MultiSimulateResponse>(
+            "MultiSimulateResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MultiSimulateResponse {
+    const NAME: &'static str = "MultiSimulateResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.responses.push(is.read_message()?);
+                },
+                18 => {
+                    self.error = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.responses {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.responses {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MultiSimulateResponse {
+        MultiSimulateResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.responses.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MultiSimulateResponse {
+        static instance: MultiSimulateResponse = MultiSimulateResponse {
+            responses: ::std::vec::Vec::new(),
+            error: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MultiSimulateResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MultiSimulateResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MultiSimulateResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MultiSimulateResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.EstimateFuelRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct EstimateFuelRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelRequest.transaction)
+    pub transaction: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelRequest.height)
+    pub height: u64,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelRequest.txindex)
+    pub txindex: u32,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelRequest.block_vfsize)
+    pub block_vfsize: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.EstimateFuelRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a EstimateFuelRequest {
+    fn default() -> &'a EstimateFuelRequest {
+        <EstimateFuelRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EstimateFuelRequest {
+    pub fn new() -> EstimateFuelRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "transaction",
+            |m: &EstimateFuelRequest| { &m.transaction },
+            |m: &mut EstimateFuelRequest| { &mut m.transaction },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &EstimateFuelRequest| { &m.height },
+            |m: &mut EstimateFuelRequest| { &mut m.height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "txindex",
+            |m: &EstimateFuelRequest| { &m.txindex },
+            |m: &mut EstimateFuelRequest| { &mut m.txindex },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "block_vfsize",
+            |m: &EstimateFuelRequest| { &m.block_vfsize },
+            |m: &mut EstimateFuelRequest| { &mut m.block_vfsize },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EstimateFuelRequest>(
+            "EstimateFuelRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for EstimateFuelRequest {
+    const NAME: &'static str = "EstimateFuelRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.transaction = is.read_bytes()?;
+                },
+                16 => {
+                    self.height = is.read_uint64()?;
+                },
+                24 => {
+                    self.txindex = is.read_uint32()?;
+                },
+                32 => {
+                    self.block_vfsize = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.transaction.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.transaction);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.height);
+        }
+        if self.txindex != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.txindex);
+        }
+        if self.block_vfsize != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.block_vfsize);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.transaction.is_empty() {
+            os.write_bytes(1, &self.transaction)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if self.txindex != 0 {
+            os.write_uint32(3, self.txindex)?;
+        }
+        if self.block_vfsize != 0 {
+            os.write_uint64(4, self.block_vfsize)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> EstimateFuelRequest {
+        EstimateFuelRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.transaction.clear();
+        self.height = 0;
+        self.txindex = 0;
+        self.block_vfsize = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static EstimateFuelRequest {
+        static instance: EstimateFuelRequest = EstimateFuelRequest {
+            transaction: ::std::vec::Vec::new(),
+            height: 0,
+            txindex: 0,
+            block_vfsize: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for EstimateFuelRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EstimateFuelRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for EstimateFuelRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EstimateFuelRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.EstimateFuelResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct EstimateFuelResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.fuel_needed)
+    pub fuel_needed: u64,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.fuel_allocated)
+    pub fuel_allocated: u64,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.vfsize)
+    pub vfsize: u64,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.padding)
+    pub padding: u64,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.messages)
+    pub messages: ::std::vec::Vec<SimulateResponse>,
+    // @@protoc_insertion_point(field:alkanes.EstimateFuelResponse.error)
+    pub error: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.EstimateFuelResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a EstimateFuelResponse {
+    fn default() -> &'a EstimateFuelResponse {
+        <EstimateFuelResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EstimateFuelResponse {
+    pub fn new() -> EstimateFuelResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "fuel_needed",
+            |m: &EstimateFuelResponse| { &m.fuel_needed },
+            |m: &mut EstimateFuelResponse| { &mut m.fuel_needed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "fuel_allocated",
+            |m: &EstimateFuelResponse| { &m.fuel_allocated },
+            |m: &mut EstimateFuelResponse| { &mut m.fuel_allocated },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "vfsize",
+            |m: &EstimateFuelResponse| { &m.vfsize },
+            |m: &mut EstimateFuelResponse| { &mut m.vfsize },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "padding",
+            |m: &EstimateFuelResponse| { &m.padding },
+            |m: &mut EstimateFuelResponse| { &mut m.padding },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "messages",
+            |m: &EstimateFuelResponse| { &m.messages },
+            |m: &mut EstimateFuelResponse| { &mut m.messages },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &EstimateFuelResponse| { &m.error },
+            |m: &mut EstimateFuelResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EstimateFuelResponse>(
+            "EstimateFuelResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for EstimateFuelResponse {
+    const NAME: &'static str = "EstimateFuelResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.fuel_needed = is.read_uint64()?;
+                },
+                16 => {
+                    self.fuel_allocated = is.read_uint64()?;
+                },
+                24 => {
+                    self.vfsize = is.read_uint64()?;
+                },
+                32 => {
+                    self.padding = is.read_uint64()?;
+                },
+                42 => {
+                    self.messages.push(is.read_message()?);
+                },
+                50 => {
+                    self.error = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.fuel_needed != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.fuel_needed);
+        }
+        if self.fuel_allocated != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.fuel_allocated);
+        }
+        if self.vfsize != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.vfsize);
+        }
+        if self.padding != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.padding);
+        }
+        for value in &self.messages {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.fuel_needed != 0 {
+            os.write_uint64(1, self.fuel_needed)?;
+        }
+        if self.fuel_allocated != 0 {
+            os.write_uint64(2, self.fuel_allocated)?;
+        }
+        if self.vfsize != 0 {
+            os.write_uint64(3, self.vfsize)?;
+        }
+        if self.padding != 0 {
+            os.write_uint64(4, self.padding)?;
+        }
+        for v in &self.messages {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        if !self.error.is_empty() {
+            os.write_string(6, &self.error)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> EstimateFuelResponse {
+        EstimateFuelResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.fuel_needed = 0;
+        self.fuel_allocated = 0;
+        self.vfsize = 0;
+        self.padding = 0;
+        self.messages.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static EstimateFuelResponse {
+        static instance: EstimateFuelResponse = EstimateFuelResponse {
+            fuel_needed: 0,
+            fuel_allocated: 0,
+            vfsize: 0,
+            padding: 0,
+            messages: ::std::vec::Vec::new(),
+            error: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for EstimateFuelResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EstimateFuelResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for EstimateFuelResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EstimateFuelResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.InputBalances)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct InputBalances {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.InputBalances.input)
+    pub input: u32,
+    // @@protoc_insertion_point(field:alkanes.InputBalances.balances)
+    pub balances: ::std::vec::Vec<AlkaneTransfer>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.InputBalances.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a InputBalances {
+    fn default() -> &'a InputBalances {
+        <InputBalances as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InputBalances {
+    pub fn new() -> InputBalances {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "input",
+            |m: &InputBalances| { &m.input },
+            |m: &mut InputBalances| { &mut m.input },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "balances",
+            |m: &InputBalances| { &m.balances },
+            |m: &mut InputBalances| { &mut m.balances },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InputBalances>(
+            "InputBalances",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for InputBalances {
+    const NAME: &'static str = "InputBalances";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.input = is.read_uint32()?;
+                },
+                18 => {
+                    self.balances.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.input != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.input);
+        }
+        for value in &self.balances {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.input != 0 {
+            os.write_uint32(1, self.input)?;
+        }
+        for v in &self.balances {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> InputBalances {
+        InputBalances::new()
+    }
+
+    fn clear(&mut self) {
+        self.input = 0;
+        self.balances.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static InputBalances {
+        static instance: InputBalances = InputBalances {
+            input: 0,
+            balances: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for InputBalances {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("InputBalances").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for InputBalances {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InputBalances {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.SimulateTransactionRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SimulateTransactionRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionRequest.transaction)
+    pub transaction: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionRequest.height)
+    pub height: u64,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionRequest.txindex)
+    pub txindex: u32,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionRequest.inputs)
+    pub inputs: ::std::vec::Vec<InputBalances>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.SimulateTransactionRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SimulateTransactionRequest {
+    fn default() -> &'a SimulateTransactionRequest {
+        <SimulateTransactionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SimulateTransactionRequest {
+    pub fn new() -> SimulateTransactionRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "transaction",
+            |m: &SimulateTransactionRequest| { &m.transaction },
+            |m: &mut SimulateTransactionRequest| { &mut m.transaction },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &SimulateTransactionRequest| { &m.height },
+            |m: &mut SimulateTransactionRequest| { &mut m.height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "txindex",
+            |m: &SimulateTransactionRequest| { &m.txindex },
+            |m: &mut SimulateTransactionRequest| { &mut m.txindex },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "inputs",
+            |m: &SimulateTransactionRequest| { &m.inputs },
+            |m: &mut SimulateTransactionRequest| { &mut m.inputs },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SimulateTransactionRequest>(
+            "SimulateTransactionRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SimulateTransactionRequest {
+    const NAME: &'static str = "SimulateTransactionRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.transaction = is.read_bytes()?;
+                },
+                16 => {
+                    self.height = is.read_uint64()?;
+                },
+                24 => {
+                    self.txindex = is.read_uint32()?;
+                },
+                34 => {
+                    self.inputs.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.transaction.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.transaction);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.height);
+        }
+        if self.txindex != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.txindex);
+        }
+        for value in &self.inputs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.transaction.is_empty() {
+            os.write_bytes(1, &self.transaction)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if self.txindex != 0 {
+            os.write_uint32(3, self.txindex)?;
+        }
+        for v in &self.inputs {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SimulateTransactionRequest {
+        SimulateTransactionRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.transaction.clear();
+        self.height = 0;
+        self.txindex = 0;
+        self.inputs.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SimulateTransactionRequest {
+        static instance: SimulateTransactionRequest = SimulateTransactionRequest {
+            transaction: ::std::vec::Vec::new(),
+            height: 0,
+            txindex: 0,
+            inputs: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SimulateTransactionRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SimulateTransactionRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SimulateTransactionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SimulateTransactionRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.SimulatedRevert)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SimulatedRevert {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.SimulatedRevert.outpoint)
+    pub outpoint: ::protobuf::MessageField<Outpoint>,
+    // @@protoc_insertion_point(field:alkanes.SimulatedRevert.error)
+    pub error: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.SimulatedRevert.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SimulatedRevert {
+    fn default() -> &'a SimulatedRevert {
+        <SimulatedRevert as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SimulatedRevert {
+    pub fn new() -> SimulatedRevert {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
+            "outpoint",
+            |m: &SimulatedRevert| { &m.outpoint },
+            |m: &mut SimulatedRevert| { &mut m.outpoint },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &SimulatedRevert| { &m.error },
+            |m: &mut SimulatedRevert| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SimulatedRevert>(
+            "SimulatedRevert",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SimulatedRevert {
+    const NAME: &'static str = "SimulatedRevert";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
+                },
+                18 => {
+                    self.error = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.outpoint.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.outpoint.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SimulatedRevert {
+        SimulatedRevert::new()
+    }
+
+    fn clear(&mut self) {
+        self.outpoint.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SimulatedRevert {
+        static instance: SimulatedRevert = SimulatedRevert {
+            outpoint: ::protobuf::MessageField::none(),
+            error: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SimulatedRevert {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SimulatedRevert").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SimulatedRevert {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SimulatedRevert {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.SimulateTransactionResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SimulateTransactionResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionResponse.outputs)
+    pub outputs: ::std::vec::Vec<OutpointBalances>,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionResponse.runtime_balances)
+    pub runtime_balances: ::std::vec::Vec<AlkaneTransfer>,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionResponse.traces)
+    pub traces: ::std::vec::Vec<Trace>,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionResponse.reverts)
+    pub reverts: ::std::vec::Vec<SimulatedRevert>,
+    // @@protoc_insertion_point(field:alkanes.SimulateTransactionResponse.error)
+    pub error: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.SimulateTransactionResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SimulateTransactionResponse {
+    fn default() -> &'a SimulateTransactionResponse {
+        <SimulateTransactionResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SimulateTransactionResponse {
+    pub fn new() -> SimulateTransactionResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "outputs",
+            |m: &SimulateTransactionResponse| { &m.outputs },
+            |m: &mut SimulateTransactionResponse| { &mut m.outputs },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "runtime_balances",
+            |m: &SimulateTransactionResponse| { &m.runtime_balances },
+            |m: &mut SimulateTransactionResponse| { &mut m.runtime_balances },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "traces",
+            |m: &SimulateTransactionResponse| { &m.traces },
+            |m: &mut SimulateTransactionResponse| { &mut m.traces },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "reverts",
+            |m: &SimulateTransactionResponse| { &m.reverts },
+            |m: &mut SimulateTransactionResponse| { &mut m.reverts },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &SimulateTransactionResponse| { &m.error },
+            |m: &mut SimulateTransactionResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SimulateTransactionResponse>(
+            "SimulateTransactionResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SimulateTransactionResponse {
+    const NAME: &'static str = "SimulateTransactionResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.outputs.push(is.read_message()?);
+                },
+                18 => {
+                    self.runtime_balances.push(is.read_message()?);
+                },
+                26 => {
+                    self.traces.push(is.read_message()?);
+                },
+                34 => {
+                    self.reverts.push(is.read_message()?);
+                },
+                42 => {
+                    self.error = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.outputs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.runtime_balances {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.traces {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.reverts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.outputs {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        for v in &self.runtime_balances {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        for v in &self.traces {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        for v in &self.reverts {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        if !self.error.is_empty() {
+            os.write_string(5, &self.error)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SimulateTransactionResponse {
+        SimulateTransactionResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.outputs.clear();
+        self.runtime_balances.clear();
+        self.traces.clear();
+        self.reverts.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SimulateTransactionResponse {
+        static instance: SimulateTransactionResponse = SimulateTransactionResponse {
+            outputs: ::std::vec::Vec::new(),
+            runtime_balances: ::std::vec::Vec::new(),
+            traces: ::std::vec::Vec::new(),
+            reverts: ::std::vec::Vec::new(),
+            error: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SimulateTransactionResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SimulateTransactionResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SimulateTransactionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SimulateTransactionResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkaneInventoryRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneInventoryRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryRequest.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryRequest.cursor)
+    pub cursor: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryRequest.limit)
+    pub limit: u32,
+    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryRequest.alkane)
+    pub alkane: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryRequest.min_balance)
+    pub min_balance: ::protobuf::MessageField<Uint128>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryRequest.descending)
+    pub descending: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneInventoryRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneInventoryRequest {
+    fn default() -> &'a AlkaneInventoryRequest {
+        <AlkaneInventoryRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneInventoryRequest {
+    pub fn new() -> AlkaneInventoryRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &AlkaneInventoryRequest| { &m.id },
+            |m: &mut AlkaneInventoryRequest| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "cursor",
+            |m: &AlkaneInventoryRequest| { &m.cursor },
+            |m: &mut AlkaneInventoryRequest| { &mut m.cursor },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &AlkaneInventoryRequest| { &m.limit },
+            |m: &mut AlkaneInventoryRequest| { &mut m.limit },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "alkane",
+            |m: &AlkaneInventoryRequest| { &m.alkane },
+            |m: &mut AlkaneInventoryRequest| { &mut m.alkane },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "min_balance",
+            |m: &AlkaneInventoryRequest| { &m.min_balance },
+            |m: &mut AlkaneInventoryRequest| { &mut m.min_balance },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "descending",
+            |m: &AlkaneInventoryRequest| { &m.descending },
+            |m: &mut AlkaneInventoryRequest| { &mut m.descending },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneInventoryRequest>(
+            "AlkaneInventoryRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneInventoryRequest {
+    const NAME: &'static str = "AlkaneInventoryRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                },
+                18 => {
+                    self.cursor = is.read_bytes()?;
+                },
+                24 => {
+                    self.limit = is.read_uint32()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.alkane)?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.min_balance)?;
+                },
+                48 => {
+                    self.descending = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.cursor);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.limit);
+        }
+        if let Some(v) = self.alkane.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.min_balance.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.descending != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.id.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.cursor.is_empty() {
+            os.write_bytes(2, &self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(3, self.limit)?;
+        }
+        if let Some(v) = self.alkane.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.min_balance.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        if self.descending != false {
+            os.write_bool(6, self.descending)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkaneInventoryRequest {
+        AlkaneInventoryRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id.clear();
+        self.cursor.clear();
+        self.limit = 0;
+        self.alkane.clear();
+        self.min_balance.clear();
+        self.descending = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkaneInventoryRequest {
+        static instance: AlkaneInventoryRequest = AlkaneInventoryRequest {
+            id: ::protobuf::MessageField::none(),
+            cursor: ::std::vec::Vec::new(),
+            limit: 0,
+            alkane: ::protobuf::MessageField::none(),
+            min_balance: ::protobuf::MessageField::none(),
+            descending: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkaneInventoryRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneInventoryRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkaneInventoryRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkaneInventoryRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkaneIdToOutpointRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneIdToOutpointRequest {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneIdToOutpointRequest.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneIdToOutpointRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneIdToOutpointRequest {
+    fn default() -> &'a AlkaneIdToOutpointRequest {
+        <AlkaneIdToOutpointRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneIdToOutpointRequest {
+    pub fn new() -> AlkaneIdToOutpointRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &AlkaneIdToOutpointRequest| { &m.id },
+            |m: &mut AlkaneIdToOutpointRequest| { &mut m.id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneIdToOutpointRequest>(
+            "AlkaneIdToOutpointRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneIdToOutpointRequest {
+    const NAME: &'static str = "AlkaneIdToOutpointRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.id.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkaneIdToOutpointRequest {
+        AlkaneIdToOutpointRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkaneIdToOutpointRequest {
+        static instance: AlkaneIdToOutpointRequest = AlkaneIdToOutpointRequest {
+            id: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkaneIdToOutpointRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneIdToOutpointRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkaneIdToOutpointRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkaneIdToOutpointRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkaneInventoryResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneInventoryResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryResponse.alkanes)
+    pub alkanes: ::std::vec::Vec<AlkaneTransfer>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryResponse.next_cursor)
+    pub next_cursor: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneInventoryResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneInventoryResponse {
+    fn default() -> &'a AlkaneInventoryResponse {
+        <AlkaneInventoryResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneInventoryResponse {
+    pub fn new() -> AlkaneInventoryResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "alkanes",
+            |m: &AlkaneInventoryResponse| { &m.alkanes },
+            |m: &mut AlkaneInventoryResponse| { &mut m.alkanes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "next_cursor",
+            |m: &AlkaneInventoryResponse| { &m.next_cursor },
+            |m: &mut AlkaneInventoryResponse| { &mut m.next_cursor },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneInventoryResponse>(
+            "AlkaneInventoryResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneInventoryResponse {
+    const NAME: &'static str = "AlkaneInventoryResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.alkanes.push(is.read_message()?);
+                },
+                18 => {
+                    self.next_cursor = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.alkanes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.next_cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.next_cursor);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.alkanes {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if !self.next_cursor.is_empty() {
+            os.write_bytes(2, &self.next_cursor)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkaneInventoryResponse {
+        AlkaneInventoryResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.alkanes.clear();
+        self.next_cursor.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkaneInventoryResponse {
+        static instance: AlkaneInventoryResponse = AlkaneInventoryResponse {
+            alkanes: ::std::vec::Vec::new(),
+            next_cursor: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AlkaneInventoryResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneInventoryResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AlkaneInventoryResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AlkaneInventoryResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.AlkaneIdToOutpointResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AlkaneIdToOutpointResponse {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.AlkaneIdToOutpointResponse.txid)
+    pub txid: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneIdToOutpointResponse.vout)
+    pub vout: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneIdToOutpointResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AlkaneIdToOutpointResponse {
+    fn default() -> &'a AlkaneIdToOutpointResponse {
+        <AlkaneIdToOutpointResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AlkaneIdToOutpointResponse {
+    pub fn new() -> AlkaneIdToOutpointResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "txid",
+            |m: &AlkaneIdToOutpointResponse| { &m.txid },
+            |m: &mut AlkaneIdToOutpointResponse| { &mut m.txid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "vout",
+            |m: &AlkaneIdToOutpointResponse| { &m.vout },
+            |m: &mut AlkaneIdToOutpointResponse| { &mut m.vout },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneIdToOutpointResponse>(
+            "AlkaneIdToOutpointResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AlkaneIdToOutpointResponse {
+    const NAME: &'static str = "AlkaneIdToOutpointResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.txid = is.read_bytes()?;
+                },
+                16 => {
+                    self.vout = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.txid.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.txid);
+        }
+        if self.vout != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.vout);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.txid.is_empty() {
+            os.write_bytes(1, &self.txid)?;
+        }
+        if self.vout != 0 {
+            os.write_uint32(2, self.vout)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AlkaneIdToOutpointResponse {
+        AlkaneIdToOutpointResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.txid.clear();
+        self.vout = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AlkaneIdToOutpointResponse {
+        static instance: AlkaneIdToOutpointResponse = AlkaneIdToOutpointResponse {
+            txid: ::std::vec::Vec::new(),
+            vout: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
 
-    pub fn create_alkane(&self) -> &AlkanesCreate {
-        match self.event {
-            ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(ref v)) => v,
-            _ => <AlkanesCreate as ::protobuf::Message>::default_instance(),
-        }
+impl ::protobuf::MessageFull for AlkaneIdToOutpointResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneIdToOutpointResponse").unwrap()).clone()
     }
+}
 
-    pub fn clear_create_alkane(&mut self) {
-        self.event = ::std::option::Option::None;
+impl ::std::fmt::Display for AlkaneIdToOutpointResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    pub fn has_create_alkane(&self) -> bool {
-        match self.event {
-            ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(..)) => true,
-            _ => false,
-        }
+impl ::protobuf::reflect::ProtobufValue for AlkaneIdToOutpointResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.Outpoint)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Outpoint {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.Outpoint.txid)
+    pub txid: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.Outpoint.vout)
+    pub vout: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.Outpoint.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Outpoint {
+    fn default() -> &'a Outpoint {
+        <Outpoint as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_create_alkane(&mut self, v: AlkanesCreate) {
-        self.event = ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(v))
+impl Outpoint {
+    pub fn new() -> Outpoint {
+        ::std::default::Default::default()
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_create_alkane(&mut self) -> &mut AlkanesCreate {
-        if let ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(_)) = self.event {
-        } else {
-            self.event = ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(AlkanesCreate::new()));
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "txid",
+            |m: &Outpoint| { &m.txid },
+            |m: &mut Outpoint| { &mut m.txid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "vout",
+            |m: &Outpoint| { &m.vout },
+            |m: &mut Outpoint| { &mut m.vout },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Outpoint>(
+            "Outpoint",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Outpoint {
+    const NAME: &'static str = "Outpoint";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.txid = is.read_bytes()?;
+                },
+                16 => {
+                    self.vout = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
         }
-        match self.event {
-            ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(ref mut v)) => v,
-            _ => panic!(),
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.txid.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.txid);
         }
+        if self.vout != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.vout);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
     }
 
-    // Take field
-    pub fn take_create_alkane(&mut self) -> AlkanesCreate {
-        if self.has_create_alkane() {
-            match self.event.take() {
-                ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            AlkanesCreate::new()
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.txid.is_empty() {
+            os.write_bytes(1, &self.txid)?;
+        }
+        if self.vout != 0 {
+            os.write_uint32(2, self.vout)?;
         }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Outpoint {
+        Outpoint::new()
+    }
+
+    fn clear(&mut self) {
+        self.txid.clear();
+        self.vout = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Outpoint {
+        static instance: Outpoint = Outpoint {
+            txid: ::std::vec::Vec::new(),
+            vout: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Outpoint {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Outpoint").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Outpoint {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Outpoint {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:alkanes.Trace)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Trace {
+    // message fields
+    // @@protoc_insertion_point(field:alkanes.Trace.outpoint)
+    pub outpoint: ::protobuf::MessageField<Outpoint>,
+    // @@protoc_insertion_point(field:alkanes.Trace.trace)
+    pub trace: ::protobuf::MessageField<AlkanesTrace>,
+    // special fields
+    // @@protoc_insertion_point(special_field:alkanes.Trace.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Trace {
+    fn default() -> &'a Trace {
+        <Trace as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Trace {
+    pub fn new() -> Trace {
+        ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
-        let mut oneofs = ::std::vec::Vec::with_capacity(1);
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AlkanesEnterContext>(
-            "enter_context",
-            AlkanesTraceEvent::has_enter_context,
-            AlkanesTraceEvent::enter_context,
-            AlkanesTraceEvent::mut_enter_context,
-            AlkanesTraceEvent::set_enter_context,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AlkanesExitContext>(
-            "exit_context",
-            AlkanesTraceEvent::has_exit_context,
-            AlkanesTraceEvent::exit_context,
-            AlkanesTraceEvent::mut_exit_context,
-            AlkanesTraceEvent::set_exit_context,
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
+            "outpoint",
+            |m: &Trace| { &m.outpoint },
+            |m: &mut Trace| { &mut m.outpoint },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AlkanesCreate>(
-            "create_alkane",
-            AlkanesTraceEvent::has_create_alkane,
-            AlkanesTraceEvent::create_alkane,
-            AlkanesTraceEvent::mut_create_alkane,
-            AlkanesTraceEvent::set_create_alkane,
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkanesTrace>(
+            "trace",
+            |m: &Trace| { &m.trace },
+            |m: &mut Trace| { &mut m.trace },
         ));
-        oneofs.push(alkanes_trace_event::Event::generated_oneof_descriptor_data());
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesTraceEvent>(
-            "AlkanesTraceEvent",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Trace>(
+            "Trace",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkanesTraceEvent {
-    const NAME: &'static str = "AlkanesTraceEvent";
+impl ::protobuf::Message for Trace {
+    const NAME: &'static str = "Trace";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2094,13 +6383,10 @@ impl ::protobuf::Message for AlkanesTraceEvent {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.event = ::std::option::Option::Some(alkanes_trace_event::Event::EnterContext(is.read_message()?));
-                },
-                18 => {
-                    self.event = ::std::option::Option::Some(alkanes_trace_event::Event::ExitContext(is.read_message()?));
-                },
-                26 => {
-                    self.event = ::std::option::Option::Some(alkanes_trace_event::Event::CreateAlkane(is.read_message()?));
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.trace)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2114,21 +6400,13 @@ impl ::protobuf::Message for AlkanesTraceEvent {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.event {
-            match v {
-                &alkanes_trace_event::Event::EnterContext(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &alkanes_trace_event::Event::ExitContext(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &alkanes_trace_event::Event::CreateAlkane(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-            };
+        if let Some(v) = self.outpoint.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.trace.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -2136,18 +6414,11 @@ impl ::protobuf::Message for AlkanesTraceEvent {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let ::std::option::Option::Some(ref v) = self.event {
-            match v {
-                &alkanes_trace_event::Event::EnterContext(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-                },
-                &alkanes_trace_event::Event::ExitContext(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-                },
-                &alkanes_trace_event::Event::CreateAlkane(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
-                },
-            };
+        if let Some(v) = self.outpoint.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.trace.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2161,129 +6432,83 @@ impl ::protobuf::Message for AlkanesTraceEvent {
         &mut self.special_fields
     }
 
-    fn new() -> AlkanesTraceEvent {
-        AlkanesTraceEvent::new()
+    fn new() -> Trace {
+        Trace::new()
     }
 
     fn clear(&mut self) {
-        self.event = ::std::option::Option::None;
-        self.event = ::std::option::Option::None;
-        self.event = ::std::option::Option::None;
+        self.outpoint.clear();
+        self.trace.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkanesTraceEvent {
-        static instance: AlkanesTraceEvent = AlkanesTraceEvent {
-            event: ::std::option::Option::None,
+    fn default_instance() -> &'static Trace {
+        static instance: Trace = Trace {
+            outpoint: ::protobuf::MessageField::none(),
+            trace: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkanesTraceEvent {
+impl ::protobuf::MessageFull for Trace {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesTraceEvent").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Trace").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkanesTraceEvent {
+impl ::std::fmt::Display for Trace {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkanesTraceEvent {
+impl ::protobuf::reflect::ProtobufValue for Trace {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-/// Nested message and enums of message `AlkanesTraceEvent`
-pub mod alkanes_trace_event {
-
-    #[derive(Clone,PartialEq,Debug)]
-    #[non_exhaustive]
-    // @@protoc_insertion_point(oneof:alkanes.AlkanesTraceEvent.event)
-    pub enum Event {
-        // @@protoc_insertion_point(oneof_field:alkanes.AlkanesTraceEvent.enter_context)
-        EnterContext(super::AlkanesEnterContext),
-        // @@protoc_insertion_point(oneof_field:alkanes.AlkanesTraceEvent.exit_context)
-        ExitContext(super::AlkanesExitContext),
-        // @@protoc_insertion_point(oneof_field:alkanes.AlkanesTraceEvent.create_alkane)
-        CreateAlkane(super::AlkanesCreate),
-    }
-
-    impl ::protobuf::Oneof for Event {
-    }
-
-    impl ::protobuf::OneofFull for Event {
-        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
-            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
-            descriptor.get(|| <super::AlkanesTraceEvent as ::protobuf::MessageFull>::descriptor().oneof_by_name("event").unwrap()).clone()
-        }
-    }
-
-    impl Event {
-        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
-            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Event>("event")
-        }
-    }
-}
-
-// @@protoc_insertion_point(message:alkanes.AlkanesBlockEvent)
+// @@protoc_insertion_point(message:alkanes.TraceBlockRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkanesBlockEvent {
+pub struct TraceBlockRequest {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkanesBlockEvent.traces)
-    pub traces: ::protobuf::MessageField<AlkanesTrace>,
-    // @@protoc_insertion_point(field:alkanes.AlkanesBlockEvent.outpoint)
-    pub outpoint: ::protobuf::MessageField<Outpoint>,
-    // @@protoc_insertion_point(field:alkanes.AlkanesBlockEvent.txindex)
-    pub txindex: u64,
+    // @@protoc_insertion_point(field:alkanes.TraceBlockRequest.block)
+    pub block: u64,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkanesBlockEvent.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.TraceBlockRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkanesBlockEvent {
-    fn default() -> &'a AlkanesBlockEvent {
-        <AlkanesBlockEvent as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TraceBlockRequest {
+    fn default() -> &'a TraceBlockRequest {
+        <TraceBlockRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkanesBlockEvent {
-    pub fn new() -> AlkanesBlockEvent {
+impl TraceBlockRequest {
+    pub fn new() -> TraceBlockRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkanesTrace>(
-            "traces",
-            |m: &AlkanesBlockEvent| { &m.traces },
-            |m: &mut AlkanesBlockEvent| { &mut m.traces },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
-            "outpoint",
-            |m: &AlkanesBlockEvent| { &m.outpoint },
-            |m: &mut AlkanesBlockEvent| { &mut m.outpoint },
-        ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "txindex",
-            |m: &AlkanesBlockEvent| { &m.txindex },
-            |m: &mut AlkanesBlockEvent| { &mut m.txindex },
+            "block",
+            |m: &TraceBlockRequest| { &m.block },
+            |m: &mut TraceBlockRequest| { &mut m.block },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesBlockEvent>(
-            "AlkanesBlockEvent",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TraceBlockRequest>(
+            "TraceBlockRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkanesBlockEvent {
-    const NAME: &'static str = "AlkanesBlockEvent";
+impl ::protobuf::Message for TraceBlockRequest {
+    const NAME: &'static str = "TraceBlockRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2292,14 +6517,8 @@ impl ::protobuf::Message for AlkanesBlockEvent {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.traces)?;
-                },
-                18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
-                },
-                24 => {
-                    self.txindex = is.read_uint64()?;
+                8 => {
+                    self.block = is.read_uint64()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2313,16 +6532,8 @@ impl ::protobuf::Message for AlkanesBlockEvent {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.traces.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
-        if let Some(v) = self.outpoint.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
-        if self.txindex != 0 {
-            my_size += ::protobuf::rt::uint64_size(3, self.txindex);
+        if self.block != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.block);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -2330,14 +6541,8 @@ impl ::protobuf::Message for AlkanesBlockEvent {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.traces.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        }
-        if let Some(v) = self.outpoint.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-        }
-        if self.txindex != 0 {
-            os.write_uint64(3, self.txindex)?;
+        if self.block != 0 {
+            os.write_uint64(1, self.block)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2351,64 +6556,60 @@ impl ::protobuf::Message for AlkanesBlockEvent {
         &mut self.special_fields
     }
 
-    fn new() -> AlkanesBlockEvent {
-        AlkanesBlockEvent::new()
+    fn new() -> TraceBlockRequest {
+        TraceBlockRequest::new()
     }
 
     fn clear(&mut self) {
-        self.traces.clear();
-        self.outpoint.clear();
-        self.txindex = 0;
+        self.block = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkanesBlockEvent {
-        static instance: AlkanesBlockEvent = AlkanesBlockEvent {
-            traces: ::protobuf::MessageField::none(),
-            outpoint: ::protobuf::MessageField::none(),
-            txindex: 0,
+    fn default_instance() -> &'static TraceBlockRequest {
+        static instance: TraceBlockRequest = TraceBlockRequest {
+            block: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkanesBlockEvent {
+impl ::protobuf::MessageFull for TraceBlockRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesBlockEvent").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TraceBlockRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkanesBlockEvent {
+impl ::std::fmt::Display for TraceBlockRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkanesBlockEvent {
+impl ::protobuf::reflect::ProtobufValue for TraceBlockRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkanesBlockTraceEvent)
+// @@protoc_insertion_point(message:alkanes.TraceBlockResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkanesBlockTraceEvent {
+pub struct TraceBlockResponse {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkanesBlockTraceEvent.events)
-    pub events: ::std::vec::Vec<AlkanesBlockEvent>,
+    // @@protoc_insertion_point(field:alkanes.TraceBlockResponse.traces)
+    pub traces: ::std::vec::Vec<Trace>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkanesBlockTraceEvent.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.TraceBlockResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkanesBlockTraceEvent {
-    fn default() -> &'a AlkanesBlockTraceEvent {
-        <AlkanesBlockTraceEvent as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TraceBlockResponse {
+    fn default() -> &'a TraceBlockResponse {
+        <TraceBlockResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkanesBlockTraceEvent {
-    pub fn new() -> AlkanesBlockTraceEvent {
+impl TraceBlockResponse {
+    pub fn new() -> TraceBlockResponse {
         ::std::default::Default::default()
     }
 
@@ -2416,20 +6617,20 @@ impl AlkanesBlockTraceEvent {
         let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "events",
-            |m: &AlkanesBlockTraceEvent| { &m.events },
-            |m: &mut AlkanesBlockTraceEvent| { &mut m.events },
+            "traces",
+            |m: &TraceBlockResponse| { &m.traces },
+            |m: &mut TraceBlockResponse| { &mut m.traces },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesBlockTraceEvent>(
-            "AlkanesBlockTraceEvent",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TraceBlockResponse>(
+            "TraceBlockResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkanesBlockTraceEvent {
-    const NAME: &'static str = "AlkanesBlockTraceEvent";
+impl ::protobuf::Message for TraceBlockResponse {
+    const NAME: &'static str = "TraceBlockResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2439,7 +6640,7 @@ impl ::protobuf::Message for AlkanesBlockTraceEvent {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.events.push(is.read_message()?);
+                    self.traces.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2453,7 +6654,7 @@ impl ::protobuf::Message for AlkanesBlockTraceEvent {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.events {
+        for value in &self.traces {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         };
@@ -2463,7 +6664,7 @@ impl ::protobuf::Message for AlkanesBlockTraceEvent {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.events {
+        for v in &self.traces {
             ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
@@ -2478,81 +6679,81 @@ impl ::protobuf::Message for AlkanesBlockTraceEvent {
         &mut self.special_fields
     }
 
-    fn new() -> AlkanesBlockTraceEvent {
-        AlkanesBlockTraceEvent::new()
+    fn new() -> TraceBlockResponse {
+        TraceBlockResponse::new()
     }
 
     fn clear(&mut self) {
-        self.events.clear();
+        self.traces.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkanesBlockTraceEvent {
-        static instance: AlkanesBlockTraceEvent = AlkanesBlockTraceEvent {
-            events: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static TraceBlockResponse {
+        static instance: TraceBlockResponse = TraceBlockResponse {
+            traces: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkanesBlockTraceEvent {
+impl ::protobuf::MessageFull for TraceBlockResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesBlockTraceEvent").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TraceBlockResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkanesBlockTraceEvent {
+impl ::std::fmt::Display for TraceBlockResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkanesBlockTraceEvent {
+impl ::protobuf::reflect::ProtobufValue for TraceBlockResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkanesTrace)
+// @@protoc_insertion_point(message:alkanes.BytecodeRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkanesTrace {
+pub struct BytecodeRequest {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkanesTrace.events)
-    pub events: ::std::vec::Vec<AlkanesTraceEvent>,
+    // @@protoc_insertion_point(field:alkanes.BytecodeRequest.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkanesTrace.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.BytecodeRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkanesTrace {
-    fn default() -> &'a AlkanesTrace {
-        <AlkanesTrace as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a BytecodeRequest {
+    fn default() -> &'a BytecodeRequest {
+        <BytecodeRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkanesTrace {
-    pub fn new() -> AlkanesTrace {
+impl BytecodeRequest {
+    pub fn new() -> BytecodeRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "events",
-            |m: &AlkanesTrace| { &m.events },
-            |m: &mut AlkanesTrace| { &mut m.events },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &BytecodeRequest| { &m.id },
+            |m: &mut BytecodeRequest| { &mut m.id },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesTrace>(
-            "AlkanesTrace",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BytecodeRequest>(
+            "BytecodeRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkanesTrace {
-    const NAME: &'static str = "AlkanesTrace";
+impl ::protobuf::Message for BytecodeRequest {
+    const NAME: &'static str = "BytecodeRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2562,7 +6763,7 @@ impl ::protobuf::Message for AlkanesTrace {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.events.push(is.read_message()?);
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2576,19 +6777,19 @@ impl ::protobuf::Message for AlkanesTrace {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.events {
-            let len = value.compute_size();
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.events {
+        if let Some(v) = self.id.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2601,95 +6802,81 @@ impl ::protobuf::Message for AlkanesTrace {
         &mut self.special_fields
     }
 
-    fn new() -> AlkanesTrace {
-        AlkanesTrace::new()
+    fn new() -> BytecodeRequest {
+        BytecodeRequest::new()
     }
 
     fn clear(&mut self) {
-        self.events.clear();
+        self.id.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkanesTrace {
-        static instance: AlkanesTrace = AlkanesTrace {
-            events: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static BytecodeRequest {
+        static instance: BytecodeRequest = BytecodeRequest {
+            id: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkanesTrace {
+impl ::protobuf::MessageFull for BytecodeRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesTrace").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BytecodeRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkanesTrace {
+impl ::std::fmt::Display for BytecodeRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkanesTrace {
+impl ::protobuf::reflect::ProtobufValue for BytecodeRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.SimulateResponse)
+// @@protoc_insertion_point(message:alkanes.AbiRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct SimulateResponse {
+pub struct AbiRequest {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.SimulateResponse.execution)
-    pub execution: ::protobuf::MessageField<ExtendedCallResponse>,
-    // @@protoc_insertion_point(field:alkanes.SimulateResponse.gas_used)
-    pub gas_used: u64,
-    // @@protoc_insertion_point(field:alkanes.SimulateResponse.error)
-    pub error: ::std::string::String,
+    // @@protoc_insertion_point(field:alkanes.AbiRequest.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.SimulateResponse.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.AbiRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a SimulateResponse {
-    fn default() -> &'a SimulateResponse {
-        <SimulateResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a AbiRequest {
+    fn default() -> &'a AbiRequest {
+        <AbiRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SimulateResponse {
-    pub fn new() -> SimulateResponse {
+impl AbiRequest {
+    pub fn new() -> AbiRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ExtendedCallResponse>(
-            "execution",
-            |m: &SimulateResponse| { &m.execution },
-            |m: &mut SimulateResponse| { &mut m.execution },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "gas_used",
-            |m: &SimulateResponse| { &m.gas_used },
-            |m: &mut SimulateResponse| { &mut m.gas_used },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "error",
-            |m: &SimulateResponse| { &m.error },
-            |m: &mut SimulateResponse| { &mut m.error },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &AbiRequest| { &m.id },
+            |m: &mut AbiRequest| { &mut m.id },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SimulateResponse>(
-            "SimulateResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AbiRequest>(
+            "AbiRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for SimulateResponse {
-    const NAME: &'static str = "SimulateResponse";
+impl ::protobuf::Message for AbiRequest {
+    const NAME: &'static str = "AbiRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2699,13 +6886,7 @@ impl ::protobuf::Message for SimulateResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.execution)?;
-                },
-                16 => {
-                    self.gas_used = is.read_uint64()?;
-                },
-                26 => {
-                    self.error = is.read_string()?;
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2719,31 +6900,19 @@ impl ::protobuf::Message for SimulateResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.execution.as_ref() {
+        if let Some(v) = self.id.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
-        if self.gas_used != 0 {
-            my_size += ::protobuf::rt::uint64_size(2, self.gas_used);
-        }
-        if !self.error.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.error);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.execution.as_ref() {
+        if let Some(v) = self.id.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         }
-        if self.gas_used != 0 {
-            os.write_uint64(2, self.gas_used)?;
-        }
-        if !self.error.is_empty() {
-            os.write_string(3, &self.error)?;
-        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2756,92 +6925,81 @@ impl ::protobuf::Message for SimulateResponse {
         &mut self.special_fields
     }
 
-    fn new() -> SimulateResponse {
-        SimulateResponse::new()
+    fn new() -> AbiRequest {
+        AbiRequest::new()
     }
 
     fn clear(&mut self) {
-        self.execution.clear();
-        self.gas_used = 0;
-        self.error.clear();
+        self.id.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static SimulateResponse {
-        static instance: SimulateResponse = SimulateResponse {
-            execution: ::protobuf::MessageField::none(),
-            gas_used: 0,
-            error: ::std::string::String::new(),
+    fn default_instance() -> &'static AbiRequest {
+        static instance: AbiRequest = AbiRequest {
+            id: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for SimulateResponse {
+impl ::protobuf::MessageFull for AbiRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("SimulateResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AbiRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for SimulateResponse {
+impl ::std::fmt::Display for AbiRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SimulateResponse {
+impl ::protobuf::reflect::ProtobufValue for AbiRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.MultiSimulateResponse)
+// @@protoc_insertion_point(message:alkanes.BlockRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct MultiSimulateResponse {
+pub struct BlockRequest {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.MultiSimulateResponse.responses)
-    pub responses: ::std::vec::Vec<SimulateResponse>,
-    // @@protoc_insertion_point(field:alkanes.MultiSimulateResponse.error)
-    pub error: ::std::string::String,
+    // @@protoc_insertion_point(field:alkanes.BlockRequest.height)
+    pub height: u32,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.MultiSimulateResponse.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.BlockRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a MultiSimulateResponse {
-    fn default() -> &'a MultiSimulateResponse {
-        <MultiSimulateResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a BlockRequest {
+    fn default() -> &'a BlockRequest {
+        <BlockRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl MultiSimulateResponse {
-    pub fn new() -> MultiSimulateResponse {
+impl BlockRequest {
+    pub fn new() -> BlockRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "responses",
-            |m: &MultiSimulateResponse| { &m.responses },
-            |m: &mut MultiSimulateResponse| { &mut m.responses },
-        ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "error",
-            |m: &MultiSimulateResponse| { &m.error },
-            |m: &mut MultiSimulateResponse| { &mut m.error },
+            "height",
+            |m: &BlockRequest| { &m.height },
+            |m: &mut BlockRequest| { &mut m.height },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MultiSimulateResponse>(
-            "MultiSimulateResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BlockRequest>(
+            "BlockRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for MultiSimulateResponse {
-    const NAME: &'static str = "MultiSimulateResponse";
+impl ::protobuf::Message for BlockRequest {
+    const NAME: &'static str = "BlockRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2850,11 +7008,8 @@ impl ::protobuf::Message for MultiSimulateResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.responses.push(is.read_message()?);
-                },
-                18 => {
-                    self.error = is.read_string()?;
+                8 => {
+                    self.height = is.read_uint32()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2868,12 +7023,8 @@ impl ::protobuf::Message for MultiSimulateResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.responses {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
-        if !self.error.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.error);
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.height);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -2881,11 +7032,8 @@ impl ::protobuf::Message for MultiSimulateResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.responses {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
-        if !self.error.is_empty() {
-            os.write_string(2, &self.error)?;
+        if self.height != 0 {
+            os.write_uint32(1, self.height)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2899,83 +7047,88 @@ impl ::protobuf::Message for MultiSimulateResponse {
         &mut self.special_fields
     }
 
-    fn new() -> MultiSimulateResponse {
-        MultiSimulateResponse::new()
+    fn new() -> BlockRequest {
+        BlockRequest::new()
     }
 
     fn clear(&mut self) {
-        self.responses.clear();
-        self.error.clear();
+        self.height = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static MultiSimulateResponse {
-        static instance: MultiSimulateResponse = MultiSimulateResponse {
-            responses: ::std::vec::Vec::new(),
-            error: ::std::string::String::new(),
+    fn default_instance() -> &'static BlockRequest {
+        static instance: BlockRequest = BlockRequest {
+            height: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for MultiSimulateResponse {
+impl ::protobuf::MessageFull for BlockRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("MultiSimulateResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BlockRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for MultiSimulateResponse {
+impl ::std::fmt::Display for BlockRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for MultiSimulateResponse {
+impl ::protobuf::reflect::ProtobufValue for BlockRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkaneInventoryRequest)
+// @@protoc_insertion_point(message:alkanes.BlockResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkaneInventoryRequest {
+pub struct BlockResponse {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryRequest.id)
-    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.BlockResponse.block)
+    pub block: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.BlockResponse.height)
+    pub height: u32,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkaneInventoryRequest.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.BlockResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
-
-impl<'a> ::std::default::Default for &'a AlkaneInventoryRequest {
-    fn default() -> &'a AlkaneInventoryRequest {
-        <AlkaneInventoryRequest as ::protobuf::Message>::default_instance()
+
+impl<'a> ::std::default::Default for &'a BlockResponse {
+    fn default() -> &'a BlockResponse {
+        <BlockResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkaneInventoryRequest {
-    pub fn new() -> AlkaneInventoryRequest {
+impl BlockResponse {
+    pub fn new() -> BlockResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
-            "id",
-            |m: &AlkaneInventoryRequest| { &m.id },
-            |m: &mut AlkaneInventoryRequest| { &mut m.id },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "block",
+            |m: &BlockResponse| { &m.block },
+            |m: &mut BlockResponse| { &mut m.block },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneInventoryRequest>(
-            "AlkaneInventoryRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &BlockResponse| { &m.height },
+            |m: &mut BlockResponse| { &mut m.height },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BlockResponse>(
+            "BlockResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkaneInventoryRequest {
-    const NAME: &'static str = "AlkaneInventoryRequest";
+impl ::protobuf::Message for BlockResponse {
+    const NAME: &'static str = "BlockResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2985,7 +7138,10 @@ impl ::protobuf::Message for AlkaneInventoryRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                    self.block = is.read_bytes()?;
+                },
+                16 => {
+                    self.height = is.read_uint32()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2999,9 +7155,11 @@ impl ::protobuf::Message for AlkaneInventoryRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.id.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if !self.block.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.block);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.height);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -3009,8 +7167,11 @@ impl ::protobuf::Message for AlkaneInventoryRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.id.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        if !self.block.is_empty() {
+            os.write_bytes(1, &self.block)?;
+        }
+        if self.height != 0 {
+            os.write_uint32(2, self.height)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3024,81 +7185,97 @@ impl ::protobuf::Message for AlkaneInventoryRequest {
         &mut self.special_fields
     }
 
-    fn new() -> AlkaneInventoryRequest {
-        AlkaneInventoryRequest::new()
+    fn new() -> BlockResponse {
+        BlockResponse::new()
     }
 
     fn clear(&mut self) {
-        self.id.clear();
+        self.block.clear();
+        self.height = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkaneInventoryRequest {
-        static instance: AlkaneInventoryRequest = AlkaneInventoryRequest {
-            id: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static BlockResponse {
+        static instance: BlockResponse = BlockResponse {
+            block: ::std::vec::Vec::new(),
+            height: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkaneInventoryRequest {
+impl ::protobuf::MessageFull for BlockResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneInventoryRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BlockResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkaneInventoryRequest {
+impl ::std::fmt::Display for BlockResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkaneInventoryRequest {
+impl ::protobuf::reflect::ProtobufValue for BlockResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkaneIdToOutpointRequest)
+// @@protoc_insertion_point(message:alkanes.AlkaneEventRecord)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkaneIdToOutpointRequest {
+pub struct AlkaneEventRecord {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkaneIdToOutpointRequest.id)
-    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneEventRecord.event)
+    pub event: ::protobuf::MessageField<AlkaneEvent>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneEventRecord.outpoint)
+    pub outpoint: ::protobuf::MessageField<Outpoint>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneEventRecord.height)
+    pub height: u64,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkaneIdToOutpointRequest.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneEventRecord.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkaneIdToOutpointRequest {
-    fn default() -> &'a AlkaneIdToOutpointRequest {
-        <AlkaneIdToOutpointRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a AlkaneEventRecord {
+    fn default() -> &'a AlkaneEventRecord {
+        <AlkaneEventRecord as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkaneIdToOutpointRequest {
-    pub fn new() -> AlkaneIdToOutpointRequest {
+impl AlkaneEventRecord {
+    pub fn new() -> AlkaneEventRecord {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
-            "id",
-            |m: &AlkaneIdToOutpointRequest| { &m.id },
-            |m: &mut AlkaneIdToOutpointRequest| { &mut m.id },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneEvent>(
+            "event",
+            |m: &AlkaneEventRecord| { &m.event },
+            |m: &mut AlkaneEventRecord| { &mut m.event },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneIdToOutpointRequest>(
-            "AlkaneIdToOutpointRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
+            "outpoint",
+            |m: &AlkaneEventRecord| { &m.outpoint },
+            |m: &mut AlkaneEventRecord| { &mut m.outpoint },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &AlkaneEventRecord| { &m.height },
+            |m: &mut AlkaneEventRecord| { &mut m.height },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneEventRecord>(
+            "AlkaneEventRecord",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkaneIdToOutpointRequest {
-    const NAME: &'static str = "AlkaneIdToOutpointRequest";
+impl ::protobuf::Message for AlkaneEventRecord {
+    const NAME: &'static str = "AlkaneEventRecord";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3108,7 +7285,13 @@ impl ::protobuf::Message for AlkaneIdToOutpointRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.event)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
+                },
+                24 => {
+                    self.height = is.read_uint64()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3122,19 +7305,32 @@ impl ::protobuf::Message for AlkaneIdToOutpointRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.id.as_ref() {
+        if let Some(v) = self.event.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.outpoint.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.height);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.id.as_ref() {
+        if let Some(v) = self.event.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         }
+        if let Some(v) = self.outpoint.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(3, self.height)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3147,81 +7343,113 @@ impl ::protobuf::Message for AlkaneIdToOutpointRequest {
         &mut self.special_fields
     }
 
-    fn new() -> AlkaneIdToOutpointRequest {
-        AlkaneIdToOutpointRequest::new()
+    fn new() -> AlkaneEventRecord {
+        AlkaneEventRecord::new()
     }
 
     fn clear(&mut self) {
-        self.id.clear();
+        self.event.clear();
+        self.outpoint.clear();
+        self.height = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkaneIdToOutpointRequest {
-        static instance: AlkaneIdToOutpointRequest = AlkaneIdToOutpointRequest {
-            id: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static AlkaneEventRecord {
+        static instance: AlkaneEventRecord = AlkaneEventRecord {
+            event: ::protobuf::MessageField::none(),
+            outpoint: ::protobuf::MessageField::none(),
+            height: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkaneIdToOutpointRequest {
+impl ::protobuf::MessageFull for AlkaneEventRecord {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneIdToOutpointRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneEventRecord").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkaneIdToOutpointRequest {
+impl ::std::fmt::Display for AlkaneEventRecord {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkaneIdToOutpointRequest {
+impl ::protobuf::reflect::ProtobufValue for AlkaneEventRecord {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkaneInventoryResponse)
+// @@protoc_insertion_point(message:alkanes.EventsByAlkaneRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkaneInventoryResponse {
+pub struct EventsByAlkaneRequest {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkaneInventoryResponse.alkanes)
-    pub alkanes: ::std::vec::Vec<AlkaneTransfer>,
+    // @@protoc_insertion_point(field:alkanes.EventsByAlkaneRequest.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.EventsByAlkaneRequest.from_height)
+    pub from_height: u64,
+    // @@protoc_insertion_point(field:alkanes.EventsByAlkaneRequest.to_height)
+    pub to_height: u64,
+    // @@protoc_insertion_point(field:alkanes.EventsByAlkaneRequest.cursor)
+    pub cursor: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.EventsByAlkaneRequest.limit)
+    pub limit: u32,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkaneInventoryResponse.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.EventsByAlkaneRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkaneInventoryResponse {
-    fn default() -> &'a AlkaneInventoryResponse {
-        <AlkaneInventoryResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a EventsByAlkaneRequest {
+    fn default() -> &'a EventsByAlkaneRequest {
+        <EventsByAlkaneRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkaneInventoryResponse {
-    pub fn new() -> AlkaneInventoryResponse {
+impl EventsByAlkaneRequest {
+    pub fn new() -> EventsByAlkaneRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "alkanes",
-            |m: &AlkaneInventoryResponse| { &m.alkanes },
-            |m: &mut AlkaneInventoryResponse| { &mut m.alkanes },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &EventsByAlkaneRequest| { &m.id },
+            |m: &mut EventsByAlkaneRequest| { &mut m.id },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneInventoryResponse>(
-            "AlkaneInventoryResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "from_height",
+            |m: &EventsByAlkaneRequest| { &m.from_height },
+            |m: &mut EventsByAlkaneRequest| { &mut m.from_height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "to_height",
+            |m: &EventsByAlkaneRequest| { &m.to_height },
+            |m: &mut EventsByAlkaneRequest| { &mut m.to_height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "cursor",
+            |m: &EventsByAlkaneRequest| { &m.cursor },
+            |m: &mut EventsByAlkaneRequest| { &mut m.cursor },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &EventsByAlkaneRequest| { &m.limit },
+            |m: &mut EventsByAlkaneRequest| { &mut m.limit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EventsByAlkaneRequest>(
+            "EventsByAlkaneRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkaneInventoryResponse {
-    const NAME: &'static str = "AlkaneInventoryResponse";
+impl ::protobuf::Message for EventsByAlkaneRequest {
+    const NAME: &'static str = "EventsByAlkaneRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3231,7 +7459,19 @@ impl ::protobuf::Message for AlkaneInventoryResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.alkanes.push(is.read_message()?);
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                },
+                16 => {
+                    self.from_height = is.read_uint64()?;
+                },
+                24 => {
+                    self.to_height = is.read_uint64()?;
+                },
+                34 => {
+                    self.cursor = is.read_bytes()?;
+                },
+                40 => {
+                    self.limit = is.read_uint32()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3245,19 +7485,43 @@ impl ::protobuf::Message for AlkaneInventoryResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.alkanes {
-            let len = value.compute_size();
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
+        }
+        if self.from_height != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.from_height);
+        }
+        if self.to_height != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.to_height);
+        }
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.cursor);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.limit);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.alkanes {
+        if let Some(v) = self.id.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
+        }
+        if self.from_height != 0 {
+            os.write_uint64(2, self.from_height)?;
+        }
+        if self.to_height != 0 {
+            os.write_uint64(3, self.to_height)?;
+        }
+        if !self.cursor.is_empty() {
+            os.write_bytes(4, &self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(5, self.limit)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3270,88 +7534,96 @@ impl ::protobuf::Message for AlkaneInventoryResponse {
         &mut self.special_fields
     }
 
-    fn new() -> AlkaneInventoryResponse {
-        AlkaneInventoryResponse::new()
+    fn new() -> EventsByAlkaneRequest {
+        EventsByAlkaneRequest::new()
     }
 
     fn clear(&mut self) {
-        self.alkanes.clear();
+        self.id.clear();
+        self.from_height = 0;
+        self.to_height = 0;
+        self.cursor.clear();
+        self.limit = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkaneInventoryResponse {
-        static instance: AlkaneInventoryResponse = AlkaneInventoryResponse {
-            alkanes: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static EventsByAlkaneRequest {
+        static instance: EventsByAlkaneRequest = EventsByAlkaneRequest {
+            id: ::protobuf::MessageField::none(),
+            from_height: 0,
+            to_height: 0,
+            cursor: ::std::vec::Vec::new(),
+            limit: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkaneInventoryResponse {
+impl ::protobuf::MessageFull for EventsByAlkaneRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneInventoryResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EventsByAlkaneRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkaneInventoryResponse {
+impl ::std::fmt::Display for EventsByAlkaneRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkaneInventoryResponse {
+impl ::protobuf::reflect::ProtobufValue for EventsByAlkaneRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.AlkaneIdToOutpointResponse)
+// @@protoc_insertion_point(message:alkanes.EventsResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AlkaneIdToOutpointResponse {
+pub struct EventsResponse {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.AlkaneIdToOutpointResponse.txid)
-    pub txid: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:alkanes.AlkaneIdToOutpointResponse.vout)
-    pub vout: u32,
+    // @@protoc_insertion_point(field:alkanes.EventsResponse.events)
+    pub events: ::std::vec::Vec<AlkaneEventRecord>,
+    // @@protoc_insertion_point(field:alkanes.EventsResponse.next_cursor)
+    pub next_cursor: ::std::vec::Vec<u8>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.AlkaneIdToOutpointResponse.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.EventsResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AlkaneIdToOutpointResponse {
-    fn default() -> &'a AlkaneIdToOutpointResponse {
-        <AlkaneIdToOutpointResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a EventsResponse {
+    fn default() -> &'a EventsResponse {
+        <EventsResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AlkaneIdToOutpointResponse {
-    pub fn new() -> AlkaneIdToOutpointResponse {
+impl EventsResponse {
+    pub fn new() -> EventsResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "txid",
-            |m: &AlkaneIdToOutpointResponse| { &m.txid },
-            |m: &mut AlkaneIdToOutpointResponse| { &mut m.txid },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &EventsResponse| { &m.events },
+            |m: &mut EventsResponse| { &mut m.events },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "vout",
-            |m: &AlkaneIdToOutpointResponse| { &m.vout },
-            |m: &mut AlkaneIdToOutpointResponse| { &mut m.vout },
+            "next_cursor",
+            |m: &EventsResponse| { &m.next_cursor },
+            |m: &mut EventsResponse| { &mut m.next_cursor },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneIdToOutpointResponse>(
-            "AlkaneIdToOutpointResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EventsResponse>(
+            "EventsResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AlkaneIdToOutpointResponse {
-    const NAME: &'static str = "AlkaneIdToOutpointResponse";
+impl ::protobuf::Message for EventsResponse {
+    const NAME: &'static str = "EventsResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3361,10 +7633,10 @@ impl ::protobuf::Message for AlkaneIdToOutpointResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.txid = is.read_bytes()?;
+                    self.events.push(is.read_message()?);
                 },
-                16 => {
-                    self.vout = is.read_uint32()?;
+                18 => {
+                    self.next_cursor = is.read_bytes()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3378,11 +7650,12 @@ impl ::protobuf::Message for AlkaneIdToOutpointResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.txid.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.txid);
-        }
-        if self.vout != 0 {
-            my_size += ::protobuf::rt::uint32_size(2, self.vout);
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.next_cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.next_cursor);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -3390,11 +7663,11 @@ impl ::protobuf::Message for AlkaneIdToOutpointResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.txid.is_empty() {
-            os.write_bytes(1, &self.txid)?;
-        }
-        if self.vout != 0 {
-            os.write_uint32(2, self.vout)?;
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if !self.next_cursor.is_empty() {
+            os.write_bytes(2, &self.next_cursor)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3408,90 +7681,90 @@ impl ::protobuf::Message for AlkaneIdToOutpointResponse {
         &mut self.special_fields
     }
 
-    fn new() -> AlkaneIdToOutpointResponse {
-        AlkaneIdToOutpointResponse::new()
+    fn new() -> EventsResponse {
+        EventsResponse::new()
     }
 
-    fn clear(&mut self) {
-        self.txid.clear();
-        self.vout = 0;
+    fn clear(&mut self) {
+        self.events.clear();
+        self.next_cursor.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AlkaneIdToOutpointResponse {
-        static instance: AlkaneIdToOutpointResponse = AlkaneIdToOutpointResponse {
-            txid: ::std::vec::Vec::new(),
-            vout: 0,
+    fn default_instance() -> &'static EventsResponse {
+        static instance: EventsResponse = EventsResponse {
+            events: ::std::vec::Vec::new(),
+            next_cursor: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AlkaneIdToOutpointResponse {
+impl ::protobuf::MessageFull for EventsResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneIdToOutpointResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EventsResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AlkaneIdToOutpointResponse {
+impl ::std::fmt::Display for EventsResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AlkaneIdToOutpointResponse {
+impl ::protobuf::reflect::ProtobufValue for EventsResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.Outpoint)
+// @@protoc_insertion_point(message:alkanes.CreatedAlkane)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct Outpoint {
+pub struct CreatedAlkane {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.Outpoint.txid)
-    pub txid: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:alkanes.Outpoint.vout)
-    pub vout: u32,
+    // @@protoc_insertion_point(field:alkanes.CreatedAlkane.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.CreatedAlkane.outpoint)
+    pub outpoint: ::protobuf::MessageField<Outpoint>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.Outpoint.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.CreatedAlkane.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Outpoint {
-    fn default() -> &'a Outpoint {
-        <Outpoint as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CreatedAlkane {
+    fn default() -> &'a CreatedAlkane {
+        <CreatedAlkane as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Outpoint {
-    pub fn new() -> Outpoint {
+impl CreatedAlkane {
+    pub fn new() -> CreatedAlkane {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "txid",
-            |m: &Outpoint| { &m.txid },
-            |m: &mut Outpoint| { &mut m.txid },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &CreatedAlkane| { &m.id },
+            |m: &mut CreatedAlkane| { &mut m.id },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "vout",
-            |m: &Outpoint| { &m.vout },
-            |m: &mut Outpoint| { &mut m.vout },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
+            "outpoint",
+            |m: &CreatedAlkane| { &m.outpoint },
+            |m: &mut CreatedAlkane| { &mut m.outpoint },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Outpoint>(
-            "Outpoint",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CreatedAlkane>(
+            "CreatedAlkane",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Outpoint {
-    const NAME: &'static str = "Outpoint";
+impl ::protobuf::Message for CreatedAlkane {
+    const NAME: &'static str = "CreatedAlkane";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3501,10 +7774,10 @@ impl ::protobuf::Message for Outpoint {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.txid = is.read_bytes()?;
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
                 },
-                16 => {
-                    self.vout = is.read_uint32()?;
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3518,11 +7791,13 @@ impl ::protobuf::Message for Outpoint {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.txid.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.txid);
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
-        if self.vout != 0 {
-            my_size += ::protobuf::rt::uint32_size(2, self.vout);
+        if let Some(v) = self.outpoint.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -3530,11 +7805,11 @@ impl ::protobuf::Message for Outpoint {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.txid.is_empty() {
-            os.write_bytes(1, &self.txid)?;
+        if let Some(v) = self.id.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         }
-        if self.vout != 0 {
-            os.write_uint32(2, self.vout)?;
+        if let Some(v) = self.outpoint.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3548,64 +7823,64 @@ impl ::protobuf::Message for Outpoint {
         &mut self.special_fields
     }
 
-    fn new() -> Outpoint {
-        Outpoint::new()
+    fn new() -> CreatedAlkane {
+        CreatedAlkane::new()
     }
 
     fn clear(&mut self) {
-        self.txid.clear();
-        self.vout = 0;
+        self.id.clear();
+        self.outpoint.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Outpoint {
-        static instance: Outpoint = Outpoint {
-            txid: ::std::vec::Vec::new(),
-            vout: 0,
+    fn default_instance() -> &'static CreatedAlkane {
+        static instance: CreatedAlkane = CreatedAlkane {
+            id: ::protobuf::MessageField::none(),
+            outpoint: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Outpoint {
+impl ::protobuf::MessageFull for CreatedAlkane {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Outpoint").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CreatedAlkane").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Outpoint {
+impl ::std::fmt::Display for CreatedAlkane {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Outpoint {
+impl ::protobuf::reflect::ProtobufValue for CreatedAlkane {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.Trace)
+// @@protoc_insertion_point(message:alkanes.OutpointBalances)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct Trace {
+pub struct OutpointBalances {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.Trace.outpoint)
+    // @@protoc_insertion_point(field:alkanes.OutpointBalances.outpoint)
     pub outpoint: ::protobuf::MessageField<Outpoint>,
-    // @@protoc_insertion_point(field:alkanes.Trace.trace)
-    pub trace: ::protobuf::MessageField<AlkanesTrace>,
+    // @@protoc_insertion_point(field:alkanes.OutpointBalances.balances)
+    pub balances: ::std::vec::Vec<AlkaneTransfer>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.Trace.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.OutpointBalances.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Trace {
-    fn default() -> &'a Trace {
-        <Trace as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a OutpointBalances {
+    fn default() -> &'a OutpointBalances {
+        <OutpointBalances as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Trace {
-    pub fn new() -> Trace {
+impl OutpointBalances {
+    pub fn new() -> OutpointBalances {
         ::std::default::Default::default()
     }
 
@@ -3614,24 +7889,24 @@ impl Trace {
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
             "outpoint",
-            |m: &Trace| { &m.outpoint },
-            |m: &mut Trace| { &mut m.outpoint },
+            |m: &OutpointBalances| { &m.outpoint },
+            |m: &mut OutpointBalances| { &mut m.outpoint },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkanesTrace>(
-            "trace",
-            |m: &Trace| { &m.trace },
-            |m: &mut Trace| { &mut m.trace },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "balances",
+            |m: &OutpointBalances| { &m.balances },
+            |m: &mut OutpointBalances| { &mut m.balances },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Trace>(
-            "Trace",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<OutpointBalances>(
+            "OutpointBalances",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Trace {
-    const NAME: &'static str = "Trace";
+impl ::protobuf::Message for OutpointBalances {
+    const NAME: &'static str = "OutpointBalances";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3644,7 +7919,7 @@ impl ::protobuf::Message for Trace {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
                 },
                 18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.trace)?;
+                    self.balances.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3662,10 +7937,10 @@ impl ::protobuf::Message for Trace {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
-        if let Some(v) = self.trace.as_ref() {
-            let len = v.compute_size();
+        for value in &self.balances {
+            let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -3675,9 +7950,9 @@ impl ::protobuf::Message for Trace {
         if let Some(v) = self.outpoint.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         }
-        if let Some(v) = self.trace.as_ref() {
+        for v in &self.balances {
             ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-        }
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3690,83 +7965,125 @@ impl ::protobuf::Message for Trace {
         &mut self.special_fields
     }
 
-    fn new() -> Trace {
-        Trace::new()
+    fn new() -> OutpointBalances {
+        OutpointBalances::new()
     }
 
     fn clear(&mut self) {
         self.outpoint.clear();
-        self.trace.clear();
+        self.balances.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Trace {
-        static instance: Trace = Trace {
+    fn default_instance() -> &'static OutpointBalances {
+        static instance: OutpointBalances = OutpointBalances {
             outpoint: ::protobuf::MessageField::none(),
-            trace: ::protobuf::MessageField::none(),
+            balances: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Trace {
+impl ::protobuf::MessageFull for OutpointBalances {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Trace").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("OutpointBalances").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Trace {
+impl ::std::fmt::Display for OutpointBalances {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Trace {
+impl ::protobuf::reflect::ProtobufValue for OutpointBalances {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.TraceBlockRequest)
+// @@protoc_insertion_point(message:alkanes.BlockNotification)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct TraceBlockRequest {
+pub struct BlockNotification {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.TraceBlockRequest.block)
-    pub block: u64,
+    // @@protoc_insertion_point(field:alkanes.BlockNotification.version)
+    pub version: u32,
+    // @@protoc_insertion_point(field:alkanes.BlockNotification.height)
+    pub height: u64,
+    // @@protoc_insertion_point(field:alkanes.BlockNotification.blockhash)
+    pub blockhash: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.BlockNotification.outpoint_balances)
+    pub outpoint_balances: ::std::vec::Vec<OutpointBalances>,
+    // @@protoc_insertion_point(field:alkanes.BlockNotification.created_alkanes)
+    pub created_alkanes: ::std::vec::Vec<CreatedAlkane>,
+    // @@protoc_insertion_point(field:alkanes.BlockNotification.traces)
+    pub traces: ::std::vec::Vec<AlkanesBlockEvent>,
+    // @@protoc_insertion_point(field:alkanes.BlockNotification.spent_outpoints)
+    pub spent_outpoints: ::std::vec::Vec<Outpoint>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.TraceBlockRequest.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.BlockNotification.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a TraceBlockRequest {
-    fn default() -> &'a TraceBlockRequest {
-        <TraceBlockRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a BlockNotification {
+    fn default() -> &'a BlockNotification {
+        <BlockNotification as ::protobuf::Message>::default_instance()
     }
 }
 
-impl TraceBlockRequest {
-    pub fn new() -> TraceBlockRequest {
+impl BlockNotification {
+    pub fn new() -> BlockNotification {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(7);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "block",
-            |m: &TraceBlockRequest| { &m.block },
-            |m: &mut TraceBlockRequest| { &mut m.block },
+            "version",
+            |m: &BlockNotification| { &m.version },
+            |m: &mut BlockNotification| { &mut m.version },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TraceBlockRequest>(
-            "TraceBlockRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &BlockNotification| { &m.height },
+            |m: &mut BlockNotification| { &mut m.height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "blockhash",
+            |m: &BlockNotification| { &m.blockhash },
+            |m: &mut BlockNotification| { &mut m.blockhash },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "outpoint_balances",
+            |m: &BlockNotification| { &m.outpoint_balances },
+            |m: &mut BlockNotification| { &mut m.outpoint_balances },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "created_alkanes",
+            |m: &BlockNotification| { &m.created_alkanes },
+            |m: &mut BlockNotification| { &mut m.created_alkanes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "traces",
+            |m: &BlockNotification| { &m.traces },
+            |m: &mut BlockNotification| { &mut m.traces },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "spent_outpoints",
+            |m: &BlockNotification| { &m.spent_outpoints },
+            |m: &mut BlockNotification| { &mut m.spent_outpoints },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BlockNotification>(
+            "BlockNotification",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for TraceBlockRequest {
-    const NAME: &'static str = "TraceBlockRequest";
+impl ::protobuf::Message for BlockNotification {
+    const NAME: &'static str = "BlockNotification";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3776,7 +8093,25 @@ impl ::protobuf::Message for TraceBlockRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.block = is.read_uint64()?;
+                    self.version = is.read_uint32()?;
+                },
+                16 => {
+                    self.height = is.read_uint64()?;
+                },
+                26 => {
+                    self.blockhash = is.read_bytes()?;
+                },
+                34 => {
+                    self.outpoint_balances.push(is.read_message()?);
+                },
+                42 => {
+                    self.created_alkanes.push(is.read_message()?);
+                },
+                50 => {
+                    self.traces.push(is.read_message()?);
+                },
+                58 => {
+                    self.spent_outpoints.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3790,18 +8125,58 @@ impl ::protobuf::Message for TraceBlockRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.block != 0 {
-            my_size += ::protobuf::rt::uint64_size(1, self.block);
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.version);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.height);
+        }
+        if !self.blockhash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.blockhash);
         }
+        for value in &self.outpoint_balances {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.created_alkanes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.traces {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.spent_outpoints {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.block != 0 {
-            os.write_uint64(1, self.block)?;
+        if self.version != 0 {
+            os.write_uint32(1, self.version)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if !self.blockhash.is_empty() {
+            os.write_bytes(3, &self.blockhash)?;
         }
+        for v in &self.outpoint_balances {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        for v in &self.created_alkanes {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        for v in &self.traces {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        };
+        for v in &self.spent_outpoints {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3814,81 +8189,142 @@ impl ::protobuf::Message for TraceBlockRequest {
         &mut self.special_fields
     }
 
-    fn new() -> TraceBlockRequest {
-        TraceBlockRequest::new()
+    fn new() -> BlockNotification {
+        BlockNotification::new()
     }
 
     fn clear(&mut self) {
-        self.block = 0;
+        self.version = 0;
+        self.height = 0;
+        self.blockhash.clear();
+        self.outpoint_balances.clear();
+        self.created_alkanes.clear();
+        self.traces.clear();
+        self.spent_outpoints.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static TraceBlockRequest {
-        static instance: TraceBlockRequest = TraceBlockRequest {
-            block: 0,
+    fn default_instance() -> &'static BlockNotification {
+        static instance: BlockNotification = BlockNotification {
+            version: 0,
+            height: 0,
+            blockhash: ::std::vec::Vec::new(),
+            outpoint_balances: ::std::vec::Vec::new(),
+            created_alkanes: ::std::vec::Vec::new(),
+            traces: ::std::vec::Vec::new(),
+            spent_outpoints: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for TraceBlockRequest {
+impl ::protobuf::MessageFull for BlockNotification {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("TraceBlockRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BlockNotification").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for TraceBlockRequest {
+impl ::std::fmt::Display for BlockNotification {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for TraceBlockRequest {
+impl ::protobuf::reflect::ProtobufValue for BlockNotification {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.TraceBlockResponse)
+// @@protoc_insertion_point(message:alkanes.AlkaneRegistryEntry)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct TraceBlockResponse {
+pub struct AlkaneRegistryEntry {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.TraceBlockResponse.traces)
-    pub traces: ::std::vec::Vec<Trace>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRegistryEntry.id)
+    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRegistryEntry.height)
+    pub height: u64,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRegistryEntry.outpoint)
+    pub outpoint: ::protobuf::MessageField<Outpoint>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRegistryEntry.kind)
+    pub kind: ::protobuf::EnumOrUnknown<AlkaneDeployKind>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRegistryEntry.factory)
+    pub factory: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRegistryEntry.bytecode_hash)
+    pub bytecode_hash: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRegistryEntry.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRegistryEntry.symbol)
+    pub symbol: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.TraceBlockResponse.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneRegistryEntry.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a TraceBlockResponse {
-    fn default() -> &'a TraceBlockResponse {
-        <TraceBlockResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a AlkaneRegistryEntry {
+    fn default() -> &'a AlkaneRegistryEntry {
+        <AlkaneRegistryEntry as ::protobuf::Message>::default_instance()
     }
 }
 
-impl TraceBlockResponse {
-    pub fn new() -> TraceBlockResponse {
+impl AlkaneRegistryEntry {
+    pub fn new() -> AlkaneRegistryEntry {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(8);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "traces",
-            |m: &TraceBlockResponse| { &m.traces },
-            |m: &mut TraceBlockResponse| { &mut m.traces },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "id",
+            |m: &AlkaneRegistryEntry| { &m.id },
+            |m: &mut AlkaneRegistryEntry| { &mut m.id },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TraceBlockResponse>(
-            "TraceBlockResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &AlkaneRegistryEntry| { &m.height },
+            |m: &mut AlkaneRegistryEntry| { &mut m.height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
+            "outpoint",
+            |m: &AlkaneRegistryEntry| { &m.outpoint },
+            |m: &mut AlkaneRegistryEntry| { &mut m.outpoint },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "kind",
+            |m: &AlkaneRegistryEntry| { &m.kind },
+            |m: &mut AlkaneRegistryEntry| { &mut m.kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
+            "factory",
+            |m: &AlkaneRegistryEntry| { &m.factory },
+            |m: &mut AlkaneRegistryEntry| { &mut m.factory },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bytecode_hash",
+            |m: &AlkaneRegistryEntry| { &m.bytecode_hash },
+            |m: &mut AlkaneRegistryEntry| { &mut m.bytecode_hash },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &AlkaneRegistryEntry| { &m.name },
+            |m: &mut AlkaneRegistryEntry| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "symbol",
+            |m: &AlkaneRegistryEntry| { &m.symbol },
+            |m: &mut AlkaneRegistryEntry| { &mut m.symbol },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneRegistryEntry>(
+            "AlkaneRegistryEntry",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for TraceBlockResponse {
-    const NAME: &'static str = "TraceBlockResponse";
+impl ::protobuf::Message for AlkaneRegistryEntry {
+    const NAME: &'static str = "AlkaneRegistryEntry";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3898,7 +8334,28 @@ impl ::protobuf::Message for TraceBlockResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.traces.push(is.read_message()?);
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                },
+                16 => {
+                    self.height = is.read_uint64()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
+                },
+                32 => {
+                    self.kind = is.read_enum_or_unknown()?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.factory)?;
+                },
+                50 => {
+                    self.bytecode_hash = is.read_bytes()?;
+                },
+                58 => {
+                    self.name = is.read_string()?;
+                },
+                66 => {
+                    self.symbol = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3912,19 +8369,63 @@ impl ::protobuf::Message for TraceBlockResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.traces {
-            let len = value.compute_size();
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.height);
+        }
+        if let Some(v) = self.outpoint.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.kind != ::protobuf::EnumOrUnknown::new(AlkaneDeployKind::CREATE) {
+            my_size += ::protobuf::rt::int32_size(4, self.kind.value());
+        }
+        if let Some(v) = self.factory.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.bytecode_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.bytecode_hash);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.name);
+        }
+        if !self.symbol.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.symbol);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.traces {
+        if let Some(v) = self.id.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if let Some(v) = self.outpoint.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if self.kind != ::protobuf::EnumOrUnknown::new(AlkaneDeployKind::CREATE) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.kind))?;
+        }
+        if let Some(v) = self.factory.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        if !self.bytecode_hash.is_empty() {
+            os.write_bytes(6, &self.bytecode_hash)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(7, &self.name)?;
+        }
+        if !self.symbol.is_empty() {
+            os.write_string(8, &self.symbol)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3937,81 +8438,109 @@ impl ::protobuf::Message for TraceBlockResponse {
         &mut self.special_fields
     }
 
-    fn new() -> TraceBlockResponse {
-        TraceBlockResponse::new()
+    fn new() -> AlkaneRegistryEntry {
+        AlkaneRegistryEntry::new()
     }
 
     fn clear(&mut self) {
-        self.traces.clear();
+        self.id.clear();
+        self.height = 0;
+        self.outpoint.clear();
+        self.kind = ::protobuf::EnumOrUnknown::new(AlkaneDeployKind::CREATE);
+        self.factory.clear();
+        self.bytecode_hash.clear();
+        self.name.clear();
+        self.symbol.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static TraceBlockResponse {
-        static instance: TraceBlockResponse = TraceBlockResponse {
-            traces: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static AlkaneRegistryEntry {
+        static instance: AlkaneRegistryEntry = AlkaneRegistryEntry {
+            id: ::protobuf::MessageField::none(),
+            height: 0,
+            outpoint: ::protobuf::MessageField::none(),
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            factory: ::protobuf::MessageField::none(),
+            bytecode_hash: ::std::vec::Vec::new(),
+            name: ::std::string::String::new(),
+            symbol: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for TraceBlockResponse {
+impl ::protobuf::MessageFull for AlkaneRegistryEntry {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("TraceBlockResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneRegistryEntry").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for TraceBlockResponse {
+impl ::std::fmt::Display for AlkaneRegistryEntry {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for TraceBlockResponse {
+impl ::protobuf::reflect::ProtobufValue for AlkaneRegistryEntry {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.BytecodeRequest)
+// @@protoc_insertion_point(message:alkanes.ListAlkanesRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct BytecodeRequest {
+pub struct ListAlkanesRequest {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.BytecodeRequest.id)
-    pub id: ::protobuf::MessageField<AlkaneId>,
+    // @@protoc_insertion_point(field:alkanes.ListAlkanesRequest.cursor)
+    pub cursor: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.ListAlkanesRequest.limit)
+    pub limit: u32,
+    // @@protoc_insertion_point(field:alkanes.ListAlkanesRequest.descending)
+    pub descending: bool,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.BytecodeRequest.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.ListAlkanesRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a BytecodeRequest {
-    fn default() -> &'a BytecodeRequest {
-        <BytecodeRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ListAlkanesRequest {
+    fn default() -> &'a ListAlkanesRequest {
+        <ListAlkanesRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl BytecodeRequest {
-    pub fn new() -> BytecodeRequest {
+impl ListAlkanesRequest {
+    pub fn new() -> ListAlkanesRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlkaneId>(
-            "id",
-            |m: &BytecodeRequest| { &m.id },
-            |m: &mut BytecodeRequest| { &mut m.id },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "cursor",
+            |m: &ListAlkanesRequest| { &m.cursor },
+            |m: &mut ListAlkanesRequest| { &mut m.cursor },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BytecodeRequest>(
-            "BytecodeRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &ListAlkanesRequest| { &m.limit },
+            |m: &mut ListAlkanesRequest| { &mut m.limit },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "descending",
+            |m: &ListAlkanesRequest| { &m.descending },
+            |m: &mut ListAlkanesRequest| { &mut m.descending },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListAlkanesRequest>(
+            "ListAlkanesRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for BytecodeRequest {
-    const NAME: &'static str = "BytecodeRequest";
+impl ::protobuf::Message for ListAlkanesRequest {
+    const NAME: &'static str = "ListAlkanesRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -4021,7 +8550,13 @@ impl ::protobuf::Message for BytecodeRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                    self.cursor = is.read_bytes()?;
+                },
+                16 => {
+                    self.limit = is.read_uint32()?;
+                },
+                24 => {
+                    self.descending = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -4035,9 +8570,14 @@ impl ::protobuf::Message for BytecodeRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.id.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.cursor);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.limit);
+        }
+        if self.descending != false {
+            my_size += 1 + 1;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -4045,8 +8585,14 @@ impl ::protobuf::Message for BytecodeRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.id.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        if !self.cursor.is_empty() {
+            os.write_bytes(1, &self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(2, self.limit)?;
+        }
+        if self.descending != false {
+            os.write_bool(3, self.descending)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4060,81 +8606,99 @@ impl ::protobuf::Message for BytecodeRequest {
         &mut self.special_fields
     }
 
-    fn new() -> BytecodeRequest {
-        BytecodeRequest::new()
+    fn new() -> ListAlkanesRequest {
+        ListAlkanesRequest::new()
     }
 
     fn clear(&mut self) {
-        self.id.clear();
+        self.cursor.clear();
+        self.limit = 0;
+        self.descending = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static BytecodeRequest {
-        static instance: BytecodeRequest = BytecodeRequest {
-            id: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static ListAlkanesRequest {
+        static instance: ListAlkanesRequest = ListAlkanesRequest {
+            cursor: ::std::vec::Vec::new(),
+            limit: 0,
+            descending: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for BytecodeRequest {
+impl ::protobuf::MessageFull for ListAlkanesRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("BytecodeRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListAlkanesRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for BytecodeRequest {
+impl ::std::fmt::Display for ListAlkanesRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for BytecodeRequest {
+impl ::protobuf::reflect::ProtobufValue for ListAlkanesRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.BlockRequest)
+// @@protoc_insertion_point(message:alkanes.AlkanesByBytecodeHashRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct BlockRequest {
+pub struct AlkanesByBytecodeHashRequest {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.BlockRequest.height)
-    pub height: u32,
+    // @@protoc_insertion_point(field:alkanes.AlkanesByBytecodeHashRequest.bytecode_hash)
+    pub bytecode_hash: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesByBytecodeHashRequest.cursor)
+    pub cursor: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:alkanes.AlkanesByBytecodeHashRequest.limit)
+    pub limit: u32,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.BlockRequest.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.AlkanesByBytecodeHashRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a BlockRequest {
-    fn default() -> &'a BlockRequest {
-        <BlockRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a AlkanesByBytecodeHashRequest {
+    fn default() -> &'a AlkanesByBytecodeHashRequest {
+        <AlkanesByBytecodeHashRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl BlockRequest {
-    pub fn new() -> BlockRequest {
+impl AlkanesByBytecodeHashRequest {
+    pub fn new() -> AlkanesByBytecodeHashRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "height",
-            |m: &BlockRequest| { &m.height },
-            |m: &mut BlockRequest| { &mut m.height },
+            "bytecode_hash",
+            |m: &AlkanesByBytecodeHashRequest| { &m.bytecode_hash },
+            |m: &mut AlkanesByBytecodeHashRequest| { &mut m.bytecode_hash },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BlockRequest>(
-            "BlockRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "cursor",
+            |m: &AlkanesByBytecodeHashRequest| { &m.cursor },
+            |m: &mut AlkanesByBytecodeHashRequest| { &mut m.cursor },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &AlkanesByBytecodeHashRequest| { &m.limit },
+            |m: &mut AlkanesByBytecodeHashRequest| { &mut m.limit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkanesByBytecodeHashRequest>(
+            "AlkanesByBytecodeHashRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for BlockRequest {
-    const NAME: &'static str = "BlockRequest";
+impl ::protobuf::Message for AlkanesByBytecodeHashRequest {
+    const NAME: &'static str = "AlkanesByBytecodeHashRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -4143,8 +8707,14 @@ impl ::protobuf::Message for BlockRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.height = is.read_uint32()?;
+                10 => {
+                    self.bytecode_hash = is.read_bytes()?;
+                },
+                18 => {
+                    self.cursor = is.read_bytes()?;
+                },
+                24 => {
+                    self.limit = is.read_uint32()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -4158,8 +8728,14 @@ impl ::protobuf::Message for BlockRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.height != 0 {
-            my_size += ::protobuf::rt::uint32_size(1, self.height);
+        if !self.bytecode_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.bytecode_hash);
+        }
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.cursor);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.limit);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -4167,8 +8743,14 @@ impl ::protobuf::Message for BlockRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.height != 0 {
-            os.write_uint32(1, self.height)?;
+        if !self.bytecode_hash.is_empty() {
+            os.write_bytes(1, &self.bytecode_hash)?;
+        }
+        if !self.cursor.is_empty() {
+            os.write_bytes(2, &self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(3, self.limit)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4182,88 +8764,92 @@ impl ::protobuf::Message for BlockRequest {
         &mut self.special_fields
     }
 
-    fn new() -> BlockRequest {
-        BlockRequest::new()
+    fn new() -> AlkanesByBytecodeHashRequest {
+        AlkanesByBytecodeHashRequest::new()
     }
 
     fn clear(&mut self) {
-        self.height = 0;
+        self.bytecode_hash.clear();
+        self.cursor.clear();
+        self.limit = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static BlockRequest {
-        static instance: BlockRequest = BlockRequest {
-            height: 0,
+    fn default_instance() -> &'static AlkanesByBytecodeHashRequest {
+        static instance: AlkanesByBytecodeHashRequest = AlkanesByBytecodeHashRequest {
+            bytecode_hash: ::std::vec::Vec::new(),
+            cursor: ::std::vec::Vec::new(),
+            limit: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for BlockRequest {
+impl ::protobuf::MessageFull for AlkanesByBytecodeHashRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("BlockRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkanesByBytecodeHashRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for BlockRequest {
+impl ::std::fmt::Display for AlkanesByBytecodeHashRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for BlockRequest {
+impl ::protobuf::reflect::ProtobufValue for AlkanesByBytecodeHashRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:alkanes.BlockResponse)
+// @@protoc_insertion_point(message:alkanes.AlkaneRegistryResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct BlockResponse {
+pub struct AlkaneRegistryResponse {
     // message fields
-    // @@protoc_insertion_point(field:alkanes.BlockResponse.block)
-    pub block: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:alkanes.BlockResponse.height)
-    pub height: u32,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRegistryResponse.alkanes)
+    pub alkanes: ::std::vec::Vec<AlkaneRegistryEntry>,
+    // @@protoc_insertion_point(field:alkanes.AlkaneRegistryResponse.next_cursor)
+    pub next_cursor: ::std::vec::Vec<u8>,
     // special fields
-    // @@protoc_insertion_point(special_field:alkanes.BlockResponse.special_fields)
+    // @@protoc_insertion_point(special_field:alkanes.AlkaneRegistryResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a BlockResponse {
-    fn default() -> &'a BlockResponse {
-        <BlockResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a AlkaneRegistryResponse {
+    fn default() -> &'a AlkaneRegistryResponse {
+        <AlkaneRegistryResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl BlockResponse {
-    pub fn new() -> BlockResponse {
+impl AlkaneRegistryResponse {
+    pub fn new() -> AlkaneRegistryResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "block",
-            |m: &BlockResponse| { &m.block },
-            |m: &mut BlockResponse| { &mut m.block },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "alkanes",
+            |m: &AlkaneRegistryResponse| { &m.alkanes },
+            |m: &mut AlkaneRegistryResponse| { &mut m.alkanes },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "height",
-            |m: &BlockResponse| { &m.height },
-            |m: &mut BlockResponse| { &mut m.height },
+            "next_cursor",
+            |m: &AlkaneRegistryResponse| { &m.next_cursor },
+            |m: &mut AlkaneRegistryResponse| { &mut m.next_cursor },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BlockResponse>(
-            "BlockResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AlkaneRegistryResponse>(
+            "AlkaneRegistryResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for BlockResponse {
-    const NAME: &'static str = "BlockResponse";
+impl ::protobuf::Message for AlkaneRegistryResponse {
+    const NAME: &'static str = "AlkaneRegistryResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -4273,10 +8859,10 @@ impl ::protobuf::Message for BlockResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.block = is.read_bytes()?;
+                    self.alkanes.push(is.read_message()?);
                 },
-                16 => {
-                    self.height = is.read_uint32()?;
+                18 => {
+                    self.next_cursor = is.read_bytes()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -4290,11 +8876,12 @@ impl ::protobuf::Message for BlockResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.block.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.block);
-        }
-        if self.height != 0 {
-            my_size += ::protobuf::rt::uint32_size(2, self.height);
+        for value in &self.alkanes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.next_cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.next_cursor);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -4302,11 +8889,11 @@ impl ::protobuf::Message for BlockResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.block.is_empty() {
-            os.write_bytes(1, &self.block)?;
-        }
-        if self.height != 0 {
-            os.write_uint32(2, self.height)?;
+        for v in &self.alkanes {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if !self.next_cursor.is_empty() {
+            os.write_bytes(2, &self.next_cursor)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4320,40 +8907,40 @@ impl ::protobuf::Message for BlockResponse {
         &mut self.special_fields
     }
 
-    fn new() -> BlockResponse {
-        BlockResponse::new()
+    fn new() -> AlkaneRegistryResponse {
+        AlkaneRegistryResponse::new()
     }
 
     fn clear(&mut self) {
-        self.block.clear();
-        self.height = 0;
+        self.alkanes.clear();
+        self.next_cursor.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static BlockResponse {
-        static instance: BlockResponse = BlockResponse {
-            block: ::std::vec::Vec::new(),
-            height: 0,
+    fn default_instance() -> &'static AlkaneRegistryResponse {
+        static instance: AlkaneRegistryResponse = AlkaneRegistryResponse {
+            alkanes: ::std::vec::Vec::new(),
+            next_cursor: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for BlockResponse {
+impl ::protobuf::MessageFull for AlkaneRegistryResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("BlockResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AlkaneRegistryResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for BlockResponse {
+impl ::std::fmt::Display for AlkaneRegistryResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for BlockResponse {
+impl ::protobuf::reflect::ProtobufValue for AlkaneRegistryResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
@@ -4491,6 +9078,73 @@ impl AlkanesTraceStatusFlag {
     }
 }
 
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:alkanes.AlkaneDeployKind)
+pub enum AlkaneDeployKind {
+    // @@protoc_insertion_point(enum_value:alkanes.AlkaneDeployKind.CREATE)
+    CREATE = 0,
+    // @@protoc_insertion_point(enum_value:alkanes.AlkaneDeployKind.CREATERESERVED)
+    CREATERESERVED = 1,
+    // @@protoc_insertion_point(enum_value:alkanes.AlkaneDeployKind.FACTORY)
+    FACTORY = 2,
+}
+
+impl ::protobuf::Enum for AlkaneDeployKind {
+    const NAME: &'static str = "AlkaneDeployKind";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<AlkaneDeployKind> {
+        match value {
+            0 => ::std::option::Option::Some(AlkaneDeployKind::CREATE),
+            1 => ::std::option::Option::Some(AlkaneDeployKind::CREATERESERVED),
+            2 => ::std::option::Option::Some(AlkaneDeployKind::FACTORY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<AlkaneDeployKind> {
+        match str {
+            "CREATE" => ::std::option::Option::Some(AlkaneDeployKind::CREATE),
+            "CREATERESERVED" => ::std::option::Option::Some(AlkaneDeployKind::CREATERESERVED),
+            "FACTORY" => ::std::option::Option::Some(AlkaneDeployKind::FACTORY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [AlkaneDeployKind] = &[
+        AlkaneDeployKind::CREATE,
+        AlkaneDeployKind::CREATERESERVED,
+        AlkaneDeployKind::FACTORY,
+    ];
+}
+
+impl ::protobuf::EnumFull for AlkaneDeployKind {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("AlkaneDeployKind").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for AlkaneDeployKind {
+    fn default() -> Self {
+        AlkaneDeployKind::CREATE
+    }
+}
+
+impl AlkaneDeployKind {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<AlkaneDeployKind>("AlkaneDeployKind")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\ralkanes.proto\x12\x07alkanes\")\n\x07uint128\x12\x0e\n\x02lo\x18\x01\
     \x20\x01(\x04R\x02lo\x12\x0e\n\x02hi\x18\x02\x20\x01(\x04R\x02hi\"T\n\
@@ -4499,7 +9153,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     tx\"[\n\x0eAlkaneTransfer\x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11.alkanes\
     .AlkaneIdR\x02id\x12&\n\x05value\x18\x02\x20\x01(\x0b2\x10.alkanes.uint1\
     28R\x05value\"O\n\x14MultiSimulateRequest\x127\n\x07parcels\x18\x01\x20\
-    \x03(\x0b2\x1d.alkanes.MessageContextParcelR\x07parcels\"\xa4\x02\n\x14M\
+    \x03(\x0b2\x1d.alkanes.MessageContextParcelR\x07parcels\"\xf5\x02\n\x14M\
     essageContextParcel\x121\n\x07alkanes\x18\x01\x20\x03(\x0b2\x17.alkanes.\
     AlkaneTransferR\x07alkanes\x12\x20\n\x0btransaction\x18\x02\x20\x01(\x0c\
     R\x0btransaction\x12\x14\n\x05block\x18\x03\x20\x01(\x0cR\x05block\x12\
@@ -4507,62 +9161,168 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x18\x06\x20\x01(\rR\x07txindex\x12\x1a\n\x08calldata\x18\x05\x20\x01(\
     \x0cR\x08calldata\x12\x12\n\x04vout\x18\x07\x20\x01(\rR\x04vout\x12\x18\
     \n\x07pointer\x18\x08\x20\x01(\rR\x07pointer\x12%\n\x0erefund_pointer\
-    \x18\t\x20\x01(\rR\rrefundPointer\"6\n\x0cKeyValuePair\x12\x10\n\x03key\
-    \x18\x01\x20\x01(\x0cR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\
-    \x05value\"\x8e\x01\n\x14ExtendedCallResponse\x121\n\x07alkanes\x18\x01\
-    \x20\x03(\x0b2\x17.alkanes.AlkaneTransferR\x07alkanes\x12/\n\x07storage\
-    \x18\x02\x20\x03(\x0b2\x15.alkanes.KeyValuePairR\x07storage\x12\x12\n\
-    \x04data\x18\x03\x20\x01(\x0cR\x04data\"\xe1\x01\n\x07Context\x12)\n\x06\
-    myself\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x06myself\x12)\n\x06c\
-    aller\x18\x02\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x06caller\x12(\n\x06in\
-    puts\x18\x03\x20\x03(\x0b2\x10.alkanes.uint128R\x06inputs\x12\x12\n\x04v\
-    out\x18\x04\x20\x01(\rR\x04vout\x12B\n\x10incoming_alkanes\x18\x05\x20\
-    \x03(\x0b2\x17.alkanes.AlkaneTransferR\x0fincomingAlkanes\"J\n\x0cTraceC\
-    ontext\x12&\n\x05inner\x18\x01\x20\x01(\x0b2\x10.alkanes.ContextR\x05inn\
-    er\x12\x12\n\x04fuel\x18\x02\x20\x01(\x04R\x04fuel\"\x82\x01\n\x13Alkane\
-    sEnterContext\x12:\n\tcall_type\x18\x01\x20\x01(\x0e2\x1d.alkanes.Alkane\
-    sTraceCallTypeR\x08callType\x12/\n\x07context\x18\x02\x20\x01(\x0b2\x15.\
-    alkanes.TraceContextR\x07context\"\x88\x01\n\x12AlkanesExitContext\x127\
-    \n\x06status\x18\x01\x20\x01(\x0e2\x1f.alkanes.AlkanesTraceStatusFlagR\
-    \x06status\x129\n\x08response\x18\x02\x20\x01(\x0b2\x1d.alkanes.Extended\
-    CallResponseR\x08response\"A\n\rAlkanesCreate\x120\n\nnew_alkane\x18\x01\
-    \x20\x01(\x0b2\x11.alkanes.AlkaneIdR\tnewAlkane\"\xe2\x01\n\x11AlkanesTr\
-    aceEvent\x12C\n\renter_context\x18\x01\x20\x01(\x0b2\x1c.alkanes.Alkanes\
-    EnterContextH\0R\x0centerContext\x12@\n\x0cexit_context\x18\x02\x20\x01(\
-    \x0b2\x1b.alkanes.AlkanesExitContextH\0R\x0bexitContext\x12=\n\rcreate_a\
-    lkane\x18\x03\x20\x01(\x0b2\x16.alkanes.AlkanesCreateH\0R\x0ccreateAlkan\
-    eB\x07\n\x05event\"\x8b\x01\n\x11AlkanesBlockEvent\x12-\n\x06traces\x18\
-    \x01\x20\x01(\x0b2\x15.alkanes.AlkanesTraceR\x06traces\x12-\n\x08outpoin\
-    t\x18\x02\x20\x01(\x0b2\x11.alkanes.OutpointR\x08outpoint\x12\x18\n\x07t\
-    xindex\x18\x03\x20\x01(\x04R\x07txindex\"L\n\x16AlkanesBlockTraceEvent\
-    \x122\n\x06events\x18\x01\x20\x03(\x0b2\x1a.alkanes.AlkanesBlockEventR\
-    \x06events\"B\n\x0cAlkanesTrace\x122\n\x06events\x18\x01\x20\x03(\x0b2\
-    \x1a.alkanes.AlkanesTraceEventR\x06events\"\x80\x01\n\x10SimulateRespons\
-    e\x12;\n\texecution\x18\x01\x20\x01(\x0b2\x1d.alkanes.ExtendedCallRespon\
-    seR\texecution\x12\x19\n\x08gas_used\x18\x02\x20\x01(\x04R\x07gasUsed\
-    \x12\x14\n\x05error\x18\x03\x20\x01(\tR\x05error\"f\n\x15MultiSimulateRe\
-    sponse\x127\n\tresponses\x18\x01\x20\x03(\x0b2\x19.alkanes.SimulateRespo\
-    nseR\tresponses\x12\x14\n\x05error\x18\x02\x20\x01(\tR\x05error\";\n\x16\
-    AlkaneInventoryRequest\x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11.alkanes.Al\
-    kaneIdR\x02id\">\n\x19AlkaneIdToOutpointRequest\x12!\n\x02id\x18\x01\x20\
-    \x01(\x0b2\x11.alkanes.AlkaneIdR\x02id\"L\n\x17AlkaneInventoryResponse\
-    \x121\n\x07alkanes\x18\x01\x20\x03(\x0b2\x17.alkanes.AlkaneTransferR\x07\
-    alkanes\"D\n\x1aAlkaneIdToOutpointResponse\x12\x12\n\x04txid\x18\x01\x20\
-    \x01(\x0cR\x04txid\x12\x12\n\x04vout\x18\x02\x20\x01(\rR\x04vout\"2\n\
-    \x08Outpoint\x12\x12\n\x04txid\x18\x01\x20\x01(\x0cR\x04txid\x12\x12\n\
-    \x04vout\x18\x02\x20\x01(\rR\x04vout\"c\n\x05Trace\x12-\n\x08outpoint\
-    \x18\x01\x20\x01(\x0b2\x11.alkanes.OutpointR\x08outpoint\x12+\n\x05trace\
-    \x18\x02\x20\x01(\x0b2\x15.alkanes.AlkanesTraceR\x05trace\")\n\x11TraceB\
-    lockRequest\x12\x14\n\x05block\x18\x01\x20\x01(\x04R\x05block\"<\n\x12Tr\
-    aceBlockResponse\x12&\n\x06traces\x18\x01\x20\x03(\x0b2\x0e.alkanes.Trac\
-    eR\x06traces\"4\n\x0fBytecodeRequest\x12!\n\x02id\x18\x01\x20\x01(\x0b2\
-    \x11.alkanes.AlkaneIdR\x02id\"&\n\x0cBlockRequest\x12\x16\n\x06height\
-    \x18\x01\x20\x01(\rR\x06height\"=\n\rBlockResponse\x12\x14\n\x05block\
-    \x18\x01\x20\x01(\x0cR\x05block\x12\x16\n\x06height\x18\x02\x20\x01(\rR\
-    \x06height*L\n\x14AlkanesTraceCallType\x12\x08\n\x04NONE\x10\0\x12\x08\n\
+    \x18\t\x20\x01(\rR\rrefundPointer\x125\n\toverrides\x18\n\x20\x01(\x0b2\
+    \x17.alkanes.StateOverridesR\toverrides\x12\x18\n\x07profile\x18\x0b\x20\
+    \x01(\x08R\x07profile\"6\n\x0cKeyValuePair\x12\x10\n\x03key\x18\x01\x20\
+    \x01(\x0cR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\x05value\"\
+    \xba\x01\n\x13AlkaneStateOverride\x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11\
+    .alkanes.AlkaneIdR\x02id\x12\x1a\n\x08bytecode\x18\x02\x20\x01(\x0cR\x08\
+    bytecode\x12/\n\x07storage\x18\x03\x20\x03(\x0b2\x15.alkanes.KeyValuePai\
+    rR\x07storage\x123\n\x08balances\x18\x04\x20\x03(\x0b2\x17.alkanes.Alkan\
+    eTransferR\x08balances\"H\n\x0eStateOverrides\x126\n\x07alkanes\x18\x01\
+    \x20\x03(\x0b2\x1c.alkanes.AlkaneStateOverrideR\x07alkanes\"\x8e\x01\n\
+    \x14ExtendedCallResponse\x121\n\x07alkanes\x18\x01\x20\x03(\x0b2\x17.alk\
+    anes.AlkaneTransferR\x07alkanes\x12/\n\x07storage\x18\x02\x20\x03(\x0b2\
+    \x15.alkanes.KeyValuePairR\x07storage\x12\x12\n\x04data\x18\x03\x20\x01(\
+    \x0cR\x04data\"\xe1\x01\n\x07Context\x12)\n\x06myself\x18\x01\x20\x01(\
+    \x0b2\x11.alkanes.AlkaneIdR\x06myself\x12)\n\x06caller\x18\x02\x20\x01(\
+    \x0b2\x11.alkanes.AlkaneIdR\x06caller\x12(\n\x06inputs\x18\x03\x20\x03(\
+    \x0b2\x10.alkanes.uint128R\x06inputs\x12\x12\n\x04vout\x18\x04\x20\x01(\
+    \rR\x04vout\x12B\n\x10incoming_alkanes\x18\x05\x20\x03(\x0b2\x17.alkanes\
+    .AlkaneTransferR\x0fincomingAlkanes\"J\n\x0cTraceContext\x12&\n\x05inner\
+    \x18\x01\x20\x01(\x0b2\x10.alkanes.ContextR\x05inner\x12\x12\n\x04fuel\
+    \x18\x02\x20\x01(\x04R\x04fuel\"\x82\x01\n\x13AlkanesEnterContext\x12:\n\
+    \tcall_type\x18\x01\x20\x01(\x0e2\x1d.alkanes.AlkanesTraceCallTypeR\x08c\
+    allType\x12/\n\x07context\x18\x02\x20\x01(\x0b2\x15.alkanes.TraceContext\
+    R\x07context\"^\n\x0bStorageRead\x12\x10\n\x03key\x18\x01\x20\x01(\x0cR\
+    \x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\x05value\x12'\n\x05owne\
+    r\x18\x03\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x05owner\"Z\n\x0cStorageWr\
+    ite\x12\x10\n\x03key\x18\x01\x20\x01(\x0cR\x03key\x12\x1b\n\told_value\
+    \x18\x02\x20\x01(\x0cR\x08oldValue\x12\x1b\n\tnew_value\x18\x03\x20\x01(\
+    \x0cR\x08newValue\"\x8e\x01\n\rTraceTransfer\x12%\n\x04from\x18\x01\x20\
+    \x01(\x0b2\x11.alkanes.AlkaneIdR\x04from\x12!\n\x02to\x18\x02\x20\x01(\
+    \x0b2\x11.alkanes.AlkaneIdR\x02to\x123\n\x08transfer\x18\x03\x20\x01(\
+    \x0b2\x17.alkanes.AlkaneTransferR\x08transfer\"\xd4\x02\n\x12AlkanesExit\
+    Context\x127\n\x06status\x18\x01\x20\x01(\x0e2\x1f.alkanes.AlkanesTraceS\
+    tatusFlagR\x06status\x129\n\x08response\x18\x02\x20\x01(\x0b2\x1d.alkane\
+    s.ExtendedCallResponseR\x08response\x12\x1b\n\tfuel_used\x18\x03\x20\x01\
+    (\x04R\x08fuelUsed\x129\n\rstorage_reads\x18\x04\x20\x03(\x0b2\x14.alkan\
+    es.StorageReadR\x0cstorageReads\x12<\n\x0estorage_writes\x18\x05\x20\x03\
+    (\x0b2\x15.alkanes.StorageWriteR\rstorageWrites\x124\n\ttransfers\x18\
+    \x06\x20\x03(\x0b2\x16.alkanes.TraceTransferR\ttransfers\"A\n\rAlkanesCr\
+    eate\x120\n\nnew_alkane\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\tnew\
+    Alkane\"d\n\x0bAlkaneEvent\x12)\n\x06alkane\x18\x01\x20\x01(\x0b2\x11.al\
+    kanes.AlkaneIdR\x06alkane\x12\x16\n\x06topics\x18\x02\x20\x03(\x0cR\x06t\
+    opics\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04data\"\x8e\x02\n\x11Alk\
+    anesTraceEvent\x12C\n\renter_context\x18\x01\x20\x01(\x0b2\x1c.alkanes.A\
+    lkanesEnterContextH\0R\x0centerContext\x12@\n\x0cexit_context\x18\x02\
+    \x20\x01(\x0b2\x1b.alkanes.AlkanesExitContextH\0R\x0bexitContext\x12=\n\
+    \rcreate_alkane\x18\x03\x20\x01(\x0b2\x16.alkanes.AlkanesCreateH\0R\x0cc\
+    reateAlkane\x12*\n\x04emit\x18\x04\x20\x01(\x0b2\x14.alkanes.AlkaneEvent\
+    H\0R\x04emitB\x07\n\x05event\"\x8b\x01\n\x11AlkanesBlockEvent\x12-\n\x06\
+    traces\x18\x01\x20\x01(\x0b2\x15.alkanes.AlkanesTraceR\x06traces\x12-\n\
+    \x08outpoint\x18\x02\x20\x01(\x0b2\x11.alkanes.OutpointR\x08outpoint\x12\
+    \x18\n\x07txindex\x18\x03\x20\x01(\x04R\x07txindex\"L\n\x16AlkanesBlockT\
+    raceEvent\x122\n\x06events\x18\x01\x20\x03(\x0b2\x1a.alkanes.AlkanesBloc\
+    kEventR\x06events\"B\n\x0cAlkanesTrace\x122\n\x06events\x18\x01\x20\x03(\
+    \x0b2\x1a.alkanes.AlkanesTraceEventR\x06events\"\xdd\x01\n\x10SimulateRe\
+    sponse\x12;\n\texecution\x18\x01\x20\x01(\x0b2\x1d.alkanes.ExtendedCallR\
+    esponseR\texecution\x12\x19\n\x08gas_used\x18\x02\x20\x01(\x04R\x07gasUs\
+    ed\x12\x14\n\x05error\x18\x03\x20\x01(\tR\x05error\x12+\n\x05trace\x18\
+    \x04\x20\x01(\x0b2\x15.alkanes.AlkanesTraceR\x05trace\x12.\n\x07profile\
+    \x18\x05\x20\x01(\x0b2\x14.alkanes.FuelProfileR\x07profile\"f\n\x10HostF\
+    unctionFuel\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x14\n\x05c\
+    alls\x18\x02\x20\x01(\x04R\x05calls\x12\x14\n\x05bytes\x18\x03\x20\x01(\
+    \x04R\x05bytes\x12\x12\n\x04fuel\x18\x04\x20\x01(\x04R\x04fuel\"\xc6\x02\
+    \n\x0bFuelProfile\x12)\n\x06target\x18\x01\x20\x01(\x0b2\x11.alkanes.Alk\
+    aneIdR\x06target\x12(\n\x06inputs\x18\x02\x20\x03(\x0b2\x10.alkanes.uint\
+    128R\x06inputs\x12\x14\n\x05total\x18\x03\x20\x01(\x04R\x05total\x12\"\n\
+    \x0cinstructions\x18\x04\x20\x01(\x04R\x0cinstructions\x12\x18\n\x07stor\
+    age\x18\x05\x20\x01(\x04R\x07storage\x12@\n\x0ehost_functions\x18\x06\
+    \x20\x03(\x0b2\x19.alkanes.HostFunctionFuelR\rhostFunctions\x120\n\x08ch\
+    ildren\x18\x07\x20\x03(\x0b2\x14.alkanes.FuelProfileR\x08children\x12\
+    \x1a\n\x08reverted\x18\x08\x20\x01(\x08R\x08reverted\"f\n\x15MultiSimula\
+    teResponse\x127\n\tresponses\x18\x01\x20\x03(\x0b2\x19.alkanes.SimulateR\
+    esponseR\tresponses\x12\x14\n\x05error\x18\x02\x20\x01(\tR\x05error\"\
+    \x8c\x01\n\x13EstimateFuelRequest\x12\x20\n\x0btransaction\x18\x01\x20\
+    \x01(\x0cR\x0btransaction\x12\x16\n\x06height\x18\x02\x20\x01(\x04R\x06h\
+    eight\x12\x18\n\x07txindex\x18\x03\x20\x01(\rR\x07txindex\x12!\n\x0cbloc\
+    k_vfsize\x18\x04\x20\x01(\x04R\x0bblockVfsize\"\xdd\x01\n\x14EstimateFue\
+    lResponse\x12\x1f\n\x0bfuel_needed\x18\x01\x20\x01(\x04R\nfuelNeeded\x12\
+    %\n\x0efuel_allocated\x18\x02\x20\x01(\x04R\rfuelAllocated\x12\x16\n\x06\
+    vfsize\x18\x03\x20\x01(\x04R\x06vfsize\x12\x18\n\x07padding\x18\x04\x20\
+    \x01(\x04R\x07padding\x125\n\x08messages\x18\x05\x20\x03(\x0b2\x19.alkan\
+    es.SimulateResponseR\x08messages\x12\x14\n\x05error\x18\x06\x20\x01(\tR\
+    \x05error\"Z\n\rInputBalances\x12\x14\n\x05input\x18\x01\x20\x01(\rR\x05\
+    input\x123\n\x08balances\x18\x02\x20\x03(\x0b2\x17.alkanes.AlkaneTransfe\
+    rR\x08balances\"\xa0\x01\n\x1aSimulateTransactionRequest\x12\x20\n\x0btr\
+    ansaction\x18\x01\x20\x01(\x0cR\x0btransaction\x12\x16\n\x06height\x18\
+    \x02\x20\x01(\x04R\x06height\x12\x18\n\x07txindex\x18\x03\x20\x01(\rR\
+    \x07txindex\x12.\n\x06inputs\x18\x04\x20\x03(\x0b2\x16.alkanes.InputBala\
+    ncesR\x06inputs\"V\n\x0fSimulatedRevert\x12-\n\x08outpoint\x18\x01\x20\
+    \x01(\x0b2\x11.alkanes.OutpointR\x08outpoint\x12\x14\n\x05error\x18\x02\
+    \x20\x01(\tR\x05error\"\x88\x02\n\x1bSimulateTransactionResponse\x123\n\
+    \x07outputs\x18\x01\x20\x03(\x0b2\x19.alkanes.OutpointBalancesR\x07outpu\
+    ts\x12B\n\x10runtime_balances\x18\x02\x20\x03(\x0b2\x17.alkanes.AlkaneTr\
+    ansferR\x0fruntimeBalances\x12&\n\x06traces\x18\x03\x20\x03(\x0b2\x0e.al\
+    kanes.TraceR\x06traces\x122\n\x07reverts\x18\x04\x20\x03(\x0b2\x18.alkan\
+    es.SimulatedRevertR\x07reverts\x12\x14\n\x05error\x18\x05\x20\x01(\tR\
+    \x05error\"\xe7\x01\n\x16AlkaneInventoryRequest\x12!\n\x02id\x18\x01\x20\
+    \x01(\x0b2\x11.alkanes.AlkaneIdR\x02id\x12\x16\n\x06cursor\x18\x02\x20\
+    \x01(\x0cR\x06cursor\x12\x14\n\x05limit\x18\x03\x20\x01(\rR\x05limit\x12\
+    )\n\x06alkane\x18\x04\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x06alkane\x121\
+    \n\x0bmin_balance\x18\x05\x20\x01(\x0b2\x10.alkanes.uint128R\nminBalance\
+    \x12\x1e\n\ndescending\x18\x06\x20\x01(\x08R\ndescending\">\n\x19AlkaneI\
+    dToOutpointRequest\x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11.alkanes.Alkane\
+    IdR\x02id\"m\n\x17AlkaneInventoryResponse\x121\n\x07alkanes\x18\x01\x20\
+    \x03(\x0b2\x17.alkanes.AlkaneTransferR\x07alkanes\x12\x1f\n\x0bnext_curs\
+    or\x18\x02\x20\x01(\x0cR\nnextCursor\"D\n\x1aAlkaneIdToOutpointResponse\
+    \x12\x12\n\x04txid\x18\x01\x20\x01(\x0cR\x04txid\x12\x12\n\x04vout\x18\
+    \x02\x20\x01(\rR\x04vout\"2\n\x08Outpoint\x12\x12\n\x04txid\x18\x01\x20\
+    \x01(\x0cR\x04txid\x12\x12\n\x04vout\x18\x02\x20\x01(\rR\x04vout\"c\n\
+    \x05Trace\x12-\n\x08outpoint\x18\x01\x20\x01(\x0b2\x11.alkanes.OutpointR\
+    \x08outpoint\x12+\n\x05trace\x18\x02\x20\x01(\x0b2\x15.alkanes.AlkanesTr\
+    aceR\x05trace\")\n\x11TraceBlockRequest\x12\x14\n\x05block\x18\x01\x20\
+    \x01(\x04R\x05block\"<\n\x12TraceBlockResponse\x12&\n\x06traces\x18\x01\
+    \x20\x03(\x0b2\x0e.alkanes.TraceR\x06traces\"4\n\x0fBytecodeRequest\x12!\
+    \n\x02id\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x02id\"/\n\nAbiRequ\
+    est\x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x02id\"&\n\
+    \x0cBlockRequest\x12\x16\n\x06height\x18\x01\x20\x01(\rR\x06height\"=\n\
+    \rBlockResponse\x12\x14\n\x05block\x18\x01\x20\x01(\x0cR\x05block\x12\
+    \x16\n\x06height\x18\x02\x20\x01(\rR\x06height\"\x86\x01\n\x11AlkaneEven\
+    tRecord\x12*\n\x05event\x18\x01\x20\x01(\x0b2\x14.alkanes.AlkaneEventR\
+    \x05event\x12-\n\x08outpoint\x18\x02\x20\x01(\x0b2\x11.alkanes.OutpointR\
+    \x08outpoint\x12\x16\n\x06height\x18\x03\x20\x01(\x04R\x06height\"\xa6\
+    \x01\n\x15EventsByAlkaneRequest\x12!\n\x02id\x18\x01\x20\x01(\x0b2\x11.a\
+    lkanes.AlkaneIdR\x02id\x12\x1f\n\x0bfrom_height\x18\x02\x20\x01(\x04R\nf\
+    romHeight\x12\x1b\n\tto_height\x18\x03\x20\x01(\x04R\x08toHeight\x12\x16\
+    \n\x06cursor\x18\x04\x20\x01(\x0cR\x06cursor\x12\x14\n\x05limit\x18\x05\
+    \x20\x01(\rR\x05limit\"e\n\x0eEventsResponse\x122\n\x06events\x18\x01\
+    \x20\x03(\x0b2\x1a.alkanes.AlkaneEventRecordR\x06events\x12\x1f\n\x0bnex\
+    t_cursor\x18\x02\x20\x01(\x0cR\nnextCursor\"a\n\rCreatedAlkane\x12!\n\
+    \x02id\x18\x01\x20\x01(\x0b2\x11.alkanes.AlkaneIdR\x02id\x12-\n\x08outpo\
+    int\x18\x02\x20\x01(\x0b2\x11.alkanes.OutpointR\x08outpoint\"v\n\x10Outp\
+    ointBalances\x12-\n\x08outpoint\x18\x01\x20\x01(\x0b2\x11.alkanes.Outpoi\
+    ntR\x08outpoint\x123\n\x08balances\x18\x02\x20\x03(\x0b2\x17.alkanes.Alk\
+    aneTransferR\x08balances\"\xdc\x02\n\x11BlockNotification\x12\x18\n\x07v\
+    ersion\x18\x01\x20\x01(\rR\x07version\x12\x16\n\x06height\x18\x02\x20\
+    \x01(\x04R\x06height\x12\x1c\n\tblockhash\x18\x03\x20\x01(\x0cR\tblockha\
+    sh\x12F\n\x11outpoint_balances\x18\x04\x20\x03(\x0b2\x19.alkanes.Outpoin\
+    tBalancesR\x10outpointBalances\x12?\n\x0fcreated_alkanes\x18\x05\x20\x03\
+    (\x0b2\x16.alkanes.CreatedAlkaneR\x0ecreatedAlkanes\x122\n\x06traces\x18\
+    \x06\x20\x03(\x0b2\x1a.alkanes.AlkanesBlockEventR\x06traces\x12:\n\x0fsp\
+    ent_outpoints\x18\x07\x20\x03(\x0b2\x11.alkanes.OutpointR\x0espentOutpoi\
+    nts\"\xac\x02\n\x13AlkaneRegistryEntry\x12!\n\x02id\x18\x01\x20\x01(\x0b\
+    2\x11.alkanes.AlkaneIdR\x02id\x12\x16\n\x06height\x18\x02\x20\x01(\x04R\
+    \x06height\x12-\n\x08outpoint\x18\x03\x20\x01(\x0b2\x11.alkanes.Outpoint\
+    R\x08outpoint\x12-\n\x04kind\x18\x04\x20\x01(\x0e2\x19.alkanes.AlkaneDep\
+    loyKindR\x04kind\x12+\n\x07factory\x18\x05\x20\x01(\x0b2\x11.alkanes.Alk\
+    aneIdR\x07factory\x12#\n\rbytecode_hash\x18\x06\x20\x01(\x0cR\x0cbytecod\
+    eHash\x12\x12\n\x04name\x18\x07\x20\x01(\tR\x04name\x12\x16\n\x06symbol\
+    \x18\x08\x20\x01(\tR\x06symbol\"b\n\x12ListAlkanesRequest\x12\x16\n\x06c\
+    ursor\x18\x01\x20\x01(\x0cR\x06cursor\x12\x14\n\x05limit\x18\x02\x20\x01\
+    (\rR\x05limit\x12\x1e\n\ndescending\x18\x03\x20\x01(\x08R\ndescending\"q\
+    \n\x1cAlkanesByBytecodeHashRequest\x12#\n\rbytecode_hash\x18\x01\x20\x01\
+    (\x0cR\x0cbytecodeHash\x12\x16\n\x06cursor\x18\x02\x20\x01(\x0cR\x06curs\
+    or\x12\x14\n\x05limit\x18\x03\x20\x01(\rR\x05limit\"q\n\x16AlkaneRegistr\
+    yResponse\x126\n\x07alkanes\x18\x01\x20\x03(\x0b2\x1c.alkanes.AlkaneRegi\
+    stryEntryR\x07alkanes\x12\x1f\n\x0bnext_cursor\x18\x02\x20\x01(\x0cR\nne\
+    xtCursor*L\n\x14AlkanesTraceCallType\x12\x08\n\x04NONE\x10\0\x12\x08\n\
     \x04CALL\x10\x01\x12\x10\n\x0cDELEGATECALL\x10\x02\x12\x0e\n\nSTATICCALL\
     \x10\x03*2\n\x16AlkanesTraceStatusFlag\x12\x0b\n\x07SUCCESS\x10\0\x12\
-    \x0b\n\x07FAILURE\x10\x01b\x06proto3\
+    \x0b\n\x07FAILURE\x10\x01*?\n\x10AlkaneDeployKind\x12\n\n\x06CREATE\x10\
+    \0\x12\x12\n\x0eCREATERESERVED\x10\x01\x12\x0b\n\x07FACTORY\x10\x02b\x06\
+    proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -4580,25 +9340,39 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(29);
+            let mut messages = ::std::vec::Vec::with_capacity(54);
             messages.push(Uint128::generated_message_descriptor_data());
             messages.push(AlkaneId::generated_message_descriptor_data());
             messages.push(AlkaneTransfer::generated_message_descriptor_data());
             messages.push(MultiSimulateRequest::generated_message_descriptor_data());
             messages.push(MessageContextParcel::generated_message_descriptor_data());
             messages.push(KeyValuePair::generated_message_descriptor_data());
+            messages.push(AlkaneStateOverride::generated_message_descriptor_data());
+            messages.push(StateOverrides::generated_message_descriptor_data());
             messages.push(ExtendedCallResponse::generated_message_descriptor_data());
             messages.push(Context::generated_message_descriptor_data());
             messages.push(TraceContext::generated_message_descriptor_data());
             messages.push(AlkanesEnterContext::generated_message_descriptor_data());
+            messages.push(StorageRead::generated_message_descriptor_data());
+            messages.push(StorageWrite::generated_message_descriptor_data());
+            messages.push(TraceTransfer::generated_message_descriptor_data());
             messages.push(AlkanesExitContext::generated_message_descriptor_data());
             messages.push(AlkanesCreate::generated_message_descriptor_data());
+            messages.push(AlkaneEvent::generated_message_descriptor_data());
             messages.push(AlkanesTraceEvent::generated_message_descriptor_data());
             messages.push(AlkanesBlockEvent::generated_message_descriptor_data());
             messages.push(AlkanesBlockTraceEvent::generated_message_descriptor_data());
             messages.push(AlkanesTrace::generated_message_descriptor_data());
             messages.push(SimulateResponse::generated_message_descriptor_data());
+            messages.push(HostFunctionFuel::generated_message_descriptor_data());
+            messages.push(FuelProfile::generated_message_descriptor_data());
             messages.push(MultiSimulateResponse::generated_message_descriptor_data());
+            messages.push(EstimateFuelRequest::generated_message_descriptor_data());
+            messages.push(EstimateFuelResponse::generated_message_descriptor_data());
+            messages.push(InputBalances::generated_message_descriptor_data());
+            messages.push(SimulateTransactionRequest::generated_message_descriptor_data());
+            messages.push(SimulatedRevert::generated_message_descriptor_data());
+            messages.push(SimulateTransactionResponse::generated_message_descriptor_data());
             messages.push(AlkaneInventoryRequest::generated_message_descriptor_data());
             messages.push(AlkaneIdToOutpointRequest::generated_message_descriptor_data());
             messages.push(AlkaneInventoryResponse::generated_message_descriptor_data());
@@ -4608,11 +9382,23 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(TraceBlockRequest::generated_message_descriptor_data());
             messages.push(TraceBlockResponse::generated_message_descriptor_data());
             messages.push(BytecodeRequest::generated_message_descriptor_data());
+            messages.push(AbiRequest::generated_message_descriptor_data());
             messages.push(BlockRequest::generated_message_descriptor_data());
             messages.push(BlockResponse::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(2);
+            messages.push(AlkaneEventRecord::generated_message_descriptor_data());
+            messages.push(EventsByAlkaneRequest::generated_message_descriptor_data());
+            messages.push(EventsResponse::generated_message_descriptor_data());
+            messages.push(CreatedAlkane::generated_message_descriptor_data());
+            messages.push(OutpointBalances::generated_message_descriptor_data());
+            messages.push(BlockNotification::generated_message_descriptor_data());
+            messages.push(AlkaneRegistryEntry::generated_message_descriptor_data());
+            messages.push(ListAlkanesRequest::generated_message_descriptor_data());
+            messages.push(AlkanesByBytecodeHashRequest::generated_message_descriptor_data());
+            messages.push(AlkaneRegistryResponse::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(3);
             enums.push(AlkanesTraceCallType::generated_enum_descriptor_data());
             enums.push(AlkanesTraceStatusFlag::generated_enum_descriptor_data());
+            enums.push(AlkaneDeployKind::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
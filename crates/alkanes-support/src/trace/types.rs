@@ -1,4 +1,5 @@
 use crate::context::Context;
+use crate::event::AlkaneEvent;
 use crate::id::AlkaneId;
 use crate::parcel::{AlkaneTransfer, AlkaneTransferParcel};
 use crate::proto;
@@ -14,10 +15,68 @@ pub struct TraceContext {
     pub fuel: u64,
 }
 
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StorageRead {
+    /// The alkane whose storage was read, or `None` for the frame's own.
+    pub owner: Option<AlkaneId>,
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StorageWrite {
+    pub key: Vec<u8>,
+    pub old_value: Vec<u8>,
+    pub new_value: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TraceTransfer {
+    pub from: AlkaneId,
+    pub to: AlkaneId,
+    pub transfer: AlkaneTransfer,
+}
+
+/// What a call frame did while it ran. Fuel includes the fuel of nested calls;
+/// reads, writes and transfers are only the frame's own.
+#[derive(Debug, Clone, Default)]
+pub struct TraceFrame {
+    pub fuel_used: u64,
+    pub storage_reads: Vec<StorageRead>,
+    pub storage_writes: Vec<StorageWrite>,
+    pub transfers: Vec<TraceTransfer>,
+}
+
+impl TraceFrame {
+    pub fn transfer(&mut self, parcel: &AlkaneTransferParcel, from: &AlkaneId, to: &AlkaneId) {
+        self.transfers
+            .extend(parcel.0.iter().map(|transfer| TraceTransfer {
+                from: from.clone(),
+                to: to.clone(),
+                transfer: transfer.clone(),
+            }));
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct TraceResponse {
     pub inner: ExtendedCallResponse,
     pub fuel_used: u64,
+    pub storage_reads: Vec<StorageRead>,
+    pub storage_writes: Vec<StorageWrite>,
+    pub transfers: Vec<TraceTransfer>,
+}
+
+impl TraceResponse {
+    pub fn new(inner: ExtendedCallResponse, frame: TraceFrame) -> Self {
+        TraceResponse {
+            inner,
+            fuel_used: frame.fuel_used,
+            storage_reads: frame.storage_reads,
+            storage_writes: frame.storage_writes,
+            transfers: frame.transfers,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -28,13 +87,14 @@ pub enum TraceEvent {
     RevertContext(TraceResponse),
     ReturnContext(TraceResponse),
     CreateAlkane(AlkaneId),
+    Emit(AlkaneEvent),
 }
 
 impl Into<TraceResponse> for ExtendedCallResponse {
     fn into(self) -> TraceResponse {
         TraceResponse {
             inner: self,
-            fuel_used: 0,
+            ..Default::default()
         }
     }
 }
@@ -71,10 +131,44 @@ impl Into<proto::alkanes::Context> for Context {
     }
 }
 
+impl Into<proto::alkanes::StorageRead> for StorageRead {
+    fn into(self) -> proto::alkanes::StorageRead {
+        let mut result = proto::alkanes::StorageRead::new();
+        result.owner = MessageField::from_option(self.owner.map(|v| v.into()));
+        result.key = self.key;
+        result.value = self.value;
+        result
+    }
+}
+
+impl Into<proto::alkanes::StorageWrite> for StorageWrite {
+    fn into(self) -> proto::alkanes::StorageWrite {
+        let mut result = proto::alkanes::StorageWrite::new();
+        result.key = self.key;
+        result.old_value = self.old_value;
+        result.new_value = self.new_value;
+        result
+    }
+}
+
+impl Into<proto::alkanes::TraceTransfer> for TraceTransfer {
+    fn into(self) -> proto::alkanes::TraceTransfer {
+        let mut result = proto::alkanes::TraceTransfer::new();
+        result.from = MessageField::some(self.from.into());
+        result.to = MessageField::some(self.to.into());
+        result.transfer = MessageField::some(self.transfer.into());
+        result
+    }
+}
+
 impl Into<proto::alkanes::AlkanesExitContext> for TraceResponse {
     fn into(self) -> proto::alkanes::AlkanesExitContext {
         let mut result = proto::alkanes::AlkanesExitContext::new();
         result.response = MessageField::some(self.inner.into());
+        result.fuel_used = self.fuel_used;
+        result.storage_reads = self.storage_reads.into_iter().map(|v| v.into()).collect();
+        result.storage_writes = self.storage_writes.into_iter().map(|v| v.into()).collect();
+        result.transfers = self.transfers.into_iter().map(|v| v.into()).collect();
         result
     }
 }
@@ -130,6 +224,7 @@ impl Into<proto::alkanes::AlkanesTraceEvent> for TraceEvent {
                 creation.new_alkane = MessageField::some(v.into());
                 proto::alkanes::alkanes_trace_event::Event::CreateAlkane(creation)
             }
+            TraceEvent::Emit(v) => proto::alkanes::alkanes_trace_event::Event::Emit(v.into()),
         });
         result
     }
@@ -194,7 +289,40 @@ impl From<proto::alkanes::AlkanesExitContext> for TraceResponse {
             .unwrap_or_else(|_| ExtendedCallResponse::default());
         TraceResponse {
             inner: response,
-            fuel_used: 0,
+            fuel_used: v.fuel_used,
+            storage_reads: v.storage_reads.into_iter().map(|v| v.into()).collect(),
+            storage_writes: v.storage_writes.into_iter().map(|v| v.into()).collect(),
+            transfers: v.transfers.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl From<proto::alkanes::StorageRead> for StorageRead {
+    fn from(v: proto::alkanes::StorageRead) -> StorageRead {
+        StorageRead {
+            owner: v.owner.into_option().map(|v| v.into()),
+            key: v.key,
+            value: v.value,
+        }
+    }
+}
+
+impl From<proto::alkanes::StorageWrite> for StorageWrite {
+    fn from(v: proto::alkanes::StorageWrite) -> StorageWrite {
+        StorageWrite {
+            key: v.key,
+            old_value: v.old_value,
+            new_value: v.new_value,
+        }
+    }
+}
+
+impl From<proto::alkanes::TraceTransfer> for TraceTransfer {
+    fn from(v: proto::alkanes::TraceTransfer) -> TraceTransfer {
+        TraceTransfer {
+            from: field_or_default(v.from),
+            to: field_or_default(v.to),
+            transfer: field_or_default(v.transfer),
         }
     }
 }
@@ -249,6 +377,7 @@ impl From<proto::alkanes::AlkanesTraceEvent> for TraceEvent {
                 proto::alkanes::alkanes_trace_event::Event::CreateAlkane(v) => {
                     TraceEvent::CreateAlkane(field_or_default(v.new_alkane))
                 }
+                proto::alkanes::alkanes_trace_event::Event::Emit(v) => TraceEvent::Emit(v.into()),
             }
         } else {
             TraceEvent::CreateAlkane(AlkaneId { block: 0, tx: 0 })
@@ -259,9 +388,40 @@ impl From<proto::alkanes::AlkanesTraceEvent> for TraceEvent {
 #[derive(Debug, Default)]
 pub struct Trace(pub Arc<Mutex<Vec<TraceEvent>>>);
 
+// index of the event that entered the frame still open at the end of `events`
+fn open_frame(events: &[TraceEvent]) -> usize {
+    let mut depth = 0;
+    for (i, event) in events.iter().enumerate().rev() {
+        match event {
+            TraceEvent::EnterCall(_)
+            | TraceEvent::EnterDelegatecall(_)
+            | TraceEvent::EnterStaticcall(_) => {
+                if depth == 0 {
+                    return i;
+                }
+                depth -= 1;
+            }
+            TraceEvent::ReturnContext(_) | TraceEvent::RevertContext(_) => depth += 1,
+            _ => {}
+        }
+    }
+    0
+}
+
 impl Trace {
     pub fn clock(&self, event: TraceEvent) {
-        self.0.lock().unwrap().push(event);
+        let mut events = self.0.lock().unwrap();
+        if let TraceEvent::RevertContext(_) = &event {
+            // a revert discards the events its frame emitted along with the
+            // rest of its state
+            let start = open_frame(&events);
+            let mut index = 0;
+            events.retain(|v| {
+                index += 1;
+                index <= start || !matches!(v, TraceEvent::Emit(_))
+            });
+        }
+        events.push(event);
     }
 }
 
@@ -86,3 +86,36 @@ pub fn shift_bytes32_or_err(v: &mut Vec<u128>) -> Result<Vec<u8>> {
         .ok_or("")
         .map_err(|_| anyhow!("failed to shift bytes32 from list"))
 }
+
+/// Packs bytes into a list as their length followed by the bytes in 16-byte
+/// little-endian words, the last one zero padded.
+pub fn pack_bytes(bytes: &[u8]) -> Vec<u128> {
+    let mut result = vec![bytes.len() as u128];
+    result.extend(bytes.chunks(16).map(|chunk| {
+        let mut word = [0u8; 16];
+        word[..chunk.len()].copy_from_slice(chunk);
+        u128::from_le_bytes(word)
+    }));
+    result
+}
+
+/// Reverses `pack_bytes`.
+pub fn shift_bytes(v: &mut Vec<u128>) -> Option<Vec<u8>> {
+    let length: usize = shift(v)?.try_into().ok()?;
+    let words = length.div_ceil(16);
+    if v.len() < words {
+        return None;
+    }
+    let mut result = v
+        .drain(..words)
+        .flat_map(|word| word.to_le_bytes())
+        .collect::<Vec<u8>>();
+    result.truncate(length);
+    Some(result)
+}
+
+pub fn shift_bytes_or_err(v: &mut Vec<u128>) -> Result<Vec<u8>> {
+    shift_bytes(v)
+        .ok_or("")
+        .map_err(|_| anyhow!("failed to shift bytes from list"))
+}
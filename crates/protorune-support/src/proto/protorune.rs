@@ -1410,6 +1410,10 @@ pub struct PaginationInput {
     pub start: u32,
     // @@protoc_insertion_point(field:protorune.PaginationInput.end)
     pub end: u32,
+    // @@protoc_insertion_point(field:protorune.PaginationInput.cursor)
+    pub cursor: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:protorune.PaginationInput.limit)
+    pub limit: u32,
     // special fields
     // @@protoc_insertion_point(special_field:protorune.PaginationInput.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -1427,7 +1431,7 @@ impl PaginationInput {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "start",
@@ -1439,6 +1443,16 @@ impl PaginationInput {
             |m: &PaginationInput| { &m.end },
             |m: &mut PaginationInput| { &mut m.end },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "cursor",
+            |m: &PaginationInput| { &m.cursor },
+            |m: &mut PaginationInput| { &mut m.cursor },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &PaginationInput| { &m.limit },
+            |m: &mut PaginationInput| { &mut m.limit },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PaginationInput>(
             "PaginationInput",
             fields,
@@ -1463,6 +1477,12 @@ impl ::protobuf::Message for PaginationInput {
                 16 => {
                     self.end = is.read_uint32()?;
                 },
+                26 => {
+                    self.cursor = is.read_bytes()?;
+                },
+                32 => {
+                    self.limit = is.read_uint32()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1481,6 +1501,12 @@ impl ::protobuf::Message for PaginationInput {
         if self.end != 0 {
             my_size += ::protobuf::rt::uint32_size(2, self.end);
         }
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.cursor);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.limit);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -1493,6 +1519,12 @@ impl ::protobuf::Message for PaginationInput {
         if self.end != 0 {
             os.write_uint32(2, self.end)?;
         }
+        if !self.cursor.is_empty() {
+            os.write_bytes(3, &self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(4, self.limit)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1512,6 +1544,8 @@ impl ::protobuf::Message for PaginationInput {
     fn clear(&mut self) {
         self.start = 0;
         self.end = 0;
+        self.cursor.clear();
+        self.limit = 0;
         self.special_fields.clear();
     }
 
@@ -1519,6 +1553,8 @@ impl ::protobuf::Message for PaginationInput {
         static instance: PaginationInput = PaginationInput {
             start: 0,
             end: 0,
+            cursor: ::std::vec::Vec::new(),
+            limit: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -1542,12 +1578,160 @@ impl ::protobuf::reflect::ProtobufValue for PaginationInput {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+// @@protoc_insertion_point(message:protorune.WalletFilter)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct WalletFilter {
+    // message fields
+    // @@protoc_insertion_point(field:protorune.WalletFilter.rune)
+    pub rune: ::protobuf::MessageField<ProtoruneRuneId>,
+    // @@protoc_insertion_point(field:protorune.WalletFilter.min_balance)
+    pub min_balance: ::protobuf::MessageField<Uint128>,
+    // special fields
+    // @@protoc_insertion_point(special_field:protorune.WalletFilter.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a WalletFilter {
+    fn default() -> &'a WalletFilter {
+        <WalletFilter as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WalletFilter {
+    pub fn new() -> WalletFilter {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ProtoruneRuneId>(
+            "rune",
+            |m: &WalletFilter| { &m.rune },
+            |m: &mut WalletFilter| { &mut m.rune },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "min_balance",
+            |m: &WalletFilter| { &m.min_balance },
+            |m: &mut WalletFilter| { &mut m.min_balance },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<WalletFilter>(
+            "WalletFilter",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for WalletFilter {
+    const NAME: &'static str = "WalletFilter";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.rune)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.min_balance)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.rune.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.min_balance.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.rune.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.min_balance.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> WalletFilter {
+        WalletFilter::new()
+    }
+
+    fn clear(&mut self) {
+        self.rune.clear();
+        self.min_balance.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static WalletFilter {
+        static instance: WalletFilter = WalletFilter {
+            rune: ::protobuf::MessageField::none(),
+            min_balance: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for WalletFilter {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("WalletFilter").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for WalletFilter {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WalletFilter {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 // @@protoc_insertion_point(message:protorune.WalletRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
 pub struct WalletRequest {
     // message fields
     // @@protoc_insertion_point(field:protorune.WalletRequest.wallet)
     pub wallet: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:protorune.WalletRequest.pagination)
+    pub pagination: ::protobuf::MessageField<PaginationInput>,
+    // @@protoc_insertion_point(field:protorune.WalletRequest.filter)
+    pub filter: ::protobuf::MessageField<WalletFilter>,
+    // @@protoc_insertion_point(field:protorune.WalletRequest.descending)
+    pub descending: bool,
     // special fields
     // @@protoc_insertion_point(special_field:protorune.WalletRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -1565,13 +1749,28 @@ impl WalletRequest {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "wallet",
             |m: &WalletRequest| { &m.wallet },
             |m: &mut WalletRequest| { &mut m.wallet },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, PaginationInput>(
+            "pagination",
+            |m: &WalletRequest| { &m.pagination },
+            |m: &mut WalletRequest| { &mut m.pagination },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, WalletFilter>(
+            "filter",
+            |m: &WalletRequest| { &m.filter },
+            |m: &mut WalletRequest| { &mut m.filter },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "descending",
+            |m: &WalletRequest| { &m.descending },
+            |m: &mut WalletRequest| { &mut m.descending },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<WalletRequest>(
             "WalletRequest",
             fields,
@@ -1593,6 +1792,15 @@ impl ::protobuf::Message for WalletRequest {
                 10 => {
                     self.wallet = is.read_bytes()?;
                 },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.filter)?;
+                },
+                32 => {
+                    self.descending = is.read_bool()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1608,6 +1816,17 @@ impl ::protobuf::Message for WalletRequest {
         if !self.wallet.is_empty() {
             my_size += ::protobuf::rt::bytes_size(1, &self.wallet);
         }
+        if let Some(v) = self.pagination.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.filter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.descending != false {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -1617,6 +1836,15 @@ impl ::protobuf::Message for WalletRequest {
         if !self.wallet.is_empty() {
             os.write_bytes(1, &self.wallet)?;
         }
+        if let Some(v) = self.pagination.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.filter.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if self.descending != false {
+            os.write_bool(4, self.descending)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1635,12 +1863,18 @@ impl ::protobuf::Message for WalletRequest {
 
     fn clear(&mut self) {
         self.wallet.clear();
+        self.pagination.clear();
+        self.filter.clear();
+        self.descending = false;
         self.special_fields.clear();
     }
 
     fn default_instance() -> &'static WalletRequest {
         static instance: WalletRequest = WalletRequest {
             wallet: ::std::vec::Vec::new(),
+            pagination: ::protobuf::MessageField::none(),
+            filter: ::protobuf::MessageField::none(),
+            descending: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -1672,6 +1906,8 @@ pub struct WalletResponse {
     pub outpoints: ::std::vec::Vec<OutpointResponse>,
     // @@protoc_insertion_point(field:protorune.WalletResponse.balances)
     pub balances: ::protobuf::MessageField<BalanceSheet>,
+    // @@protoc_insertion_point(field:protorune.WalletResponse.next_cursor)
+    pub next_cursor: ::std::vec::Vec<u8>,
     // special fields
     // @@protoc_insertion_point(special_field:protorune.WalletResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -1689,7 +1925,7 @@ impl WalletResponse {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "outpoints",
@@ -1701,6 +1937,11 @@ impl WalletResponse {
             |m: &WalletResponse| { &m.balances },
             |m: &mut WalletResponse| { &mut m.balances },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "next_cursor",
+            |m: &WalletResponse| { &m.next_cursor },
+            |m: &mut WalletResponse| { &mut m.next_cursor },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<WalletResponse>(
             "WalletResponse",
             fields,
@@ -1725,6 +1966,9 @@ impl ::protobuf::Message for WalletResponse {
                 18 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.balances)?;
                 },
+                26 => {
+                    self.next_cursor = is.read_bytes()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1745,6 +1989,9 @@ impl ::protobuf::Message for WalletResponse {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if !self.next_cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.next_cursor);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -1757,6 +2004,9 @@ impl ::protobuf::Message for WalletResponse {
         if let Some(v) = self.balances.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
+        if !self.next_cursor.is_empty() {
+            os.write_bytes(3, &self.next_cursor)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1776,6 +2026,7 @@ impl ::protobuf::Message for WalletResponse {
     fn clear(&mut self) {
         self.outpoints.clear();
         self.balances.clear();
+        self.next_cursor.clear();
         self.special_fields.clear();
     }
 
@@ -1783,6 +2034,7 @@ impl ::protobuf::Message for WalletResponse {
         static instance: WalletResponse = WalletResponse {
             outpoints: ::std::vec::Vec::new(),
             balances: ::protobuf::MessageField::none(),
+            next_cursor: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -1814,6 +2066,12 @@ pub struct ProtorunesWalletRequest {
     pub wallet: ::std::vec::Vec<u8>,
     // @@protoc_insertion_point(field:protorune.ProtorunesWalletRequest.protocol_tag)
     pub protocol_tag: ::protobuf::MessageField<Uint128>,
+    // @@protoc_insertion_point(field:protorune.ProtorunesWalletRequest.pagination)
+    pub pagination: ::protobuf::MessageField<PaginationInput>,
+    // @@protoc_insertion_point(field:protorune.ProtorunesWalletRequest.filter)
+    pub filter: ::protobuf::MessageField<WalletFilter>,
+    // @@protoc_insertion_point(field:protorune.ProtorunesWalletRequest.descending)
+    pub descending: bool,
     // special fields
     // @@protoc_insertion_point(special_field:protorune.ProtorunesWalletRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -1831,7 +2089,7 @@ impl ProtorunesWalletRequest {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "wallet",
@@ -1843,6 +2101,21 @@ impl ProtorunesWalletRequest {
             |m: &ProtorunesWalletRequest| { &m.protocol_tag },
             |m: &mut ProtorunesWalletRequest| { &mut m.protocol_tag },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, PaginationInput>(
+            "pagination",
+            |m: &ProtorunesWalletRequest| { &m.pagination },
+            |m: &mut ProtorunesWalletRequest| { &mut m.pagination },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, WalletFilter>(
+            "filter",
+            |m: &ProtorunesWalletRequest| { &m.filter },
+            |m: &mut ProtorunesWalletRequest| { &mut m.filter },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "descending",
+            |m: &ProtorunesWalletRequest| { &m.descending },
+            |m: &mut ProtorunesWalletRequest| { &mut m.descending },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ProtorunesWalletRequest>(
             "ProtorunesWalletRequest",
             fields,
@@ -1867,6 +2140,15 @@ impl ::protobuf::Message for ProtorunesWalletRequest {
                 18 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.protocol_tag)?;
                 },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.filter)?;
+                },
+                40 => {
+                    self.descending = is.read_bool()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1886,6 +2168,17 @@ impl ::protobuf::Message for ProtorunesWalletRequest {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if let Some(v) = self.pagination.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.filter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.descending != false {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -1898,6 +2191,15 @@ impl ::protobuf::Message for ProtorunesWalletRequest {
         if let Some(v) = self.protocol_tag.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
+        if let Some(v) = self.pagination.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let Some(v) = self.filter.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if self.descending != false {
+            os.write_bool(5, self.descending)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1917,6 +2219,9 @@ impl ::protobuf::Message for ProtorunesWalletRequest {
     fn clear(&mut self) {
         self.wallet.clear();
         self.protocol_tag.clear();
+        self.pagination.clear();
+        self.filter.clear();
+        self.descending = false;
         self.special_fields.clear();
     }
 
@@ -1924,6 +2229,9 @@ impl ::protobuf::Message for ProtorunesWalletRequest {
         static instance: ProtorunesWalletRequest = ProtorunesWalletRequest {
             wallet: ::std::vec::Vec::new(),
             protocol_tag: ::protobuf::MessageField::none(),
+            pagination: ::protobuf::MessageField::none(),
+            filter: ::protobuf::MessageField::none(),
+            descending: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -3302,6 +3610,928 @@ impl ::protobuf::reflect::ProtobufValue for Runtime {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+// @@protoc_insertion_point(message:protorune.HoldersRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct HoldersRequest {
+    // message fields
+    // @@protoc_insertion_point(field:protorune.HoldersRequest.id)
+    pub id: ::protobuf::MessageField<ProtoruneRuneId>,
+    // @@protoc_insertion_point(field:protorune.HoldersRequest.protocol_tag)
+    pub protocol_tag: ::protobuf::MessageField<Uint128>,
+    // @@protoc_insertion_point(field:protorune.HoldersRequest.cursor)
+    pub cursor: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:protorune.HoldersRequest.limit)
+    pub limit: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:protorune.HoldersRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HoldersRequest {
+    fn default() -> &'a HoldersRequest {
+        <HoldersRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HoldersRequest {
+    pub fn new() -> HoldersRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ProtoruneRuneId>(
+            "id",
+            |m: &HoldersRequest| { &m.id },
+            |m: &mut HoldersRequest| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "protocol_tag",
+            |m: &HoldersRequest| { &m.protocol_tag },
+            |m: &mut HoldersRequest| { &mut m.protocol_tag },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "cursor",
+            |m: &HoldersRequest| { &m.cursor },
+            |m: &mut HoldersRequest| { &mut m.cursor },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &HoldersRequest| { &m.limit },
+            |m: &mut HoldersRequest| { &mut m.limit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HoldersRequest>(
+            "HoldersRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HoldersRequest {
+    const NAME: &'static str = "HoldersRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.protocol_tag)?;
+                },
+                26 => {
+                    self.cursor = is.read_bytes()?;
+                },
+                32 => {
+                    self.limit = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.protocol_tag.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.cursor);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.limit);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.id.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.protocol_tag.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if !self.cursor.is_empty() {
+            os.write_bytes(3, &self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(4, self.limit)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HoldersRequest {
+        HoldersRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id.clear();
+        self.protocol_tag.clear();
+        self.cursor.clear();
+        self.limit = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HoldersRequest {
+        static instance: HoldersRequest = HoldersRequest {
+            id: ::protobuf::MessageField::none(),
+            protocol_tag: ::protobuf::MessageField::none(),
+            cursor: ::std::vec::Vec::new(),
+            limit: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HoldersRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HoldersRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HoldersRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HoldersRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:protorune.Holder)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Holder {
+    // message fields
+    // @@protoc_insertion_point(field:protorune.Holder.address)
+    pub address: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:protorune.Holder.outpoint)
+    pub outpoint: ::protobuf::MessageField<Outpoint>,
+    // @@protoc_insertion_point(field:protorune.Holder.balance)
+    pub balance: ::protobuf::MessageField<Uint128>,
+    // special fields
+    // @@protoc_insertion_point(special_field:protorune.Holder.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Holder {
+    fn default() -> &'a Holder {
+        <Holder as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Holder {
+    pub fn new() -> Holder {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "address",
+            |m: &Holder| { &m.address },
+            |m: &mut Holder| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Outpoint>(
+            "outpoint",
+            |m: &Holder| { &m.outpoint },
+            |m: &mut Holder| { &mut m.outpoint },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "balance",
+            |m: &Holder| { &m.balance },
+            |m: &mut Holder| { &mut m.balance },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Holder>(
+            "Holder",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Holder {
+    const NAME: &'static str = "Holder";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.address = is.read_bytes()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.outpoint)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.balance)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.address);
+        }
+        if let Some(v) = self.outpoint.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.balance.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.address.is_empty() {
+            os.write_bytes(1, &self.address)?;
+        }
+        if let Some(v) = self.outpoint.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.balance.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Holder {
+        Holder::new()
+    }
+
+    fn clear(&mut self) {
+        self.address.clear();
+        self.outpoint.clear();
+        self.balance.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Holder {
+        static instance: Holder = Holder {
+            address: ::std::vec::Vec::new(),
+            outpoint: ::protobuf::MessageField::none(),
+            balance: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Holder {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Holder").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Holder {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Holder {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:protorune.HoldersResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct HoldersResponse {
+    // message fields
+    // @@protoc_insertion_point(field:protorune.HoldersResponse.holders)
+    pub holders: ::std::vec::Vec<Holder>,
+    // @@protoc_insertion_point(field:protorune.HoldersResponse.next_cursor)
+    pub next_cursor: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:protorune.HoldersResponse.count)
+    pub count: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:protorune.HoldersResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HoldersResponse {
+    fn default() -> &'a HoldersResponse {
+        <HoldersResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HoldersResponse {
+    pub fn new() -> HoldersResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "holders",
+            |m: &HoldersResponse| { &m.holders },
+            |m: &mut HoldersResponse| { &mut m.holders },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "next_cursor",
+            |m: &HoldersResponse| { &m.next_cursor },
+            |m: &mut HoldersResponse| { &mut m.next_cursor },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &HoldersResponse| { &m.count },
+            |m: &mut HoldersResponse| { &mut m.count },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HoldersResponse>(
+            "HoldersResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HoldersResponse {
+    const NAME: &'static str = "HoldersResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.holders.push(is.read_message()?);
+                },
+                18 => {
+                    self.next_cursor = is.read_bytes()?;
+                },
+                24 => {
+                    self.count = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.holders {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.next_cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.next_cursor);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.count);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.holders {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if !self.next_cursor.is_empty() {
+            os.write_bytes(2, &self.next_cursor)?;
+        }
+        if self.count != 0 {
+            os.write_uint64(3, self.count)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HoldersResponse {
+        HoldersResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.holders.clear();
+        self.next_cursor.clear();
+        self.count = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HoldersResponse {
+        static instance: HoldersResponse = HoldersResponse {
+            holders: ::std::vec::Vec::new(),
+            next_cursor: ::std::vec::Vec::new(),
+            count: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HoldersResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HoldersResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HoldersResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HoldersResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:protorune.HolderCountResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct HolderCountResponse {
+    // message fields
+    // @@protoc_insertion_point(field:protorune.HolderCountResponse.count)
+    pub count: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:protorune.HolderCountResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HolderCountResponse {
+    fn default() -> &'a HolderCountResponse {
+        <HolderCountResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HolderCountResponse {
+    pub fn new() -> HolderCountResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &HolderCountResponse| { &m.count },
+            |m: &mut HolderCountResponse| { &mut m.count },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HolderCountResponse>(
+            "HolderCountResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HolderCountResponse {
+    const NAME: &'static str = "HolderCountResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.count = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.count != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.count);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.count != 0 {
+            os.write_uint64(1, self.count)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HolderCountResponse {
+        HolderCountResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.count = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HolderCountResponse {
+        static instance: HolderCountResponse = HolderCountResponse {
+            count: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HolderCountResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HolderCountResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HolderCountResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HolderCountResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:protorune.SupplyRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SupplyRequest {
+    // message fields
+    // @@protoc_insertion_point(field:protorune.SupplyRequest.id)
+    pub id: ::protobuf::MessageField<ProtoruneRuneId>,
+    // @@protoc_insertion_point(field:protorune.SupplyRequest.protocol_tag)
+    pub protocol_tag: ::protobuf::MessageField<Uint128>,
+    // special fields
+    // @@protoc_insertion_point(special_field:protorune.SupplyRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SupplyRequest {
+    fn default() -> &'a SupplyRequest {
+        <SupplyRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SupplyRequest {
+    pub fn new() -> SupplyRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ProtoruneRuneId>(
+            "id",
+            |m: &SupplyRequest| { &m.id },
+            |m: &mut SupplyRequest| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "protocol_tag",
+            |m: &SupplyRequest| { &m.protocol_tag },
+            |m: &mut SupplyRequest| { &mut m.protocol_tag },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SupplyRequest>(
+            "SupplyRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SupplyRequest {
+    const NAME: &'static str = "SupplyRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.id)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.protocol_tag)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.protocol_tag.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.id.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.protocol_tag.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SupplyRequest {
+        SupplyRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id.clear();
+        self.protocol_tag.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SupplyRequest {
+        static instance: SupplyRequest = SupplyRequest {
+            id: ::protobuf::MessageField::none(),
+            protocol_tag: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SupplyRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SupplyRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SupplyRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SupplyRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:protorune.SupplyResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SupplyResponse {
+    // message fields
+    // @@protoc_insertion_point(field:protorune.SupplyResponse.minted)
+    pub minted: ::protobuf::MessageField<Uint128>,
+    // @@protoc_insertion_point(field:protorune.SupplyResponse.burned)
+    pub burned: ::protobuf::MessageField<Uint128>,
+    // @@protoc_insertion_point(field:protorune.SupplyResponse.circulating)
+    pub circulating: ::protobuf::MessageField<Uint128>,
+    // special fields
+    // @@protoc_insertion_point(special_field:protorune.SupplyResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SupplyResponse {
+    fn default() -> &'a SupplyResponse {
+        <SupplyResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SupplyResponse {
+    pub fn new() -> SupplyResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "minted",
+            |m: &SupplyResponse| { &m.minted },
+            |m: &mut SupplyResponse| { &mut m.minted },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "burned",
+            |m: &SupplyResponse| { &m.burned },
+            |m: &mut SupplyResponse| { &mut m.burned },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Uint128>(
+            "circulating",
+            |m: &SupplyResponse| { &m.circulating },
+            |m: &mut SupplyResponse| { &mut m.circulating },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SupplyResponse>(
+            "SupplyResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SupplyResponse {
+    const NAME: &'static str = "SupplyResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.minted)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.burned)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.circulating)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.minted.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.burned.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.circulating.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.minted.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.burned.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.circulating.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SupplyResponse {
+        SupplyResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.minted.clear();
+        self.burned.clear();
+        self.circulating.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SupplyResponse {
+        static instance: SupplyResponse = SupplyResponse {
+            minted: ::protobuf::MessageField::none(),
+            burned: ::protobuf::MessageField::none(),
+            circulating: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SupplyResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SupplyResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SupplyResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SupplyResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0fprotorune.proto\x12\tprotorune\":\n\x06RuneId\x12\x16\n\x06height\
     \x18\x01\x20\x01(\rR\x06height\x12\x18\n\x07txindex\x18\x02\x20\x01(\rR\
@@ -3326,34 +4556,62 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x08balances\x12/\n\x08outpoint\x18\x02\x20\x01(\x0b2\x13.protorune.Outp\
     ointR\x08outpoint\x12)\n\x06output\x18\x03\x20\x01(\x0b2\x11.protorune.O\
     utputR\x06output\x12\x16\n\x06height\x18\x04\x20\x01(\rR\x06height\x12\
-    \x18\n\x07txindex\x18\x05\x20\x01(\rR\x07txindex\"9\n\x0fPaginationInput\
+    \x18\n\x07txindex\x18\x05\x20\x01(\rR\x07txindex\"g\n\x0fPaginationInput\
     \x12\x14\n\x05start\x18\x01\x20\x01(\rR\x05start\x12\x10\n\x03end\x18\
-    \x02\x20\x01(\rR\x03end\"'\n\rWalletRequest\x12\x16\n\x06wallet\x18\x01\
-    \x20\x01(\x0cR\x06wallet\"\x80\x01\n\x0eWalletResponse\x129\n\toutpoints\
+    \x02\x20\x01(\rR\x03end\x12\x16\n\x06cursor\x18\x03\x20\x01(\x0cR\x06cur\
+    sor\x12\x14\n\x05limit\x18\x04\x20\x01(\rR\x05limit\"s\n\x0cWalletFilter\
+    \x12.\n\x04rune\x18\x01\x20\x01(\x0b2\x1a.protorune.ProtoruneRuneIdR\x04\
+    rune\x123\n\x0bmin_balance\x18\x02\x20\x01(\x0b2\x12.protorune.uint128R\
+    \nminBalance\"\xb4\x01\n\rWalletRequest\x12\x16\n\x06wallet\x18\x01\x20\
+    \x01(\x0cR\x06wallet\x12:\n\npagination\x18\x02\x20\x01(\x0b2\x1a.protor\
+    une.PaginationInputR\npagination\x12/\n\x06filter\x18\x03\x20\x01(\x0b2\
+    \x17.protorune.WalletFilterR\x06filter\x12\x1e\n\ndescending\x18\x04\x20\
+    \x01(\x08R\ndescending\"\xa1\x01\n\x0eWalletResponse\x129\n\toutpoints\
     \x18\x01\x20\x03(\x0b2\x1b.protorune.OutpointResponseR\toutpoints\x123\n\
     \x08balances\x18\x02\x20\x01(\x0b2\x17.protorune.BalanceSheetR\x08balanc\
-    es\"h\n\x17ProtorunesWalletRequest\x12\x16\n\x06wallet\x18\x01\x20\x01(\
-    \x0cR\x06wallet\x125\n\x0cprotocol_tag\x18\x02\x20\x01(\x0b2\x12.protoru\
-    ne.uint128R\x0bprotocolTag\".\n\x14RunesByHeightRequest\x12\x16\n\x06hei\
-    ght\x18\x01\x20\x01(\x04R\x06height\"j\n\x19ProtorunesByHeightRequest\
-    \x12\x16\n\x06height\x18\x01\x20\x01(\x04R\x06height\x125\n\x0cprotocol_\
-    tag\x18\x02\x20\x01(\x0b2\x12.protorune.uint128R\x0bprotocolTag\"6\n\rRu\
-    nesResponse\x12%\n\x05runes\x18\x01\x20\x03(\x0b2\x0f.protorune.RuneR\
-    \x05runes\"\\\n\tProtoBurn\x125\n\x0cprotocol_tag\x18\x01\x20\x01(\x0b2\
-    \x12.protorune.uint128R\x0bprotocolTag\x12\x18\n\x07pointer\x18\x02\x20\
-    \x01(\rR\x07pointer\")\n\x07uint128\x12\x0e\n\x02lo\x18\x01\x20\x01(\x04\
-    R\x02lo\x12\x0e\n\x02hi\x18\x02\x20\x01(\x04R\x02hi\"d\n\x06Clause\x12.\
-    \n\x04rune\x18\x01\x20\x01(\x0b2\x1a.protorune.ProtoruneRuneIdR\x04rune\
-    \x12*\n\x06amount\x18\x02\x20\x01(\x0b2\x12.protorune.uint128R\x06amount\
-    \"8\n\tPredicate\x12+\n\x07clauses\x18\x01\x20\x03(\x0b2\x11.protorune.C\
-    lauseR\x07clauses\"\x9f\x01\n\x0cProtoMessage\x12\x1a\n\x08calldata\x18\
-    \x01\x20\x01(\x0cR\x08calldata\x122\n\tpredicate\x18\x02\x20\x01(\x0b2\
-    \x14.protorune.PredicateR\tpredicate\x12\x18\n\x07pointer\x18\x03\x20\
-    \x01(\rR\x07pointer\x12%\n\x0erefund_pointer\x18\x04\x20\x01(\rR\rrefund\
-    Pointer\"E\n\x0cRuntimeInput\x125\n\x0cprotocol_tag\x18\x01\x20\x01(\x0b\
-    2\x12.protorune.uint128R\x0bprotocolTag\">\n\x07Runtime\x123\n\x08balanc\
-    es\x18\x01\x20\x01(\x0b2\x17.protorune.BalanceSheetR\x08balancesb\x06pro\
-    to3\
+    es\x12\x1f\n\x0bnext_cursor\x18\x03\x20\x01(\x0cR\nnextCursor\"\xf5\x01\
+    \n\x17ProtorunesWalletRequest\x12\x16\n\x06wallet\x18\x01\x20\x01(\x0cR\
+    \x06wallet\x125\n\x0cprotocol_tag\x18\x02\x20\x01(\x0b2\x12.protorune.ui\
+    nt128R\x0bprotocolTag\x12:\n\npagination\x18\x03\x20\x01(\x0b2\x1a.proto\
+    rune.PaginationInputR\npagination\x12/\n\x06filter\x18\x04\x20\x01(\x0b2\
+    \x17.protorune.WalletFilterR\x06filter\x12\x1e\n\ndescending\x18\x05\x20\
+    \x01(\x08R\ndescending\".\n\x14RunesByHeightRequest\x12\x16\n\x06height\
+    \x18\x01\x20\x01(\x04R\x06height\"j\n\x19ProtorunesByHeightRequest\x12\
+    \x16\n\x06height\x18\x01\x20\x01(\x04R\x06height\x125\n\x0cprotocol_tag\
+    \x18\x02\x20\x01(\x0b2\x12.protorune.uint128R\x0bprotocolTag\"6\n\rRunes\
+    Response\x12%\n\x05runes\x18\x01\x20\x03(\x0b2\x0f.protorune.RuneR\x05ru\
+    nes\"\\\n\tProtoBurn\x125\n\x0cprotocol_tag\x18\x01\x20\x01(\x0b2\x12.pr\
+    otorune.uint128R\x0bprotocolTag\x12\x18\n\x07pointer\x18\x02\x20\x01(\rR\
+    \x07pointer\")\n\x07uint128\x12\x0e\n\x02lo\x18\x01\x20\x01(\x04R\x02lo\
+    \x12\x0e\n\x02hi\x18\x02\x20\x01(\x04R\x02hi\"d\n\x06Clause\x12.\n\x04ru\
+    ne\x18\x01\x20\x01(\x0b2\x1a.protorune.ProtoruneRuneIdR\x04rune\x12*\n\
+    \x06amount\x18\x02\x20\x01(\x0b2\x12.protorune.uint128R\x06amount\"8\n\t\
+    Predicate\x12+\n\x07clauses\x18\x01\x20\x03(\x0b2\x11.protorune.ClauseR\
+    \x07clauses\"\x9f\x01\n\x0cProtoMessage\x12\x1a\n\x08calldata\x18\x01\
+    \x20\x01(\x0cR\x08calldata\x122\n\tpredicate\x18\x02\x20\x01(\x0b2\x14.p\
+    rotorune.PredicateR\tpredicate\x12\x18\n\x07pointer\x18\x03\x20\x01(\rR\
+    \x07pointer\x12%\n\x0erefund_pointer\x18\x04\x20\x01(\rR\rrefundPointer\
+    \"E\n\x0cRuntimeInput\x125\n\x0cprotocol_tag\x18\x01\x20\x01(\x0b2\x12.p\
+    rotorune.uint128R\x0bprotocolTag\">\n\x07Runtime\x123\n\x08balances\x18\
+    \x01\x20\x01(\x0b2\x17.protorune.BalanceSheetR\x08balances\"\xa1\x01\n\
+    \x0eHoldersRequest\x12*\n\x02id\x18\x01\x20\x01(\x0b2\x1a.protorune.Prot\
+    oruneRuneIdR\x02id\x125\n\x0cprotocol_tag\x18\x02\x20\x01(\x0b2\x12.prot\
+    orune.uint128R\x0bprotocolTag\x12\x16\n\x06cursor\x18\x03\x20\x01(\x0cR\
+    \x06cursor\x12\x14\n\x05limit\x18\x04\x20\x01(\rR\x05limit\"\x81\x01\n\
+    \x06Holder\x12\x18\n\x07address\x18\x01\x20\x01(\x0cR\x07address\x12/\n\
+    \x08outpoint\x18\x02\x20\x01(\x0b2\x13.protorune.OutpointR\x08outpoint\
+    \x12,\n\x07balance\x18\x03\x20\x01(\x0b2\x12.protorune.uint128R\x07balan\
+    ce\"u\n\x0fHoldersResponse\x12+\n\x07holders\x18\x01\x20\x03(\x0b2\x11.p\
+    rotorune.HolderR\x07holders\x12\x1f\n\x0bnext_cursor\x18\x02\x20\x01(\
+    \x0cR\nnextCursor\x12\x14\n\x05count\x18\x03\x20\x01(\x04R\x05count\"+\n\
+    \x13HolderCountResponse\x12\x14\n\x05count\x18\x01\x20\x01(\x04R\x05coun\
+    t\"r\n\rSupplyRequest\x12*\n\x02id\x18\x01\x20\x01(\x0b2\x1a.protorune.P\
+    rotoruneRuneIdR\x02id\x125\n\x0cprotocol_tag\x18\x02\x20\x01(\x0b2\x12.p\
+    rotorune.uint128R\x0bprotocolTag\"\x9e\x01\n\x0eSupplyResponse\x12*\n\
+    \x06minted\x18\x01\x20\x01(\x0b2\x12.protorune.uint128R\x06minted\x12*\n\
+    \x06burned\x18\x02\x20\x01(\x0b2\x12.protorune.uint128R\x06burned\x124\n\
+    \x0bcirculating\x18\x03\x20\x01(\x0b2\x12.protorune.uint128R\x0bcirculat\
+    ingb\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -3371,7 +4629,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(23);
+            let mut messages = ::std::vec::Vec::with_capacity(30);
             messages.push(RuneId::generated_message_descriptor_data());
             messages.push(ProtoruneRuneId::generated_message_descriptor_data());
             messages.push(Rune::generated_message_descriptor_data());
@@ -3382,6 +4640,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(Output::generated_message_descriptor_data());
             messages.push(OutpointResponse::generated_message_descriptor_data());
             messages.push(PaginationInput::generated_message_descriptor_data());
+            messages.push(WalletFilter::generated_message_descriptor_data());
             messages.push(WalletRequest::generated_message_descriptor_data());
             messages.push(WalletResponse::generated_message_descriptor_data());
             messages.push(ProtorunesWalletRequest::generated_message_descriptor_data());
@@ -3395,6 +4654,12 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(ProtoMessage::generated_message_descriptor_data());
             messages.push(RuntimeInput::generated_message_descriptor_data());
             messages.push(Runtime::generated_message_descriptor_data());
+            messages.push(HoldersRequest::generated_message_descriptor_data());
+            messages.push(Holder::generated_message_descriptor_data());
+            messages.push(HoldersResponse::generated_message_descriptor_data());
+            messages.push(HolderCountResponse::generated_message_descriptor_data());
+            messages.push(SupplyRequest::generated_message_descriptor_data());
+            messages.push(SupplyResponse::generated_message_descriptor_data());
             let mut enums = ::std::vec::Vec::with_capacity(0);
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
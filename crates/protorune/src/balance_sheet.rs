@@ -1,3 +1,4 @@
+use crate::versioned;
 use anyhow::{anyhow, Result};
 use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
 use metashrew_support::index_pointer::KeyValuePointer;
@@ -24,13 +25,14 @@ pub trait PersistentRecord: BalanceSheetOperations {
         for (rune, balance) in self.balances() {
             if *balance != 0u128 && !is_cenotaph {
                 let rune_bytes: Vec<u8> = (*rune).into();
-                runes_ptr.append(rune_bytes.clone().into());
+                versioned::append(&runes_ptr, rune_bytes.clone().into());
 
-                balances_ptr.append_value::<u128>(*balance);
+                versioned::append_value::<_, u128>(&balances_ptr, *balance);
 
-                runes_to_balances_ptr
-                    .select(&rune_bytes)
-                    .set_value::<u128>(*balance);
+                versioned::set_value::<_, u128>(
+                    &mut runes_to_balances_ptr.select(&rune_bytes),
+                    *balance,
+                );
             }
         }
     }
@@ -49,11 +51,12 @@ pub trait PersistentRecord: BalanceSheetOperations {
             .ok_or(anyhow!("no balance found"))?;
         if *balance != 0u128 && !is_cenotaph {
             let rune_bytes: Vec<u8> = (*rune).into();
-            runes_ptr.append(rune_bytes.clone().into());
-            balances_ptr.append_value::<u128>(*balance);
-            runes_to_balances_ptr
-                .select(&rune_bytes)
-                .set_value::<u128>(*balance);
+            versioned::append(&runes_ptr, rune_bytes.clone().into());
+            versioned::append_value::<_, u128>(&balances_ptr, *balance);
+            versioned::set_value::<_, u128>(
+                &mut runes_to_balances_ptr.select(&rune_bytes),
+                *balance,
+            );
         }
 
         Ok(())
@@ -175,7 +178,7 @@ pub fn load_sheet<T: KeyValuePointer + Clone>(ptr: &T) -> BalanceSheet<T> {
 
     for i in 0..length {
         let rune = ProtoruneRuneId::from(runes_ptr.select_index(i).get());
-        let balance = balances_ptr.select_index(i).get_value::<u128>();
+        let balance = versioned::get_value::<_, u128>(&balances_ptr.select_index(i));
         result.set(&rune, balance);
     }
     result
@@ -188,9 +191,9 @@ pub fn clear_balances<T: KeyValuePointer>(ptr: &T) {
     let runes_to_balances_ptr = ptr.keyword("/id_to_balance");
 
     for i in 0..length {
-        balances_ptr.select_index(i).set_value::<u128>(0);
+        versioned::set_value::<_, u128>(&mut balances_ptr.select_index(i), 0);
         let rune = balances_ptr.select_index(i).get();
-        runes_to_balances_ptr.select(&rune).set_value::<u128>(0);
+        versioned::set_value::<_, u128>(&mut runes_to_balances_ptr.select(&rune), 0);
     }
 }
 
@@ -19,9 +19,9 @@ use bitcoin::hashes::Hash;
 use once_cell::sync::Lazy;
 
 
-pub static HTTP_URL: Lazy<String> = Lazy::new(|| {
-    env!("ALKANES_HTTP_URL", "ALKANES_HTTP_URL environment variable must be set at compile time").to_string()
-});
+// optional: without it, block notifications default to disabled
+pub static HTTP_URL: Lazy<Option<String>> =
+    Lazy::new(|| option_env!("ALKANES_HTTP_URL").map(|v| v.to_string()));
 
 #[cfg(not(feature = "test-utils"))]
 #[link(wasm_import_module = "env")]
@@ -30,6 +30,10 @@ extern "C" {
     pub fn __post_json(url_ptr: i32, url_len: i32, body_ptr: i32, body_len: i32);
 }
 
+#[cfg(feature = "test-utils")]
+pub fn post_json(_url: &str, _body: &str) {}
+
+#[cfg(not(feature = "test-utils"))]
 #[no_mangle]
 pub extern "C" fn post_json(url: &str, body: &str) {
     unsafe {
@@ -0,0 +1,307 @@
+use crate::balance_sheet::load_sheet;
+use crate::tables::{self, RuneTable};
+use crate::versioned;
+use anyhow::{anyhow, Result};
+use bitcoin::OutPoint;
+use metashrew_core::index_pointer::IndexPointer;
+use metashrew_support::index_pointer::KeyValuePointer;
+use protorune_support::balance_sheet::{BalanceSheetOperations, ProtoruneRuneId};
+use protorune_support::utils::{consensus_decode, consensus_encode};
+use std::io::Cursor;
+use std::sync::Arc;
+
+// Holders of each protorune, per protocol, under `/holders/<tag>/`:
+//
+//   balance/<rune><holder>   total held across the holder's outpoints
+//   count/<rune>             number of holders with a nonzero total
+//   byoutpoint/<outpoint>    holder an outpoint was credited to
+//   head/<rune>              holder with the largest total
+//   tail/<rune>              holder with the smallest total
+//   next/<rune><holder>      holder after this one, largest total first
+//   prev/<rune><holder>      holder before this one
+//
+// A holder is the address an output pays to, or a zero byte followed by the
+// outpoint for scripts without an address. Everything is versioned, so holders
+// follow reorgs and can be read at a past height.
+//
+// The next/prev links keep holders ordered by total, ties broken by holder key.
+// A holder whose total changes is moved from its old slot one neighbour at a
+// time, and a new holder is placed starting from the smallest, so the cost of an
+// update grows with how far the holder moves rather than with the holder count.
+
+pub fn holders_pointer(protocol_tag: u128) -> IndexPointer {
+    IndexPointer::from_keyword(format!("/holders/{protocol_tag}/").as_str())
+}
+
+pub fn holder_for(outpoint: &OutPoint) -> Result<Vec<u8>> {
+    let outpoint_bytes = consensus_encode(outpoint)?;
+    let address = versioned::get(&tables::OUTPOINT_SPENDABLE_BY.select(&outpoint_bytes));
+    if address.len() != 0 {
+        return Ok(address.as_ref().clone());
+    }
+    let mut holder = vec![0u8];
+    holder.extend(outpoint_bytes);
+    Ok(holder)
+}
+
+/// Splits a holder key into the address or the outpoint it stands for.
+pub fn decode_holder(holder: &Vec<u8>) -> Result<(Vec<u8>, Option<OutPoint>)> {
+    match holder.first() {
+        Some(0) => Ok((
+            vec![],
+            Some(consensus_decode::<OutPoint>(&mut Cursor::new(
+                holder[1..].to_vec(),
+            ))?),
+        )),
+        _ => Ok((holder.clone(), None)),
+    }
+}
+
+fn key(rune: &ProtoruneRuneId, holder: &Vec<u8>) -> Vec<u8> {
+    let mut result: Vec<u8> = rune.clone().into();
+    result.extend(holder);
+    result
+}
+
+fn balance<T: KeyValuePointer>(root: &T, rune: &ProtoruneRuneId, holder: &Vec<u8>) -> u128 {
+    versioned::get_value::<_, u128>(&root.keyword("balance/").select(&key(rune, holder)))
+}
+
+// whether (balance, holder) is listed before (other_balance, other)
+fn precedes(balance: u128, holder: &Vec<u8>, other_balance: u128, other: &Vec<u8>) -> bool {
+    balance > other_balance || (balance == other_balance && holder < other)
+}
+
+fn link<T: KeyValuePointer>(
+    root: &T,
+    rune: &ProtoruneRuneId,
+    from: &Option<Vec<u8>>,
+    to: &Option<Vec<u8>>,
+) {
+    let rune_bytes: Vec<u8> = rune.clone().into();
+    let to_value = Arc::new(to.clone().unwrap_or_default());
+    let from_value = Arc::new(from.clone().unwrap_or_default());
+    match from {
+        Some(from) => versioned::set(
+            &mut root.keyword("next/").select(&key(rune, from)),
+            to_value,
+        ),
+        None => versioned::set(&mut root.keyword("head/").select(&rune_bytes), to_value),
+    }
+    match to {
+        Some(to) => versioned::set(
+            &mut root.keyword("prev/").select(&key(rune, to)),
+            from_value,
+        ),
+        None => versioned::set(&mut root.keyword("tail/").select(&rune_bytes), from_value),
+    }
+}
+
+fn holder_at<T: KeyValuePointer>(ptr: &T) -> Option<Vec<u8>> {
+    let value = versioned::get(ptr);
+    if value.len() == 0 {
+        None
+    } else {
+        Some(value.as_ref().clone())
+    }
+}
+
+fn neighbour<T: KeyValuePointer>(
+    root: &T,
+    rune: &ProtoruneRuneId,
+    direction: &str,
+    holder: &Vec<u8>,
+) -> Option<Vec<u8>> {
+    holder_at(&root.keyword(direction).select(&key(rune, holder)))
+}
+
+/// Moves `holder` to its place in the ordering. `prev` and `next` are the slot it
+/// starts from, which must be adjacent in the list.
+fn place<T: KeyValuePointer>(
+    root: &T,
+    rune: &ProtoruneRuneId,
+    holder: &Vec<u8>,
+    mut prev: Option<Vec<u8>>,
+    mut next: Option<Vec<u8>>,
+) {
+    let amount = balance(root, rune, holder);
+    while let Some(candidate) = prev.clone() {
+        if precedes(balance(root, rune, &candidate), &candidate, amount, holder) {
+            break;
+        }
+        prev = neighbour(root, rune, "prev/", &candidate);
+        next = Some(candidate);
+    }
+    while let Some(candidate) = next.clone() {
+        if !precedes(balance(root, rune, &candidate), &candidate, amount, holder) {
+            break;
+        }
+        next = neighbour(root, rune, "next/", &candidate);
+        prev = Some(candidate);
+    }
+    link(root, rune, &prev, &Some(holder.clone()));
+    link(root, rune, &Some(holder.clone()), &next);
+}
+
+/// Takes `holder` out of the ordering and returns the slot it was in.
+fn unlink<T: KeyValuePointer>(
+    root: &T,
+    rune: &ProtoruneRuneId,
+    holder: &Vec<u8>,
+) -> (Option<Vec<u8>>, Option<Vec<u8>>) {
+    let prev = neighbour(root, rune, "prev/", holder);
+    let next = neighbour(root, rune, "next/", holder);
+    link(root, rune, &prev, &next);
+    versioned::set(
+        &mut root.keyword("prev/").select(&key(rune, holder)),
+        Arc::new(vec![]),
+    );
+    versioned::set(
+        &mut root.keyword("next/").select(&key(rune, holder)),
+        Arc::new(vec![]),
+    );
+    (prev, next)
+}
+
+fn adjust<T: KeyValuePointer>(
+    root: &T,
+    rune: &ProtoruneRuneId,
+    holder: &Vec<u8>,
+    credit: u128,
+    debit: u128,
+) -> Result<()> {
+    let mut balance_ptr = root.keyword("balance/").select(&key(rune, holder));
+    let before = versioned::get_value::<_, u128>(&balance_ptr);
+    let after = before
+        .checked_add(credit)
+        .ok_or_else(|| anyhow!("holder balance overflow"))?
+        .saturating_sub(debit);
+    if before == after {
+        return Ok(());
+    }
+    versioned::set_value::<_, u128>(&mut balance_ptr, after);
+    let mut count = root.keyword("count/").select(&rune.clone().into());
+    let (prev, next) = if before == 0 {
+        versioned::set_value::<_, u32>(&mut count, versioned::get_value::<_, u32>(&count) + 1);
+        (
+            holder_at(&root.keyword("tail/").select(&rune.clone().into())),
+            None,
+        )
+    } else {
+        unlink(root, rune, holder)
+    };
+    if after == 0 {
+        versioned::set_value::<_, u32>(&mut count, versioned::get_value::<_, u32>(&count) - 1);
+    } else {
+        place(root, rune, holder, prev, next);
+    }
+    Ok(())
+}
+
+/// Credits the balances saved to `outpoint` to its holder. `root` is the
+/// `holders_pointer` of the protocol, derived into the caller's atomic pointer.
+pub fn credit_outpoint<T: KeyValuePointer, S: BalanceSheetOperations>(
+    root: &T,
+    outpoint: &OutPoint,
+    sheet: &S,
+) -> Result<()> {
+    let holder = holder_for(outpoint)?;
+    let mut nonzero = sheet.balances().iter().filter(|(_, v)| **v != 0).peekable();
+    if nonzero.peek().is_none() {
+        return Ok(());
+    }
+    versioned::set(
+        &mut root
+            .keyword("byoutpoint/")
+            .select(&consensus_encode(outpoint)?),
+        Arc::new(holder.clone()),
+    );
+    for (rune, balance) in nonzero {
+        adjust(root, rune, &holder, *balance, 0)?;
+    }
+    Ok(())
+}
+
+/// Debits the balances of a spent outpoint from its holder. Must run before the
+/// balances of the outpoint are cleared.
+pub fn debit_outpoint<T: KeyValuePointer>(
+    root: &T,
+    table: &RuneTable,
+    outpoint: &OutPoint,
+) -> Result<()> {
+    let outpoint_bytes = consensus_encode(outpoint)?;
+    let holder = versioned::get(&root.keyword("byoutpoint/").select(&outpoint_bytes));
+    if holder.len() == 0 {
+        return Ok(());
+    }
+    let sheet = load_sheet(&table.OUTPOINT_TO_RUNES.select(&outpoint_bytes));
+    for (rune, balance) in sheet.balances() {
+        if *balance != 0 {
+            adjust(root, rune, holder.as_ref(), 0, *balance)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn holder_count(protocol_tag: u128, rune: &ProtoruneRuneId) -> u32 {
+    versioned::get_value::<_, u32>(
+        &holders_pointer(protocol_tag)
+            .keyword("count/")
+            .select(&rune.clone().into()),
+    )
+}
+
+fn encode_cursor(balance: u128, holder: &Vec<u8>) -> Vec<u8> {
+    let mut cursor = balance.to_le_bytes().to_vec();
+    cursor.extend(holder);
+    cursor
+}
+
+/// Holders of `rune` with their totals, largest first. The cursor is the total
+/// and holder of the next entry, and is empty once the last holder has been
+/// returned. A page reads only the holders it returns, unless the cursor holder
+/// has since changed total, in which case the page resumes from the first holder
+/// ordered after the cursor.
+pub fn holders(
+    protocol_tag: u128,
+    rune: &ProtoruneRuneId,
+    cursor: &Vec<u8>,
+    limit: u32,
+) -> Result<(Vec<(Vec<u8>, u128)>, Vec<u8>)> {
+    let root = holders_pointer(protocol_tag);
+    let mut current = match cursor.len() {
+        0 => holder_at(&root.keyword("head/").select(&rune.clone().into())),
+        len if len > 16 => {
+            let after = u128::from_le_bytes(cursor[..16].try_into()?);
+            let holder = cursor[16..].to_vec();
+            if balance(&root, rune, &holder) == after {
+                Some(holder)
+            } else {
+                let mut current = holder_at(&root.keyword("head/").select(&rune.clone().into()));
+                while let Some(candidate) = current.clone() {
+                    if !precedes(balance(&root, rune, &candidate), &candidate, after, &holder) {
+                        break;
+                    }
+                    current = neighbour(&root, rune, "next/", &candidate);
+                }
+                current
+            }
+        }
+        _ => return Err(anyhow!("malformed holders cursor")),
+    };
+    let limit = match limit {
+        0 => usize::MAX,
+        limit => limit as usize,
+    };
+    let mut result: Vec<(Vec<u8>, u128)> = vec![];
+    while let Some(holder) = current {
+        if result.len() >= limit {
+            let amount = balance(&root, rune, &holder);
+            return Ok((result, encode_cursor(amount, &holder)));
+        }
+        current = neighbour(&root, rune, "next/", &holder);
+        let amount = balance(&root, rune, &holder);
+        result.push((holder, amount));
+    }
+    Ok((result, vec![]))
+}
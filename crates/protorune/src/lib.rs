@@ -34,18 +34,22 @@ use protorune_support::{
 use std::collections::{HashMap, HashSet};
 use std::ops::Sub;
 use std::sync::Arc;
+use versioned::VersionedPointer;
 
 pub mod balance_sheet;
 pub mod message;
 pub mod protoburn;
 pub mod protorune_init;
 pub mod protostone;
+pub mod supply;
 pub mod tables;
 pub mod block_info;
+pub mod holders;
 #[cfg(feature = "test-utils")]
 pub mod test_helpers;
 #[cfg(test)]
 pub mod tests;
+pub mod versioned;
 pub mod view;
 
 pub struct Protorune(());
@@ -392,10 +396,10 @@ impl Protorune {
                 && (offset_start == 0 || height >= offset_start + etching_height)
                 && (offset_end == 0 || height < etching_height + offset_end)
             {
-                tables::RUNES
-                    .MINTS_REMAINING
-                    .select(&name)
-                    .set_value(remaining.sub(1));
+                versioned::set_value(
+                    &mut tables::RUNES.MINTS_REMAINING.select(&name),
+                    remaining.sub(1),
+                );
                 balance_sheet.increase(
                     &(ProtoruneRuneId {
                         block: u128::from(mint.block),
@@ -451,25 +455,30 @@ impl Protorune {
             return Ok(());
         }
         let rune_id = ProtoruneRuneId::new(height.into(), index.into());
-        atomic
-            .derive(&tables::RUNES.RUNE_ID_TO_ETCHING.select(&rune_id.into()))
-            .set(Arc::new(indexer_rune_name.clone()));
-        atomic
-            .derive(&tables::RUNES.ETCHING_TO_RUNE_ID.select(&indexer_rune_name))
-            .set(rune_id.into());
-        atomic
-            .derive(&tables::RUNES.RUNE_ID_TO_HEIGHT.select(&rune_id.into()))
-            .set_value(height);
+        versioned::set(
+            &mut atomic.derive(&tables::RUNES.RUNE_ID_TO_ETCHING.select(&rune_id.into())),
+            Arc::new(indexer_rune_name.clone()),
+        );
+        versioned::set(
+            &mut atomic.derive(&tables::RUNES.ETCHING_TO_RUNE_ID.select(&indexer_rune_name)),
+            rune_id.into(),
+        );
+        versioned::set_value(
+            &mut atomic.derive(&tables::RUNES.RUNE_ID_TO_HEIGHT.select(&rune_id.into())),
+            height,
+        );
 
         if let Some(divisibility) = etching.divisibility {
-            atomic
-                .derive(&tables::RUNES.DIVISIBILITY.select(&indexer_rune_name))
-                .set_value(divisibility);
+            versioned::set_value(
+                &mut atomic.derive(&tables::RUNES.DIVISIBILITY.select(&indexer_rune_name)),
+                divisibility,
+            );
         }
         if let Some(premine) = etching.premine {
-            atomic
-                .derive(&tables::RUNES.PREMINE.select(&indexer_rune_name))
-                .set_value(premine);
+            versioned::set_value(
+                &mut atomic.derive(&tables::RUNES.PREMINE.select(&indexer_rune_name)),
+                premine,
+            );
             let rune = ProtoruneRuneId {
                 block: u128::from(height),
                 tx: u128::from(index),
@@ -480,56 +489,67 @@ impl Protorune {
         }
         if let Some(terms) = etching.terms {
             if let Some(amount) = terms.amount {
-                atomic
-                    .derive(&tables::RUNES.AMOUNT.select(&indexer_rune_name))
-                    .set_value(amount);
+                versioned::set_value(
+                    &mut atomic.derive(&tables::RUNES.AMOUNT.select(&indexer_rune_name)),
+                    amount,
+                );
             }
             if let Some(cap) = terms.cap {
-                atomic
-                    .derive(&tables::RUNES.CAP.select(&indexer_rune_name))
-                    .set_value(cap);
-                atomic
-                    .derive(&tables::RUNES.MINTS_REMAINING.select(&indexer_rune_name))
-                    .set_value(cap);
+                versioned::set_value(
+                    &mut atomic.derive(&tables::RUNES.CAP.select(&indexer_rune_name)),
+                    cap,
+                );
+                versioned::set_value(
+                    &mut atomic.derive(&tables::RUNES.MINTS_REMAINING.select(&indexer_rune_name)),
+                    cap,
+                );
             }
             if let (Some(height_start), Some(height_end)) = (terms.height.0, terms.height.1) {
-                atomic
-                    .derive(&tables::RUNES.HEIGHTSTART.select(&indexer_rune_name))
-                    .set_value(height_start);
-
-                atomic
-                    .derive(&tables::RUNES.HEIGHTEND.select(&indexer_rune_name))
-                    .set_value(height_end);
+                versioned::set_value(
+                    &mut atomic.derive(&tables::RUNES.HEIGHTSTART.select(&indexer_rune_name)),
+                    height_start,
+                );
+
+                versioned::set_value(
+                    &mut atomic.derive(&tables::RUNES.HEIGHTEND.select(&indexer_rune_name)),
+                    height_end,
+                );
             }
             if let (Some(offset_start), Some(offset_end)) = (terms.offset.0, terms.offset.1) {
-                atomic
-                    .derive(&tables::RUNES.OFFSETSTART.select(&indexer_rune_name))
-                    .set_value(offset_start);
-                atomic
-                    .derive(&tables::RUNES.OFFSETEND.select(&indexer_rune_name))
-                    .set_value(offset_end);
+                versioned::set_value(
+                    &mut atomic.derive(&tables::RUNES.OFFSETSTART.select(&indexer_rune_name)),
+                    offset_start,
+                );
+                versioned::set_value(
+                    &mut atomic.derive(&tables::RUNES.OFFSETEND.select(&indexer_rune_name)),
+                    offset_end,
+                );
             }
         }
 
         // runes spec states this is the default symbol if symbol is omitted
         let symbol = etching.symbol.unwrap_or('¤');
-        atomic
-            .derive(&tables::RUNES.SYMBOL.select(&indexer_rune_name))
-            .set_value(symbol as u32);
+        versioned::set_value(
+            &mut atomic.derive(&tables::RUNES.SYMBOL.select(&indexer_rune_name)),
+            symbol as u32,
+        );
 
         if let Some(spacers) = etching.spacers {
-            atomic
-                .derive(&tables::RUNES.SPACERS.select(&indexer_rune_name))
-                .set_value(spacers);
+            versioned::set_value(
+                &mut atomic.derive(&tables::RUNES.SPACERS.select(&indexer_rune_name)),
+                spacers,
+            );
         }
 
-        atomic
-            .derive(&tables::RUNES.ETCHINGS.select(&indexer_rune_name))
-            .append(Arc::new(indexer_rune_name.clone()));
+        versioned::append(
+            &atomic.derive(&tables::RUNES.ETCHINGS.select(&indexer_rune_name)),
+            Arc::new(indexer_rune_name.clone()),
+        );
 
-        atomic
-            .derive(&tables::HEIGHT_TO_RUNES.select_value(height))
-            .append(Arc::new(indexer_rune_name.clone()));
+        versioned::append(
+            &atomic.derive(&tables::HEIGHT_TO_RUNES.select_value(height)),
+            Arc::new(indexer_rune_name.clone()),
+        );
 
         Ok(())
     }
@@ -614,14 +634,18 @@ impl Protorune {
 
         for (txindex, transaction) in txdata.iter().enumerate() {
             let tx_id = transaction.compute_txid();
-            tables::RUNES
-                .TXID_TO_TXINDEX
-                .select(&tx_id.as_byte_array().to_vec())
-                .set_value(txindex as u32);
+            versioned::set_value(
+                &mut tables::RUNES
+                    .TXID_TO_TXINDEX
+                    .select(&tx_id.as_byte_array().to_vec()),
+                txindex as u32,
+            );
             for (_index, input) in transaction.input.iter().enumerate() {
-                tables::OUTPOINT_SPENDABLE_BY
-                    .select(&consensus_encode(&input.previous_output)?)
-                    .nullify();
+                versioned::set(
+                    &mut tables::OUTPOINT_SPENDABLE_BY
+                        .select(&consensus_encode(&input.previous_output)?),
+                    Arc::new(vec![]),
+                );
             }
             for (index, output) in transaction.output.iter().enumerate() {
                 let outpoint = OutPoint {
@@ -638,12 +662,14 @@ impl Protorune {
                     #[cfg(feature = "cache")]
                     updated_addresses.insert(address.to_vec());
 
-                    tables::OUTPOINTS_FOR_ADDRESS
-                        .select(&address.clone())
-                        .append(Arc::new(outpoint_bytes.clone()));
-                    tables::OUTPOINT_SPENDABLE_BY
-                        .select(&outpoint_bytes.clone())
-                        .set(Arc::new(address.clone()))
+                    versioned::append(
+                        &tables::OUTPOINTS_FOR_ADDRESS.select(&address.clone()),
+                        Arc::new(outpoint_bytes.clone()),
+                    );
+                    versioned::set(
+                        &mut tables::OUTPOINT_SPENDABLE_BY.select(&outpoint_bytes.clone()),
+                        Arc::new(address.clone()),
+                    );
                 }
             }
         }
@@ -661,10 +687,12 @@ impl Protorune {
 
         for (txindex, transaction) in txdata.iter().enumerate() {
             let tx_id = transaction.compute_txid();
-            tables::RUNES
-                .TXID_TO_TXINDEX
-                .select(&tx_id.as_byte_array().to_vec())
-                .set_value(txindex as u32);
+            versioned::set_value(
+                &mut tables::RUNES
+                    .TXID_TO_TXINDEX
+                    .select(&tx_id.as_byte_array().to_vec()),
+                txindex as u32,
+            );
             for (index, output) in transaction.output.iter().enumerate() {
                 let outpoint = OutPoint {
                     txid: tx_id.clone(),
@@ -682,24 +710,26 @@ impl Protorune {
                         updated_addresses.insert(address.to_vec());
                     }
 
-                    tables::OUTPOINTS_FOR_ADDRESS
-                        .select(&address.clone())
-                        .append(Arc::new(outpoint_bytes.clone()));
+                    versioned::append(
+                        &tables::OUTPOINTS_FOR_ADDRESS.select(&address.clone()),
+                        Arc::new(outpoint_bytes.clone()),
+                    );
                     if address.len() > 0 {
-                        tables::OUTPOINT_SPENDABLE_BY_ADDRESS
-                            .select(&address.clone())
-                            .append_ll(Arc::new(outpoint_bytes.clone()));
-                        let pos = tables::OUTPOINT_SPENDABLE_BY_ADDRESS
-                            .select(&address.clone())
-                            .length()
-                            - 1;
-                        tables::OUTPOINT_SPENDABLE_BY_ADDRESS
-                            .select(&outpoint_bytes.clone())
-                            .set_value(pos);
+                        let spendable = VersionedPointer(
+                            tables::OUTPOINT_SPENDABLE_BY_ADDRESS.select(&address.clone()),
+                        );
+                        spendable.append_ll(Arc::new(outpoint_bytes.clone()));
+                        let pos = spendable.length() - 1;
+                        versioned::set_value(
+                            &mut tables::OUTPOINT_SPENDABLE_BY_ADDRESS
+                                .select(&outpoint_bytes.clone()),
+                            pos,
+                        );
                     }
-                    tables::OUTPOINT_SPENDABLE_BY
-                        .select(&outpoint_bytes.clone())
-                        .set(Arc::new(address.clone()))
+                    versioned::set(
+                        &mut tables::OUTPOINT_SPENDABLE_BY.select(&outpoint_bytes.clone()),
+                        Arc::new(address.clone()),
+                    );
                 }
             }
             for input in transaction.input.iter() {
@@ -713,13 +743,13 @@ impl Protorune {
                     #[cfg(feature = "cache")]
                     updated_addresses.insert(address.as_ref().to_vec());
 
-                    tables::OUTPOINT_SPENDABLE_BY_ADDRESS
-                        .select(&address)
+                    VersionedPointer(tables::OUTPOINT_SPENDABLE_BY_ADDRESS.select(&address))
                         .delete_value(pos);
                     if pos > 0 {
-                        tables::OUTPOINT_SPENDABLE_BY_ADDRESS
-                            .select(&outpoint_bytes)
-                            .nullify();
+                        VersionedPointer(
+                            tables::OUTPOINT_SPENDABLE_BY_ADDRESS.select(&outpoint_bytes),
+                        )
+                        .nullify();
                     }
                 }
             }
@@ -734,14 +764,13 @@ impl Protorune {
             .HEIGHT_TO_TRANSACTION_IDS
             .select_value::<u64>(height);
         for tx in &block.txdata {
-            ptr.append(Arc::new(tx.compute_txid().as_byte_array().to_vec()));
+            versioned::append(&ptr, Arc::new(tx.compute_txid().as_byte_array().to_vec()));
         }
         Ok(())
     }
     pub fn index_op_return_outpoints(block: &Block, height: u64) -> Result<()> {
-        let mut ptr = tables::OUTPOINT_BY_HEIGHT
-            .select_value::<u64>(height);
-        ptr.nullify();
+        let ptr = tables::OUTPOINT_BY_HEIGHT.select_value::<u64>(height);
+        VersionedPointer(ptr.clone()).nullify();
         for tx in &block.txdata {
             let output_len = tx.output.len();
             for i in 0..output_len {
@@ -755,7 +784,7 @@ impl Protorune {
                             vout: combined_vout,
                         }),
                     )?;
-                    ptr.append(Arc::new(outpoint_bytes.clone()));
+                    versioned::append(&ptr, Arc::new(outpoint_bytes.clone()));
                 }
             }
         }
@@ -771,19 +800,21 @@ impl Protorune {
                         vout: i as u32,
                     }),
                 )?;
-                atomic
-                    .derive(&tables::RUNES.OUTPOINT_TO_HEIGHT.select(&outpoint_bytes))
-                    .set_value(height);
-                atomic
-                    .derive(&tables::OUTPOINT_TO_OUTPUT.select(&outpoint_bytes))
-                    .set(Arc::new(
+                versioned::set_value(
+                    &mut atomic.derive(&tables::RUNES.OUTPOINT_TO_HEIGHT.select(&outpoint_bytes)),
+                    height,
+                );
+                versioned::set(
+                    &mut atomic.derive(&tables::OUTPOINT_TO_OUTPUT.select(&outpoint_bytes)),
+                    Arc::new(
                         (proto::protorune::Output {
                             script: tx.output[i].clone().script_pubkey.into_bytes(),
                             value: tx.output[i].clone().value.to_sat(),
                             special_fields: SpecialFields::new(),
                         })
                         .write_to_bytes()?,
-                    ));
+                    ),
+                );
             }
         }
         atomic.commit();
@@ -799,8 +830,14 @@ impl Protorune {
         // Process all outputs, including the last one
         // The OP_RETURN doesn't have to be at the end
         for i in 0..tx.output.len() {
-            // Skip OP_RETURN outputs
+            // Skip OP_RETURN outputs, burning whatever was sent to them
             if tx.output[i].script_pubkey.is_op_return() {
+                if let Some(sheet) = map.get(&(i as u32)) {
+                    supply::record_burn_sheet(
+                        &atomic.derive(&supply::supply_pointer(T::protocol_tag())),
+                        sheet,
+                    )?;
+                }
                 continue;
             }
 
@@ -824,6 +861,11 @@ impl Protorune {
                 ),
                 false,
             );
+            holders::credit_outpoint(
+                &atomic.derive(&holders::holders_pointer(T::protocol_tag())),
+                &outpoint,
+                &sheet,
+            )?;
         }
         if map.contains_key(&u32::MAX) {
             map.get(&u32::MAX)
@@ -994,6 +1036,13 @@ impl Protorune {
                 &mut proto_balances_by_output,
             )?;
             for input in &tx.input {
+                // debited through the same atomic pointer as the credits above, so
+                // a holder paying themselves nets out correctly
+                holders::debit_outpoint(
+                    &atomic.derive(&holders::holders_pointer(T::protocol_tag())),
+                    &table,
+                    &input.previous_output,
+                )?;
                 //all inputs must be used up, even in cenotaphs
                 let key = consensus_encode(&input.previous_output)?;
                 clear_balances(&mut table.OUTPOINT_TO_RUNES.select(&key));
@@ -1002,20 +1051,52 @@ impl Protorune {
         Ok(())
     }
 
+    /// Protorunes on an input that no protostone moved (no runestone, no protostone
+    /// for the protocol, a cenotaph, or a reverted runestone) can never be spent
+    /// again, so they are counted as burned and taken from their holder.
+    pub fn index_unspent_protorunes<T: MessageContext>(block: &Block) -> Result<()> {
+        let table = tables::RuneTable::for_protocol(T::protocol_tag());
+        let holders_root = holders::holders_pointer(T::protocol_tag());
+        let supply_root = supply::supply_pointer(T::protocol_tag());
+        for tx in &block.txdata {
+            if tx.is_coinbase() {
+                continue;
+            }
+            for input in &tx.input {
+                let sheet = load_sheet(
+                    &table
+                        .OUTPOINT_TO_RUNES
+                        .select(&consensus_encode(&input.previous_output)?),
+                );
+                if sheet.balances().values().all(|v| *v == 0) {
+                    continue;
+                }
+                holders::debit_outpoint(&holders_root, &table, &input.previous_output)?;
+                supply::record_burn_sheet(&supply_root, &sheet)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn index_block<T: MessageContext>(block: Block, height: u64) -> Result<HashSet<Vec<u8>>> {
+        versioned::set_height(height);
         let init_result = initialized_protocol_index().map_err(|e| anyhow!(e.to_string()));
         let add_result =
             add_to_indexable_protocols(T::protocol_tag()).map_err(|e| anyhow!(e.to_string()));
         init_result?;
         add_result?;
-        tables::RUNES
-            .HEIGHT_TO_BLOCKHASH
-            .select_value::<u64>(height)
-            .set(Arc::new(consensus_encode(&block.block_hash())?));
-        tables::RUNES
-            .BLOCKHASH_TO_HEIGHT
-            .select(&consensus_encode(&block.block_hash())?)
-            .set_value::<u64>(height);
+        versioned::set(
+            &mut tables::RUNES
+                .HEIGHT_TO_BLOCKHASH
+                .select_value::<u64>(height),
+            Arc::new(consensus_encode(&block.block_hash())?),
+        );
+        versioned::set_value::<_, u64>(
+            &mut tables::RUNES
+                .BLOCKHASH_TO_HEIGHT
+                .select(&consensus_encode(&block.block_hash())?),
+            height,
+        );
         Self::index_transaction_ids(&block, height)?;
         Self::index_op_return_outpoints(&block, height)?;
         Self::index_outpoints(&block, height)?;
@@ -1024,6 +1105,7 @@ impl Protorune {
         let updated_addresses = Self::index_spendables(&block.txdata)?;
 
         Self::index_unspendables::<T>(&block, height)?;
+        Self::index_unspent_protorunes::<T>(&block)?;
 
         // Return the set of updated addresses
         Ok(updated_addresses)
@@ -1,4 +1,6 @@
+use crate::supply;
 use crate::tables::{RuneTable, RUNES};
+use crate::versioned;
 use anyhow::{anyhow, Result};
 use bitcoin::{OutPoint, Txid};
 use metashrew_core::index_pointer::AtomicPointer;
@@ -34,26 +36,34 @@ impl Protoburn {
         proto_balances_by_output: &mut HashMap<u32, BalanceSheet<AtomicPointer>>,
         outpoint: OutPoint,
     ) -> Result<()> {
-        let table = RuneTable::for_protocol(self.tag.ok_or(anyhow!("no tag found"))?);
-        for (rune, _balance) in balance_sheet.balances().into_iter() {
+        let tag = self.tag.ok_or(anyhow!("no tag found"))?;
+        let table = RuneTable::for_protocol(tag);
+        for (rune, balance) in balance_sheet.balances().into_iter() {
+            // burned as runes, created as protorunes of the protocol
+            supply::record_mint(&atomic.derive(&supply::supply_pointer(tag)), rune, *balance)?;
             let runeid: Arc<Vec<u8>> = (*rune).into();
             let name = RUNES.RUNE_ID_TO_ETCHING.select(&runeid).get();
-            atomic
-                .derive(&table.RUNE_ID_TO_ETCHING.select(&runeid))
-                .set(name.clone());
-            atomic
-                .derive(&table.ETCHING_TO_RUNE_ID.select(&name))
-                .set(runeid);
-            atomic
-                .derive(&table.SPACERS.select(&name))
-                .set(RUNES.SPACERS.select(&name).get());
-            atomic
-                .derive(&table.DIVISIBILITY.select(&name))
-                .set(RUNES.DIVISIBILITY.select(&name).get());
-            atomic
-                .derive(&table.SYMBOL.select(&name))
-                .set(RUNES.SYMBOL.select(&name).get());
-            atomic.derive(&table.ETCHINGS).append(name);
+            versioned::set(
+                &mut atomic.derive(&table.RUNE_ID_TO_ETCHING.select(&runeid)),
+                name.clone(),
+            );
+            versioned::set(
+                &mut atomic.derive(&table.ETCHING_TO_RUNE_ID.select(&name)),
+                runeid,
+            );
+            versioned::set(
+                &mut atomic.derive(&table.SPACERS.select(&name)),
+                RUNES.SPACERS.select(&name).get(),
+            );
+            versioned::set(
+                &mut atomic.derive(&table.DIVISIBILITY.select(&name)),
+                RUNES.DIVISIBILITY.select(&name).get(),
+            );
+            versioned::set(
+                &mut atomic.derive(&table.SYMBOL.select(&name)),
+                RUNES.SYMBOL.select(&name).get(),
+            );
+            versioned::append(&atomic.derive(&table.ETCHINGS), name);
         }
         if !proto_balances_by_output.contains_key(&outpoint.vout) {
             proto_balances_by_output.insert(outpoint.vout, BalanceSheet::default());
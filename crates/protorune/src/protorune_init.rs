@@ -1,5 +1,6 @@
 use crate::message::MessageContext;
 use crate::tables::RuneTable;
+use crate::versioned;
 use metashrew_core::index_pointer::AtomicPointer;
 #[allow(unused_imports)]
 use metashrew_core::{
@@ -23,8 +24,8 @@ pub fn index_unique_protorunes<T: MessageContext>(
         .map(|v| -> Vec<u8> { v.into() })
         .for_each(|v| {
             if seen_table.select(&v).get().as_ref().len() == 0 {
-                seen_table.select(&v).set(Arc::new(vec![0x01]));
-                table.select_value::<u64>(height).append(Arc::new(v));
+                versioned::set(&mut seen_table.select(&v), Arc::new(vec![0x01]));
+                versioned::append(&table.select_value::<u64>(height), Arc::new(v));
             }
         });
 }
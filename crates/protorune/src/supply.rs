@@ -0,0 +1,67 @@
+use crate::versioned;
+use anyhow::{anyhow, Result};
+use metashrew_core::index_pointer::IndexPointer;
+use metashrew_support::index_pointer::KeyValuePointer;
+use protorune_support::balance_sheet::{BalanceSheetOperations, ProtoruneRuneId};
+
+// Supply of each protorune, per protocol, under `/supply/<tag>/`:
+//
+//   minted/<rune>   total created, by protoburns or by an alkane minting itself
+//   burned/<rune>   total sent to OP_RETURN outputs or left on inputs that no
+//                   protostone spent, cenotaphs included
+//
+// Circulating supply is the difference. Amounts held by contracts count as
+// circulating.
+
+pub fn supply_pointer(protocol_tag: u128) -> IndexPointer {
+    IndexPointer::from_keyword(format!("/supply/{protocol_tag}/").as_str())
+}
+
+fn add<T: KeyValuePointer>(mut ptr: T, amount: u128) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let total = versioned::get_value::<_, u128>(&ptr)
+        .checked_add(amount)
+        .ok_or_else(|| anyhow!("supply overflow"))?;
+    versioned::set_value::<_, u128>(&mut ptr, total);
+    Ok(())
+}
+
+/// `root` is the `supply_pointer` of the protocol, derived into the caller's
+/// atomic pointer when there is one.
+pub fn record_mint<T: KeyValuePointer>(
+    root: &T,
+    rune: &ProtoruneRuneId,
+    amount: u128,
+) -> Result<()> {
+    add(root.keyword("minted/").select(&rune.clone().into()), amount)
+}
+
+pub fn record_burn<T: KeyValuePointer>(
+    root: &T,
+    rune: &ProtoruneRuneId,
+    amount: u128,
+) -> Result<()> {
+    add(root.keyword("burned/").select(&rune.clone().into()), amount)
+}
+
+pub fn record_burn_sheet<T: KeyValuePointer, S: BalanceSheetOperations>(
+    root: &T,
+    sheet: &S,
+) -> Result<()> {
+    for (rune, balance) in sheet.balances() {
+        record_burn(root, rune, *balance)?;
+    }
+    Ok(())
+}
+
+/// Minted and burned totals of `rune`.
+pub fn supply(protocol_tag: u128, rune: &ProtoruneRuneId) -> (u128, u128) {
+    let root = supply_pointer(protocol_tag);
+    let rune_bytes: Vec<u8> = rune.clone().into();
+    (
+        versioned::get_value::<_, u128>(&root.keyword("minted/").select(&rune_bytes)),
+        versioned::get_value::<_, u128>(&root.keyword("burned/").select(&rune_bytes)),
+    )
+}
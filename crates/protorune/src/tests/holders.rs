@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+    use crate::balance_sheet::PersistentRecord;
+    use crate::holders::{credit_outpoint, debit_outpoint, holder_count, holders, holders_pointer};
+    use crate::tables::{self, RuneTable};
+    use crate::test_helpers::clear;
+    use crate::versioned;
+    use anyhow::Result;
+    use bitcoin::hashes::Hash;
+    use bitcoin::{OutPoint, Txid};
+    use metashrew_core::index_pointer::IndexPointer;
+    use metashrew_support::index_pointer::KeyValuePointer;
+    use protorune_support::balance_sheet::{BalanceSheet, BalanceSheetOperations, ProtoruneRuneId};
+    use protorune_support::utils::consensus_encode;
+    use std::sync::Arc;
+    use wasm_bindgen_test::*;
+
+    const PROTOCOL_TAG: u128 = 122;
+
+    fn outpoint(vout: u32) -> OutPoint {
+        OutPoint {
+            txid: Txid::all_zeros(),
+            vout,
+        }
+    }
+
+    fn fund(
+        table: &RuneTable,
+        outpoint: &OutPoint,
+        address: Option<&str>,
+        rune: &ProtoruneRuneId,
+        balance: u128,
+    ) -> Result<()> {
+        let outpoint_bytes = consensus_encode(outpoint)?;
+        if let Some(address) = address {
+            versioned::set(
+                &mut tables::OUTPOINT_SPENDABLE_BY.select(&outpoint_bytes),
+                Arc::new(address.as_bytes().to_vec()),
+            );
+        }
+        let sheet = BalanceSheet::<IndexPointer>::from_pairs(vec![rune.clone()], vec![balance]);
+        sheet.save(&table.OUTPOINT_TO_RUNES.select(&outpoint_bytes), false);
+        credit_outpoint(&holders_pointer(PROTOCOL_TAG), outpoint, &sheet)
+    }
+
+    fn balances(rune: &ProtoruneRuneId) -> Result<Vec<(Vec<u8>, u128)>> {
+        Ok(holders(PROTOCOL_TAG, rune, &vec![], 0)?.0)
+    }
+
+    #[wasm_bindgen_test]
+    fn test_holders_by_balance() -> Result<()> {
+        clear();
+        versioned::set_height(1);
+        let table = RuneTable::for_protocol(PROTOCOL_TAG);
+        let rune = ProtoruneRuneId::new(2, 1);
+        fund(&table, &outpoint(0), Some("alice"), &rune, 10)?;
+        fund(&table, &outpoint(1), Some("bob"), &rune, 30)?;
+        fund(&table, &outpoint(2), Some("alice"), &rune, 5)?;
+        fund(&table, &outpoint(3), None, &rune, 1)?;
+        assert_eq!(holder_count(PROTOCOL_TAG, &rune), 3);
+        let all = balances(&rune)?;
+        assert_eq!(all[0], (b"bob".to_vec(), 30));
+        assert_eq!(all[1], (b"alice".to_vec(), 15));
+        // an output without an address is its own holder
+        assert_eq!(all[2].0[0], 0);
+
+        let (first, cursor) = holders(PROTOCOL_TAG, &rune, &vec![], 2)?;
+        assert_eq!(first.len(), 2);
+        let (rest, cursor) = holders(PROTOCOL_TAG, &rune, &cursor, 2)?;
+        assert_eq!(rest, vec![all[2].clone()]);
+        assert!(cursor.is_empty());
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_spent_holders_are_removed() -> Result<()> {
+        clear();
+        versioned::set_height(1);
+        let table = RuneTable::for_protocol(PROTOCOL_TAG);
+        let rune = ProtoruneRuneId::new(2, 1);
+        let root = holders_pointer(PROTOCOL_TAG);
+        fund(&table, &outpoint(0), Some("alice"), &rune, 10)?;
+        fund(&table, &outpoint(1), Some("bob"), &rune, 30)?;
+        fund(&table, &outpoint(2), Some("carol"), &rune, 20)?;
+
+        versioned::set_height(2);
+        debit_outpoint(&root, &table, &outpoint(0))?;
+        assert_eq!(holder_count(PROTOCOL_TAG, &rune), 2);
+        assert_eq!(
+            balances(&rune)?,
+            vec![(b"bob".to_vec(), 30), (b"carol".to_vec(), 20)]
+        );
+        debit_outpoint(&root, &table, &outpoint(2))?;
+        assert_eq!(balances(&rune)?, vec![(b"bob".to_vec(), 30)]);
+
+        versioned::set_view_height(1);
+        assert_eq!(holder_count(PROTOCOL_TAG, &rune), 3);
+        versioned::set_height(3);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_holders_reorder_and_resume() -> Result<()> {
+        clear();
+        versioned::set_height(1);
+        let table = RuneTable::for_protocol(PROTOCOL_TAG);
+        let rune = ProtoruneRuneId::new(2, 1);
+        let root = holders_pointer(PROTOCOL_TAG);
+        fund(&table, &outpoint(0), Some("alice"), &rune, 10)?;
+        fund(&table, &outpoint(1), Some("bob"), &rune, 30)?;
+        fund(&table, &outpoint(2), Some("carol"), &rune, 20)?;
+        fund(&table, &outpoint(3), Some("dave"), &rune, 20)?;
+        // ties are ordered by holder
+        assert_eq!(
+            balances(&rune)?,
+            vec![
+                (b"bob".to_vec(), 30),
+                (b"carol".to_vec(), 20),
+                (b"dave".to_vec(), 20),
+                (b"alice".to_vec(), 10)
+            ]
+        );
+
+        let (first, cursor) = holders(PROTOCOL_TAG, &rune, &vec![], 2)?;
+        assert_eq!(first, vec![(b"bob".to_vec(), 30), (b"carol".to_vec(), 20)]);
+        // alice moves to the top and bob to the bottom between pages
+        fund(&table, &outpoint(4), Some("alice"), &rune, 40)?;
+        debit_outpoint(&root, &table, &outpoint(1))?;
+        fund(&table, &outpoint(5), Some("bob"), &rune, 5)?;
+        assert_eq!(
+            balances(&rune)?,
+            vec![
+                (b"alice".to_vec(), 50),
+                (b"carol".to_vec(), 20),
+                (b"dave".to_vec(), 20),
+                (b"bob".to_vec(), 5)
+            ]
+        );
+        let (rest, cursor) = holders(PROTOCOL_TAG, &rune, &cursor, 2)?;
+        assert_eq!(rest, vec![(b"dave".to_vec(), 20), (b"bob".to_vec(), 5)]);
+        assert!(cursor.is_empty());
+
+        // a cursor whose holder has since left resumes from its old place
+        let (_, cursor) = holders(PROTOCOL_TAG, &rune, &vec![], 2)?;
+        debit_outpoint(&root, &table, &outpoint(3))?;
+        let (rest, _) = holders(PROTOCOL_TAG, &rune, &cursor, 0)?;
+        assert_eq!(rest, vec![(b"bob".to_vec(), 5)]);
+        assert!(holders(PROTOCOL_TAG, &rune, &vec![1, 2, 3], 0).is_err());
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub mod holders;
 pub mod index_op_return_position;
 pub mod index_pointer_ll;
 pub mod index_protoburns;
@@ -6,6 +7,7 @@ pub mod index_protorunes_by_address;
 pub mod index_runes;
 pub mod index_runes_edicts;
 pub mod index_runes_mint;
+pub mod supply;
 #[cfg(test)]
 // pub mod multi_protocol;
 pub mod test_cenotaphs;
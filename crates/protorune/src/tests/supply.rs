@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use crate::supply::{record_burn, record_burn_sheet, record_mint, supply, supply_pointer};
+    use crate::test_helpers::clear;
+    use crate::versioned;
+    use crate::view::supply_by_id;
+    use anyhow::Result;
+    use metashrew_core::index_pointer::IndexPointer;
+    use protobuf::{Message, MessageField};
+    use protorune_support::balance_sheet::{BalanceSheet, ProtoruneRuneId};
+    use protorune_support::proto::protorune::SupplyRequest;
+    use wasm_bindgen_test::*;
+
+    const PROTOCOL_TAG: u128 = 122;
+
+    #[wasm_bindgen_test]
+    fn test_supply_totals() -> Result<()> {
+        clear();
+        versioned::set_height(1);
+        let rune = ProtoruneRuneId::new(2, 1);
+        let other = ProtoruneRuneId::new(2, 2);
+        let root = supply_pointer(PROTOCOL_TAG);
+        record_mint(&root, &rune, 100)?;
+        record_mint(&root, &rune, 50)?;
+        record_mint(&root, &other, 7)?;
+        record_burn(&root, &rune, 30)?;
+        let sheet = BalanceSheet::<IndexPointer>::from_pairs(
+            vec![rune.clone(), other.clone()],
+            vec![20, 7],
+        );
+        record_burn_sheet(&root, &sheet)?;
+        assert_eq!(supply(PROTOCOL_TAG, &rune), (150, 50));
+        assert_eq!(supply(PROTOCOL_TAG, &other), (7, 7));
+        // other protocols are tracked separately
+        assert_eq!(supply(PROTOCOL_TAG + 1, &rune), (0, 0));
+
+        let mut req = SupplyRequest::new();
+        req.id = MessageField::some(rune.clone().into());
+        req.protocol_tag = MessageField::some(PROTOCOL_TAG.into());
+        let response = supply_by_id(&req.write_to_bytes()?)?;
+        assert_eq!(response.circulating.clone().unwrap().lo, 100);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_supply_at_height() -> Result<()> {
+        clear();
+        let rune = ProtoruneRuneId::new(2, 1);
+        let root = supply_pointer(PROTOCOL_TAG);
+        versioned::set_height(1);
+        record_mint(&root, &rune, 100)?;
+        versioned::set_height(2);
+        record_burn(&root, &rune, 40)?;
+        assert_eq!(supply(PROTOCOL_TAG, &rune), (100, 40));
+        versioned::set_view_height(1);
+        assert_eq!(supply(PROTOCOL_TAG, &rune), (100, 0));
+        versioned::set_height(3);
+        Ok(())
+    }
+}
@@ -0,0 +1,282 @@
+use metashrew_core::index_pointer::IndexPointer;
+use metashrew_support::byte_view::ByteView;
+use metashrew_support::index_pointer::KeyValuePointer;
+use std::sync::Arc;
+
+// Height-versioned values.
+//
+// Every write made through `set` / `set_value` / `append` also records
+// `(height, value)` in a history list kept under `/versioned/<key>`, one entry per
+// block that touched the key. Reads made through `get` / `get_value` answer at the
+// view height when one is set and at the tip otherwise, so indexing pays nothing
+// for the lookup.
+//
+// The first write of a key in a block also adds the key to the journal for that
+// height under `/journal/<height>`. `rollback` walks the journal to put every key
+// back to its value as of the previous block, which is what a reorg needs.
+//
+// A key that already held a value when it was first written through this module
+// has that value seeded into its history at height 0, so earlier heights read it
+// and a rollback puts it back. A key never written through this module reads its
+// current value at any height.
+//
+// Write cost: besides the value itself, the first write of a key in a block
+// stores a copy of the value tagged with the height and a journal entry, and
+// finds its place in the history with a binary search. Later writes in the same
+// block overwrite that copy, and writes that leave the value unchanged record
+// nothing, so a key costs one copy per block in which its value changes.
+
+static mut _HEIGHT: u64 = 0;
+static mut _VIEW_HEIGHT: Option<u64> = None;
+
+/// Sets the height recorded with writes, called once per indexed block. Indexing
+/// always reads the tip, so this also drops any view height left behind.
+pub fn set_height(height: u64) {
+    unsafe {
+        _HEIGHT = height;
+        _VIEW_HEIGHT = None;
+    }
+}
+
+pub fn get_height() -> u64 {
+    unsafe { _HEIGHT }
+}
+
+/// Makes reads answer as of the end of block `height`. Writes made afterwards (by a
+/// simulation) are recorded at the same height so they shadow the historical value.
+pub fn set_view_height(height: u64) {
+    unsafe {
+        _VIEW_HEIGHT = Some(height);
+        _HEIGHT = height;
+    }
+}
+
+/// Sets the view height from the height a view request carries. Zero, which
+/// clients sent before views honoured it, and any height at or above the last
+/// indexed block read the tip.
+pub fn set_requested_view_height(height: u64) {
+    match indexed_height() {
+        Some(tip) if height != 0 && height < tip => set_view_height(height),
+        tip => set_height(tip.unwrap_or_default()),
+    }
+}
+
+pub fn get_view_height() -> Option<u64> {
+    unsafe { _VIEW_HEIGHT }
+}
+
+pub fn history_pointer<T: KeyValuePointer>(ptr: &T) -> T {
+    let mut key = b"/versioned/".to_vec();
+    key.extend(ptr.unwrap().as_ref());
+    let mut history = T::wrap(&key);
+    history.inherits(ptr);
+    history
+}
+
+fn entry_height(entry: &Arc<Vec<u8>>) -> u64 {
+    if entry.len() < 8 {
+        0
+    } else {
+        u64::from_le_bytes((&entry[0..8]).try_into().unwrap())
+    }
+}
+
+fn entry(height: u64, v: &Arc<Vec<u8>>) -> Arc<Vec<u8>> {
+    let mut result = height.to_le_bytes().to_vec();
+    result.extend(v.as_ref());
+    Arc::new(result)
+}
+
+// index of the first history entry recorded after `height`
+fn upper_bound<T: KeyValuePointer>(history: &T, length: u32, height: u64) -> u32 {
+    let (mut lo, mut hi) = (0u32, length);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if entry_height(&history.select_index(mid).get()) <= height {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+pub fn journal_pointer<T: KeyValuePointer>(ptr: &T, height: u64) -> T {
+    let mut key = b"/journal/".to_vec();
+    key.extend(&height.to_le_bytes());
+    let mut journal = T::wrap(&key);
+    journal.inherits(ptr);
+    journal
+}
+
+fn record<T: KeyValuePointer>(ptr: &T, history: &T, v: &Arc<Vec<u8>>) {
+    let height = get_height();
+    let length = history.length();
+    let position = upper_bound(history, length, height);
+    if position > 0 {
+        let last = history.select_index(position - 1).get();
+        if entry_height(&last) == height {
+            history.select_index(position - 1).set(entry(height, v));
+            return;
+        }
+        if position == length && last[8..] == v[..] {
+            return;
+        }
+    }
+    journal_pointer(ptr, height).append(ptr.unwrap());
+    if position == length {
+        history.append(entry(height, v));
+    } else {
+        // only a simulation at a past height writes behind the tip; shift the
+        // newer entries up to keep the list ordered
+        history.append(history.select_index(length - 1).get());
+        for i in (position..length - 1).rev() {
+            history
+                .select_index(i + 1)
+                .set(history.select_index(i).get());
+        }
+        history.select_index(position).set(entry(height, v));
+    }
+}
+
+pub fn set<T: KeyValuePointer>(ptr: &mut T, v: Arc<Vec<u8>>) {
+    let history = history_pointer(ptr);
+    if history.length() == 0 {
+        let current = ptr.get();
+        if current.len() > 0 {
+            history.append(entry(0, &current));
+        }
+    }
+    ptr.set(v.clone());
+    record(ptr, &history, &v);
+}
+
+/// An `IndexPointer` whose every write goes through `set`, so the list helpers
+/// built on `KeyValuePointer::set` (`append_ll`, `delete_value`, `nullify`) are
+/// journaled like any other versioned write.
+#[derive(Clone, Default)]
+pub struct VersionedPointer(pub IndexPointer);
+
+impl KeyValuePointer for VersionedPointer {
+    fn wrap(word: &Vec<u8>) -> Self {
+        Self(IndexPointer::wrap(word))
+    }
+    fn unwrap(&self) -> Arc<Vec<u8>> {
+        self.0.unwrap()
+    }
+    fn inherits(&mut self, from: &Self) {
+        self.0.inherits(&from.0)
+    }
+    fn set(&mut self, v: Arc<Vec<u8>>) {
+        set(&mut self.0, v)
+    }
+    fn get(&self) -> Arc<Vec<u8>> {
+        self.0.get()
+    }
+}
+
+pub fn set_value<T: KeyValuePointer, V: ByteView>(ptr: &mut T, v: V) {
+    set(ptr, Arc::new(v.to_bytes()));
+}
+
+/// Versioned `KeyValuePointer::append`: both the new element and the list length
+/// are recorded, so a rollback shortens the list again.
+pub fn append<T: KeyValuePointer>(ptr: &T, v: Arc<Vec<u8>>) {
+    let length = ptr.length();
+    set(&mut ptr.select_index(length), v);
+    set_value::<_, u32>(&mut ptr.length_key(), length + 1);
+}
+
+pub fn append_value<T: KeyValuePointer, V: ByteView>(ptr: &T, v: V) {
+    append(ptr, Arc::new(v.to_bytes()));
+}
+
+/// Value of `ptr` as of the end of block `height`.
+pub fn get_at<T: KeyValuePointer>(ptr: &T, height: u64) -> Arc<Vec<u8>> {
+    let history = history_pointer(ptr);
+    let length = history.length();
+    if length == 0 {
+        return ptr.get();
+    }
+    match upper_bound(&history, length, height) {
+        0 => Arc::new(vec![]),
+        position => Arc::new(history.select_index(position - 1).get()[8..].to_vec()),
+    }
+}
+
+pub fn get<T: KeyValuePointer>(ptr: &T) -> Arc<Vec<u8>> {
+    match get_view_height() {
+        Some(height) => get_at(ptr, height),
+        None => ptr.get(),
+    }
+}
+
+pub fn get_value<T: KeyValuePointer, V: ByteView>(ptr: &T) -> V {
+    let value = get(ptr);
+    if value.len() == 0 {
+        V::zero()
+    } else {
+        V::from_bytes(value.as_ref().clone())
+    }
+}
+
+fn journal_tip() -> IndexPointer {
+    IndexPointer::from_keyword("/journal/tip")
+}
+
+// puts `ptr` back to its value as of the end of block `height - 1`
+fn revert(ptr: &IndexPointer, height: u64) {
+    let history = history_pointer(ptr);
+    let keep = match height {
+        0 => 0,
+        height => upper_bound(&history, history.length(), height - 1),
+    };
+    history.length_key().set_value::<u32>(keep);
+    ptr.clone().set(match keep {
+        0 => Arc::new(vec![]),
+        keep => Arc::new(history.select_index(keep - 1).get()[8..].to_vec()),
+    });
+}
+
+/// Reverts every versioned key written at `height` or later, leaving the index as
+/// it was at the end of block `height - 1`.
+pub fn rollback(height: u64) {
+    let mut tip = journal_tip();
+    if tip.get().len() == 0 {
+        return;
+    }
+    let latest = tip.get_value::<u64>();
+    for h in (height..=latest).rev() {
+        let journal = journal_pointer(&IndexPointer::default(), h);
+        for key in journal.get_list() {
+            revert(&IndexPointer::wrap(key.as_ref()), height);
+        }
+        journal.length_key().set_value::<u32>(0);
+    }
+    match height {
+        0 => tip.nullify(),
+        height => tip.set_value::<u64>(height - 1),
+    }
+}
+
+/// Height of the last block written through this module, if any.
+pub fn indexed_height() -> Option<u64> {
+    let tip = journal_tip();
+    if tip.get().len() == 0 {
+        None
+    } else {
+        Some(tip.get_value::<u64>())
+    }
+}
+
+/// Starts indexing block `height`. A height that was already indexed means the
+/// block replaces one on an abandoned branch, so its state is rolled back first.
+pub fn begin_block(height: u64) {
+    if let Some(latest) = indexed_height() {
+        if height <= latest {
+            rollback(height);
+        }
+    }
+    journal_tip().set_value::<u64>(height);
+    set_height(height);
+}
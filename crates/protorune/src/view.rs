@@ -1,5 +1,5 @@
 use crate::tables::RuneTable;
-use crate::{balance_sheet::load_sheet, tables};
+use crate::{balance_sheet::load_sheet, holders, supply, tables, versioned};
 use anyhow::{anyhow, Result};
 use bitcoin;
 use protorune_support::balance_sheet::{BalanceSheetOperations, ProtoruneRuneId};
@@ -18,6 +18,7 @@ use protorune_support::utils::{consensus_decode, outpoint_encode};
 use bitcoin::hashes::Hash;
 use bitcoin::OutPoint;
 //use metashrew_core::utils::{ consume_exact, consume_sized_int };
+use metashrew_core::index_pointer::IndexPointer;
 #[allow(unused_imports)]
 use metashrew_core::{println, stdio::stdout};
 use metashrew_support::index_pointer::KeyValuePointer;
@@ -25,6 +26,7 @@ use protobuf::{Message, MessageField, SpecialFields};
 #[allow(unused_imports)]
 use std::fmt::Write;
 use std::io::Cursor;
+use std::sync::Arc;
 
 pub fn outpoint_to_bytes(outpoint: &OutPoint) -> Result<Vec<u8>> {
     Ok(outpoint_encode(outpoint)?)
@@ -162,31 +164,19 @@ pub fn outpoint_to_outpoint_response(outpoint: &OutPoint) -> Result<OutpointResp
 pub fn runes_by_address(input: &Vec<u8>) -> Result<WalletResponse> {
     let mut result: WalletResponse = WalletResponse::new();
     if let Some(req) = proto::protorune::WalletRequest::parse_from_bytes(input).ok() {
-        result.outpoints = tables::OUTPOINTS_FOR_ADDRESS
-            .select(&req.wallet)
-            .get_list()
-            .into_iter()
-            .map(|v| -> Result<OutPoint> {
-                let mut cursor = Cursor::new(v.as_ref().clone());
-                Ok(consensus_decode::<bitcoin::blockdata::transaction::OutPoint>(&mut cursor)?)
-            })
-            .collect::<Result<Vec<OutPoint>>>()?
-            .into_iter()
-            .filter_map(|v| -> Option<Result<OutpointResponse>> {
-                let outpoint_bytes = match outpoint_to_bytes(&v) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        return Some(Err(e));
-                    }
-                };
-                let _address = tables::OUTPOINT_SPENDABLE_BY.select(&outpoint_bytes).get();
-                if req.wallet.len() == _address.len() {
-                    Some(outpoint_to_outpoint_response(&v))
-                } else {
-                    None
+        (result.outpoints, result.next_cursor) = paginate(
+            &tables::OUTPOINTS_FOR_ADDRESS.select(&req.wallet),
+            &req.pagination,
+            req.descending,
+            |v| -> Result<Option<OutpointResponse>> {
+                let outpoint = consensus_decode::<OutPoint>(&mut Cursor::new(v.as_ref().clone()))?;
+                if !spendable_by(&outpoint, &req.wallet)? {
+                    return Ok(None);
                 }
-            })
-            .collect::<Result<Vec<OutpointResponse>>>()?;
+                let response = outpoint_to_outpoint_response(&outpoint)?;
+                Ok(matches_filter(&response, &req.filter).then_some(response))
+            },
+        )?;
     }
     Ok(result)
 }
@@ -223,37 +213,120 @@ pub fn runes_by_outpoint(input: &Vec<u8>) -> Result<OutpointResponse> {
     }
 }
 
+fn spendable_by(outpoint: &OutPoint, wallet: &Vec<u8>) -> Result<bool> {
+    let address =
+        versioned::get(&tables::OUTPOINT_SPENDABLE_BY.select(&outpoint_to_bytes(outpoint)?));
+    Ok(wallet.len() == address.len())
+}
+
+/// True when `response` holds a balance of at least `min_balance` (and never less
+/// than 1) of the filtered rune, or of any rune when none is given. Without a
+/// filter everything matches.
+pub fn matches_filter(
+    response: &OutpointResponse,
+    filter: &MessageField<proto::protorune::WalletFilter>,
+) -> bool {
+    let filter = match filter.as_ref() {
+        Some(v) => v,
+        None => return true,
+    };
+    let min_balance = std::cmp::max(
+        filter
+            .min_balance
+            .clone()
+            .into_option()
+            .map_or(0u128, |v| v.into()),
+        1,
+    );
+    response.balances.entries.iter().any(|entry| {
+        let balance: u128 = entry.balance.clone().unwrap_or_default().into();
+        balance >= min_balance
+            && filter
+                .rune
+                .as_ref()
+                .map_or(true, |rune| entry.rune.runeId.as_ref() == Some(rune))
+    })
+}
+
+/// Most list entries a single page visits, so a filter rejecting most of a long
+/// list (spent outpoints of a busy address) cannot make one request walk all of it.
+pub const MAX_PAGE_SCAN: usize = 1000;
+
+/// Walks the list at `list` in insertion order (which for the per-address tables
+/// is height order), or backwards when `descending`, and keeps what `f` returns.
+///
+/// The walk resumes from `pagination.cursor` when set and otherwise skips
+/// `pagination.start` elements. It stops after `pagination.limit` results, or
+/// `end - start` when no limit is given, or once it has visited `MAX_PAGE_SCAN`
+/// entries, and returns the cursor to resume from, which is empty once the list
+/// is exhausted. A page can therefore come back short, or empty, with a cursor.
+pub fn paginate<T, F>(
+    list: &IndexPointer,
+    pagination: &MessageField<proto::protorune::PaginationInput>,
+    descending: bool,
+    mut f: F,
+) -> Result<(Vec<T>, Vec<u8>)>
+where
+    F: FnMut(Arc<Vec<u8>>) -> Result<Option<T>>,
+{
+    let length = versioned::get_value::<_, u32>(&list.length_key());
+    let default_pagination = proto::protorune::PaginationInput::new();
+    let pagination = pagination.as_ref().unwrap_or(&default_pagination);
+    let limit = match (pagination.limit, pagination.end) {
+        (0, end) if end > pagination.start => (end - pagination.start) as usize,
+        (0, _) => usize::MAX,
+        (limit, _) => limit as usize,
+    };
+    // cursors hold the list index of the next element to visit
+    let mut next: Option<u32> = if pagination.cursor.len() == 4 {
+        Some(u32::from_le_bytes(pagination.cursor.as_slice().try_into()?))
+    } else if pagination.cursor.len() != 0 {
+        return Err(anyhow!("malformed pagination cursor"));
+    } else if descending {
+        length.checked_sub(pagination.start + 1)
+    } else {
+        Some(pagination.start)
+    };
+    let mut result: Vec<T> = vec![];
+    let mut scanned = 0;
+    while let Some(index) = next.filter(|index| *index < length) {
+        if result.len() >= limit || scanned >= MAX_PAGE_SCAN {
+            break;
+        }
+        scanned += 1;
+        next = if descending {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1)
+        };
+        if let Some(v) = f(versioned::get(&list.select_index(index)))? {
+            result.push(v);
+        }
+    }
+    let cursor = match next.filter(|index| *index < length) {
+        Some(index) => index.to_le_bytes().to_vec(),
+        None => vec![],
+    };
+    Ok((result, cursor))
+}
+
 pub fn protorunes_by_address(input: &Vec<u8>) -> Result<WalletResponse> {
     let mut result: WalletResponse = WalletResponse::new();
     if let Some(req) = proto::protorune::ProtorunesWalletRequest::parse_from_bytes(input).ok() {
-        result.outpoints = tables::OUTPOINTS_FOR_ADDRESS
-            .select(&req.wallet)
-            .get_list()
-            .into_iter()
-            .map(|v| -> Result<OutPoint> {
-                let mut cursor = Cursor::new(v.as_ref().clone());
-                Ok(consensus_decode::<bitcoin::blockdata::transaction::OutPoint>(&mut cursor)?)
-            })
-            .collect::<Result<Vec<OutPoint>>>()?
-            .into_iter()
-            .filter_map(|v| -> Option<Result<OutpointResponse>> {
-                let outpoint_bytes = match outpoint_to_bytes(&v) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        return Some(Err(e));
-                    }
-                };
-                let _address = tables::OUTPOINT_SPENDABLE_BY.select(&outpoint_bytes).get();
-                if req.wallet.len() == _address.len() {
-                    Some(protorune_outpoint_to_outpoint_response(
-                        &v,
-                        req.clone().protocol_tag.into_option().unwrap().into(),
-                    ))
-                } else {
-                    None
+        let protocol_tag: u128 = req.clone().protocol_tag.into_option().unwrap().into();
+        (result.outpoints, result.next_cursor) = paginate(
+            &tables::OUTPOINTS_FOR_ADDRESS.select(&req.wallet),
+            &req.pagination,
+            req.descending,
+            |v| -> Result<Option<OutpointResponse>> {
+                let outpoint = consensus_decode::<OutPoint>(&mut Cursor::new(v.as_ref().clone()))?;
+                if !spendable_by(&outpoint, &req.wallet)? {
+                    return Ok(None);
                 }
-            })
-            .collect::<Result<Vec<OutpointResponse>>>()?;
+                let response = protorune_outpoint_to_outpoint_response(&outpoint, protocol_tag)?;
+                Ok(matches_filter(&response, &req.filter).then_some(response))
+            },
+        )?;
     }
     Ok(result)
 }
@@ -341,3 +414,57 @@ pub fn protorunes_by_height(input: &Vec<u8>) -> Result<RunesResponse> {
     }
     Ok(result)
 }
+
+pub fn holders_by_rune(input: &Vec<u8>) -> Result<proto::protorune::HoldersResponse> {
+    let req = proto::protorune::HoldersRequest::parse_from_bytes(input)?;
+    let protocol_tag: u128 = req.protocol_tag.clone().unwrap_or_default().into();
+    let rune: ProtoruneRuneId = req
+        .id
+        .clone()
+        .into_option()
+        .ok_or_else(|| anyhow!("holders request has no rune id"))?
+        .into();
+    let mut result = proto::protorune::HoldersResponse::new();
+    let (page, next_cursor) = holders::holders(protocol_tag, &rune, &req.cursor, req.limit)?;
+    for (holder, balance) in page {
+        let (address, outpoint) = holders::decode_holder(&holder)?;
+        result.holders.push(proto::protorune::Holder {
+            address,
+            outpoint: outpoint.as_ref().map(core_outpoint_to_proto).into(),
+            balance: MessageField::some(balance.into()),
+            ..Default::default()
+        });
+    }
+    result.next_cursor = next_cursor;
+    result.count = holders::holder_count(protocol_tag, &rune) as u64;
+    Ok(result)
+}
+
+pub fn holder_count(input: &Vec<u8>) -> Result<proto::protorune::HolderCountResponse> {
+    let req = proto::protorune::HoldersRequest::parse_from_bytes(input)?;
+    let rune: ProtoruneRuneId = req
+        .id
+        .clone()
+        .into_option()
+        .ok_or_else(|| anyhow!("holders request has no rune id"))?
+        .into();
+    let mut result = proto::protorune::HolderCountResponse::new();
+    result.count = holders::holder_count(req.protocol_tag.unwrap_or_default().into(), &rune) as u64;
+    Ok(result)
+}
+
+pub fn supply_by_id(input: &Vec<u8>) -> Result<proto::protorune::SupplyResponse> {
+    let req = proto::protorune::SupplyRequest::parse_from_bytes(input)?;
+    let rune: ProtoruneRuneId = req
+        .id
+        .clone()
+        .into_option()
+        .ok_or_else(|| anyhow!("supply request has no rune id"))?
+        .into();
+    let (minted, burned) = supply::supply(req.protocol_tag.unwrap_or_default().into(), &rune);
+    let mut result = proto::protorune::SupplyResponse::new();
+    result.minted = MessageField::some(minted.into());
+    result.burned = MessageField::some(burned.into());
+    result.circulating = MessageField::some(minted.saturating_sub(burned).into());
+    Ok(result)
+}
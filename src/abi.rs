@@ -0,0 +1,275 @@
+use crate::registry::registry_entry;
+use crate::tables::ABI_BY_BYTECODE_HASH;
+use crate::vm::cache::load_binary;
+use crate::vm::instance::AlkanesInstance;
+use crate::vm::revert::TypedRevert;
+use crate::vm::runtime::AlkanesRuntimeContext;
+use alkanes_support::abi::{AbiValue, ContractAbi, MethodAbi};
+use alkanes_support::id::AlkaneId;
+use alkanes_support::parcel::AlkaneTransferParcel;
+use alkanes_support::trace::{TraceContext, TraceEvent};
+use anyhow::{anyhow, Result};
+use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
+use metashrew_support::index_pointer::KeyValuePointer;
+use protorune::versioned;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub const META_FUEL: u64 = 100_000_000;
+
+type Frame = (Option<ContractAbi>, Option<MethodAbi>);
+
+/// Runs `__meta` on `binary`, linked as of `height`.
+fn meta_abi(binary: Arc<Vec<u8>>, height: u64) -> Result<ContractAbi> {
+    let context = Arc::new(Mutex::new(AlkanesRuntimeContext::default()));
+    context.lock().unwrap().message.height = height;
+    let mut instance = AlkanesInstance::from_alkane(context, binary, META_FUEL)?;
+    Ok(serde_json::from_slice(&instance.call_meta()?)?)
+}
+
+/// Runs `__meta` on the binary deployed at `id`.
+pub fn extract_abi(id: &AlkaneId) -> Result<ContractAbi> {
+    let payload =
+        versioned::get(&IndexPointer::from_keyword("/alkanes/").select(&id.clone().into()));
+    if payload.len() == 0 {
+        return Err(anyhow!("no binary deployed at {}:{}", id.block, id.tx));
+    }
+    meta_abi(load_binary(id, payload)?, versioned::get_height())
+}
+
+/// Records the ABI of a binary deployed at `height` under its bytecode hash, so
+/// clones of a factory share one entry and `__meta` runs once per bytecode.
+/// Binaries without a `__meta` export, or whose export traps, runs out of
+/// `META_FUEL` or does not parse, are skipped and the deployment goes ahead.
+pub fn record_abi(
+    atomic: &AtomicPointer,
+    height: u64,
+    bytecode_hash: &Vec<u8>,
+    binary: &Arc<Vec<u8>>,
+) -> Result<()> {
+    let mut pointer = atomic.derive(&ABI_BY_BYTECODE_HASH.select(bytecode_hash));
+    if versioned::get(&pointer).len() > 0 {
+        return Ok(());
+    }
+    if let Ok(abi) = meta_abi(binary.clone(), height) {
+        versioned::set(&mut pointer, Arc::new(serde_json::to_vec(&abi)?));
+    }
+    Ok(())
+}
+
+/// The ABI recorded when `id` was deployed, or the one its binary exports for
+/// alkanes deployed before ABIs were recorded.
+pub fn abi_for(id: &AlkaneId) -> Result<ContractAbi> {
+    if let Some(entry) = registry_entry(&id.clone().into())? {
+        let stored = versioned::get(&ABI_BY_BYTECODE_HASH.select(&entry.bytecode_hash));
+        if stored.len() > 0 {
+            return Ok(serde_json::from_slice(stored.as_ref())?);
+        }
+    }
+    extract_abi(id)
+}
+
+fn alkane_id_to_string(id: &AlkaneId) -> String {
+    format!("{}:{}", id.block, id.tx)
+}
+
+/// Amounts are decimal strings and bytes are hex, as in the notification JSON.
+pub fn value_to_json(value: &AbiValue) -> Value {
+    match value {
+        AbiValue::U128(v) => json!(v.to_string()),
+        AbiValue::Bool(v) => json!(v),
+        AbiValue::String(v) => json!(v),
+        AbiValue::AlkaneId(v) => json!(alkane_id_to_string(v)),
+        AbiValue::Bytes(v) => json!(hex::encode(v)),
+        AbiValue::List(v) => v.iter().map(value_to_json).collect(),
+        AbiValue::Option(v) => v.as_deref().map_or(Value::Null, value_to_json),
+    }
+}
+
+fn transfers_to_json(parcel: &AlkaneTransferParcel) -> Value {
+    parcel
+        .0
+        .iter()
+        .map(|v| {
+            json!({
+                "id": alkane_id_to_string(&v.id),
+                "value": v.value.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Names the opcode and arguments of a cellpack sent to an alkane with `abi`.
+/// Inputs that do not decode are kept as they are.
+pub fn call_to_json(abi: Option<&ContractAbi>, inputs: &[u128]) -> Value {
+    let raw = || {
+        inputs
+            .iter()
+            .map(|v| json!(v.to_string()))
+            .collect::<Value>()
+    };
+    let (opcode, args) = match inputs.split_first() {
+        Some(v) => v,
+        None => return json!({ "inputs": raw() }),
+    };
+    match abi.and_then(|v| v.method(*opcode)) {
+        Some(method) => match method.decode_inputs(args) {
+            Ok(values) => json!({
+                "opcode": opcode.to_string(),
+                "method": method.name,
+                "params": method
+                    .params
+                    .iter()
+                    .zip(values.iter())
+                    .map(|(param, value)| (param.name.clone(), value_to_json(value)))
+                    .collect::<serde_json::Map<String, Value>>(),
+            }),
+            Err(e) => json!({
+                "opcode": opcode.to_string(),
+                "method": method.name,
+                "inputs": raw(),
+                "error": e.to_string(),
+            }),
+        },
+        None => json!({ "opcode": opcode.to_string(), "inputs": raw() }),
+    }
+}
+
+/// Names the typed error an alkane with `abi` reverted with and its fields.
+/// Reverts with a message, or with a code the ABI does not declare, give `None`.
+pub fn error_to_json(abi: Option<&ContractAbi>, data: &[u8]) -> Option<Value> {
+    let (error, values) = abi?.decode_error(data)?;
+    Some(json!({
+        "name": error.name,
+        "code": error.code,
+        "params": error
+            .params
+            .iter()
+            .zip(values.iter())
+            .map(|(param, value)| (param.name.clone(), value_to_json(value)))
+            .collect::<serde_json::Map<String, Value>>(),
+    }))
+}
+
+/// Describes why a call failed, naming a typed error the alkane reverted with
+/// through its ABI.
+pub fn describe_error(e: &anyhow::Error) -> String {
+    e.downcast_ref::<TypedRevert>()
+        .and_then(|revert| error_to_json(abi_for(&revert.target).ok().as_ref(), &revert.data))
+        .map_or_else(
+            || e.to_string(),
+            |error| format!("ALKANES: revert: {}", error),
+        )
+}
+
+/// The error a trace ending in a revert records: its message, or the typed
+/// error named through the ABI of the alkane whose frame reverted.
+pub fn trace_error(events: &[TraceEvent]) -> Option<String> {
+    let Some(TraceEvent::RevertContext(revert)) = events.last() else {
+        return None;
+    };
+    // the alkane each open frame runs, so the final revert is read with the
+    // ABI of the frame it closes
+    let mut frames: Vec<&AlkaneId> = vec![];
+    for event in &events[..events.len() - 1] {
+        match event {
+            TraceEvent::EnterCall(context)
+            | TraceEvent::EnterDelegatecall(context)
+            | TraceEvent::EnterStaticcall(context) => frames.push(&context.target),
+            TraceEvent::ReturnContext(_) | TraceEvent::RevertContext(_) => {
+                frames.pop();
+            }
+            _ => {}
+        }
+    }
+    let data = &revert.inner.data;
+    let typed = frames
+        .pop()
+        .and_then(|target| error_to_json(abi_for(target).ok().as_ref(), data));
+    Some(typed.map_or_else(
+        || String::from_utf8_lossy(data.get(4..).unwrap_or_default()).to_string(),
+        |error| error.to_string(),
+    ))
+}
+
+/// Renders trace events with the ABIs of the alkanes they call, so opcodes,
+/// arguments and returned data read as named, typed values.
+pub fn decode_trace(events: &[TraceEvent]) -> Value {
+    let mut abis: HashMap<AlkaneId, Option<ContractAbi>> = HashMap::new();
+    // the alkane and method each open frame runs, to read the data it returns
+    // or the error it reverts with
+    let mut frames: Vec<Frame> = vec![];
+    let mut enter = |kind: &str, context: &TraceContext, frames: &mut Vec<Frame>| {
+        let abi = abis
+            .entry(context.target.clone())
+            .or_insert_with(|| abi_for(&context.target).ok())
+            .as_ref();
+        frames.push((
+            abi.cloned(),
+            context
+                .inner
+                .inputs
+                .first()
+                .and_then(|opcode| abi.and_then(|v| v.method(*opcode)))
+                .cloned(),
+        ));
+        let mut result = json!({
+            "event": kind,
+            "target": alkane_id_to_string(&context.target),
+            "caller": alkane_id_to_string(&context.inner.caller),
+            "fuel": context.fuel,
+            "incoming_alkanes": transfers_to_json(&context.inner.incoming_alkanes),
+        });
+        if let (Value::Object(result), Value::Object(call)) =
+            (&mut result, call_to_json(abi, &context.inner.inputs))
+        {
+            result.extend(call);
+        }
+        result
+    };
+    events
+        .iter()
+        .map(|event| match event {
+            TraceEvent::EnterCall(context) => enter("call", context, &mut frames),
+            TraceEvent::EnterDelegatecall(context) => enter("delegatecall", context, &mut frames),
+            TraceEvent::EnterStaticcall(context) => enter("staticcall", context, &mut frames),
+            TraceEvent::ReturnContext(response) => {
+                let data = match frames.pop().and_then(|(_, method)| method) {
+                    Some(method) => value_to_json(&method.decode_data(&response.inner.data)),
+                    None => json!(hex::encode(&response.inner.data)),
+                };
+                json!({
+                    "event": "return",
+                    "fuel_used": response.fuel_used,
+                    "alkanes": transfers_to_json(&response.inner.alkanes),
+                    "data": data,
+                })
+            }
+            TraceEvent::RevertContext(response) => {
+                let abi = frames.pop().and_then(|(abi, _)| abi);
+                // revert data is a 4 byte selector followed by the message, or
+                // the code of a typed error followed by its fields
+                let data = &response.inner.data;
+                let error = error_to_json(abi.as_ref(), data).unwrap_or_else(|| {
+                    json!(String::from_utf8_lossy(data.get(4..).unwrap_or_default()))
+                });
+                json!({
+                    "event": "revert",
+                    "fuel_used": response.fuel_used,
+                    "error": error,
+                })
+            }
+            TraceEvent::CreateAlkane(id) => json!({
+                "event": "create",
+                "alkane": alkane_id_to_string(id),
+            }),
+            TraceEvent::Emit(event) => json!({
+                "event": "emit",
+                "alkane": alkane_id_to_string(&event.alkane),
+                "topics": event.topics.iter().map(hex::encode).collect::<Vec<String>>(),
+                "data": hex::encode(&event.data),
+            }),
+        })
+        .collect()
+}
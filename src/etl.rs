@@ -5,17 +5,28 @@ use bitcoin::consensus::encode::Decodable;
 use metashrew_core::index_pointer::IndexPointer;
 use metashrew_support::index_pointer::KeyValuePointer;
 use once_cell::sync::Lazy;
+use protorune::versioned;
 use std::io::Cursor;
 use std::sync::Arc;
 
 pub static BLOCKS: Lazy<IndexPointer> = Lazy::new(|| IndexPointer::from_keyword("/blockdata/"));
 
 pub fn index_extensions(height: u32, v: &bitcoin::Block) {
-    BLOCKS.select_value(height).set(Arc::new(serialize(v)))
+    versioned::set(&mut BLOCKS.select_value(height), Arc::new(serialize(v)))
+}
+
+/// The 80-byte header of the block at `height`, read off the front of its
+/// serialized form without decoding the transactions.
+pub fn get_header(height: u32) -> Result<Vec<u8>> {
+    let block_data = versioned::get(&BLOCKS.select_value(height));
+    if block_data.len() < 80 {
+        return Err(anyhow!("Block not found for height: {}", height));
+    }
+    Ok(block_data[..80].to_vec())
 }
 
 pub fn get_block(height: u32) -> Result<bitcoin::Block> {
-    let block_data = BLOCKS.select_value(height).get();
+    let block_data = versioned::get(&BLOCKS.select_value(height));
     if block_data.len() == 0 {
         return Err(anyhow!("Block not found for height: {}", height));
     }
@@ -0,0 +1,125 @@
+use crate::tables::{EVENTS_BY_ALKANE, EVENTS_BY_HEIGHT, EVENTS_BY_OUTPOINT};
+use alkanes_support::event::AlkaneEvent;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto;
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::Hash;
+use bitcoin::OutPoint;
+use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
+use metashrew_support::index_pointer::KeyValuePointer;
+use metashrew_support::utils::consensus_encode;
+use protobuf::{Message, MessageField};
+use protorune::versioned;
+use protorune::view::paginate;
+use protorune_support::proto::protorune::PaginationInput;
+use std::sync::Arc;
+
+pub fn event_record(
+    event: &AlkaneEvent,
+    outpoint: &OutPoint,
+    height: u64,
+) -> proto::alkanes::AlkaneEventRecord {
+    let mut record = proto::alkanes::AlkaneEventRecord::new();
+    record.event = MessageField::some(event.clone().into());
+    record.outpoint = MessageField::some(proto::alkanes::Outpoint {
+        txid: outpoint.txid.as_byte_array().to_vec(),
+        vout: outpoint.vout,
+        ..Default::default()
+    });
+    record.height = height;
+    record
+}
+
+// events are written through the atomic pointer of the emitting frame so a
+// revert of that frame (or of any caller) discards them with the rest of its state
+pub fn save_event(
+    atomic: &mut AtomicPointer,
+    event: &AlkaneEvent,
+    outpoint: &OutPoint,
+    height: u64,
+) -> Result<()> {
+    let record = Arc::new(event_record(event, outpoint, height).write_to_bytes()?);
+    versioned::append(
+        &atomic.derive(&EVENTS_BY_OUTPOINT.select(&consensus_encode::<OutPoint>(outpoint)?)),
+        record.clone(),
+    );
+    versioned::append(
+        &atomic.derive(&EVENTS_BY_HEIGHT.select_value(height)),
+        record.clone(),
+    );
+    versioned::append(
+        &atomic.derive(&EVENTS_BY_ALKANE.select(&event.alkane.clone().into())),
+        record,
+    );
+    Ok(())
+}
+
+fn record_at(list: &IndexPointer, index: u32) -> Result<proto::alkanes::AlkaneEventRecord> {
+    Ok(proto::alkanes::AlkaneEventRecord::parse_from_bytes(
+        versioned::get(&list.select_index(index)).as_ref(),
+    )?)
+}
+
+// index of the first record in `list` whose height is above `height`; records
+// are appended in block order, so it can be bisected
+fn first_above(list: &IndexPointer, length: u32, height: u64) -> Result<u32> {
+    let (mut lo, mut hi) = (0u32, length);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if record_at(list, mid)?.height <= height {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(lo)
+}
+
+/// A page of the events emitted by `id` with `from_height <= height <= to_height`,
+/// oldest first, and the cursor to resume from. A `to_height` of zero leaves the
+/// range open at the top and a `limit` of zero returns the rest of the range.
+pub fn events_by_alkane(
+    id: &AlkaneId,
+    from_height: u64,
+    to_height: u64,
+    cursor: &Vec<u8>,
+    limit: u32,
+) -> Result<(Vec<proto::alkanes::AlkaneEventRecord>, Vec<u8>)> {
+    let list = EVENTS_BY_ALKANE.select(&id.clone().into());
+    let length = versioned::get_value::<_, u32>(&list.length_key());
+    let end = match to_height {
+        0 => length,
+        to_height => first_above(&list, length, to_height)?,
+    };
+    let start = match cursor.len() {
+        0 => match from_height {
+            0 => 0,
+            from_height => first_above(&list, length, from_height - 1)?,
+        },
+        4 => u32::from_le_bytes(cursor.as_slice().try_into()?),
+        _ => return Err(anyhow!("malformed pagination cursor")),
+    };
+    if start >= end {
+        return Ok((vec![], vec![]));
+    }
+    // every record in the range matches, so bounding the limit by it stops the
+    // walk at `end`
+    let pagination = PaginationInput {
+        start,
+        limit: match limit {
+            0 => end - start,
+            limit => limit.min(end - start),
+        },
+        ..Default::default()
+    };
+    let (records, next) = paginate(&list, &MessageField::some(pagination), false, |v| {
+        Ok(Some(proto::alkanes::AlkaneEventRecord::parse_from_bytes(
+            v.as_ref(),
+        )?))
+    })?;
+    let next = match next.len() {
+        4 if u32::from_le_bytes(next.as_slice().try_into()?) < end => next,
+        _ => vec![],
+    };
+    Ok((records, next))
+}
@@ -1,5 +1,7 @@
+use crate::etl;
 use crate::message::AlkaneMessageContext;
 use crate::network::{genesis, is_genesis};
+use crate::notifications::notify_block;
 use crate::vm::fuel::FuelTank;
 use anyhow::Result;
 use bitcoin::blockdata::block::Block;
@@ -8,7 +10,7 @@ use metashrew_core::{
     println,
     stdio::{stdout, Write},
 };
-use protorune::Protorune;
+use protorune::{versioned, Protorune};
 #[allow(unused_imports)]
 use metashrew_support::index_pointer::KeyValuePointer;
 use protorune_support::network::{set_network, NetworkParams};
@@ -82,6 +84,8 @@ use std::sync::Arc;
 
 pub fn index_block(block: &Block, height: u32) -> Result<()> {
     configure_network();
+    versioned::begin_block(height.into());
+    etl::index_extensions(height, block);
     let really_is_genesis = is_genesis(height.into());
     if really_is_genesis {
         genesis(&block).unwrap();
@@ -113,9 +117,10 @@ pub fn index_block(block: &Block, height: u32) -> Result<()> {
             match protorunes_by_address(&request.write_to_bytes()?) {
                 Ok(full_response) => {
                     // Cache the serialized full WalletResponse
-                    CACHED_WALLET_RESPONSE
-                        .select(&address)
-                        .set(Arc::new(full_response.write_to_bytes()?));
+                    versioned::set(
+                        &mut CACHED_WALLET_RESPONSE.select(&address),
+                        Arc::new(full_response.write_to_bytes()?),
+                    );
 
                     // Create a filtered version with only outpoints that have runes
                     let mut filtered_response = full_response.clone();
@@ -139,9 +144,10 @@ pub fn index_block(block: &Block, height: u32) -> Result<()> {
                         .collect::<Vec<protorune_support::proto::protorune::OutpointResponse>>();
 
                     // Cache the serialized filtered WalletResponse
-                    CACHED_FILTERED_WALLET_RESPONSE
-                        .select(&address)
-                        .set(Arc::new(filtered_response.write_to_bytes()?));
+                    versioned::set(
+                        &mut CACHED_FILTERED_WALLET_RESPONSE.select(&address),
+                        Arc::new(filtered_response.write_to_bytes()?),
+                    );
                 }
                 Err(e) => {
                     println!("Error caching wallet response for address: {:?}", e);
@@ -150,5 +156,6 @@ pub fn index_block(block: &Block, height: u32) -> Result<()> {
         }
     }
 
+    notify_block(block, height.into())?;
     Ok(())
 }
@@ -16,12 +16,16 @@ use metashrew_support::utils::{consensus_decode, consume_sized_int, consume_to_e
 use protobuf::{Message, MessageField};
 use std::io::Cursor;
 use view::parcels_from_protobuf;
+pub mod abi;
 pub mod block;
 pub mod etl;
+pub mod events;
 pub mod indexer;
 pub mod message;
 pub mod network;
+pub mod notifications;
 pub mod precompiled;
+pub mod registry;
 pub mod tables;
 #[cfg(any(test, feature = "test-utils"))]
 pub mod tests;
@@ -30,8 +34,6 @@ pub mod utils;
 pub mod view;
 pub mod vm;
 use crate::indexer::index_block;
-use protorune::block_info::{HTTP_URL, post_json, get_block_info, BlockInfo};
-use serde_json::json;
 
 /*
 All the #[no_mangle] configs will fail during github action cargo test step
@@ -59,16 +61,21 @@ Thus, going to add not(test) to all these functions
 pub fn multisimluate() -> i32 {
     configure_network();
     let data = input();
-    let _height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
+    let height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
+    protorune::versioned::set_requested_view_height(height as u64);
     let reader = &data[4..];
     let mut result: proto::alkanes::MultiSimulateResponse =
         proto::alkanes::MultiSimulateResponse::new();
-    let responses = multi_simulate_safe(
-        &parcels_from_protobuf(
-            proto::alkanes::MultiSimulateRequest::parse_from_bytes(reader).unwrap(),
-        ),
-        u64::MAX,
-    );
+    let parcels = match parcels_from_protobuf(
+        proto::alkanes::MultiSimulateRequest::parse_from_bytes(reader).unwrap(),
+    ) {
+        Ok(parcels) => parcels,
+        Err(e) => {
+            result.error = abi::describe_error(&e);
+            return export_bytes(result.write_to_bytes().unwrap());
+        }
+    };
+    let responses = multi_simulate_safe(&parcels, u64::MAX);
 
     for response in responses {
         let mut res = proto::alkanes::SimulateResponse::new();
@@ -78,7 +85,7 @@ pub fn multisimluate() -> i32 {
                 res.gas_used = gas_used;
             }
             Err(e) => {
-                result.error = e.to_string();
+                result.error = abi::describe_error(&e);
             }
         }
         result.responses.push(res);
@@ -92,21 +99,35 @@ pub fn multisimluate() -> i32 {
 pub fn simulate() -> i32 {
     configure_network();
     let data = input();
-    let _height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
+    let height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
+    protorune::versioned::set_requested_view_height(height as u64);
     let reader = &data[4..];
     let mut result: proto::alkanes::SimulateResponse = proto::alkanes::SimulateResponse::new();
-    match simulate_safe(
-        &parcel_from_protobuf(
-            proto::alkanes::MessageContextParcel::parse_from_bytes(reader).unwrap(),
-        ),
-        u64::MAX,
-    ) {
+    let request = proto::alkanes::MessageContextParcel::parse_from_bytes(reader).unwrap();
+    let profile = request.profile;
+    let parcel = match parcel_from_protobuf(request) {
+        Ok(parcel) => parcel,
+        Err(e) => {
+            result.error = abi::describe_error(&e);
+            return export_bytes(result.write_to_bytes().unwrap());
+        }
+    };
+    let simulated = if profile {
+        let (simulated, profile) = view::simulate_profiled_safe(&parcel, u64::MAX);
+        if let Some(profile) = profile {
+            result.profile = MessageField::some(profile.into());
+        }
+        simulated
+    } else {
+        simulate_safe(&parcel, u64::MAX)
+    };
+    match simulated {
         Ok((response, gas_used)) => {
             result.execution = MessageField::some(response.into());
             result.gas_used = gas_used;
         }
         Err(e) => {
-            result.error = e.to_string();
+            result.error = abi::describe_error(&e);
         }
     }
     export_bytes(result.write_to_bytes().unwrap())
@@ -119,9 +140,11 @@ pub fn meta() -> i32 {
     let data = input();
     let _height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
     let reader = &data[4..];
-    match meta_safe(&parcel_from_protobuf(
+    match parcel_from_protobuf(
         proto::alkanes::MessageContextParcel::parse_from_bytes(reader).unwrap(),
-    )) {
+    )
+    .and_then(|parcel| meta_safe(&parcel))
+    {
         Ok(response) => export_bytes(response),
         Err(_) => export_bytes(vec![]),
     }
@@ -180,32 +203,24 @@ pub fn spendablesbyaddress() -> i32 {
 pub fn protorunesbyaddress() -> i32 {
     configure_network();
     let mut data: Cursor<Vec<u8>> = Cursor::new(input());
-    let _height = consume_sized_int::<u32>(&mut data).unwrap();
-    let input_data = consume_to_end(&mut data).unwrap();
-    //  let _request = protorune_support::proto::protorune::ProtorunesWalletRequest::parse_from_bytes(&input_data).unwrap();
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    protorune::versioned::set_requested_view_height(height as u64);
+    let mut input_data = consume_to_end(&mut data).unwrap();
+    // outpoints without a balance are dropped before paging so that every page is full
+    if let Ok(mut request) =
+        protorune_support::proto::protorune::ProtorunesWalletRequest::parse_from_bytes(&input_data)
+    {
+        if request.filter.is_none() {
+            request.filter =
+                MessageField::some(protorune_support::proto::protorune::WalletFilter::new());
+            input_data = request.write_to_bytes().unwrap();
+        }
+    }
 
-    let mut result: protorune_support::proto::protorune::WalletResponse =
+    let result: protorune_support::proto::protorune::WalletResponse =
         view::protorunes_by_address(&input_data)
             .unwrap_or_else(|_| protorune_support::proto::protorune::WalletResponse::new());
 
-    result.outpoints = result
-        .outpoints
-        .into_iter()
-        .filter_map(|v| {
-            if v.clone()
-                .balances
-                .unwrap_or_else(|| protorune_support::proto::protorune::BalanceSheet::new())
-                .entries
-                .len()
-                == 0
-            {
-                None
-            } else {
-                Some(v)
-            }
-        })
-        .collect::<Vec<protorune_support::proto::protorune::OutpointResponse>>();
-
     export_bytes(result.write_to_bytes().unwrap())
 }
 
@@ -293,6 +308,22 @@ pub fn alkanes_id_to_outpoint() -> i32 {
     export_bytes(result.write_to_bytes().unwrap())
 }
 
+#[cfg(not(test))]
+#[no_mangle]
+pub fn eventsbyalkane() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    protorune::versioned::set_requested_view_height(height as u64);
+    let data_vec = consume_to_end(&mut data).unwrap();
+    let result: alkanes_support::proto::alkanes::EventsResponse = view::events_by_alkane(&data_vec)
+        .unwrap_or_else(|err| {
+            eprintln!("Error in eventsbyalkane: {:?}", err);
+            alkanes_support::proto::alkanes::EventsResponse::new()
+        });
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
 #[cfg(not(test))]
 #[no_mangle]
 pub fn traceblock() -> i32 {
@@ -302,6 +333,15 @@ pub fn traceblock() -> i32 {
     export_bytes(view::traceblock(height).unwrap())
 }
 
+#[cfg(not(test))]
+#[no_mangle]
+pub fn blocknotification() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    export_bytes(view::block_notification(height as u64).unwrap())
+}
+
 #[cfg(not(test))]
 #[no_mangle]
 pub fn trace() -> i32 {
@@ -317,6 +357,30 @@ pub fn trace() -> i32 {
     export_bytes(view::trace(&outpoint).unwrap())
 }
 
+#[cfg(not(test))]
+#[no_mangle]
+pub fn decodetrace() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let _height = consume_sized_int::<u32>(&mut data).unwrap();
+    let outpoint: OutPoint = protorune_support::proto::protorune::Outpoint::parse_from_bytes(
+        &consume_to_end(&mut data).unwrap(),
+    )
+    .unwrap()
+    .try_into()
+    .unwrap();
+    export_bytes(view::decodetrace(&outpoint).unwrap_or_default())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn getabi() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let _height = consume_sized_int::<u32>(&mut data).unwrap();
+    export_bytes(view::getabi(&consume_to_end(&mut data).unwrap()).unwrap_or_default())
+}
+
 #[cfg(not(test))]
 #[no_mangle]
 pub fn getbytecode() -> i32 {
@@ -351,6 +415,174 @@ pub fn runesbyheight() -> i32 {
     export_bytes(result.write_to_bytes().unwrap())
 }
 
+#[cfg(not(test))]
+#[no_mangle]
+pub fn alkaneinventory() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    protorune::versioned::set_requested_view_height(height as u64);
+    let result: proto::alkanes::AlkaneInventoryResponse = view::alkane_inventory(
+        &proto::alkanes::AlkaneInventoryRequest::parse_from_bytes(
+            &consume_to_end(&mut data).unwrap(),
+        )
+        .unwrap(),
+    )
+    .unwrap_or_else(|err| {
+        eprintln!("Error in alkaneinventory: {:?}", err);
+        proto::alkanes::AlkaneInventoryResponse::new()
+    });
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn listalkanes() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    protorune::versioned::set_requested_view_height(height as u64);
+    let result: proto::alkanes::AlkaneRegistryResponse =
+        view::list_alkanes(&consume_to_end(&mut data).unwrap()).unwrap_or_else(|err| {
+            eprintln!("Error in listalkanes: {:?}", err);
+            proto::alkanes::AlkaneRegistryResponse::new()
+        });
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn alkanesbybytecodehash() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    protorune::versioned::set_requested_view_height(height as u64);
+    let result: proto::alkanes::AlkaneRegistryResponse =
+        view::alkanes_by_bytecode_hash(&consume_to_end(&mut data).unwrap()).unwrap_or_else(|err| {
+            eprintln!("Error in alkanesbybytecodehash: {:?}", err);
+            proto::alkanes::AlkaneRegistryResponse::new()
+        });
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn holdersbyalkane() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    protorune::versioned::set_requested_view_height(height as u64);
+    let result: protorune_support::proto::protorune::HoldersResponse =
+        view::holders_by_alkane(&consume_to_end(&mut data).unwrap()).unwrap_or_else(|err| {
+            eprintln!("Error in holdersbyalkane: {:?}", err);
+            protorune_support::proto::protorune::HoldersResponse::new()
+        });
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn holdercount() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    protorune::versioned::set_requested_view_height(height as u64);
+    let result: protorune_support::proto::protorune::HolderCountResponse =
+        view::holder_count(&consume_to_end(&mut data).unwrap()).unwrap_or_else(|err| {
+            eprintln!("Error in holdercount: {:?}", err);
+            protorune_support::proto::protorune::HolderCountResponse::new()
+        });
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn supplybyid() -> i32 {
+    configure_network();
+    let mut data: Cursor<Vec<u8>> = Cursor::new(input());
+    let height = consume_sized_int::<u32>(&mut data).unwrap();
+    protorune::versioned::set_requested_view_height(height as u64);
+    let result: protorune_support::proto::protorune::SupplyResponse =
+        view::supply_by_id(&consume_to_end(&mut data).unwrap()).unwrap_or_else(|err| {
+            eprintln!("Error in supplybyid: {:?}", err);
+            protorune_support::proto::protorune::SupplyResponse::new()
+        });
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn estimatefuel() -> i32 {
+    configure_network();
+    let data = input();
+    let height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
+    protorune::versioned::set_requested_view_height(height as u64);
+    let reader = &data[4..];
+    let result: proto::alkanes::EstimateFuelResponse =
+        proto::alkanes::EstimateFuelRequest::parse_from_bytes(reader)
+            .map_err(anyhow::Error::from)
+            .and_then(|request| view::estimate_fuel(&request))
+            .unwrap_or_else(|err| {
+                let mut result = proto::alkanes::EstimateFuelResponse::new();
+                result.error = err.to_string();
+                result
+            });
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn simulatetransaction() -> i32 {
+    configure_network();
+    let data = input();
+    let height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
+    protorune::versioned::set_requested_view_height(height as u64);
+    let reader = &data[4..];
+    let result: proto::alkanes::SimulateTransactionResponse =
+        proto::alkanes::SimulateTransactionRequest::parse_from_bytes(reader)
+            .map_err(anyhow::Error::from)
+            .and_then(|request| view::simulate_transaction(&request))
+            .unwrap_or_else(|err| {
+                let mut result = proto::alkanes::SimulateTransactionResponse::new();
+                result.error = err.to_string();
+                result
+            });
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
+#[cfg(not(test))]
+#[no_mangle]
+pub fn sequentialsimulate() -> i32 {
+    configure_network();
+    let data = input();
+    let height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
+    protorune::versioned::set_requested_view_height(height as u64);
+    let reader = &data[4..];
+    let mut result = proto::alkanes::MultiSimulateResponse::new();
+    match proto::alkanes::MultiSimulateRequest::parse_from_bytes(reader) {
+        Ok(request) => {
+            for (response, trace) in view::sequential_simulate_safe(request.parcels, u64::MAX) {
+                let mut res = proto::alkanes::SimulateResponse::new();
+                match response {
+                    Ok((response, gas_used)) => {
+                        res.execution = MessageField::some(response.into());
+                        res.gas_used = gas_used;
+                    }
+                    Err(e) => {
+                        res.error = abi::describe_error(&e);
+                    }
+                }
+                res.trace = MessageField::some(trace.into());
+                result.responses.push(res);
+            }
+        }
+        Err(e) => {
+            result.error = e.to_string();
+        }
+    }
+    export_bytes(result.write_to_bytes().unwrap())
+}
+
 // #[no_mangle]
 // pub fn alkane_balance_sheet() -> i32 {
 //     let data = input();
@@ -372,8 +604,6 @@ pub fn runesbyheight() -> i32 {
 pub fn _start() {
     let data = input();
     let height = u32::from_le_bytes((&data[0..4]).try_into().unwrap());
-    let previous_block_info = get_block_info(height as u64 - 1).unwrap();
-
     let reader = &data[4..];
     #[cfg(any(feature = "dogecoin", feature = "luckycoin", feature = "bellscoin"))]
     let block: Block = AuxpowBlock::parse(&mut Cursor::<Vec<u8>>::new(reader.to_vec()))
@@ -383,18 +613,9 @@ pub fn _start() {
     let block: Block =
         consensus_decode::<Block>(&mut Cursor::<Vec<u8>>::new(reader.to_vec())).unwrap();
 
+    notifications::configure_notifications().unwrap();
     index_block(&block, height).unwrap();
-    etl::index_extensions(height, &block);
     flush();
-
-    let current_block_info = get_block_info(height as u64).unwrap();
-
-    let combined_block_info = json!({
-        "previous": previous_block_info.to_json(),
-        "current": current_block_info.to_json()
-    });
-
-    post_json(&HTTP_URL.clone(), &combined_block_info.to_string());
 }
 
 #[cfg(test)]
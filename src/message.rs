@@ -1,8 +1,10 @@
 use crate::network::{genesis::GENESIS_BLOCK, is_active};
 use crate::trace::save_trace;
-use crate::utils::{credit_balances, debit_balances, pipe_storagemap_to};
+use crate::utils::{credit_balances, debit_balances, pipe_storagemap_to, storage_writes};
 use crate::vm::{
     fuel::{FuelTank, VirtualFuelBytes},
+    precompiles::{is_precompile, run_precompile},
+    revert::revert_data,
     runtime::AlkanesRuntimeContext,
     utils::{prepare_context, run_after_special, run_special_cellpacks},
 };
@@ -72,7 +74,26 @@ pub fn handle_message(
         parcel, &cellpack,
     )));
     let mut atomic = parcel.atomic.derive(&IndexPointer::default());
-    let (caller, myself, binary) = run_special_cellpacks(context.clone(), &cellpack)?;
+    let (caller, myself, binary) = match run_special_cellpacks(context.clone(), &cellpack) {
+        Ok(v) => v,
+        Err(e) => {
+            // failures before execution, such as a rejected deployment, never enter a
+            // frame, so the trace is the reason alone
+            let mut response = ExtendedCallResponse::default();
+            response.data = revert_data(&e);
+            let trace = context.lock().unwrap().trace.clone();
+            trace.clock(TraceEvent::RevertContext(response.into()));
+            save_trace(
+                &OutPoint {
+                    txid: parcel.transaction.compute_txid(),
+                    vout: parcel.vout,
+                },
+                parcel.height,
+                trace,
+            )?;
+            return Err(e);
+        }
+    };
 
     #[cfg(feature = "debug-log")]
     {
@@ -107,15 +128,21 @@ pub fn handle_message(
         target,
         fuel,
     }));
-    run_after_special(context.clone(), binary, fuel)
+    let result = if is_precompile(&myself, parcel.height) {
+        run_precompile(context.clone(), &myself, fuel)
+    } else {
+        run_after_special(context.clone(), binary, fuel)
+    };
+    result
         .and_then(|(response, gas_used)| {
             FuelTank::consume_fuel(gas_used)?;
-            pipe_storagemap_to(
-                &response.storage,
-                &mut atomic.derive(
-                    &IndexPointer::from_keyword("/alkanes/").select(&myself.clone().into()),
-                ),
-            );
+            let mut frame = context.lock().unwrap().frame.clone();
+            let mut storage_pointer = atomic
+                .derive(&IndexPointer::from_keyword("/alkanes/").select(&myself.clone().into()));
+            frame
+                .storage_writes
+                .extend(storage_writes(&response.storage, &storage_pointer));
+            pipe_storagemap_to(&response.storage, &mut storage_pointer);
             let mut combined = parcel.runtime_balances.as_ref().clone();
             <BalanceSheet<AtomicPointer> as TryFrom<Vec<RuneTransfer>>>::try_from(
                 parcel.runes.clone(),
@@ -126,12 +153,12 @@ pub fn handle_message(
             )?;
             combined.debit_mintable(&sheet, &mut atomic)?;
             debit_balances(&mut atomic, &myself, &response.alkanes)?;
+            frame.transfer(&response.alkanes, &myself, &caller);
             let cloned = context.clone().lock().unwrap().trace.clone();
             let response_alkanes = response.alkanes.clone();
-            cloned.clock(TraceEvent::ReturnContext(TraceResponse {
-                inner: response.into(),
-                fuel_used: gas_used,
-            }));
+            cloned.clock(TraceEvent::ReturnContext(TraceResponse::new(
+                response, frame,
+            )));
             save_trace(
                 &OutPoint {
                     txid: parcel.transaction.compute_txid(),
@@ -179,13 +206,12 @@ pub fn handle_message(
             FuelTank::drain_fuel();
             let mut response = ExtendedCallResponse::default();
 
-            response.data = vec![0x08, 0xc3, 0x79, 0xa0];
-            response.data.extend(e.to_string().as_bytes());
+            response.data = revert_data(&e);
             let cloned = context.clone().lock().unwrap().trace.clone();
-            cloned.clock(TraceEvent::RevertContext(TraceResponse {
-                inner: response,
-                fuel_used: u64::MAX,
-            }));
+            let frame = context.lock().unwrap().frame.clone();
+            cloned.clock(TraceEvent::RevertContext(TraceResponse::new(
+                response, frame,
+            )));
             save_trace(
                 &OutPoint {
                     txid: parcel.transaction.compute_txid(),
@@ -7,20 +7,24 @@ use crate::precompiled::{
 };
 use crate::utils::pipe_storagemap_to;
 use crate::view::simulate_parcel;
-use crate::vm::utils::sequence_pointer;
+use crate::vm::cache::invalidate;
+use crate::vm::utils::{record_creation, sequence_pointer};
 use alkanes_support::cellpack::Cellpack;
 use alkanes_support::gz::compress;
 use alkanes_support::id::AlkaneId;
 use alkanes_support::parcel::AlkaneTransferParcel;
+use alkanes_support::proto::alkanes::AlkaneDeployKind;
 use anyhow::Result;
 use bitcoin::hashes::Hash;
 use bitcoin::{Block, OutPoint, Transaction, Txid};
 use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
 use metashrew_support::index_pointer::KeyValuePointer;
 use protorune::balance_sheet::PersistentRecord;
+use protorune::holders;
 use protorune::message::{MessageContext, MessageContextParcel};
 #[allow(unused_imports)]
 use protorune::tables::{RuneTable, RUNES};
+use protorune::versioned;
 use protorune_support::balance_sheet::BalanceSheet;
 use protorune_support::utils::outpoint_encode;
 use std::sync::Arc;
@@ -123,6 +127,49 @@ pub mod genesis {
     pub const GENESIS_OUTPOINT_BLOCK_HEIGHT: u64 = 288_906;
 }
 
+/// Heights from which consensus changes apply. Networks other than regtest keep
+/// them at `u64::MAX` until a height is scheduled.
+#[cfg(all(
+    not(feature = "mainnet"),
+    not(feature = "dogecoin"),
+    not(feature = "bellscoin"),
+    not(feature = "fractal"),
+    not(feature = "luckycoin")
+))]
+pub mod activation {
+    /// CREATE and CREATERESERVED payloads are checked against the bytecode policy.
+    pub const DEPLOY_VALIDATION: u64 = 1;
+    /// Calls to the precompile block run the built-in precompiles.
+    pub const PRECOMPILES: u64 = 1;
+    /// Alkanes may import `__emit`. Below it such a module fails to link, as it
+    /// did before the host function existed.
+    pub const EVENTS: u64 = 1;
+    /// Alkanes may import `__load_block_header`, `__load_median_time_past` and
+    /// `__load_blockhash`.
+    pub const BLOCK_HEADERS: u64 = 1;
+    /// Alkanes may import `__request_foreign_storage` and `__load_foreign_storage`.
+    pub const FOREIGN_STORAGE: u64 = 1;
+    /// Reverts whose data is not an error string surface that data as a
+    /// `TypedRevert` naming the alkane whose code reverted.
+    pub const TYPED_REVERTS: u64 = 1;
+}
+
+#[cfg(any(
+    feature = "mainnet",
+    feature = "dogecoin",
+    feature = "bellscoin",
+    feature = "fractal",
+    feature = "luckycoin"
+))]
+pub mod activation {
+    pub const DEPLOY_VALIDATION: u64 = u64::MAX;
+    pub const PRECOMPILES: u64 = u64::MAX;
+    pub const EVENTS: u64 = u64::MAX;
+    pub const BLOCK_HEADERS: u64 = u64::MAX;
+    pub const FOREIGN_STORAGE: u64 = u64::MAX;
+    pub const TYPED_REVERTS: u64 = u64::MAX;
+}
+
 pub fn is_active(height: u64) -> bool {
     height >= genesis::GENESIS_BLOCK
 }
@@ -149,17 +196,19 @@ pub fn is_genesis(height: u64) -> bool {
         false
     };
     if is_genesis {
-        init_ptr.set_value::<u8>(0x01);
+        versioned::set_value::<_, u8>(&mut init_ptr, 0x01);
     }
     is_genesis
 }
 
 pub fn genesis(block: &Block) -> Result<()> {
-    IndexPointer::from_keyword("/alkanes/")
-        .select(&(AlkaneId { block: 2, tx: 0 }).into())
-        .set(Arc::new(compress(genesis_alkane_bytes())?));
+    versioned::set(
+        &mut IndexPointer::from_keyword("/alkanes/").select(&(AlkaneId { block: 2, tx: 0 }).into()),
+        Arc::new(compress(genesis_alkane_bytes())?),
+    );
+    invalidate(&AlkaneId { block: 2, tx: 0 });
     let mut atomic: AtomicPointer = AtomicPointer::default();
-    sequence_pointer(&atomic).set_value::<u128>(1);
+    versioned::set_value::<_, u128>(&mut sequence_pointer(&atomic), 1);
     let myself = AlkaneId { block: 2, tx: 0 };
     let parcel = MessageContextParcel {
         atomic: atomic.derive(&IndexPointer::default()),
@@ -191,7 +240,7 @@ pub fn genesis(block: &Block) -> Result<()> {
             Err(e)
         }
     })?;
-    let outpoint_bytes = outpoint_encode(&OutPoint {
+    let outpoint = OutPoint {
         txid: Txid::from_byte_array(
             <Vec<u8> as AsRef<[u8]>>::as_ref(
                 &hex::decode(genesis::GENESIS_OUTPOINT)?
@@ -203,11 +252,21 @@ pub fn genesis(block: &Block) -> Result<()> {
             .try_into()?,
         ),
         vout: 0,
-    })?;
-    <AlkaneTransferParcel as TryInto<BalanceSheet<AtomicPointer>>>::try_into(
+    };
+    let outpoint_bytes = outpoint_encode(&outpoint)?;
+    record_creation(
+        &atomic,
+        genesis::GENESIS_BLOCK,
+        &outpoint,
+        &myself,
+        AlkaneDeployKind::CREATE,
+        None,
+        &Arc::new(genesis_alkane_bytes()),
+    )?;
+    let sheet = <AlkaneTransferParcel as TryInto<BalanceSheet<AtomicPointer>>>::try_into(
         response.alkanes.into(),
-    )?
-    .save(
+    )?;
+    sheet.save(
         &mut atomic.derive(
             &RuneTable::for_protocol(AlkaneMessageContext::protocol_tag())
                 .OUTPOINT_TO_RUNES
@@ -215,27 +274,36 @@ pub fn genesis(block: &Block) -> Result<()> {
         ),
         false,
     );
+    holders::credit_outpoint(
+        &atomic.derive(&holders::holders_pointer(
+            AlkaneMessageContext::protocol_tag(),
+        )),
+        &outpoint,
+        &sheet,
+    )?;
     pipe_storagemap_to(
         &response.storage,
         &mut atomic.derive(&IndexPointer::from_keyword("/alkanes/").select(&myself.clone().into())),
     );
 
-    atomic
-        .derive(&RUNES.OUTPOINT_TO_HEIGHT.select(&outpoint_bytes))
-        .set_value(genesis::GENESIS_OUTPOINT_BLOCK_HEIGHT);
-    atomic
-        .derive(
+    versioned::set_value(
+        &mut atomic.derive(&RUNES.OUTPOINT_TO_HEIGHT.select(&outpoint_bytes)),
+        genesis::GENESIS_OUTPOINT_BLOCK_HEIGHT,
+    );
+    versioned::append(
+        &atomic.derive(
             &RUNES
                 .HEIGHT_TO_TRANSACTION_IDS
                 .select_value::<u64>(genesis::GENESIS_OUTPOINT_BLOCK_HEIGHT),
-        )
-        .append(Arc::new(
+        ),
+        Arc::new(
             hex::decode(genesis::GENESIS_OUTPOINT)?
                 .iter()
                 .cloned()
                 .rev()
                 .collect::<Vec<u8>>(),
-        ));
+        ),
+    );
     atomic.commit();
     Ok(())
 }
@@ -0,0 +1,292 @@
+use crate::message::AlkaneMessageContext;
+use crate::tables::{CREATED_BY_HEIGHT, NOTIFICATIONS_BY_HEIGHT};
+use crate::view::block_events;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::parcel::AlkaneTransfer;
+use alkanes_support::proto;
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::Hash;
+use bitcoin::{Block, OutPoint};
+use metashrew_core::index_pointer::IndexPointer;
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use metashrew_support::index_pointer::KeyValuePointer;
+use metashrew_support::utils::consensus_decode;
+use once_cell::sync::Lazy;
+use protobuf::{Message, MessageField};
+use protorune::balance_sheet::load_sheet;
+use protorune::block_info::{post_json, HTTP_URL};
+use protorune::message::MessageContext;
+use protorune::tables::RuneTable;
+use protorune::versioned;
+use protorune_support::balance_sheet::{BalanceSheet, BalanceSheetOperations};
+use protorune_support::utils::consensus_encode;
+use serde_json::{json, Value};
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+
+/// Bumped whenever a field is removed from or changes meaning in the payload, in
+/// both the JSON and the protobuf encoding.
+pub const NOTIFICATION_VERSION: u32 = 1;
+
+pub trait NotificationSink {
+    fn notify(&self, notification: &proto::alkanes::BlockNotification) -> Result<()>;
+}
+
+pub struct DisabledSink;
+
+impl NotificationSink for DisabledSink {
+    fn notify(&self, _notification: &proto::alkanes::BlockNotification) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Posts the JSON payload through the `__post_json` host import.
+pub struct HostSink {
+    pub url: String,
+}
+
+impl NotificationSink for HostSink {
+    fn notify(&self, notification: &proto::alkanes::BlockNotification) -> Result<()> {
+        post_json(&self.url, &notification_to_json(notification).to_string());
+        Ok(())
+    }
+}
+
+/// Stores the protobuf payload in the index, to be read back with the
+/// `blocknotification` view. The entry is versioned so a reorg drops it.
+pub struct QueueSink;
+
+impl NotificationSink for QueueSink {
+    fn notify(&self, notification: &proto::alkanes::BlockNotification) -> Result<()> {
+        versioned::set(
+            &mut NOTIFICATIONS_BY_HEIGHT.select_value(notification.height),
+            Arc::new(notification.write_to_bytes()?),
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotificationMode {
+    Disabled,
+    Host(String),
+    Queue,
+}
+
+impl NotificationMode {
+    pub fn sink(&self) -> Box<dyn NotificationSink> {
+        match self {
+            NotificationMode::Disabled => Box::new(DisabledSink),
+            NotificationMode::Host(url) => Box::new(HostSink { url: url.clone() }),
+            NotificationMode::Queue => Box::new(QueueSink),
+        }
+    }
+}
+
+impl NotificationMode {
+    /// Reads a mode named `disabled`, `host` or `queue`. `host` posts to `url`,
+    /// which it requires. Without a name, a `url` alone selects `host`.
+    pub fn from_config(mode: Option<&str>, url: Option<&str>) -> Result<Self> {
+        match (mode, url) {
+            (None, None) | (Some("disabled"), _) => Ok(NotificationMode::Disabled),
+            (None, Some(url)) | (Some("host"), Some(url)) => {
+                Ok(NotificationMode::Host(url.to_string()))
+            }
+            (Some("host"), None) => Err(anyhow!("host notifications need ALKANES_HTTP_URL")),
+            (Some("queue"), _) => Ok(NotificationMode::Queue),
+            (Some(mode), _) => Err(anyhow!("unknown notification mode: {}", mode)),
+        }
+    }
+}
+
+static NOTIFICATION_MODE: Lazy<Mutex<Option<NotificationMode>>> = Lazy::new(|| Mutex::new(None));
+
+pub fn set_notification_mode(mode: NotificationMode) {
+    *NOTIFICATION_MODE.lock().unwrap() = Some(mode);
+}
+
+/// Sets the mode the indexer was built for, from `ALKANES_NOTIFICATIONS`
+/// (`disabled`, `host` or `queue`) and `ALKANES_HTTP_URL`. Called by `_start`
+/// before each block; a mode set with `set_notification_mode` is kept.
+pub fn configure_notifications() -> Result<()> {
+    let mut mode = NOTIFICATION_MODE.lock().unwrap();
+    if mode.is_none() {
+        *mode = Some(NotificationMode::from_config(
+            option_env!("ALKANES_NOTIFICATIONS"),
+            HTTP_URL.as_deref(),
+        )?);
+    }
+    Ok(())
+}
+
+/// The configured mode, or no notifications when none was configured.
+pub fn get_notification_mode() -> NotificationMode {
+    NOTIFICATION_MODE
+        .lock()
+        .unwrap()
+        .clone()
+        .unwrap_or(NotificationMode::Disabled)
+}
+
+pub(crate) fn outpoint_to_proto(outpoint: &OutPoint) -> proto::alkanes::Outpoint {
+    proto::alkanes::Outpoint {
+        txid: outpoint.txid.as_byte_array().to_vec(),
+        vout: outpoint.vout,
+        ..Default::default()
+    }
+}
+
+/// The nonzero balances of `sheet`.
+pub(crate) fn sheet_to_proto<P: KeyValuePointer + Clone>(
+    sheet: &BalanceSheet<P>,
+) -> Vec<proto::alkanes::AlkaneTransfer> {
+    sheet
+        .balances()
+        .iter()
+        .filter(|(_, value)| **value != 0)
+        .map(|(id, value)| {
+            (AlkaneTransfer {
+                id: id.clone().into(),
+                value: *value,
+            })
+            .into()
+        })
+        .collect()
+}
+
+fn outpoint_balances(
+    table: &RuneTable,
+    outpoint: &OutPoint,
+) -> Result<Option<proto::alkanes::OutpointBalances>> {
+    let balances = sheet_to_proto(&load_sheet(
+        &table.OUTPOINT_TO_RUNES.select(&consensus_encode(outpoint)?),
+    ));
+    if balances.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(proto::alkanes::OutpointBalances {
+        outpoint: MessageField::some(outpoint_to_proto(outpoint)),
+        balances,
+        ..Default::default()
+    }))
+}
+
+fn created_alkanes(height: u64) -> Result<Vec<proto::alkanes::CreatedAlkane>> {
+    CREATED_BY_HEIGHT
+        .select_value(height)
+        .get_list()
+        .into_iter()
+        .map(|id_bytes| -> Result<proto::alkanes::CreatedAlkane> {
+            let id = AlkaneId::parse(&mut Cursor::new(id_bytes.as_ref().clone()))?;
+            let outpoint_bytes = IndexPointer::from_keyword("/alkanes_id_to_outpoint/")
+                .select(id_bytes.as_ref())
+                .get();
+            let outpoint =
+                consensus_decode::<OutPoint>(&mut Cursor::new(outpoint_bytes.as_ref().clone()))?;
+            Ok(proto::alkanes::CreatedAlkane {
+                id: MessageField::some(id.into()),
+                outpoint: MessageField::some(outpoint_to_proto(&outpoint)),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Builds the payload for a block that has just been indexed.
+pub fn block_notification(block: &Block, height: u64) -> Result<proto::alkanes::BlockNotification> {
+    let table = RuneTable::for_protocol(AlkaneMessageContext::protocol_tag());
+    let mut result = proto::alkanes::BlockNotification::new();
+    result.version = NOTIFICATION_VERSION;
+    result.height = height;
+    result.blockhash = block.block_hash().as_byte_array().to_vec();
+    for tx in &block.txdata {
+        let txid = tx.compute_txid();
+        for vout in 0..tx.output.len() {
+            let outpoint = OutPoint {
+                txid,
+                vout: vout as u32,
+            };
+            if let Some(balances) = outpoint_balances(&table, &outpoint)? {
+                result.outpoint_balances.push(balances);
+            }
+        }
+        if tx.is_coinbase() {
+            continue;
+        }
+        for input in &tx.input {
+            // the balances of a spent outpoint are zeroed, but the rune list stays
+            if table
+                .OUTPOINT_TO_RUNES
+                .select(&consensus_encode(&input.previous_output)?)
+                .keyword("/runes")
+                .length()
+                != 0
+            {
+                result
+                    .spent_outpoints
+                    .push(outpoint_to_proto(&input.previous_output));
+            }
+        }
+    }
+    result.created_alkanes = created_alkanes(height)?;
+    result.traces = block_events(height.try_into()?)?;
+    Ok(result)
+}
+
+fn txid_to_hex(txid: &Vec<u8>) -> String {
+    hex::encode(txid.iter().rev().cloned().collect::<Vec<u8>>())
+}
+
+fn outpoint_to_json(outpoint: &proto::alkanes::Outpoint) -> Value {
+    json!({
+        "txid": txid_to_hex(&outpoint.txid),
+        "vout": outpoint.vout,
+    })
+}
+
+fn alkane_id_to_string(id: &proto::alkanes::AlkaneId) -> String {
+    let id: AlkaneId = id.clone().into();
+    format!("{}:{}", id.block, id.tx)
+}
+
+/// JSON form of the payload. Amounts are decimal strings and txids are in the
+/// usual display byte order.
+pub fn notification_to_json(notification: &proto::alkanes::BlockNotification) -> Value {
+    json!({
+        "version": notification.version,
+        "height": notification.height,
+        "blockhash": txid_to_hex(&notification.blockhash),
+        "outpoint_balances": notification.outpoint_balances.iter().map(|v| {
+            let mut entry = outpoint_to_json(&v.outpoint);
+            entry["balances"] = v.balances.iter().map(|transfer| json!({
+                "id": alkane_id_to_string(&transfer.id),
+                "value": u128::from(transfer.value.clone().unwrap_or_default()).to_string(),
+            })).collect();
+            entry
+        }).collect::<Vec<Value>>(),
+        "created_alkanes": notification.created_alkanes.iter().map(|v| json!({
+            "id": alkane_id_to_string(&v.id),
+            "outpoint": outpoint_to_json(&v.outpoint),
+        })).collect::<Vec<Value>>(),
+        "traces": notification.traces.iter().map(|v| json!({
+            "outpoint": outpoint_to_json(&v.outpoint),
+            "txindex": v.txindex,
+            "trace": hex::encode(v.traces.write_to_bytes().unwrap_or_default()),
+        })).collect::<Vec<Value>>(),
+        "spent_outpoints": notification.spent_outpoints.iter().map(outpoint_to_json).collect::<Vec<Value>>(),
+    })
+}
+
+/// Hands the block to the configured sink. Nothing is computed when
+/// notifications are disabled.
+pub fn notify_block(block: &Block, height: u64) -> Result<()> {
+    let mode = get_notification_mode();
+    if mode == NotificationMode::Disabled {
+        return Ok(());
+    }
+    mode.sink().notify(&block_notification(block, height)?)
+}
@@ -0,0 +1,95 @@
+use crate::tables::{ALKANES_BY_BYTECODE_HASH, ALKANES_REGISTRY, ALKANES_REGISTRY_LIST};
+use crate::view::get_statics;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto;
+use anyhow::Result;
+use bitcoin::hashes::{sha256, Hash};
+use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
+use metashrew_support::index_pointer::KeyValuePointer;
+use protobuf::{Message, MessageField};
+use protorune::versioned;
+use protorune::view::paginate;
+use protorune_support::proto::protorune::PaginationInput;
+use std::sync::Arc;
+
+/// Hash of the decompressed wasm, so clones share the hash of their factory.
+pub fn bytecode_hash(binary: &[u8]) -> Vec<u8> {
+    sha256::Hash::hash(binary).to_byte_array().to_vec()
+}
+
+pub fn register(atomic: &AtomicPointer, entry: &proto::alkanes::AlkaneRegistryEntry) -> Result<()> {
+    let id: Vec<u8> = AlkaneId::from(entry.id.clone().unwrap()).into();
+    versioned::set(
+        &mut atomic.derive(&ALKANES_REGISTRY.select(&id)),
+        Arc::new(entry.write_to_bytes()?),
+    );
+    versioned::append(&atomic.derive(&ALKANES_REGISTRY_LIST), Arc::new(id.clone()));
+    versioned::append(
+        &atomic.derive(&ALKANES_BY_BYTECODE_HASH.select(&entry.bytecode_hash)),
+        Arc::new(id),
+    );
+    Ok(())
+}
+
+pub fn registry_entry(id: &Vec<u8>) -> Result<Option<proto::alkanes::AlkaneRegistryEntry>> {
+    let bytes = versioned::get(&ALKANES_REGISTRY.select(id));
+    if bytes.len() == 0 {
+        return Ok(None);
+    }
+    Ok(Some(proto::alkanes::AlkaneRegistryEntry::parse_from_bytes(
+        bytes.as_ref(),
+    )?))
+}
+
+/// Fills in name and symbol from the contract. Statics are read when a view asks
+/// for them rather than at index time, and `get_statics` caches them per alkane.
+fn with_statics(
+    mut entry: proto::alkanes::AlkaneRegistryEntry,
+) -> proto::alkanes::AlkaneRegistryEntry {
+    if let Some(id) = entry.id.clone().into_option() {
+        (entry.name, entry.symbol) = get_statics(&id.into());
+    }
+    entry
+}
+
+fn registry_page(
+    list: &IndexPointer,
+    cursor: &Vec<u8>,
+    limit: u32,
+    descending: bool,
+) -> Result<proto::alkanes::AlkaneRegistryResponse> {
+    let mut result = proto::alkanes::AlkaneRegistryResponse::new();
+    let pagination = PaginationInput {
+        cursor: cursor.clone(),
+        limit,
+        ..Default::default()
+    };
+    (result.alkanes, result.next_cursor) =
+        paginate(list, &MessageField::some(pagination), descending, |id| {
+            Ok(registry_entry(id.as_ref())?.map(with_statics))
+        })?;
+    Ok(result)
+}
+
+/// Every alkane in creation order, or newest first with `descending`.
+pub fn list_alkanes(
+    req: &proto::alkanes::ListAlkanesRequest,
+) -> Result<proto::alkanes::AlkaneRegistryResponse> {
+    registry_page(
+        &ALKANES_REGISTRY_LIST,
+        &req.cursor,
+        req.limit,
+        req.descending,
+    )
+}
+
+pub fn alkanes_by_bytecode_hash(
+    req: &proto::alkanes::AlkanesByBytecodeHashRequest,
+) -> Result<proto::alkanes::AlkaneRegistryResponse> {
+    registry_page(
+        &ALKANES_BY_BYTECODE_HASH.select(&req.bytecode_hash),
+        &req.cursor,
+        req.limit,
+        false,
+    )
+}
@@ -6,3 +6,30 @@ pub static TRACES: Lazy<IndexPointer> = Lazy::new(|| IndexPointer::from_keyword(
 
 pub static TRACES_BY_HEIGHT: Lazy<IndexPointer> =
     Lazy::new(|| IndexPointer::from_keyword("/trace/"));
+
+pub static EVENTS_BY_OUTPOINT: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/events/byoutpoint/"));
+
+pub static EVENTS_BY_HEIGHT: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/events/byheight/"));
+
+pub static EVENTS_BY_ALKANE: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/events/byalkane/"));
+
+pub static CREATED_BY_HEIGHT: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/created/byheight/"));
+
+pub static NOTIFICATIONS_BY_HEIGHT: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/notifications/byheight/"));
+
+pub static ALKANES_REGISTRY: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/registry/byid/"));
+
+pub static ALKANES_REGISTRY_LIST: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/registry/list"));
+
+pub static ALKANES_BY_BYTECODE_HASH: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/registry/bybytecodehash/"));
+
+pub static ABI_BY_BYTECODE_HASH: Lazy<IndexPointer> =
+    Lazy::new(|| IndexPointer::from_keyword("/abi/bybytecodehash/"));
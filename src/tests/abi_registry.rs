@@ -0,0 +1,94 @@
+use crate::index_block;
+use crate::registry::{bytecode_hash, registry_entry};
+use crate::tables::ABI_BY_BYTECODE_HASH;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::abi::ContractAbi;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::AbiRequest;
+use anyhow::Result;
+use bitcoin::{OutPoint, Transaction};
+use metashrew_support::index_pointer::KeyValuePointer;
+use protobuf::{Message, MessageField};
+use protorune::versioned;
+use serde_json::Value;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn decoded(tx: &Transaction) -> Result<Vec<Value>> {
+    let events: Value = serde_json::from_slice(&view::decodetrace(&OutPoint {
+        txid: tx.compute_txid(),
+        vout: 3,
+    })?)?;
+    Ok(events.as_array().unwrap().clone())
+}
+
+#[wasm_bindgen_test]
+fn test_abi_recorded_and_traces_decoded() -> Result<()> {
+    clear();
+    let target = AlkaneId { block: 2, tx: 1 };
+    let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![72, 42],
+        }]
+        .into(),
+    );
+    index_block(&deploy, 840_000)?;
+
+    let entry = registry_entry(&target.clone().into())?.unwrap();
+    assert_eq!(
+        entry.bytecode_hash,
+        bytecode_hash(&alkanes_std_test_build::get_bytes())
+    );
+    let stored: ContractAbi = serde_json::from_slice(
+        versioned::get(&ABI_BY_BYTECODE_HASH.select(&entry.bytecode_hash)).as_ref(),
+    )?;
+    let mut request = AbiRequest::new();
+    assert!(view::getabi(&request.write_to_bytes()?).is_err());
+    request.id = MessageField::some(target.clone().into());
+    let abi: ContractAbi = serde_json::from_slice(&view::getabi(&request.write_to_bytes()?)?)?;
+    assert_eq!(abi, stored);
+    assert_eq!(abi.contract, "LoggerAlkane");
+    let method = abi.method(73).unwrap();
+    assert_eq!(method.name, "test_load_from");
+    assert_eq!(method.params[0].kind, "AlkaneId");
+    assert_eq!(method.returns, "u128");
+
+    let mut hello = [0u8; 16];
+    hello[..5].copy_from_slice(b"hello");
+    let block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![vec![]; 2],
+        [
+            Cellpack {
+                target: target.clone(),
+                inputs: vec![73, 2, 1],
+            },
+            Cellpack {
+                target: target.clone(),
+                inputs: vec![12, 2, u128::from_le_bytes(hello), 0x6874],
+            },
+        ]
+        .into(),
+    );
+    index_block(&block, 840_001)?;
+
+    let events = decoded(&block.txdata[1])?;
+    assert_eq!(events[0]["event"], "call");
+    assert_eq!(events[0]["method"], "test_load_from");
+    assert_eq!(events[0]["params"]["target"], "2:1");
+    let last = events.last().unwrap();
+    assert_eq!(last["event"], "return");
+    assert_eq!(last["data"], "42");
+
+    let events = decoded(&block.txdata[2])?;
+    assert_eq!(events[0]["method"], "process_strings");
+    assert_eq!(
+        events[0]["params"]["strings"],
+        serde_json::json!(["hello", "th"])
+    );
+    Ok(())
+}
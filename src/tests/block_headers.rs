@@ -0,0 +1,134 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::trace::{Trace, TraceEvent};
+use anyhow::Result;
+use bitcoin::{Block, OutPoint, Transaction};
+use metashrew_support::utils::consensus_encode;
+use protorune::test_helpers::create_block_with_coinbase_tx;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const START: u32 = 840_000;
+
+// minutes past 1000 run through a permutation of 0..11, so eleven consecutive
+// blocks have a median time of 1300
+fn block_time(height: u32) -> u32 {
+    1_000 + ((height - START) * 7 % 11) * 60
+}
+
+fn index_timed(mut block: Block, height: u32) -> Result<Block> {
+    block.header.time = block_time(height);
+    index_block(&block, height)?;
+    Ok(block)
+}
+
+fn last_event(tx: &Transaction) -> Result<TraceEvent> {
+    let trace: Trace = view::trace(&OutPoint {
+        txid: tx.compute_txid(),
+        vout: 3,
+    })?
+    .try_into()?;
+    let events = trace.0.lock().expect("Mutex poisoned");
+    Ok(events.last().cloned().expect("no trace events"))
+}
+
+fn returned(tx: &Transaction) -> Result<Vec<u8>> {
+    match last_event(tx)? {
+        TraceEvent::ReturnContext(response) => Ok(response.inner.data),
+        _ => panic!("call reverted"),
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_historical_headers() -> Result<()> {
+    clear();
+    let deployed = index_timed(
+        alkane_helpers::init_with_multiple_cellpacks_with_tx(
+            [alkanes_std_test_build::get_bytes()].into(),
+            [Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![99],
+            }]
+            .into(),
+        ),
+        START,
+    )?;
+    let mut headers = vec![deployed.header];
+    for height in START + 1..START + 10 {
+        headers.push(index_timed(create_block_with_coinbase_tx(height), height)?.header);
+    }
+
+    let current = START + 10;
+    let target = AlkaneId { block: 2, tx: 1 };
+    let call = |inputs: Vec<u128>| Cellpack {
+        target: target.clone(),
+        inputs,
+    };
+    let mut block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![vec![]; 4],
+        [
+            call(vec![70, START as u128 + 3]),
+            call(vec![70, current as u128]),
+            call(vec![71, current as u128]),
+            call(vec![70, current as u128 + 1]),
+        ]
+        .into(),
+    );
+    block.header.time = block_time(current);
+    index_block(&block, current)?;
+    headers.push(block.header);
+
+    for (tx, height) in block.txdata[1..3].iter().zip([START + 3, current]) {
+        let header = headers[(height - START) as usize];
+        let mut expected = consensus_encode(&header)?;
+        expected.extend(consensus_encode(&header.block_hash())?);
+        assert_eq!(returned(tx)?, expected);
+    }
+    assert_eq!(
+        returned(&block.txdata[3])?,
+        1_300u128.to_le_bytes().to_vec()
+    );
+    // heights past the block being indexed are refused
+    assert!(matches!(
+        last_event(&block.txdata[4])?,
+        TraceEvent::RevertContext(_)
+    ));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_median_time_past_short_history() -> Result<()> {
+    clear();
+    index_timed(
+        alkane_helpers::init_with_multiple_cellpacks_with_tx(
+            [alkanes_std_test_build::get_bytes()].into(),
+            [Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![99],
+            }]
+            .into(),
+        ),
+        START,
+    )?;
+    let block = index_timed(
+        alkane_helpers::init_with_multiple_cellpacks_with_tx(
+            vec![vec![]],
+            [Cellpack {
+                target: AlkaneId { block: 2, tx: 1 },
+                inputs: vec![71, START as u128 + 1],
+            }]
+            .into(),
+        ),
+        START + 1,
+    )?;
+    // only two blocks are indexed, so the median is taken over both of them
+    assert_eq!(
+        returned(&block.txdata[1])?,
+        (block_time(START + 1) as u128).to_le_bytes().to_vec()
+    );
+    Ok(())
+}
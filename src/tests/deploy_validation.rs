@@ -0,0 +1,126 @@
+use crate::index_block;
+use crate::registry::registry_entry;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::{alkanes_std_owned_token_build, alkanes_std_test_build};
+use crate::vm::validation::{validate_binary, validate_deployment, DeployRejection};
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::gz::compress;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::trace::{Trace, TraceEvent};
+use anyhow::Result;
+use bitcoin::OutPoint;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const HEADER: [u8; 8] = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+fn module(sections: &[&[u8]]) -> Vec<u8> {
+    let mut result = HEADER.to_vec();
+    for section in sections {
+        result.extend_from_slice(section);
+    }
+    result
+}
+
+// (type (func (result f64)))
+const FLOAT_TYPE: &[u8] = &[0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7c];
+// (type (func))
+const EMPTY_TYPE: &[u8] = &[0x01, 0x04, 0x01, 0x60, 0x00, 0x00];
+// (import "env" "__nope" (func (type 0)))
+const UNKNOWN_IMPORT: &[u8] = &[
+    0x02, 0x0e, 0x01, 0x03, 0x65, 0x6e, 0x76, 0x06, 0x5f, 0x5f, 0x6e, 0x6f, 0x70, 0x65, 0x00, 0x00,
+];
+// (memory 1000)
+const LARGE_MEMORY: &[u8] = &[0x05, 0x04, 0x01, 0x00, 0xe8, 0x07];
+// (table 1 100000 funcref)
+const GROWABLE_TABLE: &[u8] = &[0x04, 0x07, 0x01, 0x70, 0x01, 0x01, 0xa0, 0x8d, 0x06];
+
+fn deploy(binary: Vec<u8>, height: u32) -> Result<String> {
+    clear();
+    let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [binary].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![0],
+        }]
+        .into(),
+    );
+    index_block(&test_block, height)?;
+
+    let outpoint = OutPoint {
+        txid: test_block.txdata.last().unwrap().compute_txid(),
+        vout: 3,
+    };
+    let trace: Trace = view::trace(&outpoint)?.try_into()?;
+    let events = trace.0.lock().expect("Mutex poisoned");
+    match events.last() {
+        Some(TraceEvent::RevertContext(response)) => {
+            Ok(String::from_utf8_lossy(&response.inner.data[4..]).to_string())
+        }
+        _ => panic!("expected the deployment to revert"),
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_std_binaries_pass() -> Result<()> {
+    validate_binary(&alkanes_std_test_build::get_bytes())?;
+    validate_binary(&alkanes_std_owned_token_build::get_bytes())?;
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_policy_rejections() -> Result<()> {
+    assert_eq!(
+        validate_binary(&module(&[FLOAT_TYPE])),
+        Err(DeployRejection::FloatingPoint)
+    );
+    assert_eq!(
+        validate_binary(&module(&[EMPTY_TYPE, UNKNOWN_IMPORT])),
+        Err(DeployRejection::UnknownImport {
+            module: "env".to_string(),
+            name: "__nope".to_string(),
+        })
+    );
+    assert!(matches!(
+        validate_binary(&module(&[LARGE_MEMORY])),
+        Err(DeployRejection::MemoryTooLarge { pages: 1000, .. })
+    ));
+    assert!(matches!(
+        validate_binary(&module(&[GROWABLE_TABLE])),
+        Err(DeployRejection::TableTooLarge {
+            elements: 100000,
+            ..
+        })
+    ));
+    assert_eq!(
+        validate_binary(&module(&[])),
+        Err(DeployRejection::MissingExport("__execute"))
+    );
+    assert!(matches!(
+        validate_binary(&[0x00, 0x61, 0x73]),
+        Err(DeployRejection::InvalidModule(_))
+    ));
+    assert!(matches!(
+        validate_deployment(&compress(vec![0u8; 8 * 1024 * 1024])?),
+        Err(DeployRejection::BinaryTooLarge { .. })
+    ));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_rejected_deployment_is_traced() -> Result<()> {
+    let reason = deploy(module(&[FLOAT_TYPE]), 840_000)?;
+    assert_eq!(reason, DeployRejection::FloatingPoint.to_string());
+    // nothing was stored under the id the deployment would have taken
+    assert!(registry_entry(&AlkaneId { block: 2, tx: 1 }.into())?.is_none());
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_deployment_unchecked_before_activation() -> Result<()> {
+    // the payload is stored and run as before, failing for want of `__execute`
+    let reason = deploy(module(&[FLOAT_TYPE]), 0)?;
+    assert_ne!(reason, DeployRejection::FloatingPoint.to_string());
+    Ok(())
+}
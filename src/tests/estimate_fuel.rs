@@ -0,0 +1,135 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use crate::vm::fuel::{FuelTank, MINIMUM_FUEL, TOTAL_FUEL};
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::{EstimateFuelRequest, EstimateFuelResponse};
+use alkanes_support::trace::{Trace, TraceEvent};
+use anyhow::Result;
+use bitcoin::{OutPoint, Transaction, Witness};
+use metashrew_support::utils::consensus_encode;
+use protorune::test_helpers::create_block_with_coinbase_tx;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_padding_for() -> Result<()> {
+    assert_eq!(FuelTank::padding_for(MINIMUM_FUEL, 100, 1_000_000), Some(0));
+    assert_eq!(FuelTank::padding_for(TOTAL_FUEL, 100, 100), Some(0));
+    assert_eq!(FuelTank::padding_for(TOTAL_FUEL, 100, 1_000_000), None);
+
+    let needed = 1_000_000;
+    let padding = FuelTank::padding_for(needed, 100, 1_000_000).unwrap();
+    assert!(FuelTank::allocated_fuel(100 + padding, 1_000_000 + padding) >= needed);
+    assert!(FuelTank::allocated_fuel(99 + padding, 999_999 + padding) < needed);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_estimate_matches_indexed_fuel() -> Result<()> {
+    clear();
+    let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![99],
+        }]
+        .into(),
+    );
+    index_block(&deploy, 840_000)?;
+
+    let tx = alkane_helpers::create_cellpack_with_witness(
+        Witness::new(),
+        Cellpack {
+            target: AlkaneId { block: 2, tx: 1 },
+            inputs: vec![5],
+        },
+    );
+    let mut request = EstimateFuelRequest::new();
+    request.transaction = consensus_encode(&tx)?;
+    request.height = 840_001;
+    request.txindex = 1;
+
+    let alone = view::estimate_fuel(&request)?;
+    assert_eq!(alone.messages.len(), 1);
+    assert!(alone.messages[0].error.is_empty());
+    assert!(alone.fuel_needed > 0);
+    assert_eq!(alone.fuel_allocated, TOTAL_FUEL);
+    assert_eq!(alone.padding, 0);
+
+    // in a crowded block the transaction only gets the minimum
+    request.block_vfsize = alone.vfsize * 1_000_000;
+    let crowded = view::estimate_fuel(&request)?;
+    assert_eq!(crowded.fuel_needed, alone.fuel_needed);
+    assert_eq!(crowded.fuel_allocated, MINIMUM_FUEL);
+
+    let mut block = create_block_with_coinbase_tx(840_001);
+    block.txdata.push(tx.clone());
+    index_block(&block, 840_001)?;
+    let trace: Trace = view::trace(&OutPoint {
+        txid: tx.compute_txid(),
+        vout: 3,
+    })?
+    .try_into()?;
+    let events = trace.0.lock().expect("Mutex poisoned");
+    match events.last() {
+        Some(TraceEvent::ReturnContext(response)) => {
+            assert_eq!(response.fuel_used, alone.fuel_needed)
+        }
+        _ => panic!("call did not return"),
+    }
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_estimate_with_incoming_alkanes() -> Result<()> {
+    clear();
+    // the deployment mints 100 of 2:1 to its first output
+    let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }]
+        .into(),
+    );
+    index_block(&deploy, 840_000)?;
+    let check_incoming = Cellpack {
+        target: AlkaneId { block: 2, tx: 1 },
+        inputs: vec![3],
+    };
+    let estimate = |tx: &Transaction| -> Result<EstimateFuelResponse> {
+        let mut request = EstimateFuelRequest::new();
+        request.transaction = consensus_encode(tx)?;
+        request.height = 840_001;
+        request.txindex = 1;
+        view::estimate_fuel(&request)
+    };
+
+    let funded = estimate(
+        &alkane_helpers::create_multiple_cellpack_with_witness_and_in(
+            Witness::new(),
+            [check_incoming.clone()].into(),
+            OutPoint {
+                txid: deploy.txdata.last().unwrap().compute_txid(),
+                vout: 0,
+            },
+            false,
+        ),
+    )?;
+    assert!(funded.error.is_empty());
+    assert!(funded.messages[0].error.is_empty());
+    assert!(funded.fuel_needed > 0);
+
+    // without the alkanes the call reverts, and no padding is offered for it
+    let unfunded = estimate(&alkane_helpers::create_cellpack_with_witness(
+        Witness::new(),
+        check_incoming,
+    ))?;
+    assert!(!unfunded.messages[0].error.is_empty());
+    assert!(unfunded.error.starts_with("message at vout 3 reverted"));
+    assert_eq!(unfunded.padding, 0);
+    Ok(())
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use crate::events::events_by_alkane;
+    use crate::index_block;
+    use crate::tests::helpers::{self as alkane_helpers, clear};
+    use crate::tests::std::alkanes_std_test_build;
+    use crate::view;
+    use alkanes_support::cellpack::Cellpack;
+    use alkanes_support::event::AlkaneEvent;
+    use alkanes_support::id::AlkaneId;
+    use alkanes_support::trace::{Trace, TraceEvent};
+    use anyhow::Result;
+    use bitcoin::{Block, OutPoint};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    fn emitting_block() -> Block {
+        alkane_helpers::init_with_multiple_cellpacks_with_tx(
+            [alkanes_std_test_build::get_bytes(), vec![], vec![]].into(),
+            [
+                Cellpack {
+                    target: AlkaneId { block: 1, tx: 0 },
+                    inputs: vec![60, 7],
+                },
+                Cellpack {
+                    target: AlkaneId { block: 2, tx: 1 },
+                    inputs: vec![61],
+                },
+                Cellpack {
+                    target: AlkaneId { block: 2, tx: 1 },
+                    inputs: vec![60, 8],
+                },
+            ]
+            .into(),
+        )
+    }
+
+    fn emitted(block: &Block, index: usize) -> Result<usize> {
+        let trace: Trace = view::trace(&OutPoint {
+            txid: block.txdata[index].compute_txid(),
+            vout: 3,
+        })?
+        .try_into()?;
+        let events = trace.0.lock().unwrap();
+        Ok(events
+            .iter()
+            .filter(|v| matches!(v, TraceEvent::Emit(_)))
+            .count())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_emit_persists_events() -> Result<()> {
+        clear();
+        let block_height = 840_000;
+        let test_block = emitting_block();
+        index_block(&test_block, block_height)?;
+
+        let id = AlkaneId { block: 2, tx: 1 };
+        let (records, cursor) = events_by_alkane(&id, 0, 0, &vec![], 0)?;
+        // the event emitted by the reverted call is discarded, from the trace too
+        assert_eq!(records.len(), 2);
+        assert!(cursor.is_empty());
+        assert_eq!(emitted(&test_block, 2)?, 0);
+        assert_eq!(emitted(&test_block, 3)?, 1);
+        let first: AlkaneEvent = records[0].event.clone().unwrap().into();
+        assert_eq!(first.alkane, id);
+        assert_eq!(first.topics, vec![b"test".to_vec()]);
+        assert_eq!(first.data, 7u128.to_le_bytes().to_vec());
+        assert_eq!(records[1].height, block_height as u64);
+
+        let below = events_by_alkane(&id, 0, block_height as u64 - 1, &vec![], 0)?;
+        assert_eq!(below.0.len(), 0);
+        let above = events_by_alkane(&id, block_height as u64 + 1, 0, &vec![], 0)?;
+        assert_eq!(above.0.len(), 0);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_events_by_alkane_pages() -> Result<()> {
+        clear();
+        index_block(&emitting_block(), 840_000)?;
+        let id = AlkaneId { block: 2, tx: 1 };
+        let (first, cursor) = events_by_alkane(&id, 0, 0, &vec![], 1)?;
+        assert_eq!(first.len(), 1);
+        let (second, cursor) = events_by_alkane(&id, 0, 0, &cursor, 1)?;
+        assert_eq!(second.len(), 1);
+        assert!(cursor.is_empty());
+        let data = |v: &alkanes_support::proto::alkanes::AlkaneEventRecord| {
+            AlkaneEvent::from(v.event.clone().unwrap()).data
+        };
+        assert_eq!(data(&first[0]), 7u128.to_le_bytes().to_vec());
+        assert_eq!(data(&second[0]), 8u128.to_le_bytes().to_vec());
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_emit_before_activation() -> Result<()> {
+        clear();
+        index_block(&emitting_block(), 0)?;
+        // a module importing `__emit` does not link until activation
+        let id = AlkaneId { block: 2, tx: 1 };
+        assert_eq!(events_by_alkane(&id, 0, 0, &vec![], 0)?.0.len(), 0);
+        Ok(())
+    }
+}
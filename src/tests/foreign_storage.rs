@@ -0,0 +1,79 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::trace::{StorageRead, Trace, TraceEvent, TraceResponse};
+use anyhow::Result;
+use bitcoin::{OutPoint, Transaction};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn response(tx: &Transaction) -> Result<TraceResponse> {
+    let trace: Trace = view::trace(&OutPoint {
+        txid: tx.compute_txid(),
+        vout: 3,
+    })?
+    .try_into()?;
+    let events = trace.0.lock().expect("Mutex poisoned");
+    match events.last() {
+        Some(TraceEvent::ReturnContext(response)) => Ok(response.clone()),
+        _ => panic!("call did not return"),
+    }
+}
+
+fn returned(tx: &Transaction) -> Result<Vec<u8>> {
+    Ok(response(tx)?.inner.data)
+}
+
+#[wasm_bindgen_test]
+fn test_load_foreign_storage() -> Result<()> {
+    clear();
+    let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [
+            alkanes_std_test_build::get_bytes(),
+            alkanes_std_test_build::get_bytes(),
+            vec![],
+            vec![],
+        ]
+        .into(),
+        [
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![72, 42],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![99],
+            },
+            Cellpack {
+                target: AlkaneId { block: 2, tx: 2 },
+                inputs: vec![73, 2, 1],
+            },
+            // an alkane that was never deployed reads as empty storage
+            Cellpack {
+                target: AlkaneId { block: 2, tx: 2 },
+                inputs: vec![73, 2, 5],
+            },
+        ]
+        .into(),
+    );
+    index_block(&test_block, 840_000)?;
+
+    assert_eq!(
+        returned(&test_block.txdata[3])?,
+        42u128.to_le_bytes().to_vec()
+    );
+    assert!(returned(&test_block.txdata[4])?.is_empty());
+    // the read lands in the frame's trace under the alkane that was read
+    assert_eq!(
+        response(&test_block.txdata[3])?.storage_reads,
+        vec![StorageRead {
+            owner: Some(AlkaneId { block: 2, tx: 1 }),
+            key: b"/value".to_vec(),
+            value: 42u128.to_le_bytes().to_vec(),
+        }]
+    );
+    Ok(())
+}
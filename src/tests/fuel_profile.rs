@@ -0,0 +1,90 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes::vm::fuel::{FUEL_PER_LOAD_BYTE, FUEL_PER_STORE_BYTE};
+use alkanes::vm::profile::FuelProfile;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::MessageContextParcel;
+use alkanes_support::response::ExtendedCallResponse;
+use anyhow::Result;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const TARGET: AlkaneId = AlkaneId { block: 2, tx: 1 };
+
+fn profile(inputs: Vec<u128>) -> Result<(ExtendedCallResponse, u64, FuelProfile)> {
+    let mut parcel = MessageContextParcel::new();
+    parcel.height = 840_001;
+    parcel.calldata = Cellpack {
+        target: TARGET,
+        inputs,
+    }
+    .encipher();
+    let (result, profile) =
+        view::simulate_profiled_safe(&view::parcel_from_protobuf(parcel)?, u64::MAX);
+    let (response, gas_used) = result?;
+    Ok((response, gas_used, profile.unwrap()))
+}
+
+fn accounted(profile: &FuelProfile) -> u64 {
+    profile.instructions
+        + profile.storage
+        + profile
+            .children
+            .iter()
+            .filter(|v| !v.reverted)
+            .map(|v| v.total)
+            .sum::<u64>()
+}
+
+#[wasm_bindgen_test]
+fn test_fuel_profile() -> Result<()> {
+    clear();
+    let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![72, 42],
+        }]
+        .into(),
+    );
+    index_block(&deploy, 840_000)?;
+
+    let (response, gas_used, root) = profile(vec![31, 2, 1, 3, 73, 2, 1])?;
+    assert_eq!(response.data, 42u128.to_le_bytes().to_vec());
+    assert_eq!(root.target, TARGET);
+    assert_eq!(root.total, gas_used);
+    assert_eq!(accounted(&root), root.total);
+    assert!(root.instructions > 0);
+    assert_eq!(
+        root.storage,
+        FUEL_PER_STORE_BYTE * response.storage.serialize().len() as u64
+    );
+    assert_eq!(root.host_function("__call").unwrap().calls, 1);
+    assert!(root.host_function("__load_context").is_some());
+
+    assert_eq!(root.children.len(), 1);
+    let child = &root.children[0];
+    assert_eq!(child.inputs, vec![73, 2, 1]);
+    assert!(!child.reverted);
+    assert_eq!(accounted(child), child.total);
+    let load = child.host_function("__load_foreign_storage").unwrap();
+    assert_eq!(load.calls, 1);
+    // the "/value" key and the u128 stored under it
+    assert_eq!(load.bytes, 6 + 16);
+    assert_eq!(load.fuel, 22 * FUEL_PER_LOAD_BYTE);
+    assert!(child.children.is_empty());
+
+    // a reverted child is kept in the tree but not charged to its caller
+    let (_, gas_used, root) = profile(vec![34, 2, 1, 1, 61, 2, 1, 1, 99])?;
+    assert_eq!(root.total, gas_used);
+    assert_eq!(root.children.len(), 2);
+    assert!(root.children[0].reverted);
+    assert!(root.children[0].total > 0);
+    assert!(!root.children[1].reverted);
+    assert_eq!(root.host_function("__call").unwrap().calls, 2);
+    assert_eq!(accounted(&root), root.total);
+    Ok(())
+}
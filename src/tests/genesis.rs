@@ -13,6 +13,7 @@ use bitcoin::hashes::Hash;
 #[allow(unused_imports)]
 use metashrew_core::{get_cache, index_pointer::IndexPointer, println, stdio::stdout};
 use metashrew_support::index_pointer::KeyValuePointer;
+use protorune::holders::holders;
 use protorune::test_helpers::{create_block_with_coinbase_tx, create_protostone_encoded_tx};
 use protorune::view::protorune_outpoint_to_outpoint_response;
 use protorune::{balance_sheet::load_sheet, message::MessageContext, tables::RuneTable};
@@ -255,6 +256,18 @@ fn test_genesis_indexer_premine() -> Result<()> {
         sheet.get(&genesis_id),
         50_000_000u128 * (genesis::GENESIS_BLOCK as u128)
     );
+    // the premine is credited to the genesis outpoint's holder
+    let genesis_holders = holders(
+        AlkaneMessageContext::protocol_tag(),
+        &genesis_id,
+        &vec![],
+        0,
+    )?
+    .0;
+    assert_eq!(
+        genesis_holders.iter().map(|v| v.1).sum::<u128>(),
+        sheet.get(&genesis_id)
+    );
     let out = protorune_outpoint_to_outpoint_response(&outpoint, 1)?;
     let out_sheet: BalanceSheet<IndexPointer> = out.into();
     assert_eq!(sheet, out_sheet);
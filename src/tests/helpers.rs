@@ -1,5 +1,6 @@
 use crate::message::AlkaneMessageContext;
 use crate::view;
+use crate::vm::cache::clear_module_cache;
 use alkanes_support::cellpack::Cellpack;
 use alkanes_support::envelope::RawEnvelope;
 use alkanes_support::gz::compress;
@@ -91,6 +92,7 @@ pub fn configure_network() {
 
 pub fn clear() {
     clear_base();
+    clear_module_cache();
     configure_network();
 }
 
@@ -155,6 +157,23 @@ pub fn init_with_multiple_cellpacks_with_tx(
     test_block
 }
 
+/// Clears the index and indexes a block at 840000 deploying the std test contract
+/// to 2:1, initialized with `inputs`.
+#[cfg(test)]
+pub fn init_std_test(inputs: Vec<u128>) -> Result<Block> {
+    clear();
+    let test_block = init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs,
+        }]
+        .into(),
+    );
+    crate::index_block(&test_block, 840000)?;
+    Ok(test_block)
+}
+
 pub fn init_with_multiple_cellpacks(binary: Vec<u8>, cellpacks: Vec<Cellpack>) -> Block {
     let block_height = 840000;
 
@@ -0,0 +1,60 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::MessageContextParcel;
+use alkanes_support::trace::{Trace, TraceEvent};
+use anyhow::Result;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const TARGET: AlkaneId = AlkaneId { block: 2, tx: 1 };
+
+#[wasm_bindgen_test]
+fn test_generated_client_round_trip() -> Result<()> {
+    clear();
+    let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![72, 42],
+        }]
+        .into(),
+    );
+    index_block(&deploy, 840_000)?;
+
+    let mut parcel = MessageContextParcel::new();
+    parcel.height = 840_001;
+    parcel.calldata = Cellpack {
+        target: TARGET,
+        inputs: vec![83, 2, 1],
+    }
+    .encipher();
+    let trace = Trace::default();
+    let (response, _) =
+        view::simulate_parcel_with_trace(&view::parcel_from_protobuf(parcel)?, u64::MAX, &trace)?;
+
+    // each value the client encoded came back decoded by the callee
+    let mut expected = vec![1];
+    expected.extend(9u64.to_le_bytes());
+    expected.extend(Into::<Vec<u8>>::into(AlkaneId { block: 6, tx: 7 }));
+    expected.extend(Into::<Vec<u8>>::into(AlkaneId { block: 4, tx: 5 }));
+    expected.extend(300u128.to_le_bytes());
+    expected.extend(16u32.to_le_bytes());
+    expected.extend(b"sixteen bytes!!!");
+    expected.extend(b"exactly sixteen!,hi");
+    assert_eq!(response.data, expected);
+
+    // the two #[view] methods went out as staticcalls
+    let staticcalls = trace
+        .0
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|v| matches!(v, TraceEvent::EnterStaticcall(_)))
+        .count();
+    assert_eq!(staticcalls, 2);
+    Ok(())
+}
@@ -6,6 +6,8 @@ pub mod std;
 pub mod utils;
 //pub mod index_alkanes;
 #[cfg(test)]
+pub mod abi_registry;
+#[cfg(test)]
 pub mod abi_test;
 #[cfg(test)]
 //pub mod address;
@@ -16,22 +18,66 @@ pub mod arbitrary_alkane_mint;
 #[cfg(test)]
 pub mod auth_token;
 #[cfg(test)]
+pub mod block_headers;
+#[cfg(test)]
 pub mod crash;
 #[cfg(test)]
+pub mod deploy_validation;
+#[cfg(test)]
 pub mod edict_then_message;
 #[cfg(test)]
+pub mod estimate_fuel;
+#[cfg(test)]
+pub mod events;
+#[cfg(test)]
+pub mod foreign_storage;
+#[cfg(test)]
 pub mod forge;
 #[cfg(test)]
 pub mod fuel;
 #[cfg(test)]
+pub mod fuel_profile;
+#[cfg(test)]
 pub mod genesis;
 #[cfg(test)]
 pub mod memory_security_tests;
 #[cfg(test)]
+pub mod message_client;
+#[cfg(test)]
+pub mod module_cache;
+#[cfg(test)]
 pub mod networks;
 #[cfg(test)]
+pub mod notifications;
+#[cfg(test)]
+pub mod pagination;
+#[cfg(test)]
+pub mod precompiles;
+#[cfg(test)]
+pub mod registry;
+#[cfg(test)]
+pub mod reorg;
+#[cfg(test)]
+pub mod sequential_simulate;
+#[cfg(test)]
 pub mod serialization;
 #[cfg(test)]
+pub mod simulate_transaction;
+#[cfg(test)]
+pub mod state_overrides;
+#[cfg(test)]
+pub mod storage_collections;
+#[cfg(test)]
+pub mod supply;
+#[cfg(test)]
+pub mod trace_frames;
+#[cfg(test)]
+pub mod typed_dispatch;
+#[cfg(test)]
+pub mod typed_errors;
+#[cfg(test)]
 pub mod vec_input_test;
 #[cfg(test)]
+pub mod versioned;
+#[cfg(test)]
 pub mod view;
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::helpers::clear;
+    use crate::tests::std::{alkanes_std_owned_token_build, alkanes_std_test_build};
+    use crate::vm::cache::{compile, invalidate, load_binary, module_cache_stats};
+    use alkanes_support::gz::compress;
+    use alkanes_support::id::AlkaneId;
+    use anyhow::Result;
+    use std::sync::Arc;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_module_cache_hit() -> Result<()> {
+        clear();
+        let id = AlkaneId { block: 2, tx: 1 };
+        let payload = Arc::new(compress(alkanes_std_test_build::get_bytes())?);
+        let before = module_cache_stats();
+        let first = load_binary(&id, payload.clone())?;
+        let second = load_binary(&id, payload.clone())?;
+        let after = module_cache_stats();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(after.misses, before.misses + 1);
+        assert_eq!(after.hits, before.hits + 1);
+        assert_eq!(after.entries, 1);
+        compile(&second)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_module_cache_shares_clones() -> Result<()> {
+        clear();
+        let payload = compress(alkanes_std_test_build::get_bytes())?;
+        let first = load_binary(&AlkaneId { block: 2, tx: 1 }, Arc::new(payload.clone()))?;
+        let before = module_cache_stats();
+        // a clone stores its own copy of the same payload
+        let second = load_binary(&AlkaneId { block: 2, tx: 2 }, Arc::new(payload))?;
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(module_cache_stats().hits, before.hits + 1);
+        assert_eq!(module_cache_stats().entries, 1);
+        invalidate(&AlkaneId { block: 2, tx: 1 });
+        assert_eq!(module_cache_stats().entries, 1);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_module_cache_detects_overwrite() -> Result<()> {
+        clear();
+        let id = AlkaneId {
+            block: 4,
+            tx: 10001,
+        };
+        let original = Arc::new(compress(alkanes_std_test_build::get_bytes())?);
+        let replacement = Arc::new(compress(alkanes_std_owned_token_build::get_bytes())?);
+        load_binary(&id, original.clone())?;
+        let before = module_cache_stats();
+        let binary = load_binary(&id, replacement.clone())?;
+        assert_eq!(binary.as_ref(), &alkanes_std_owned_token_build::get_bytes());
+        assert_eq!(module_cache_stats().misses, before.misses + 1);
+        invalidate(&id);
+        assert_eq!(module_cache_stats().entries, 0);
+        load_binary(&id, replacement)?;
+        assert_eq!(module_cache_stats().misses, before.misses + 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use crate::index_block;
+    use crate::notifications::{
+        notification_to_json, set_notification_mode, NotificationMode, NOTIFICATION_VERSION,
+    };
+    use crate::tests::helpers::{self as alkane_helpers, clear};
+    use crate::tests::std::alkanes_std_test_build;
+    use crate::view::block_notification;
+    use alkanes_support::cellpack::Cellpack;
+    use alkanes_support::id::AlkaneId;
+    use alkanes_support::proto;
+    use anyhow::Result;
+    use protobuf::Message;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_queue_notification() -> Result<()> {
+        clear();
+        set_notification_mode(NotificationMode::Queue);
+        let block_height = 840_000;
+        let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+            [alkanes_std_test_build::get_bytes()].into(),
+            [Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![60, 7],
+            }]
+            .into(),
+        );
+        let result = index_block(&test_block, block_height);
+        set_notification_mode(NotificationMode::Disabled);
+        result?;
+
+        let notification = proto::alkanes::BlockNotification::parse_from_bytes(
+            &block_notification(block_height as u64)?,
+        )?;
+        assert_eq!(notification.version, NOTIFICATION_VERSION);
+        assert_eq!(notification.height, block_height as u64);
+        assert_eq!(notification.created_alkanes.len(), 1);
+        let created: AlkaneId = notification.created_alkanes[0].id.clone().unwrap().into();
+        assert_eq!(created, AlkaneId { block: 2, tx: 1 });
+        assert!(notification.traces.len() > 0);
+
+        let json = notification_to_json(&notification);
+        assert_eq!(json["version"], NOTIFICATION_VERSION);
+        assert_eq!(json["created_alkanes"][0]["id"], "2:1");
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_disabled_stores_nothing() -> Result<()> {
+        clear();
+        set_notification_mode(NotificationMode::Disabled);
+        let block_height = 840_000;
+        let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+            [alkanes_std_test_build::get_bytes()].into(),
+            [Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![60, 7],
+            }]
+            .into(),
+        );
+        index_block(&test_block, block_height)?;
+        assert_eq!(block_notification(block_height as u64)?.len(), 0);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_notification_mode_from_config() -> Result<()> {
+        assert_eq!(
+            NotificationMode::from_config(None, None)?,
+            NotificationMode::Disabled
+        );
+        assert_eq!(
+            NotificationMode::from_config(None, Some("http://localhost"))?,
+            NotificationMode::Host("http://localhost".to_string())
+        );
+        assert_eq!(
+            NotificationMode::from_config(Some("queue"), Some("http://localhost"))?,
+            NotificationMode::Queue
+        );
+        assert!(NotificationMode::from_config(Some("host"), None).is_err());
+        assert!(NotificationMode::from_config(Some("webhook"), None).is_err());
+        Ok(())
+    }
+}
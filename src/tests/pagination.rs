@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::helpers::clear;
+    use crate::utils::credit_balances;
+    use crate::view::alkane_inventory;
+    use alkanes_support::id::AlkaneId;
+    use alkanes_support::proto::alkanes::AlkaneInventoryRequest;
+    use anyhow::Result;
+    use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
+    use metashrew_support::index_pointer::KeyValuePointer;
+    use protobuf::MessageField;
+    use protorune::versioned;
+    use protorune::view::{paginate, MAX_PAGE_SCAN};
+    use protorune_support::proto::protorune::PaginationInput;
+    use protorune_support::rune_transfer::RuneTransfer;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    fn credit(who: &AlkaneId, what: &AlkaneId, value: u128) -> Result<()> {
+        let mut atomic = AtomicPointer::default();
+        credit_balances(
+            &mut atomic,
+            who,
+            &vec![RuneTransfer {
+                id: what.clone().into(),
+                value,
+            }],
+        )?;
+        atomic.commit();
+        Ok(())
+    }
+
+    fn inventory(req: &AlkaneInventoryRequest) -> Result<(Vec<(u128, u128)>, Vec<u8>)> {
+        let response = alkane_inventory(req)?;
+        Ok((
+            response
+                .alkanes
+                .into_iter()
+                .map(|v| {
+                    let id: AlkaneId = v.id.unwrap().into();
+                    (id.tx, v.value.unwrap().into())
+                })
+                .collect(),
+            response.next_cursor,
+        ))
+    }
+
+    fn numbers(len: u32) -> IndexPointer {
+        let list = IndexPointer::from_keyword("/numbers");
+        for i in 0..len {
+            versioned::append_value::<_, u32>(&list, i);
+        }
+        list
+    }
+
+    fn page(
+        list: &IndexPointer,
+        pagination: PaginationInput,
+        descending: bool,
+    ) -> Result<(Vec<u32>, Vec<u8>)> {
+        paginate(list, &MessageField::some(pagination), descending, |v| {
+            let n = u32::from_le_bytes(v.as_ref().as_slice().try_into()?);
+            // odd entries stand in for outpoints that are filtered out
+            Ok((n % 2 == 0).then_some(n))
+        })
+    }
+
+    #[wasm_bindgen_test]
+    fn test_paginate_cursor() -> Result<()> {
+        clear();
+        versioned::set_height(1);
+        let list = numbers(10);
+        let (first, cursor) = page(
+            &list,
+            PaginationInput {
+                limit: 2,
+                ..Default::default()
+            },
+            false,
+        )?;
+        assert_eq!(first, vec![0, 2]);
+        let (second, cursor) = page(
+            &list,
+            PaginationInput {
+                limit: 2,
+                cursor,
+                ..Default::default()
+            },
+            false,
+        )?;
+        assert_eq!(second, vec![4, 6]);
+        let (last, cursor) = page(
+            &list,
+            PaginationInput {
+                limit: 2,
+                cursor,
+                ..Default::default()
+            },
+            false,
+        )?;
+        assert_eq!(last, vec![8]);
+        assert!(cursor.is_empty());
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_paginate_scan_cap() -> Result<()> {
+        clear();
+        versioned::set_height(1);
+        let len = MAX_PAGE_SCAN as u32 + 2;
+        let list = numbers(len);
+        // only the last entry passes, as with an address whose outpoints are
+        // mostly spent
+        let last = |cursor: Vec<u8>| {
+            let pagination = PaginationInput {
+                limit: 1,
+                cursor,
+                ..Default::default()
+            };
+            paginate(&list, &MessageField::some(pagination), false, |v| {
+                let n = u32::from_le_bytes(v.as_ref().as_slice().try_into()?);
+                Ok((n == len - 1).then_some(n))
+            })
+        };
+        let (first, cursor) = last(vec![])?;
+        assert!(first.is_empty());
+        assert_eq!(cursor, (MAX_PAGE_SCAN as u32).to_le_bytes().to_vec());
+        let (second, cursor) = last(cursor)?;
+        assert_eq!(second, vec![len - 1]);
+        assert!(cursor.is_empty());
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_paginate_offsets_and_order() -> Result<()> {
+        clear();
+        versioned::set_height(1);
+        let list = numbers(10);
+        let (ascending, _) = page(
+            &list,
+            PaginationInput {
+                start: 3,
+                end: 5,
+                ..Default::default()
+            },
+            false,
+        )?;
+        assert_eq!(ascending, vec![4, 6]);
+        let (descending, cursor) = page(&list, PaginationInput::default(), true)?;
+        assert_eq!(descending, vec![8, 6, 4, 2, 0]);
+        assert!(cursor.is_empty());
+        // entries appended after the view height are not visible
+        versioned::set_height(2);
+        versioned::append_value::<_, u32>(&list, 10);
+        versioned::set_view_height(1);
+        let (visible, _) = page(&list, PaginationInput::default(), true)?;
+        assert_eq!(visible.first(), Some(&8));
+        versioned::set_height(3);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_alkane_inventory_pages() -> Result<()> {
+        clear();
+        let owner = AlkaneId { block: 2, tx: 0 };
+        versioned::set_height(100);
+        // an alkane enters the inventory once its balance is touched while nonzero
+        for tx in 1..=4 {
+            credit(&owner, &AlkaneId { block: 2, tx }, tx * 10 - 1)?;
+            credit(&owner, &AlkaneId { block: 2, tx }, 1)?;
+        }
+        credit(&owner, &AlkaneId { block: 2, tx: 1 }, 1)?;
+
+        let mut req = AlkaneInventoryRequest::new();
+        req.id = MessageField::some(owner.into());
+        let (all, cursor) = inventory(&req)?;
+        // an alkane received again while held is listed once
+        assert_eq!(all, vec![(1, 11), (2, 20), (3, 30), (4, 40)]);
+        assert!(cursor.is_empty());
+        req.limit = 3;
+        let (first, cursor) = inventory(&req)?;
+        assert_eq!(first, vec![(1, 11), (2, 20), (3, 30)]);
+        req.cursor = cursor;
+        let (second, cursor) = inventory(&req)?;
+        assert_eq!(second, vec![(4, 40)]);
+        assert!(cursor.is_empty());
+        req.cursor = vec![1, 2, 3];
+        assert!(inventory(&req).is_err());
+        req.cursor = vec![];
+        req.id = MessageField::none();
+        assert!(inventory(&req).is_err());
+
+        let mut req = AlkaneInventoryRequest::new();
+        req.id = MessageField::some(owner.into());
+        req.descending = true;
+        req.min_balance = MessageField::some(25u128.into());
+        let (large, _) = inventory(&req)?;
+        assert_eq!(large, vec![(4, 40), (3, 30)]);
+        req.alkane = MessageField::some(AlkaneId { block: 2, tx: 3 }.into());
+        let (single, _) = inventory(&req)?;
+        assert_eq!(single, vec![(3, 30)]);
+        Ok(())
+    }
+}
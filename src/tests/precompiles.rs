@@ -0,0 +1,185 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use crate::vm::precompiles::precompile;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::precompile::{self as precompiles, PRECOMPILE_BLOCK};
+use alkanes_support::trace::{Trace, TraceEvent};
+use alkanes_support::utils::pack_bytes;
+use anyhow::Result;
+use bitcoin::hashes::{ripemd160, sha256, Hash};
+use bitcoin::secp256k1::{Keypair, Message, PublicKey, Secp256k1, SecretKey};
+use bitcoin::{Block, OutPoint};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn run(cellpack: Cellpack) -> Result<Vec<u8>> {
+    Ok(precompile(&cellpack.target, cellpack.inputs)?.0)
+}
+
+fn sha(data: &[u8]) -> Vec<u8> {
+    sha256::Hash::hash(data).to_byte_array().to_vec()
+}
+
+#[wasm_bindgen_test]
+fn test_hash_precompiles() -> Result<()> {
+    assert_eq!(run(precompiles::sha256(b"abc"))?, sha(b"abc"));
+    assert_eq!(
+        run(precompiles::ripemd160(b"abc"))?,
+        ripemd160::Hash::hash(b"abc").to_byte_array().to_vec()
+    );
+    let tag = sha(b"TapLeaf");
+    assert_eq!(
+        run(precompiles::tagged_hash(b"TapLeaf", b"abc"))?,
+        sha(&[tag.as_slice(), tag.as_slice(), b"abc"].concat())
+    );
+    // a byte string longer than one word
+    let long = [7u8; 40];
+    assert_eq!(run(precompiles::sha256(&long))?, sha(&long));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_signature_precompiles() -> Result<()> {
+    let secp = Secp256k1::new();
+    let secret = SecretKey::from_slice(&[1u8; 32])?;
+    let digest = sha(b"alkanes");
+    let message = Message::from_digest_slice(&digest)?;
+
+    let keypair = Keypair::from_secret_key(&secp, &secret);
+    let xonly = keypair.x_only_public_key().0.serialize();
+    let schnorr = secp.sign_schnorr_no_aux_rand(&message, &keypair);
+    assert_eq!(
+        run(precompiles::schnorr_verify(
+            &xonly,
+            &digest,
+            schnorr.as_ref()
+        ))?,
+        vec![1]
+    );
+    assert_eq!(
+        run(precompiles::schnorr_verify(
+            &xonly,
+            &sha(b"other"),
+            schnorr.as_ref()
+        ))?,
+        vec![0]
+    );
+
+    let pubkey = PublicKey::from_secret_key(&secp, &secret).serialize();
+    let ecdsa = secp.sign_ecdsa(&message, &secret).serialize_compact();
+    assert_eq!(
+        run(precompiles::ecdsa_verify(&pubkey, &digest, &ecdsa))?,
+        vec![1]
+    );
+    // malformed keys fail verification rather than the call
+    assert_eq!(
+        run(precompiles::ecdsa_verify(&[0u8; 3], &digest, &ecdsa))?,
+        vec![0]
+    );
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_merkle_precompile() -> Result<()> {
+    let leaves = [sha(b"a"), sha(b"b"), sha(b"c")];
+    let node = sha(&[leaves[0].as_slice(), leaves[1].as_slice()].concat());
+    let root = sha(&[node.as_slice(), leaves[2].as_slice()].concat());
+
+    let proof = [leaves[1].as_slice(), leaves[2].as_slice()].concat();
+    assert_eq!(
+        run(precompiles::merkle_verify(&root, &leaves[0], 0, 3, &proof))?,
+        vec![1]
+    );
+    // the odd leaf skips the first level
+    assert_eq!(
+        run(precompiles::merkle_verify(&root, &leaves[2], 2, 3, &node))?,
+        vec![1]
+    );
+    assert_eq!(
+        run(precompiles::merkle_verify(&root, &leaves[1], 0, 3, &proof))?,
+        vec![0]
+    );
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_unknown_precompile() -> Result<()> {
+    let target = AlkaneId {
+        block: PRECOMPILE_BLOCK,
+        tx: 100,
+    };
+    assert!(precompile(&target, vec![]).is_err());
+    // truncated byte arguments are an error
+    assert!(run(Cellpack {
+        target: precompiles::SHA256,
+        inputs: vec![40, 0],
+    })
+    .is_err());
+    Ok(())
+}
+
+fn staticcall_sha256() -> Vec<u128> {
+    let packed = pack_bytes(b"abc");
+    let mut inputs = vec![
+        33,
+        precompiles::SHA256.block,
+        precompiles::SHA256.tx,
+        packed.len() as u128,
+    ];
+    inputs.extend(packed);
+    inputs
+}
+
+fn last_event(test_block: &Block) -> Result<Option<TraceEvent>> {
+    let outpoint = OutPoint {
+        txid: test_block.txdata.last().unwrap().compute_txid(),
+        vout: 3,
+    };
+    let trace: Trace = view::trace(&outpoint)?.try_into()?;
+    let events = trace.0.lock().expect("Mutex poisoned");
+    Ok(events.last().cloned())
+}
+
+#[wasm_bindgen_test]
+fn test_staticcall_precompile() -> Result<()> {
+    clear();
+    let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: staticcall_sha256(),
+        }]
+        .into(),
+    );
+    index_block(&test_block, 840_000)?;
+    match last_event(&test_block)? {
+        Some(TraceEvent::ReturnContext(response)) => {
+            assert_eq!(response.inner.data, sha(b"abc"))
+        }
+        _ => panic!("call to the precompile did not return"),
+    }
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_precompile_before_activation() -> Result<()> {
+    alkane_helpers::clear();
+    let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: staticcall_sha256(),
+        }]
+        .into(),
+    );
+    index_block(&test_block, 0)?;
+    // the precompile block is an empty alkane id until activation
+    assert!(matches!(
+        last_event(&test_block)?,
+        Some(TraceEvent::RevertContext(_))
+    ));
+    Ok(())
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use crate::index_block;
+    use crate::network::genesis;
+    use crate::registry::{alkanes_by_bytecode_hash, bytecode_hash, list_alkanes};
+    use crate::tests::helpers::{self as alkane_helpers, clear};
+    use crate::tests::std::alkanes_std_test_build;
+    use crate::utils::alkane_id_to_outpoint;
+    use alkanes_support::cellpack::Cellpack;
+    use alkanes_support::id::AlkaneId;
+    use alkanes_support::proto::alkanes::{
+        AlkaneDeployKind, AlkanesByBytecodeHashRequest, ListAlkanesRequest,
+    };
+    use anyhow::Result;
+    use bitcoin::hashes::Hash;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_registry_records_deployments() -> Result<()> {
+        clear();
+        let block_height = 840_000;
+        let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+            [alkanes_std_test_build::get_bytes(), vec![]].into(),
+            [
+                Cellpack {
+                    target: AlkaneId { block: 1, tx: 0 },
+                    inputs: vec![60, 1],
+                },
+                Cellpack {
+                    target: AlkaneId { block: 5, tx: 1 },
+                    inputs: vec![60, 2],
+                },
+            ]
+            .into(),
+        );
+        index_block(&test_block, block_height)?;
+
+        let response = list_alkanes(&ListAlkanesRequest::new())?;
+        assert_eq!(response.alkanes.len(), 3);
+        assert!(response.next_cursor.is_empty());
+        // genesis is registered like any other deployment
+        let genesis_entry = &response.alkanes[0];
+        assert_eq!(
+            AlkaneId::from(genesis_entry.id.clone().unwrap()),
+            AlkaneId { block: 2, tx: 0 }
+        );
+        assert_eq!(
+            genesis_entry.kind.enum_value_or_default(),
+            AlkaneDeployKind::CREATE
+        );
+        assert_eq!(genesis_entry.height, genesis::GENESIS_BLOCK);
+        assert_eq!(
+            alkane_id_to_outpoint(&AlkaneId { block: 2, tx: 0 })?.vout,
+            0
+        );
+        let created = &response.alkanes[1];
+        assert_eq!(
+            AlkaneId::from(created.id.clone().unwrap()),
+            AlkaneId { block: 2, tx: 1 }
+        );
+        assert_eq!(
+            created.kind.enum_value_or_default(),
+            AlkaneDeployKind::CREATE
+        );
+        assert_eq!(created.height, block_height as u64);
+        let outpoint = alkane_id_to_outpoint(&AlkaneId { block: 2, tx: 1 })?;
+        assert_eq!(
+            created.outpoint.txid,
+            outpoint.txid.as_byte_array().to_vec()
+        );
+        assert_eq!(created.outpoint.vout, outpoint.vout);
+        let hash = bytecode_hash(&alkanes_std_test_build::get_bytes());
+        assert_eq!(created.bytecode_hash, hash);
+
+        let clone = &response.alkanes[2];
+        assert_eq!(
+            clone.kind.enum_value_or_default(),
+            AlkaneDeployKind::FACTORY
+        );
+        assert_eq!(
+            AlkaneId::from(clone.factory.clone().unwrap()),
+            AlkaneId { block: 2, tx: 1 }
+        );
+        // statics are read from the contract when the registry is viewed
+        assert!(!clone.name.is_empty());
+
+        let mut req = ListAlkanesRequest::new();
+        req.limit = 1;
+        req.descending = true;
+        let newest = list_alkanes(&req)?;
+        assert_eq!(newest.alkanes[0].id, clone.id);
+        req.cursor = newest.next_cursor;
+        assert_eq!(list_alkanes(&req)?.alkanes[0].id, created.id);
+
+        let mut req = AlkanesByBytecodeHashRequest::new();
+        req.bytecode_hash = hash;
+        let same_code = alkanes_by_bytecode_hash(&req)?;
+        assert_eq!(same_code.alkanes.len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use crate::index_block;
+    use crate::tests::helpers::{self as alkane_helpers, clear};
+    use crate::tests::std::alkanes_std_test_build;
+    use alkanes_support::cellpack::Cellpack;
+    use alkanes_support::id::AlkaneId;
+    use anyhow::Result;
+    use bitcoin::Block;
+    use metashrew_core::get_cache;
+    use protorune::versioned;
+    use std::collections::BTreeMap;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    const HEIGHT: u32 = 840_000;
+
+    fn deploy_and_emit(values: Vec<u128>) -> Block {
+        let mut binaries = vec![alkanes_std_test_build::get_bytes()];
+        let mut cellpacks = vec![Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![60, values[0]],
+        }];
+        for value in values.into_iter().skip(1) {
+            binaries.push(vec![]);
+            cellpacks.push(Cellpack {
+                target: AlkaneId { block: 2, tx: 1 },
+                inputs: vec![60, value],
+            });
+        }
+        alkane_helpers::init_with_multiple_cellpacks_with_tx(binaries, cellpacks)
+    }
+
+    // the whole keyspace, less the version history and journal that record how
+    // it got there; keys a rollback empties read the same as keys never written
+    fn snapshot() -> BTreeMap<Vec<u8>, Vec<u8>> {
+        get_cache()
+            .iter()
+            .filter(|(k, v)| {
+                !v.is_empty() && !k.starts_with(b"/versioned/") && !k.starts_with(b"/journal/")
+            })
+            .map(|(k, v)| (k.as_ref().clone(), v.as_ref().clone()))
+            .collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_fork_matches_clean_index() -> Result<()> {
+        clear();
+        let fork = deploy_and_emit(vec![9]);
+        index_block(&fork, HEIGHT)?;
+        let expected = snapshot();
+
+        clear();
+        index_block(&deploy_and_emit(vec![7, 8]), HEIGHT)?;
+        index_block(&deploy_and_emit(vec![1]), HEIGHT + 1)?;
+        assert_eq!(versioned::indexed_height(), Some(HEIGHT as u64 + 1));
+        assert_ne!(snapshot(), expected);
+
+        // the fork replaces both blocks
+        index_block(&fork, HEIGHT)?;
+        assert_eq!(versioned::indexed_height(), Some(HEIGHT as u64));
+        assert_eq!(snapshot(), expected);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_rollback_restores_previous_height() -> Result<()> {
+        clear();
+        let first = deploy_and_emit(vec![7]);
+        index_block(&first, HEIGHT)?;
+        let expected = snapshot();
+        index_block(&deploy_and_emit(vec![1]), HEIGHT + 1)?;
+        versioned::rollback(HEIGHT as u64 + 1);
+        assert_eq!(versioned::indexed_height(), Some(HEIGHT as u64));
+        assert_eq!(snapshot(), expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use crate::vm::utils::sequence_pointer;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::envelope::RawEnvelope;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::MessageContextParcel;
+use alkanes_support::trace::{Trace, TraceEvent};
+use anyhow::Result;
+use bitcoin::Witness;
+use metashrew_core::index_pointer::AtomicPointer;
+use metashrew_support::index_pointer::KeyValuePointer;
+use metashrew_support::utils::consensus_encode;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn step(cellpack: Cellpack, witness: Witness) -> Result<MessageContextParcel> {
+    let mut parcel = MessageContextParcel::new();
+    parcel.height = 840_001;
+    parcel.calldata = cellpack.encipher();
+    parcel.transaction = consensus_encode(&alkane_helpers::create_cellpack_with_witness(
+        witness, cellpack,
+    ))?;
+    Ok(parcel)
+}
+
+fn created(trace: &Trace) -> Vec<AlkaneId> {
+    trace
+        .0
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|event| match event {
+            TraceEvent::CreateAlkane(id) => Some(id.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn test_sequential_simulate() -> Result<()> {
+    clear();
+    let sequence = sequence_pointer(&AtomicPointer::default()).get_value::<u128>();
+    let first = AlkaneId::new(2, sequence);
+    let second = AlkaneId::new(2, sequence + 1);
+    let deploy = || RawEnvelope::from(alkanes_std_test_build::get_bytes()).to_gzipped_witness();
+    let call = |target: &AlkaneId, inputs: Vec<u128>| Cellpack {
+        target: target.clone(),
+        inputs,
+    };
+    let create = |inputs: Vec<u128>| call(&AlkaneId { block: 1, tx: 0 }, inputs);
+
+    let steps = view::sequential_simulate_safe(
+        vec![
+            step(create(vec![72, 42]), deploy())?,
+            step(call(&first, vec![73, 2, sequence]), Witness::new())?,
+            step(call(&first, vec![61]), Witness::new())?,
+            step(create(vec![99]), deploy())?,
+            step(call(&second, vec![5]), Witness::new())?,
+        ],
+        u64::MAX,
+    );
+    assert_eq!(steps.len(), 5);
+
+    let (deployed, trace) = &steps[0];
+    assert!(deployed.as_ref().unwrap().1 > 0);
+    assert_eq!(created(trace), vec![first.clone()]);
+
+    // the second step reads the storage the deployment wrote
+    let (response, _) = steps[1].0.as_ref().unwrap();
+    assert_eq!(response.data, 42u128.to_le_bytes().to_vec());
+
+    // a failing step is reported with its trace and the rest still run
+    let (reverted, trace) = &steps[2];
+    assert!(reverted
+        .as_ref()
+        .unwrap_err()
+        .to_string()
+        .contains("reverting after emit"));
+    assert!(matches!(
+        trace.0.lock().unwrap().last(),
+        Some(TraceEvent::RevertContext(_))
+    ));
+
+    assert_eq!(created(&steps[3].1), vec![second]);
+    let (response, _) = steps[4].0.as_ref().unwrap();
+    assert_eq!(response.data, vec![0x05, 0x06, 0x07, 0x08]);
+
+    // nothing was committed to the index
+    assert_eq!(
+        sequence_pointer(&AtomicPointer::default()).get_value::<u128>(),
+        sequence
+    );
+    alkane_helpers::assert_token_id_has_no_deployment(first)?;
+    Ok(())
+}
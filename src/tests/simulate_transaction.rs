@@ -0,0 +1,132 @@
+use crate::index_block;
+use crate::message::AlkaneMessageContext;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::{
+    AlkaneTransfer, InputBalances, SimulateTransactionRequest, SimulateTransactionResponse,
+};
+use anyhow::Result;
+use bitcoin::{OutPoint, Transaction, Witness};
+use metashrew_support::index_pointer::KeyValuePointer;
+use metashrew_support::utils::consensus_encode;
+use protobuf::MessageField;
+use protorune::balance_sheet::load_sheet;
+use protorune::message::MessageContext;
+use protorune::tables::RuneTable;
+use protorune::test_helpers::{create_default_protoburn_transaction, get_mock_outpoint};
+use protorune_support::balance_sheet::BalanceSheetOperations;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const TOKEN: AlkaneId = AlkaneId { block: 2, tx: 1 };
+
+fn indexed_balance(outpoint: &OutPoint) -> Result<u128> {
+    let table = RuneTable::for_protocol(AlkaneMessageContext::protocol_tag());
+    let sheet = load_sheet(&table.OUTPOINT_TO_RUNES.select(&consensus_encode(outpoint)?));
+    Ok(sheet.get(&TOKEN.into()))
+}
+
+fn output_balance(response: &SimulateTransactionResponse, vout: u32) -> u128 {
+    response
+        .outputs
+        .iter()
+        .filter(|v| v.outpoint.vout == vout)
+        .flat_map(|v| v.balances.iter())
+        .filter(|v| Into::<AlkaneId>::into(v.id.clone().unwrap()) == TOKEN)
+        .map(|v| u128::from(v.value.clone().unwrap()))
+        .sum()
+}
+
+fn simulate(tx: &Transaction, inputs: Vec<InputBalances>) -> Result<SimulateTransactionResponse> {
+    let mut request = SimulateTransactionRequest::new();
+    request.transaction = consensus_encode(tx)?;
+    request.height = 840_001;
+    request.txindex = 1;
+    request.inputs = inputs;
+    view::simulate_transaction(&request)
+}
+
+#[wasm_bindgen_test]
+fn test_simulate_transaction() -> Result<()> {
+    clear();
+    let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![4],
+        }]
+        .into(),
+    );
+    index_block(&deploy, 840_000)?;
+    let funded = OutPoint {
+        txid: deploy.txdata.last().unwrap().compute_txid(),
+        vout: 0,
+    };
+    assert_eq!(indexed_balance(&funded)?, 100);
+
+    let tx = alkane_helpers::create_multiple_cellpack_with_witness_and_in(
+        Witness::new(),
+        vec![
+            Cellpack {
+                target: TOKEN,
+                inputs: vec![4],
+            },
+            Cellpack {
+                target: TOKEN,
+                inputs: vec![61],
+            },
+        ],
+        funded,
+        false,
+    );
+
+    let response = simulate(&tx, vec![])?;
+    assert!(response.error.is_empty());
+    assert_eq!(output_balance(&response, 0), 200);
+    assert_eq!(response.traces.len(), 2);
+    assert_eq!(response.reverts.len(), 1);
+    assert_eq!(response.reverts[0].outpoint.vout, 4);
+    assert!(response.reverts[0].error.contains("reverting after emit"));
+
+    let mut override_balance = InputBalances::new();
+    override_balance.input = 0;
+    override_balance.balances = vec![AlkaneTransfer {
+        id: MessageField::some(TOKEN.into()),
+        value: MessageField::some(1000u128.into()),
+        ..Default::default()
+    }];
+    let response = simulate(&tx, vec![override_balance])?;
+    assert_eq!(output_balance(&response, 0), 1100);
+
+    // outputs and traces stay out of the index
+    let txid = tx.compute_txid();
+    assert_eq!(indexed_balance(&OutPoint::new(txid, 0))?, 0);
+    for vout in [3, 4] {
+        assert!(view::trace(&OutPoint::new(txid, vout))?.is_empty());
+    }
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_simulate_protoburn() -> Result<()> {
+    clear();
+    // etches 1000 runes and burns them into alkanes protorunes on output 0
+    let tx = create_default_protoburn_transaction(
+        get_mock_outpoint(0),
+        AlkaneMessageContext::protocol_tag(),
+    );
+    let response = simulate(&tx, vec![])?;
+    assert!(response.error.is_empty());
+    let burned: Vec<u128> = response
+        .outputs
+        .iter()
+        .filter(|v| v.outpoint.vout == 0)
+        .flat_map(|v| v.balances.iter())
+        .map(|v| u128::from(v.value.clone().unwrap()))
+        .collect();
+    assert_eq!(burned, vec![1000]);
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::{alkanes_std_owned_token_build, alkanes_std_test_build};
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::gz::compress;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::{
+    AlkaneStateOverride, AlkaneTransfer, KeyValuePair, MessageContextParcel, StateOverrides,
+};
+use alkanes_support::response::ExtendedCallResponse;
+use anyhow::Result;
+use metashrew_core::index_pointer::IndexPointer;
+use metashrew_support::index_pointer::KeyValuePointer;
+use protobuf::MessageField;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const TARGET: AlkaneId = AlkaneId { block: 2, tx: 1 };
+const OTHER: AlkaneId = AlkaneId { block: 2, tx: 5 };
+
+fn simulate(
+    inputs: Vec<u128>,
+    overrides: Vec<AlkaneStateOverride>,
+) -> Result<ExtendedCallResponse> {
+    let mut parcel = MessageContextParcel::new();
+    parcel.height = 840_001;
+    parcel.calldata = Cellpack {
+        target: TARGET,
+        inputs,
+    }
+    .encipher();
+    if overrides.len() > 0 {
+        let mut section = StateOverrides::new();
+        section.alkanes = overrides;
+        parcel.overrides = MessageField::some(section);
+    }
+    let (response, _) = view::simulate_safe(&view::parcel_from_protobuf(parcel)?, u64::MAX)?;
+    Ok(response)
+}
+
+fn override_for(id: AlkaneId) -> AlkaneStateOverride {
+    let mut result = AlkaneStateOverride::new();
+    result.id = MessageField::some(id.into());
+    result
+}
+
+#[wasm_bindgen_test]
+fn test_state_overrides() -> Result<()> {
+    clear();
+    let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![72, 42],
+        }]
+        .into(),
+    );
+    index_block(&deploy, 840_000)?;
+    let stored = 42u128.to_le_bytes().to_vec();
+
+    assert_eq!(simulate(vec![73, 2, 1], vec![])?.data, stored);
+    let mut storage = override_for(TARGET);
+    storage.storage = vec![KeyValuePair {
+        key: b"/value".to_vec(),
+        value: 7u128.to_le_bytes().to_vec(),
+        ..Default::default()
+    }];
+    assert_eq!(
+        simulate(vec![73, 2, 1], vec![storage])?.data,
+        7u128.to_le_bytes().to_vec()
+    );
+
+    // the alkane cannot pay out what it does not hold
+    assert!(simulate(vec![30, 2, 5, 500], vec![]).is_err());
+    let mut balances = override_for(TARGET);
+    balances.balances = vec![AlkaneTransfer {
+        id: MessageField::some(OTHER.into()),
+        value: MessageField::some(500u128.into()),
+        ..Default::default()
+    }];
+    let paid = simulate(vec![30, 2, 5, 500], vec![balances])?;
+    assert_eq!(paid.alkanes.0.len(), 1);
+    assert_eq!(paid.alkanes.0[0].id, OTHER);
+    assert_eq!(paid.alkanes.0[0].value, 500);
+
+    let mut bytecode = override_for(TARGET);
+    bytecode.bytecode = compress(alkanes_std_owned_token_build::get_bytes())?;
+    assert_ne!(
+        simulate(vec![99], vec![bytecode])?.data,
+        vec![0x01, 0x02, 0x03, 0x04]
+    );
+
+    // an override that leaves out what it applies to is refused
+    assert!(simulate(vec![99], vec![AlkaneStateOverride::new()]).is_err());
+    let mut partial = override_for(TARGET);
+    partial.balances = vec![AlkaneTransfer {
+        id: MessageField::some(OTHER.into()),
+        ..Default::default()
+    }];
+    assert!(simulate(vec![99], vec![partial]).is_err());
+
+    // none of the overrides reached the index
+    assert_eq!(
+        simulate(vec![99], vec![])?.data,
+        vec![0x01, 0x02, 0x03, 0x04]
+    );
+    assert_eq!(simulate(vec![73, 2, 1], vec![])?.data, stored);
+    assert_eq!(
+        IndexPointer::from_keyword("/alkanes/")
+            .select(&TARGET.into())
+            .keyword("/storage/")
+            .select(&b"/value".to_vec())
+            .get()
+            .as_ref()
+            .clone(),
+        stored
+    );
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::codec::StorageCodec;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::MessageContextParcel;
+use anyhow::Result;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const TARGET: AlkaneId = AlkaneId { block: 2, tx: 1 };
+
+fn insert(depositor: u128, amount: u128, note: &str) -> Vec<u128> {
+    let mut bytes = [0u8; 16];
+    bytes[..note.len()].copy_from_slice(note.as_bytes());
+    vec![74, 2, depositor, amount, u128::from_le_bytes(bytes)]
+}
+
+#[wasm_bindgen_test]
+fn test_storage_collections() -> Result<()> {
+    clear();
+    let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: insert(5, 100, "a"),
+        }]
+        .into(),
+    );
+    index_block(&deploy, 840_000)?;
+    let block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        vec![vec![]; 3],
+        [insert(6, 200, "bb"), insert(5, 300, "c"), vec![75, 2, 5]]
+            .into_iter()
+            .map(|inputs| Cellpack {
+                target: TARGET,
+                inputs,
+            })
+            .collect(),
+    );
+    index_block(&block, 840_001)?;
+
+    let mut parcel = MessageContextParcel::new();
+    parcel.height = 840_002;
+    parcel.calldata = Cellpack {
+        target: TARGET,
+        inputs: vec![76],
+    }
+    .encipher();
+    let (response, _) = view::simulate_safe(&view::parcel_from_protobuf(parcel)?, u64::MAX)?;
+
+    // three inserts of 600 in total, one depositor left after the removal
+    let mut expected: Vec<u8> = [3u128, 3, 600, 1]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    expected.extend(Into::<Vec<u8>>::into(AlkaneId { block: 2, tx: 6 }));
+    expected.extend(200u128.to_le_bytes());
+    expected.extend(2u32.to_le_bytes());
+    expected.extend(b"bb");
+    assert_eq!(response.data, expected);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_empty_storage_value() -> Result<()> {
+    clear();
+    let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![77],
+        }]
+        .into(),
+    );
+    index_block(&deploy, 840_000)?;
+
+    let mut parcel = MessageContextParcel::new();
+    parcel.height = 840_001;
+    parcel.calldata = Cellpack {
+        target: TARGET,
+        inputs: vec![77],
+    }
+    .encipher();
+    let (response, _) = view::simulate_safe(&view::parcel_from_protobuf(parcel)?, u64::MAX)?;
+    // the empty note stored at deployment reads back as present
+    assert_eq!(response.data, Some(String::new()).encode());
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::index_block;
+use crate::message::AlkaneMessageContext;
+use crate::tests::helpers::{self as alkane_helpers, clear};
+use crate::tests::std::{alkanes_std_auth_token_build, alkanes_std_owned_token_build};
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::constants::AUTH_TOKEN_FACTORY_ID;
+use alkanes_support::id::AlkaneId;
+use anyhow::Result;
+use bitcoin::blockdata::transaction::Version;
+use bitcoin::{Amount, OutPoint, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Witness};
+use protorune::message::MessageContext;
+use protorune::supply::supply;
+use protorune::test_helpers::{create_block_with_coinbase_tx, get_address, ADDRESS1};
+use protorune_support::balance_sheet::ProtoruneRuneId;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_supply_follows_mint_and_burn() -> Result<()> {
+    clear();
+    let block_height = 840_000;
+    let test_block = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [
+            alkanes_std_auth_token_build::get_bytes(),
+            alkanes_std_owned_token_build::get_bytes(),
+        ]
+        .into(),
+        [
+            Cellpack {
+                target: AlkaneId {
+                    block: 3,
+                    tx: AUTH_TOKEN_FACTORY_ID,
+                },
+                inputs: vec![100],
+            },
+            Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![0, 1, 1000],
+            },
+        ]
+        .into(),
+    );
+    index_block(&test_block, block_height)?;
+
+    // the owned token mints itself on initialization
+    let token = ProtoruneRuneId { block: 2, tx: 1 };
+    assert_eq!(
+        supply(AlkaneMessageContext::protocol_tag(), &token),
+        (1000, 0)
+    );
+
+    // spending the output without a protostone burns what it held
+    let mut burn_block = create_block_with_coinbase_tx(block_height + 1);
+    burn_block.txdata.push(Transaction {
+        version: Version::ONE,
+        lock_time: bitcoin::absolute::LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint {
+                txid: test_block.txdata.last().unwrap().compute_txid(),
+                vout: 0,
+            },
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::MAX,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::from_sat(100_000_000),
+            script_pubkey: get_address(&ADDRESS1().as_str()).script_pubkey(),
+        }],
+    });
+    index_block(&burn_block, block_height + 1)?;
+    assert_eq!(
+        supply(AlkaneMessageContext::protocol_tag(), &token),
+        (1000, 1000)
+    );
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::tests::helpers as alkane_helpers;
+use crate::view;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::trace::{Trace, TraceEvent, TraceResponse};
+use anyhow::{anyhow, Result};
+use bitcoin::{Block, OutPoint};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn exit_context(block: &Block) -> Result<TraceResponse> {
+    let outpoint = OutPoint {
+        txid: block.txdata.last().unwrap().compute_txid(),
+        vout: 3,
+    };
+    let trace: Trace = view::trace(&outpoint)?.try_into()?;
+    let events = trace.0.lock().expect("Mutex poisoned");
+    match events.last().cloned() {
+        Some(TraceEvent::ReturnContext(v)) | Some(TraceEvent::RevertContext(v)) => Ok(v),
+        _ => Err(anyhow!("trace does not end with an exit context")),
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_trace_records_fuel_and_transfers() -> Result<()> {
+    let test_block = alkane_helpers::init_std_test(vec![4])?;
+
+    let exit = exit_context(&test_block)?;
+    assert!(exit.fuel_used > 0);
+    assert_eq!(exit.transfers.len(), 1);
+    assert_eq!(exit.transfers[0].from, AlkaneId { block: 2, tx: 1 });
+    assert_eq!(exit.transfers[0].transfer.id, AlkaneId { block: 2, tx: 1 });
+    assert_eq!(exit.transfers[0].transfer.value, 100);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_reverted_frame_reports_fuel() -> Result<()> {
+    let test_block = alkane_helpers::init_std_test(vec![20])?;
+
+    let exit = exit_context(&test_block)?;
+    // the infinite loop burns everything it was given
+    assert!(exit.fuel_used > 0);
+    assert_ne!(exit.fuel_used, u64::MAX);
+    assert!(exit.transfers.is_empty());
+    Ok(())
+}
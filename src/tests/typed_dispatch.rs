@@ -0,0 +1,107 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use alkane_helpers::clear;
+use alkanes::view;
+use alkanes_support::abi::{AbiValue, ContractAbi};
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::{AbiRequest, MessageContextParcel};
+use anyhow::Result;
+use protobuf::{Message, MessageField};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const TARGET: AlkaneId = AlkaneId { block: 2, tx: 1 };
+
+fn simulate(inputs: Vec<u128>) -> Result<Vec<u8>> {
+    let mut parcel = MessageContextParcel::new();
+    parcel.height = 840_001;
+    parcel.calldata = Cellpack {
+        target: TARGET,
+        inputs,
+    }
+    .encipher();
+    let (response, _) = view::simulate_safe(&view::parcel_from_protobuf(parcel)?, u64::MAX)?;
+    Ok(response.data)
+}
+
+fn pack(bytes: &[u8]) -> Vec<u128> {
+    bytes
+        .chunks(16)
+        .map(|v| {
+            let mut word = [0u8; 16];
+            word[..v.len()].copy_from_slice(v);
+            u128::from_le_bytes(word)
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn test_typed_params_and_returns() -> Result<()> {
+    clear();
+    let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![72, 42],
+        }]
+        .into(),
+    );
+    index_block(&deploy, 840_000)?;
+
+    let mut request = AbiRequest::new();
+    request.id = MessageField::some(TARGET.into());
+    let abi: ContractAbi = serde_json::from_slice(&view::getabi(&request.write_to_bytes()?)?)?;
+    let flag = abi.method(79).unwrap();
+    assert_eq!(flag.params[0].kind, "bool");
+    assert_eq!(flag.params[1].kind, "u64");
+    assert_eq!(flag.returns, "Option<u64>");
+    assert_eq!(abi.method(80).unwrap().params[0].kind, "[u8; 32]");
+    assert_eq!(abi.method(82).unwrap().params[0].kind, "Option<Deposit>");
+
+    let data = simulate(vec![79, 1, 7])?;
+    let mut expected = vec![1];
+    expected.extend(7u64.to_le_bytes());
+    assert_eq!(data, expected);
+    assert_eq!(
+        flag.decode_data(&data),
+        AbiValue::Option(Some(Box::new(AbiValue::U128(7))))
+    );
+    assert_eq!(simulate(vec![79, 0, 7])?, vec![0]);
+    // out of range for the declared types
+    assert!(simulate(vec![79, 2, 7]).is_err());
+    assert!(simulate(vec![79, 1, u64::MAX as u128 + 1]).is_err());
+
+    let hash: Vec<u8> = (0..32).collect();
+    let mut inputs = vec![80];
+    inputs.extend(pack(&hash));
+    assert_eq!(simulate(inputs)?, hash);
+
+    let data = simulate(vec![81, 2, 2, 1, 4, 5])?;
+    let mut expected: Vec<u8> = AlkaneId { block: 4, tx: 5 }.into();
+    expected.extend(Into::<Vec<u8>>::into(AlkaneId { block: 2, tx: 1 }));
+    assert_eq!(data, expected);
+    assert_eq!(
+        abi.method(81).unwrap().decode_data(&data),
+        AbiValue::List(vec![
+            AbiValue::AlkaneId(AlkaneId { block: 4, tx: 5 }),
+            AbiValue::AlkaneId(AlkaneId { block: 2, tx: 1 }),
+        ])
+    );
+
+    // a Deposit is its amount followed by the length prefixed note
+    let mut deposit = 300u128.to_le_bytes().to_vec();
+    deposit.extend(5u32.to_le_bytes());
+    deposit.extend(b"hello");
+    let mut inputs = vec![82, 1, deposit.len() as u128];
+    inputs.extend(pack(&deposit));
+    assert_eq!(simulate(inputs.clone())?, deposit);
+    assert_eq!(
+        abi.method(82).unwrap().decode_inputs(&inputs[1..])?,
+        vec![AbiValue::Option(Some(Box::new(AbiValue::Bytes(
+            deposit.clone()
+        ))))]
+    );
+    assert!(simulate(vec![82, 0]).is_err());
+    Ok(())
+}
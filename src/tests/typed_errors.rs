@@ -0,0 +1,103 @@
+use crate::index_block;
+use crate::tests::helpers::{self as alkane_helpers};
+use crate::tests::std::alkanes_std_test_build;
+use alkane_helpers::clear;
+use alkanes::vm::revert::TypedRevert;
+use alkanes::{abi, view};
+use alkanes_support::abi::{AbiValue, ContractAbi};
+use alkanes_support::cellpack::Cellpack;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto::alkanes::{AbiRequest, MessageContextParcel};
+use alkanes_support::trace::Trace;
+use anyhow::Result;
+use protobuf::{Message, MessageField};
+use serde_json::json;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const TARGET: AlkaneId = AlkaneId { block: 2, tx: 1 };
+
+fn parcel(inputs: Vec<u128>) -> Result<protorune::message::MessageContextParcel> {
+    let mut parcel = MessageContextParcel::new();
+    parcel.height = 840_001;
+    parcel.calldata = Cellpack {
+        target: TARGET,
+        inputs,
+    }
+    .encipher();
+    view::parcel_from_protobuf(parcel)
+}
+
+#[wasm_bindgen_test]
+fn test_typed_errors() -> Result<()> {
+    clear();
+    let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+        [alkanes_std_test_build::get_bytes()].into(),
+        [Cellpack {
+            target: AlkaneId { block: 1, tx: 0 },
+            inputs: vec![72, 42],
+        }]
+        .into(),
+    );
+    index_block(&deploy, 840_000)?;
+
+    let mut request = AbiRequest::new();
+    request.id = MessageField::some(TARGET.into());
+    let contract: ContractAbi = serde_json::from_slice(&view::getabi(&request.write_to_bytes()?)?)?;
+    assert_eq!(contract.errors.len(), 2);
+    assert_eq!(contract.errors[0].name, "Unauthorized");
+    assert_eq!(contract.errors[0].params[0].kind, "AlkaneId");
+    let error = &contract.errors[1];
+    assert_eq!(
+        (error.name.as_str(), error.code),
+        ("InsufficientBalance", 2)
+    );
+
+    // the revert data is the code and fields, which the ABI reads back
+    let mut data = 2u32.to_be_bytes().to_vec();
+    data.extend(5u128.to_le_bytes());
+    data.extend(3u128.to_le_bytes());
+    let (decoded, values) = contract.decode_error(&data).unwrap();
+    assert_eq!(decoded.name, "InsufficientBalance");
+    assert_eq!(values, vec![AbiValue::U128(5), AbiValue::U128(3)]);
+
+    // a failed simulation names the error
+    let e = view::simulate_safe(&parcel(vec![84, 5, 3])?, u64::MAX).unwrap_err();
+    let expected = json!({
+        "name": "InsufficientBalance",
+        "code": 2,
+        "params": { "needed": "5", "available": "3" },
+    });
+    assert_eq!(
+        abi::describe_error(&e),
+        format!("ALKANES: revert: {}", expected)
+    );
+
+    // a caller matches on the error its extcall reverted with
+    let trace = Trace::default();
+    let (response, _) =
+        view::simulate_parcel_with_trace(&parcel(vec![85, 2, 1])?, u64::MAX, &trace)?;
+    assert_eq!(response.data, 2u128.to_le_bytes().to_vec());
+
+    // and the trace of the inner frame carries the decoded error
+    let events = abi::decode_trace(&trace.0.lock().unwrap());
+    let revert = events
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|v| v["event"] == "revert")
+        .unwrap();
+    assert_eq!(revert["error"], expected);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn test_typed_errors_before_activation() -> Result<()> {
+    alkane_helpers::init_std_test(vec![72, 42])?;
+    let mut before = parcel(vec![84, 5, 3])?;
+    before.height = 0;
+    // the std test contract also imports host functions linked from their own
+    // activation, so below it the call fails to link, and never as a typed error
+    let e = view::simulate_safe(&before, u64::MAX).unwrap_err();
+    assert!(e.downcast_ref::<TypedRevert>().is_none());
+    Ok(())
+}
@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests {
+    use crate::index_block;
+    use crate::tests::helpers::{self as alkane_helpers, clear};
+    use crate::tests::std::alkanes_std_test_build;
+    use crate::utils::{
+        alkane_inventory_pointer, balance_pointer, credit_balances, pipe_storagemap_to,
+    };
+    use crate::view;
+    use alkanes_support::cellpack::Cellpack;
+    use alkanes_support::id::AlkaneId;
+    use alkanes_support::proto::alkanes::MessageContextParcel;
+    use alkanes_support::storage::StorageMap;
+    use anyhow::Result;
+    use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
+    use metashrew_support::index_pointer::KeyValuePointer;
+    use protorune::versioned;
+    use protorune_support::rune_transfer::RuneTransfer;
+    use std::sync::Arc;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    fn credit(who: &AlkaneId, what: &AlkaneId, value: u128) -> Result<()> {
+        let mut atomic = AtomicPointer::default();
+        credit_balances(
+            &mut atomic,
+            who,
+            &vec![RuneTransfer {
+                id: what.clone().into(),
+                value,
+            }],
+        )?;
+        atomic.commit();
+        Ok(())
+    }
+
+    fn balance_at(who: &AlkaneId, what: &AlkaneId, height: u64) -> u128 {
+        versioned::set_view_height(height);
+        versioned::get_value::<_, u128>(&balance_pointer(&mut AtomicPointer::default(), who, what))
+    }
+
+    #[wasm_bindgen_test]
+    fn test_balance_at_height() -> Result<()> {
+        clear();
+        let who = AlkaneId { block: 2, tx: 1 };
+        let what = AlkaneId { block: 2, tx: 2 };
+        versioned::set_height(100);
+        credit(&who, &what, 10)?;
+        versioned::set_height(102);
+        credit(&who, &what, 5)?;
+        // a second write in the same block replaces the entry for that height
+        credit(&who, &what, 1)?;
+
+        assert_eq!(balance_at(&who, &what, 99), 0);
+        assert_eq!(balance_at(&who, &what, 100), 10);
+        assert_eq!(balance_at(&who, &what, 101), 10);
+        assert_eq!(balance_at(&who, &what, 102), 16);
+        assert_eq!(balance_at(&who, &what, 1000), 16);
+        versioned::set_height(103);
+        assert_eq!(
+            balance_pointer(&mut AtomicPointer::default(), &who, &what).get_value::<u128>(),
+            16
+        );
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_storage_at_height() -> Result<()> {
+        clear();
+        let mut alkane = IndexPointer::from_keyword("/alkanes/").select(&vec![2, 1]);
+        let key = b"/slot".to_vec();
+        let write = |alkane: &mut IndexPointer, height: u64, value: &[u8]| {
+            versioned::set_height(height);
+            let mut map = StorageMap::default();
+            map.set(&key, value);
+            pipe_storagemap_to(&map, alkane);
+        };
+        write(&mut alkane, 10, b"a");
+        write(&mut alkane, 20, b"b");
+
+        let slot = alkane.keyword("/storage/").select(&key);
+        assert_eq!(
+            versioned::get_at(&slot, 9).as_ref().clone(),
+            Vec::<u8>::new()
+        );
+        assert_eq!(versioned::get_at(&slot, 15).as_ref().clone(), b"a".to_vec());
+        assert_eq!(versioned::get_at(&slot, 20).as_ref().clone(), b"b".to_vec());
+        // a simulation at a past height shadows the historical value without
+        // disturbing the entries after it, and leaves nothing behind
+        versioned::set_view_height(15);
+        let mut atomic = AtomicPointer::default();
+        atomic.checkpoint();
+        let mut simulated = atomic.derive(&slot);
+        versioned::set(&mut simulated, Arc::new(b"c".to_vec()));
+        assert_eq!(versioned::get(&simulated).as_ref().clone(), b"c".to_vec());
+        assert_eq!(
+            versioned::get_at(&simulated, 20).as_ref().clone(),
+            b"b".to_vec()
+        );
+        atomic.rollback();
+        assert_eq!(versioned::get(&slot).as_ref().clone(), b"a".to_vec());
+        versioned::set_height(21);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_value_written_before_history() -> Result<()> {
+        clear();
+        let mut ptr = IndexPointer::from_keyword("/unversioned");
+        ptr.set(Arc::new(b"old".to_vec()));
+        versioned::begin_block(30);
+        versioned::set(&mut ptr, Arc::new(b"new".to_vec()));
+        assert_eq!(
+            versioned::get_at(&ptr, 29).as_ref().clone(),
+            b"old".to_vec()
+        );
+        assert_eq!(
+            versioned::get_at(&ptr, 30).as_ref().clone(),
+            b"new".to_vec()
+        );
+        versioned::rollback(30);
+        assert_eq!(ptr.get().as_ref().clone(), b"old".to_vec());
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_requested_view_height() -> Result<()> {
+        clear();
+        let who = AlkaneId { block: 2, tx: 1 };
+        let what = AlkaneId { block: 2, tx: 2 };
+        versioned::begin_block(10);
+        credit(&who, &what, 10)?;
+        versioned::begin_block(11);
+        credit(&who, &what, 5)?;
+        let balance = || {
+            versioned::get_value::<_, u128>(&balance_pointer(
+                &mut AtomicPointer::default(),
+                &who,
+                &what,
+            ))
+        };
+        // requests without a height, or at or past the tip, read the tip
+        for height in [0, 11, 12] {
+            versioned::set_requested_view_height(height);
+            assert_eq!(versioned::get_view_height(), None);
+            assert_eq!(balance(), 15);
+        }
+        versioned::set_requested_view_height(10);
+        assert_eq!(versioned::get_view_height(), Some(10));
+        assert_eq!(balance(), 10);
+        versioned::set_height(12);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_inventory_rolls_back_with_message() -> Result<()> {
+        clear();
+        let who = AlkaneId { block: 2, tx: 1 };
+        let what = AlkaneId { block: 2, tx: 2 };
+        let mut atomic = AtomicPointer::default();
+        atomic.checkpoint();
+        // the second credit touches a nonzero balance, which lists the alkane
+        for _ in 0..2 {
+            credit_balances(
+                &mut atomic,
+                &who,
+                &vec![RuneTransfer {
+                    id: what.clone().into(),
+                    value: 1,
+                }],
+            )?;
+        }
+        assert_eq!(atomic.derive(&alkane_inventory_pointer(&who)).length(), 1);
+        atomic.rollback();
+        assert_eq!(alkane_inventory_pointer(&who).length(), 0);
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn test_simulate_before_deployment() -> Result<()> {
+        clear();
+        let deploy = alkane_helpers::init_with_multiple_cellpacks_with_tx(
+            [alkanes_std_test_build::get_bytes()].into(),
+            [Cellpack {
+                target: AlkaneId { block: 1, tx: 0 },
+                inputs: vec![5],
+            }]
+            .into(),
+        );
+        index_block(&deploy, 840_000)?;
+        let mut parcel = MessageContextParcel::new();
+        parcel.height = 840_001;
+        parcel.calldata = Cellpack {
+            target: AlkaneId { block: 2, tx: 1 },
+            inputs: vec![5],
+        }
+        .encipher();
+        let parcel = view::parcel_from_protobuf(parcel)?;
+        assert!(view::simulate_safe(&parcel, u64::MAX).is_ok());
+        // before its deployment neither the binary nor the sequence covering it
+        // are visible
+        versioned::set_view_height(839_999);
+        assert!(view::simulate_safe(&parcel, u64::MAX).is_err());
+        versioned::set_height(840_001);
+        Ok(())
+    }
+}
@@ -5,21 +5,43 @@ use anyhow::Result;
 use bitcoin::OutPoint;
 use metashrew_support::index_pointer::KeyValuePointer;
 use metashrew_support::utils::consensus_encode;
+use once_cell::sync::Lazy;
 use protobuf::Message;
-use std::sync::Arc;
+use protorune::versioned;
+use std::sync::{Arc, Mutex};
 #[allow(unused_imports)]
 use {
     metashrew_core::{println, stdio::stdout},
     std::fmt::Write,
 };
 
+// while set, traces are kept here instead of being written to the index
+static CAPTURED: Lazy<Mutex<Option<Vec<(OutPoint, Trace)>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Collects the traces of the messages run from here on in memory, until
+/// `take_traces` is called, rather than saving them.
+pub fn capture_traces() {
+    *CAPTURED.lock().unwrap() = Some(vec![]);
+}
+
+/// The traces collected since `capture_traces`, in the order they were made.
+/// Traces are saved to the index again afterwards.
+pub fn take_traces() -> Vec<(OutPoint, Trace)> {
+    CAPTURED.lock().unwrap().take().unwrap_or_default()
+}
+
 pub fn save_trace(outpoint: &OutPoint, height: u64, trace: Trace) -> Result<()> {
+    if let Some(captured) = CAPTURED.lock().unwrap().as_mut() {
+        captured.push((outpoint.clone(), trace));
+        return Ok(());
+    }
     let buffer: Vec<u8> = consensus_encode::<OutPoint>(outpoint)?;
-    TRACES.select(&buffer).set(Arc::<Vec<u8>>::new(
-        <Trace as Into<proto::alkanes::AlkanesTrace>>::into(trace).write_to_bytes()?,
-    ));
-    TRACES_BY_HEIGHT
-        .select_value(height)
-        .append(Arc::new(buffer));
+    versioned::set(
+        &mut TRACES.select(&buffer),
+        Arc::<Vec<u8>>::new(
+            <Trace as Into<proto::alkanes::AlkanesTrace>>::into(trace).write_to_bytes()?,
+        ),
+    );
+    versioned::append(&TRACES_BY_HEIGHT.select_value(height), Arc::new(buffer));
     Ok(())
 }
@@ -1,6 +1,8 @@
+use crate::message::AlkaneMessageContext;
 use alkanes_support::id::AlkaneId;
 use alkanes_support::parcel::AlkaneTransferParcel;
 use alkanes_support::storage::StorageMap;
+use alkanes_support::trace::StorageWrite;
 use alkanes_support::utils::overflow_error;
 use anyhow::{anyhow, Result};
 use bitcoin::OutPoint;
@@ -11,6 +13,8 @@ use metashrew_core::{
     stdio::{stdout, Write},
 };
 use metashrew_support::index_pointer::KeyValuePointer;
+use protorune::message::MessageContext;
+use protorune::{supply, versioned};
 use protorune_support::rune_transfer::RuneTransfer;
 use protorune_support::utils::consensus_decode;
 use std::io::Cursor;
@@ -36,12 +40,38 @@ pub fn balance_pointer(
         .select(&what_bytes)
         .keyword("/balances/")
         .select(&who_bytes);
-    if ptr.get().len() != 0 {
-        alkane_inventory_pointer(who).append(Arc::new(what_bytes));
+    if versioned::get(&ptr).len() != 0 {
+        // the inventory lists each alkane once, from the first time it is held;
+        // both are written through `atomic` so a reverted message leaves neither
+        let mut held = atomic.derive(
+            &IndexPointer::from_keyword("/alkanes")
+                .select(&who_bytes)
+                .keyword("/inventory_held/")
+                .select(&what_bytes),
+        );
+        if versioned::get(&held).len() == 0 {
+            versioned::set(&mut held, Arc::new(vec![0x01]));
+            versioned::append(
+                &atomic.derive(&alkane_inventory_pointer(who)),
+                Arc::new(what_bytes),
+            );
+        }
     }
     ptr
 }
 
+/// Balance of `what` held by `who`, read without touching the inventory of `who`.
+pub fn balance_of(who: &AlkaneId, what: &AlkaneId) -> u128 {
+    let who_bytes: Vec<u8> = who.clone().into();
+    let what_bytes: Vec<u8> = what.clone().into();
+    versioned::get_value::<_, u128>(
+        &IndexPointer::from_keyword("/alkanes/")
+            .select(&what_bytes)
+            .keyword("/balances/")
+            .select(&who_bytes),
+    )
+}
+
 pub fn alkane_inventory_pointer(who: &AlkaneId) -> IndexPointer {
     let who_bytes: Vec<u8> = who.clone().into();
     let ptr = IndexPointer::from_keyword("/alkanes")
@@ -64,6 +94,15 @@ pub fn alkane_id_to_outpoint(alkane_id: &AlkaneId) -> Result<OutPoint> {
     Ok(outpoint)
 }
 
+// an alkane sending more of itself than it holds mints the difference
+fn record_mint(atomic: &AtomicPointer, id: &AlkaneId, amount: u128) -> Result<()> {
+    supply::record_mint(
+        &atomic.derive(&supply::supply_pointer(AlkaneMessageContext::protocol_tag())),
+        &id.clone().into(),
+        amount,
+    )
+}
+
 pub fn credit_balances(
     atomic: &mut AtomicPointer,
     to: &AlkaneId,
@@ -71,12 +110,12 @@ pub fn credit_balances(
 ) -> Result<()> {
     for rune in runes.clone() {
         let mut ptr = balance_pointer(atomic, to, &rune.id.clone().into());
-        ptr.set_value::<u128>(
-            rune.value
-                .checked_add(ptr.get_value::<u128>())
-                .ok_or("")
-                .map_err(|_| anyhow!("balance overflow during credit_balances"))?,
-        );
+        let balance = rune
+            .value
+            .checked_add(versioned::get_value::<_, u128>(&ptr))
+            .ok_or("")
+            .map_err(|_| anyhow!("balance overflow during credit_balances"))?;
+        versioned::set_value::<_, u128>(&mut ptr, balance);
     }
     Ok(())
 }
@@ -88,7 +127,7 @@ pub fn debit_balances(
 ) -> Result<()> {
     for rune in runes.0.clone() {
         let mut pointer = balance_pointer(atomic, to, &rune.id.clone().into());
-        let pointer_value = pointer.get_value::<u128>();
+        let pointer_value = versioned::get_value::<_, u128>(&pointer);
         let v = {
             // NOTE: we intentionally allow alkanes to mint an infinite amount of themselves
             // It is up to the contract creator to ensure that this functionality is not abused.
@@ -96,13 +135,16 @@ pub fn debit_balances(
             if *to == rune.id {
                 match pointer_value.checked_sub(rune.value) {
                     Some(value) => value,
-                    None => pointer_value,
+                    None => {
+                        record_mint(atomic, &rune.id, rune.value - pointer_value)?;
+                        pointer_value
+                    }
                 }
             } else {
                 overflow_error(pointer_value.checked_sub(rune.value))?
             }
         };
-        pointer.set_value::<u128>(v);
+        versioned::set_value::<_, u128>(&mut pointer, v);
     }
     Ok(())
 }
@@ -116,26 +158,43 @@ pub fn transfer_from(
     for transfer in &parcel.0 {
         let mut from_pointer =
             balance_pointer(atomic, &from.clone().into(), &transfer.id.clone().into());
-        let mut balance = from_pointer.get_value::<u128>();
+        let mut balance = versioned::get_value::<_, u128>(&from_pointer);
         if balance < transfer.value {
             if &transfer.id == from {
+                record_mint(atomic, &transfer.id, transfer.value - balance)?;
                 balance = transfer.value;
             } else {
                 return Err(anyhow!("balance underflow during transfer_from"));
             }
         }
-        from_pointer.set_value::<u128>(balance - transfer.value);
+        versioned::set_value::<_, u128>(&mut from_pointer, balance - transfer.value);
         let mut to_pointer =
             balance_pointer(atomic, &to.clone().into(), &transfer.id.clone().into());
-        to_pointer.set_value::<u128>(to_pointer.get_value::<u128>() + transfer.value);
+        let to_balance = versioned::get_value::<_, u128>(&to_pointer);
+        versioned::set_value::<_, u128>(&mut to_pointer, to_balance + transfer.value);
     }
     Ok(())
 }
+/// The writes `pipe_storagemap_to` would make under `pointer`, with the values
+/// they replace.
+pub fn storage_writes<T: KeyValuePointer>(map: &StorageMap, pointer: &T) -> Vec<StorageWrite> {
+    map.0
+        .iter()
+        .map(|(k, v)| StorageWrite {
+            key: k.clone(),
+            old_value: versioned::get(&pointer.keyword("/storage/").select(k))
+                .as_ref()
+                .clone(),
+            new_value: v.clone(),
+        })
+        .collect()
+}
+
 pub fn pipe_storagemap_to<T: KeyValuePointer>(map: &StorageMap, pointer: &mut T) {
     map.0.iter().for_each(|(k, v)| {
-        pointer
-            .keyword("/storage/")
-            .select(k)
-            .set(Arc::new(v.clone()));
+        versioned::set(
+            &mut pointer.keyword("/storage/").select(k),
+            Arc::new(v.clone()),
+        );
     });
 }
@@ -1,11 +1,19 @@
+use crate::abi;
+use crate::events;
 use crate::message::AlkaneMessageContext;
 use crate::network::set_view_mode;
-use crate::tables::{TRACES, TRACES_BY_HEIGHT};
+use crate::notifications::{outpoint_to_proto, sheet_to_proto};
+use crate::registry;
+use crate::tables::{NOTIFICATIONS_BY_HEIGHT, TRACES, TRACES_BY_HEIGHT};
+use crate::trace::{capture_traces, take_traces};
 use crate::utils::{
-    alkane_id_to_outpoint, alkane_inventory_pointer, balance_pointer, credit_balances,
+    alkane_id_to_outpoint, alkane_inventory_pointer, balance_of, balance_pointer, credit_balances,
     debit_balances, pipe_storagemap_to,
 };
+use crate::vm::fuel::{FuelTank, VirtualFuelBytes};
 use crate::vm::instance::AlkanesInstance;
+use crate::vm::profile::{FuelProfile, FuelProfiler};
+use crate::vm::revert::revert_data;
 use crate::vm::runtime::AlkanesRuntimeContext;
 use crate::vm::utils::{prepare_context, run_after_special, run_special_cellpacks};
 use alkanes_support::cellpack::Cellpack;
@@ -14,9 +22,13 @@ use alkanes_support::parcel::AlkaneTransfer;
 use alkanes_support::proto;
 use alkanes_support::proto::alkanes::{
     AlkaneIdToOutpointRequest, AlkaneIdToOutpointResponse, AlkaneInventoryRequest,
-    AlkaneInventoryResponse,
+    AlkaneInventoryResponse, AlkaneRegistryResponse, AlkanesByBytecodeHashRequest,
+    EstimateFuelRequest, EstimateFuelResponse, EventsByAlkaneRequest, EventsResponse,
+    ListAlkanesRequest, SimulateTransactionRequest, SimulateTransactionResponse,
 };
 use alkanes_support::response::ExtendedCallResponse;
+use alkanes_support::trace::{Trace, TraceContext, TraceEvent, TraceResponse};
+use alkanes_support::utils::field_or_default;
 use anyhow::{anyhow, Result};
 use bitcoin::blockdata::transaction::Version;
 use bitcoin::consensus::encode::serialize;
@@ -28,11 +40,14 @@ use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
 #[allow(unused_imports)]
 use metashrew_core::{println, stdio::stdout};
 use metashrew_support::{index_pointer::KeyValuePointer, utils::consensus_encode};
+use ordinals::{Artifact, Runestone};
 use protobuf::{Message, MessageField};
-use protorune::balance_sheet::MintableDebit;
+use protorune::balance_sheet::{clear_balances, load_sheet, MintableDebit, PersistentRecord};
 use protorune::message::{MessageContext, MessageContextParcel};
-use protorune::tables::RUNES;
+use protorune::tables::{RuneTable, RUNES};
+use protorune::versioned;
 use protorune::view;
+use protorune::Protorune;
 use protorune_support::balance_sheet::ProtoruneRuneId;
 use protorune_support::balance_sheet::{BalanceSheet, BalanceSheetOperations};
 use protorune_support::rune_transfer::RuneTransfer;
@@ -43,12 +58,26 @@ use std::fmt::Write;
 use std::io::Cursor;
 use std::sync::{Arc, LazyLock, Mutex};
 
-pub fn parcels_from_protobuf(v: proto::alkanes::MultiSimulateRequest) -> Vec<MessageContextParcel> {
+pub fn parcels_from_protobuf(
+    v: proto::alkanes::MultiSimulateRequest,
+) -> Result<Vec<MessageContextParcel>> {
     v.parcels.into_iter().map(parcel_from_protobuf).collect()
 }
 
-pub fn parcel_from_protobuf(v: proto::alkanes::MessageContextParcel) -> MessageContextParcel {
+pub fn parcel_from_protobuf(
+    v: proto::alkanes::MessageContextParcel,
+) -> Result<MessageContextParcel> {
+    parcel_from_protobuf_with_atomic(v, AtomicPointer::default())
+}
+
+/// Builds the parcel over `atomic`, which is where its state overrides are
+/// written.
+pub fn parcel_from_protobuf_with_atomic(
+    v: proto::alkanes::MessageContextParcel,
+    atomic: AtomicPointer,
+) -> Result<MessageContextParcel> {
     let mut result = MessageContextParcel::default();
+    result.atomic = atomic;
     result.height = v.height;
     result.block = if v.block.len() > 0 {
         consensus_decode::<Block>(&mut Cursor::new(v.block)).unwrap()
@@ -72,7 +101,52 @@ pub fn parcel_from_protobuf(v: proto::alkanes::MessageContextParcel) -> MessageC
         .collect::<Vec<RuneTransfer>>();
     result.pointer = v.pointer;
     result.refund_pointer = v.refund_pointer;
-    result
+    apply_state_overrides(&mut result.atomic, &v.overrides)?;
+    Ok(result)
+}
+
+/// Writes `overrides` into the uncommitted `atomic` layer a simulation runs on,
+/// so it sees the replaced bytecode, storage slots and balances. The layer is
+/// dropped with the parcel and none of it reaches the index.
+pub fn apply_state_overrides(
+    atomic: &mut AtomicPointer,
+    overrides: &proto::alkanes::StateOverrides,
+) -> Result<()> {
+    for entry in overrides.alkanes.iter() {
+        let id: AlkaneId = entry
+            .id
+            .clone()
+            .into_option()
+            .ok_or_else(|| anyhow!("state override has no alkane id"))?
+            .into();
+        let mut pointer =
+            atomic.derive(&IndexPointer::from_keyword("/alkanes/").select(&id.clone().into()));
+        if entry.bytecode.len() > 0 {
+            versioned::set(&mut pointer, Arc::new(entry.bytecode.clone()));
+        }
+        for pair in entry.storage.iter() {
+            versioned::set(
+                &mut pointer.keyword("/storage/").select(&pair.key),
+                Arc::new(pair.value.clone()),
+            );
+        }
+        for transfer in entry.balances.iter() {
+            let what: AlkaneId = transfer
+                .id
+                .clone()
+                .into_option()
+                .ok_or_else(|| anyhow!("balance override of {}:{} has no id", id.block, id.tx))?
+                .into();
+            let value: u128 = transfer
+                .value
+                .clone()
+                .into_option()
+                .ok_or_else(|| anyhow!("balance override of {}:{} has no value", id.block, id.tx))?
+                .into();
+            versioned::set_value::<_, u128>(&mut balance_pointer(atomic, &id, &what), value);
+        }
+    }
+    Ok(())
 }
 
 fn default_transaction() -> Transaction {
@@ -158,14 +232,15 @@ pub fn get_statics(id: &AlkaneId) -> (String, String) {
     }
 
     // If not in cache, fetch the values
+    // this also runs while indexing, so a name that is not utf8 must not panic
     let name = call_view(id, &vec![NAME_OPCODE], STATIC_FUEL)
-        .and_then(|v| Ok(String::from_utf8(v)))
-        .unwrap_or_else(|_| Ok(String::from("{REVERT}")))
-        .unwrap();
+        .ok()
+        .and_then(|v| String::from_utf8(v).ok())
+        .unwrap_or_else(|| String::from("{REVERT}"));
     let symbol = call_view(id, &vec![SYMBOL_OPCODE], STATIC_FUEL)
-        .and_then(|v| Ok(String::from_utf8(v)))
-        .unwrap_or_else(|_| Ok(String::from("{REVERT}")))
-        .unwrap();
+        .ok()
+        .and_then(|v| String::from_utf8(v).ok())
+        .unwrap_or_else(|| String::from("{REVERT}"));
 
     // Store in cache
     if let Ok(mut cache) = STATICS_CACHE.lock() {
@@ -349,34 +424,108 @@ pub fn alkanes_id_to_outpoint(input: &Vec<u8>) -> Result<AlkaneIdToOutpointRespo
     return Ok(response);
 }
 
+pub fn events_by_alkane(input: &Vec<u8>) -> Result<EventsResponse> {
+    let request = EventsByAlkaneRequest::parse_from_bytes(input)?;
+    let mut response = EventsResponse::new();
+    (response.events, response.next_cursor) = events::events_by_alkane(
+        &field_or_default(request.id),
+        request.from_height,
+        request.to_height,
+        &request.cursor,
+        request.limit,
+    )?;
+    Ok(response)
+}
+
+pub fn list_alkanes(input: &Vec<u8>) -> Result<AlkaneRegistryResponse> {
+    registry::list_alkanes(&ListAlkanesRequest::parse_from_bytes(input)?)
+}
+
+pub fn alkanes_by_bytecode_hash(input: &Vec<u8>) -> Result<AlkaneRegistryResponse> {
+    registry::alkanes_by_bytecode_hash(&AlkanesByBytecodeHashRequest::parse_from_bytes(input)?)
+}
+
+// holder queries default to the alkanes protocol
+fn alkanes_holders_request(input: &Vec<u8>) -> Result<Vec<u8>> {
+    let mut request = protorune_support::proto::protorune::HoldersRequest::parse_from_bytes(input)?;
+    if request.protocol_tag.is_none() {
+        request.protocol_tag = MessageField::some(AlkaneMessageContext::protocol_tag().into());
+    }
+    Ok(request.write_to_bytes()?)
+}
+
+pub fn holders_by_alkane(
+    input: &Vec<u8>,
+) -> Result<protorune_support::proto::protorune::HoldersResponse> {
+    view::holders_by_rune(&alkanes_holders_request(input)?)
+}
+
+pub fn holder_count(
+    input: &Vec<u8>,
+) -> Result<protorune_support::proto::protorune::HolderCountResponse> {
+    view::holder_count(&alkanes_holders_request(input)?)
+}
+
+pub fn supply_by_id(
+    input: &Vec<u8>,
+) -> Result<protorune_support::proto::protorune::SupplyResponse> {
+    let mut request = protorune_support::proto::protorune::SupplyRequest::parse_from_bytes(input)?;
+    if request.protocol_tag.is_none() {
+        request.protocol_tag = MessageField::some(AlkaneMessageContext::protocol_tag().into());
+    }
+    view::supply_by_id(&request.write_to_bytes()?)
+}
+
+/// Balances held by `req.id`, in the order the alkanes were first received or
+/// reversed with `descending`. Pages are resumed with `cursor` and hold at most
+/// `limit` entries when one is given.
 pub fn alkane_inventory(req: &AlkaneInventoryRequest) -> Result<AlkaneInventoryResponse> {
     let mut result: AlkaneInventoryResponse = AlkaneInventoryResponse::new();
-    let alkane_inventory = alkane_inventory_pointer(&req.id.clone().unwrap().into());
-    result.alkanes = alkane_inventory
-        .get_list()
-        .into_iter()
-        .map(|alkane_held| -> proto::alkanes::AlkaneTransfer {
-            let id = alkanes_support::id::AlkaneId::parse(&mut Cursor::new(
-                alkane_held.as_ref().clone(),
+    let owner: AlkaneId = req
+        .id
+        .clone()
+        .into_option()
+        .ok_or_else(|| anyhow!("AlkaneInventoryRequest missing id"))?
+        .into();
+    let filter: Option<AlkaneId> = req.alkane.clone().into_option().map(|v| v.into());
+    let min_balance = std::cmp::max(
+        req.min_balance
+            .clone()
+            .into_option()
+            .map_or(0u128, |v| v.into()),
+        1,
+    );
+    let pagination = protorune_support::proto::protorune::PaginationInput {
+        cursor: req.cursor.clone(),
+        limit: req.limit,
+        ..Default::default()
+    };
+    (result.alkanes, result.next_cursor) = view::paginate(
+        &alkane_inventory_pointer(&owner),
+        &MessageField::some(pagination),
+        req.descending,
+        |alkane_held| -> Result<Option<proto::alkanes::AlkaneTransfer>> {
+            let id = AlkaneId::parse(&mut Cursor::new(alkane_held.as_ref().clone()))?;
+            if filter.as_ref().map_or(false, |v| *v != id) {
+                return Ok(None);
+            }
+            let balance = balance_of(&owner, &id);
+            if balance < min_balance {
+                return Ok(None);
+            }
+            Ok(Some(
+                (AlkaneTransfer {
+                    id: id,
+                    value: balance,
+                })
+                .into(),
             ))
-            .unwrap();
-            let balance_pointer = balance_pointer(
-                &mut AtomicPointer::default(),
-                &req.id.clone().unwrap().into(),
-                &id,
-            );
-            let balance = balance_pointer.get_value::<u128>();
-            (AlkaneTransfer {
-                id: id,
-                value: balance,
-            })
-            .into()
-        })
-        .collect::<Vec<proto::alkanes::AlkaneTransfer>>();
+        },
+    )?;
     Ok(result)
 }
 
-pub fn traceblock(height: u32) -> Result<Vec<u8>> {
+pub fn block_events(height: u32) -> Result<Vec<proto::alkanes::AlkanesBlockEvent>> {
     let mut block_events: Vec<proto::alkanes::AlkanesBlockEvent> = vec![];
     for outpoint in TRACES_BY_HEIGHT.select_value(height as u64).get_list() {
         let op = outpoint.clone().to_vec();
@@ -397,15 +546,27 @@ pub fn traceblock(height: u32) -> Result<Vec<u8>> {
         };
         block_events.push(block_event);
     }
+    Ok(block_events)
+}
 
+pub fn traceblock(height: u32) -> Result<Vec<u8>> {
     let result = proto::alkanes::AlkanesBlockTraceEvent {
-        events: block_events,
+        events: block_events(height)?,
         ..Default::default()
     };
 
     result.write_to_bytes().map_err(|e| anyhow!("{:?}", e))
 }
 
+/// The notification stored for `height` when notifications go to the queue.
+pub fn block_notification(height: u64) -> Result<Vec<u8>> {
+    Ok(NOTIFICATIONS_BY_HEIGHT
+        .select_value(height)
+        .get()
+        .as_ref()
+        .clone())
+}
+
 pub fn trace(outpoint: &OutPoint) -> Result<Vec<u8>> {
     Ok(TRACES
         .select(&consensus_encode::<OutPoint>(&outpoint)?)
@@ -438,6 +599,44 @@ pub fn meta_safe(parcel: &MessageContextParcel) -> Result<Vec<u8>> {
 pub fn simulate_parcel(
     parcel: &MessageContextParcel,
     fuel: u64,
+) -> Result<(ExtendedCallResponse, u64)> {
+    simulate_parcel_with_trace(parcel, fuel, &Trace::default())
+}
+
+/// Same as `simulate_parcel`, recording the call in `trace` the way the indexer
+/// would for a message.
+pub fn simulate_parcel_with_trace(
+    parcel: &MessageContextParcel,
+    fuel: u64,
+    trace: &Trace,
+) -> Result<(ExtendedCallResponse, u64)> {
+    run_simulation(parcel, fuel, trace, None)
+}
+
+/// Same as `simulate_parcel`, also returning where the fuel of the call and of
+/// every frame under it went.
+pub fn simulate_parcel_profiled(
+    parcel: &MessageContextParcel,
+    fuel: u64,
+) -> (Result<(ExtendedCallResponse, u64)>, Option<FuelProfile>) {
+    let profiler = FuelProfiler::default();
+    let result = run_simulation(parcel, fuel, &Trace::default(), Some(profiler.clone()));
+    (result, profiler.finish())
+}
+
+pub fn simulate_profiled_safe(
+    parcel: &MessageContextParcel,
+    fuel: u64,
+) -> (Result<(ExtendedCallResponse, u64)>, Option<FuelProfile>) {
+    set_view_mode();
+    simulate_parcel_profiled(parcel, fuel)
+}
+
+fn run_simulation(
+    parcel: &MessageContextParcel,
+    fuel: u64,
+    trace: &Trace,
+    profiler: Option<FuelProfiler>,
 ) -> Result<(ExtendedCallResponse, u64)> {
     let list = decode_varint_list(&mut Cursor::new(parcel.calldata.clone()))?;
     let cellpack: Cellpack = list.clone().try_into()?;
@@ -445,24 +644,58 @@ pub fn simulate_parcel(
     let context = Arc::new(Mutex::new(AlkanesRuntimeContext::from_parcel_and_cellpack(
         parcel, &cellpack,
     )));
+    context.lock().unwrap().trace = trace.clone();
+    context.lock().unwrap().profiler = profiler;
     let mut atomic = parcel.atomic.derive(&IndexPointer::default());
-    let (caller, myself, binary) = run_special_cellpacks(context.clone(), &cellpack)?;
+    let (caller, myself, binary) =
+        run_special_cellpacks(context.clone(), &cellpack).map_err(|e| {
+            let mut response = ExtendedCallResponse::default();
+            response.data = revert_data(&e);
+            trace.clock(TraceEvent::RevertContext(response.into()));
+            e
+        })?;
     credit_balances(&mut atomic, &myself, &parcel.runes)?;
     prepare_context(context.clone(), &caller, &myself, false);
-    let (response, gas_used) = run_after_special(context.clone(), binary, fuel)?;
-    pipe_storagemap_to(
-        &response.storage,
-        &mut atomic.derive(&IndexPointer::from_keyword("/alkanes/").select(&myself.clone().into())),
-    );
-    let mut combined = parcel.runtime_balances.as_ref().clone();
-    <BalanceSheet<AtomicPointer> as TryFrom<Vec<RuneTransfer>>>::try_from(parcel.runes.clone())?
-        .pipe(&mut combined)?;
-    let sheet = <BalanceSheet<AtomicPointer> as TryFrom<Vec<RuneTransfer>>>::try_from(
-        response.alkanes.clone().into(),
-    )?;
-    combined.debit_mintable(&sheet, &mut atomic)?;
-    debit_balances(&mut atomic, &myself, &response.alkanes)?;
-    Ok((response, gas_used))
+    let inner = context.lock().unwrap().flat();
+    trace.clock(TraceEvent::EnterCall(TraceContext {
+        inner,
+        target: cellpack.target.clone(),
+        fuel,
+    }));
+    run_after_special(context.clone(), binary, fuel)
+        .and_then(|(response, gas_used)| {
+            pipe_storagemap_to(
+                &response.storage,
+                &mut atomic.derive(
+                    &IndexPointer::from_keyword("/alkanes/").select(&myself.clone().into()),
+                ),
+            );
+            let mut combined = parcel.runtime_balances.as_ref().clone();
+            <BalanceSheet<AtomicPointer> as TryFrom<Vec<RuneTransfer>>>::try_from(
+                parcel.runes.clone(),
+            )?
+            .pipe(&mut combined)?;
+            let sheet = <BalanceSheet<AtomicPointer> as TryFrom<Vec<RuneTransfer>>>::try_from(
+                response.alkanes.clone().into(),
+            )?;
+            combined.debit_mintable(&sheet, &mut atomic)?;
+            debit_balances(&mut atomic, &myself, &response.alkanes)?;
+            let frame = context.lock().unwrap().frame.clone();
+            trace.clock(TraceEvent::ReturnContext(TraceResponse::new(
+                response.clone(),
+                frame,
+            )));
+            Ok((response, gas_used))
+        })
+        .or_else(|e| {
+            let mut response = ExtendedCallResponse::default();
+            response.data = revert_data(&e);
+            let frame = context.lock().unwrap().frame.clone();
+            trace.clock(TraceEvent::RevertContext(TraceResponse::new(
+                response, frame,
+            )));
+            Err(e)
+        })
 }
 
 pub fn multi_simulate(
@@ -484,6 +717,230 @@ pub fn multi_simulate_safe(
     multi_simulate(parcels, fuel)
 }
 
+/// Runs `parcels` in order over one uncommitted `AtomicPointer`, so each step sees
+/// the storage, balances and deployments left by the steps before it, and alkanes
+/// it creates get the ids `/alkanes/sequence` would hand out next. The state
+/// overrides of a step are applied when it starts. A step that fails is rolled
+/// back, overrides included, and the remaining steps still run.
+pub fn sequential_simulate(
+    parcels: Vec<proto::alkanes::MessageContextParcel>,
+    fuel: u64,
+) -> Vec<(Result<(ExtendedCallResponse, u64)>, Trace)> {
+    let mut atomic = AtomicPointer::default();
+    parcels
+        .into_iter()
+        .map(|v| {
+            atomic.checkpoint();
+            let trace = Trace::default();
+            let result = parcel_from_protobuf_with_atomic(v, atomic.clone())
+                .and_then(|parcel| simulate_parcel_with_trace(&parcel, fuel, &trace));
+            if result.is_ok() {
+                atomic.commit();
+            } else {
+                atomic.rollback();
+            }
+            (result, trace)
+        })
+        .collect()
+}
+
+pub fn sequential_simulate_safe(
+    parcels: Vec<proto::alkanes::MessageContextParcel>,
+    fuel: u64,
+) -> Vec<(Result<(ExtendedCallResponse, u64)>, Trace)> {
+    set_view_mode();
+    sequential_simulate(parcels, fuel)
+}
+
+// Indexes `tx` on `atomic`, which the caller never commits, the way a block
+// holding only `tx` at `height` would index it: input sheets come from the index,
+// the runestone's edicts and protoburns are applied and each protostone's outputs
+// feed the next. Returns the outcome with the traces of the messages run.
+fn index_alone(
+    atomic: &mut AtomicPointer,
+    tx: &Transaction,
+    runestone: &Runestone,
+    height: u64,
+    txindex: u32,
+) -> (Result<()>, Vec<(OutPoint, Trace)>) {
+    let mut block = default_block();
+    block.txdata = vec![tx.clone()];
+    FuelTank::initialize(&block);
+    capture_traces();
+    let indexed = Protorune::get_runestone_output_index(tx).and_then(|runestone_output_index| {
+        Protorune::index_runestone::<AlkaneMessageContext>(
+            atomic,
+            tx,
+            runestone,
+            height,
+            txindex,
+            &block,
+            runestone_output_index,
+        )
+    });
+    (indexed, take_traces())
+}
+
+/// Indexes `req.transaction` as `simulate_transaction` does and compares the
+/// fuel its messages use with what `FuelTank` would allocate the transaction as
+/// the first one in a block of `req.block_vfsize`. Alone in its block the
+/// transaction is metered all of `TOTAL_FUEL`, so messages run out only where
+/// no allocation could carry them. When any message fails `error` says so and
+/// no padding is given, since the fuel it would have used is unknown.
+pub fn estimate_fuel(req: &EstimateFuelRequest) -> Result<EstimateFuelResponse> {
+    set_view_mode();
+    let transaction = consensus_decode::<Transaction>(&mut Cursor::new(req.transaction.clone()))?;
+    let mut result = EstimateFuelResponse::new();
+    result.vfsize = transaction.vfsize();
+    let Some(Artifact::Runestone(runestone)) = Runestone::decipher(&transaction) else {
+        return Ok(result);
+    };
+    let (indexed, traces) = index_alone(
+        &mut AtomicPointer::default(),
+        &transaction,
+        &runestone,
+        req.height,
+        req.txindex,
+    );
+    if let Err(e) = indexed {
+        result.error = abi::describe_error(&e);
+        return Ok(result);
+    }
+    for (outpoint, trace) in traces {
+        let events = trace.0.lock().unwrap();
+        let mut response = proto::alkanes::SimulateResponse::new();
+        match events.last() {
+            Some(TraceEvent::ReturnContext(exit)) => {
+                response.execution = MessageField::some(exit.inner.clone().into());
+                response.gas_used = exit.fuel_used;
+                result.fuel_needed = result.fuel_needed.saturating_add(exit.fuel_used);
+            }
+            _ => {
+                response.error = abi::trace_error(&events).unwrap_or_default();
+                if result.error.is_empty() {
+                    result.error = format!(
+                        "message at vout {} reverted: {}",
+                        outpoint.vout, response.error
+                    );
+                }
+            }
+        }
+        result.messages.push(response);
+    }
+    if result.messages.is_empty() {
+        return Ok(result);
+    }
+    let block_vfsize = std::cmp::max(req.block_vfsize, result.vfsize);
+    result.fuel_allocated = FuelTank::allocated_fuel(result.vfsize, block_vfsize);
+    if !result.error.is_empty() {
+        return Ok(result);
+    }
+    match FuelTank::padding_for(result.fuel_needed, result.vfsize, block_vfsize) {
+        Some(padding) => result.padding = padding,
+        None => result.error = "transaction needs more fuel than a block holds".to_string(),
+    }
+    Ok(result)
+}
+
+/// Runs the protostones of `req.transaction` through the same pipeline as
+/// indexing, on an atomic pointer that is never committed. The runestone's own
+/// edicts run first, so protoburns carry what the inputs hold. Balances given
+/// in `req.inputs` replace those indexed for the matching inputs. Traces are
+/// collected in memory; the spent inputs are cleared as indexing clears them,
+/// which like any view write is never flushed.
+pub fn simulate_transaction(
+    req: &SimulateTransactionRequest,
+) -> Result<SimulateTransactionResponse> {
+    set_view_mode();
+    let tx = consensus_decode::<Transaction>(&mut Cursor::new(req.transaction.clone()))?;
+    let Some(Artifact::Runestone(runestone)) = Runestone::decipher(&tx) else {
+        return Err(anyhow!("transaction has no runestone"));
+    };
+    let table = RuneTable::for_protocol(AlkaneMessageContext::protocol_tag());
+    let mut atomic = AtomicPointer::default();
+    for input in &req.inputs {
+        let outpoint = tx
+            .input
+            .get(input.input as usize)
+            .ok_or_else(|| anyhow!("no input {}", input.input))?
+            .previous_output;
+        let ptr = atomic.derive(
+            &table
+                .OUTPOINT_TO_RUNES
+                .select(&consensus_encode(&outpoint)?),
+        );
+        clear_balances(&ptr);
+        <BalanceSheet<AtomicPointer> as TryFrom<Vec<RuneTransfer>>>::try_from(
+            input
+                .balances
+                .iter()
+                .map(|v| {
+                    Ok(RuneTransfer {
+                        id: v
+                            .id
+                            .clone()
+                            .into_option()
+                            .ok_or_else(|| anyhow!("balance of input {} has no id", input.input))?
+                            .into(),
+                        value: v
+                            .value
+                            .clone()
+                            .into_option()
+                            .ok_or_else(|| {
+                                anyhow!("balance of input {} has no value", input.input)
+                            })?
+                            .into(),
+                    })
+                })
+                .collect::<Result<Vec<RuneTransfer>>>()?,
+        )?
+        .save(&ptr, false);
+    }
+
+    let mut result = SimulateTransactionResponse::new();
+    let (indexed, traces) = index_alone(&mut atomic, &tx, &runestone, req.height, req.txindex);
+    if let Err(e) = indexed {
+        result.error = abi::describe_error(&e);
+    }
+
+    let txid = tx.compute_txid();
+    for vout in 0..tx.output.len() as u32 {
+        let outpoint = OutPoint::new(txid, vout);
+        let balances = sheet_to_proto(&load_sheet(
+            &atomic.derive(
+                &table
+                    .OUTPOINT_TO_RUNES
+                    .select(&consensus_encode(&outpoint)?),
+            ),
+        ));
+        if !balances.is_empty() {
+            result.outputs.push(proto::alkanes::OutpointBalances {
+                outpoint: MessageField::some(outpoint_to_proto(&outpoint)),
+                balances,
+                ..Default::default()
+            });
+        }
+    }
+    result.runtime_balances = sheet_to_proto(&load_sheet(&atomic.derive(&table.RUNTIME_BALANCE)));
+
+    // every message leaves a trace, reverted or not
+    for (outpoint, events) in traces {
+        if let Some(error) = abi::trace_error(&events.0.lock().unwrap()) {
+            result.reverts.push(proto::alkanes::SimulatedRevert {
+                outpoint: MessageField::some(outpoint_to_proto(&outpoint)),
+                error,
+                ..Default::default()
+            });
+        }
+        result.traces.push(proto::alkanes::Trace {
+            outpoint: MessageField::some(outpoint_to_proto(&outpoint)),
+            trace: MessageField::some(events.into()),
+            ..Default::default()
+        });
+    }
+    Ok(result)
+}
+
 pub fn getbytecode(input: &Vec<u8>) -> Result<Vec<u8>> {
     let request = alkanes_support::proto::alkanes::BytecodeRequest::parse_from_bytes(input)?;
     let alkane_id = request.id.unwrap();
@@ -502,6 +959,26 @@ pub fn getbytecode(input: &Vec<u8>) -> Result<Vec<u8>> {
     }
 }
 
+/// The ABI of an alkane as JSON, in the format `__meta` returns.
+pub fn getabi(input: &Vec<u8>) -> Result<Vec<u8>> {
+    let request = alkanes_support::proto::alkanes::AbiRequest::parse_from_bytes(input)?;
+    let alkane_id = crate::utils::from_protobuf(
+        request
+            .id
+            .into_option()
+            .ok_or_else(|| anyhow!("abi request has no alkane id"))?,
+    );
+    Ok(serde_json::to_vec(&abi::abi_for(&alkane_id)?)?)
+}
+
+/// The trace saved for `outpoint`, decoded against the ABIs of the alkanes it
+/// calls and rendered as JSON.
+pub fn decodetrace(outpoint: &OutPoint) -> Result<Vec<u8>> {
+    let trace: Trace = trace(outpoint)?.try_into()?;
+    let events = trace.0.lock().unwrap().clone();
+    Ok(serde_json::to_vec(&abi::decode_trace(&events))?)
+}
+
 pub fn getblock(input: &Vec<u8>) -> Result<Vec<u8>> {
     use crate::etl;
     use alkanes_support::proto::alkanes::{BlockRequest, BlockResponse};
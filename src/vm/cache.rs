@@ -0,0 +1,294 @@
+use super::{link_host_functions, AlkanesState, COMPILED_SIZE_FACTOR, MODULE_CACHE_BUDGET};
+use alkanes_support::{gz::decompress, id::AlkaneId};
+use anyhow::Result;
+use bitcoin::hashes::{sha256, Hash};
+#[allow(unused_imports)]
+use metashrew_core::{
+    println,
+    stdio::{stdout, Write},
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, LazyLock, Mutex};
+use wasmi::*;
+
+/// Engine shared by every alkane instance. Modules are bound to the engine they
+/// were compiled with, so the cache below only works if all of them use this one.
+pub static ENGINE: LazyLock<Engine> = LazyLock::new(|| {
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    Engine::new(&config)
+});
+
+/// Linker holding the `env` host functions, built once against `ENGINE`.
+pub static LINKER: LazyLock<Linker<AlkanesState>> = LazyLock::new(|| {
+    link_host_functions(&ENGINE).expect("failed to register alkanes host functions")
+});
+
+static MODULE_CACHE: LazyLock<Mutex<ModuleCache>> =
+    LazyLock::new(|| Mutex::new(ModuleCache::new(MODULE_CACHE_BUDGET)));
+
+type PayloadHash = [u8; 32];
+
+fn payload_hash(payload: &[u8]) -> PayloadHash {
+    sha256::Hash::hash(payload).to_byte_array()
+}
+
+struct CachedModule {
+    binary: Arc<Vec<u8>>,
+    module: Module,
+    // alkanes whose current payload has this hash; the entry goes with the last
+    ids: HashSet<AlkaneId>,
+    size: usize,
+    last_used: u64,
+}
+
+/// Estimated memory held by a cached module: the compressed payload, the
+/// decompressed wasm, and the translated code wasmi keeps for it, which runs to
+/// several times the size of the wasm it comes from.
+fn estimated_size(payload: &[u8], binary: &[u8]) -> usize {
+    payload
+        .len()
+        .saturating_add(binary.len().saturating_mul(1 + COMPILED_SIZE_FACTOR))
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ModuleCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub entries: usize,
+    pub size: usize,
+    pub budget: usize,
+}
+
+/// LRU of decompressed and compiled alkane binaries keyed by the hash of the
+/// compressed payload, so clones of one factory share a module and an
+/// overwritten binary never matches the entry of the one it replaced.
+///
+/// The budget is counted against `estimated_size` of each entry.
+pub struct ModuleCache {
+    entries: HashMap<PayloadHash, CachedModule>,
+    // the payload each alkane was last loaded with, to skip hashing it again
+    ids: HashMap<AlkaneId, (Arc<Vec<u8>>, PayloadHash)>,
+    // `Arc::as_ptr` of each cached binary, for `compile`
+    binaries: HashMap<usize, PayloadHash>,
+    size: usize,
+    budget: usize,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl ModuleCache {
+    pub fn new(budget: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            ids: HashMap::new(),
+            binaries: HashMap::new(),
+            size: 0,
+            budget,
+            tick: 0,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+    /// The hash of `payload`, reusing the one recorded for `id` when the
+    /// payload is the same allocation it was last loaded from.
+    fn hash_for(&self, id: &AlkaneId, payload: &Arc<Vec<u8>>) -> PayloadHash {
+        match self.ids.get(id) {
+            Some((last, hash)) if Arc::ptr_eq(last, payload) => *hash,
+            _ => payload_hash(payload),
+        }
+    }
+    fn get(
+        &mut self,
+        id: &AlkaneId,
+        payload: &Arc<Vec<u8>>,
+        hash: PayloadHash,
+    ) -> Option<Arc<Vec<u8>>> {
+        self.tick += 1;
+        let tick = self.tick;
+        let binary = match self.entries.get_mut(&hash) {
+            Some(entry) => {
+                entry.last_used = tick;
+                entry.ids.insert(id.clone());
+                entry.binary.clone()
+            }
+            None => {
+                self.misses += 1;
+                return None;
+            }
+        };
+        self.hits += 1;
+        self.assign(id, payload.clone(), hash);
+        Some(binary)
+    }
+    fn module_for(&self, binary: &Arc<Vec<u8>>) -> Option<Module> {
+        self.binaries
+            .get(&(Arc::as_ptr(binary) as usize))
+            .and_then(|hash| self.entries.get(hash))
+            .map(|entry| entry.module.clone())
+    }
+    /// Points `id` at the entry for `hash`, releasing the one it held before
+    /// if its binary was overwritten since.
+    fn assign(&mut self, id: &AlkaneId, payload: Arc<Vec<u8>>, hash: PayloadHash) {
+        if let Some((_, previous)) = self.ids.insert(id.clone(), (payload, hash)) {
+            if previous != hash {
+                self.release(id, &previous);
+            }
+        }
+    }
+    fn release(&mut self, id: &AlkaneId, hash: &PayloadHash) {
+        let unused = match self.entries.get_mut(hash) {
+            Some(entry) => {
+                entry.ids.remove(id);
+                entry.ids.is_empty()
+            }
+            None => false,
+        };
+        if unused {
+            self.remove(hash);
+        }
+    }
+    fn insert(
+        &mut self,
+        id: AlkaneId,
+        payload: Arc<Vec<u8>>,
+        hash: PayloadHash,
+        binary: Arc<Vec<u8>>,
+        module: Module,
+    ) {
+        let size = estimated_size(&payload, &binary);
+        if size > self.budget {
+            // nothing is cached for it, so neither is the payload it was loaded from
+            self.invalidate(&id);
+            return;
+        }
+        self.assign(&id, payload.clone(), hash);
+        while self.size + size > self.budget {
+            let oldest = match self
+                .entries
+                .iter()
+                .min_by_key(|(_, v)| v.last_used)
+                .map(|(k, _)| *k)
+            {
+                Some(v) => v,
+                None => break,
+            };
+            self.remove(&oldest);
+            self.evictions += 1;
+        }
+        self.tick += 1;
+        self.size += size;
+        self.binaries.insert(Arc::as_ptr(&binary) as usize, hash);
+        self.entries.insert(
+            hash,
+            CachedModule {
+                binary,
+                module,
+                ids: HashSet::from([id]),
+                size,
+                last_used: self.tick,
+            },
+        );
+    }
+    fn remove(&mut self, hash: &PayloadHash) {
+        if let Some(entry) = self.entries.remove(hash) {
+            self.size -= entry.size;
+            self.binaries.remove(&(Arc::as_ptr(&entry.binary) as usize));
+            for id in entry.ids {
+                self.ids.remove(&id);
+            }
+        }
+    }
+    fn invalidate(&mut self, id: &AlkaneId) {
+        if let Some((_, hash)) = self.ids.remove(id) {
+            self.release(id, &hash);
+        }
+    }
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.ids.clear();
+        self.binaries.clear();
+        self.size = 0;
+    }
+    fn stats(&self) -> ModuleCacheStats {
+        ModuleCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            entries: self.entries.len(),
+            size: self.size,
+            budget: self.budget,
+        }
+    }
+}
+
+/// Returns the decompressed binary for `id`, compiling and caching it on a miss.
+/// `payload` is the compressed binary currently stored under `/alkanes/<id>`.
+pub fn load_binary(id: &AlkaneId, payload: Arc<Vec<u8>>) -> Result<Arc<Vec<u8>>> {
+    let hash = match cached(id, &payload) {
+        Ok(binary) => return Ok(binary),
+        Err(hash) => hash,
+    };
+    let binary = Arc::new(decompress(payload.as_ref().clone())?);
+    cache_binary(id, payload, hash, binary.clone());
+    Ok(binary)
+}
+
+/// Like `load_binary`, for a payload the caller has already decompressed, as a
+/// deployment does when it validates the binary.
+pub fn store_binary(id: &AlkaneId, payload: Arc<Vec<u8>>, binary: Vec<u8>) -> Arc<Vec<u8>> {
+    let hash = match cached(id, &payload) {
+        Ok(binary) => return binary,
+        Err(hash) => hash,
+    };
+    let binary = Arc::new(binary);
+    cache_binary(id, payload, hash, binary.clone());
+    binary
+}
+
+// the cached binary for `payload`, or the hash to cache it under
+fn cached(id: &AlkaneId, payload: &Arc<Vec<u8>>) -> std::result::Result<Arc<Vec<u8>>, PayloadHash> {
+    let mut cache = MODULE_CACHE.lock().unwrap();
+    let hash = cache.hash_for(id, payload);
+    cache.get(id, payload, hash).ok_or(hash)
+}
+
+fn cache_binary(id: &AlkaneId, payload: Arc<Vec<u8>>, hash: PayloadHash, binary: Arc<Vec<u8>>) {
+    // a binary that fails to compile is left for `compile` to reject at instantiation
+    if let Ok(module) = Module::new(&ENGINE, &mut &binary[..]) {
+        MODULE_CACHE
+            .lock()
+            .unwrap()
+            .insert(id.clone(), payload, hash, binary, module);
+    }
+    #[cfg(feature = "debug-log")]
+    {
+        println!("module cache: {:?}", module_cache_stats());
+    }
+}
+
+/// Returns the compiled module for a binary handed out by `load_binary`, or
+/// compiles it against the shared engine if it did not come from the cache.
+pub fn compile(binary: &Arc<Vec<u8>>) -> Result<Module> {
+    if let Some(module) = MODULE_CACHE.lock().unwrap().module_for(binary) {
+        return Ok(module);
+    }
+    Ok(Module::new(&ENGINE, &mut &binary[..])?)
+}
+
+/// Drops the cached module for `id`; call whenever `/alkanes/<id>` is written.
+pub fn invalidate(id: &AlkaneId) {
+    MODULE_CACHE.lock().unwrap().invalidate(id);
+}
+
+pub fn clear_module_cache() {
+    MODULE_CACHE.lock().unwrap().clear();
+}
+
+pub fn module_cache_stats() -> ModuleCacheStats {
+    MODULE_CACHE.lock().unwrap().stats()
+}
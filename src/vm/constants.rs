@@ -1 +1,18 @@
 pub(super) const MEMORY_LIMIT: usize = 43554432;
+
+/// Upper bound, in estimated bytes held per cached module, on the module cache.
+pub(super) const MODULE_CACHE_BUDGET: usize = 64 * 1024 * 1024;
+
+/// Bytes of translated code wasmi is estimated to keep per byte of wasm.
+pub(super) const COMPILED_SIZE_FACTOR: usize = 4;
+
+/// Largest decompressed binary accepted when an alkane is deployed.
+pub(super) const MAX_BINARY_SIZE: usize = 4 * 1024 * 1024;
+
+/// Pages every instance memory is grown to before execution, so a module must
+/// allow at least this many.
+pub(super) const MIN_MEMORY_PAGES: u64 = 512;
+
+pub(super) const MAX_MEMORY_PAGES: u64 = (MEMORY_LIMIT / 65536) as u64;
+
+pub(super) const MAX_TABLE_ELEMENTS: u64 = 65536;
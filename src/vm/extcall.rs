@@ -3,6 +3,8 @@ use alkanes_support::trace::{TraceContext, TraceEvent};
 use metashrew_core::index_pointer::AtomicPointer;
 
 pub trait Extcall {
+    /// The host function the call comes in through.
+    fn name() -> &'static str;
     fn isdelegate() -> bool;
     fn isstatic() -> bool;
     fn event(context: TraceContext) -> TraceEvent;
@@ -29,6 +31,9 @@ pub trait Extcall {
 pub struct Call(());
 
 impl Extcall for Call {
+    fn name() -> &'static str {
+        "__call"
+    }
     fn isdelegate() -> bool {
         false
     }
@@ -43,6 +48,9 @@ impl Extcall for Call {
 pub struct Delegatecall(());
 
 impl Extcall for Delegatecall {
+    fn name() -> &'static str {
+        "__delegatecall"
+    }
     fn isdelegate() -> bool {
         true
     }
@@ -57,6 +65,9 @@ impl Extcall for Delegatecall {
 pub struct Staticcall(());
 
 impl Extcall for Staticcall {
+    fn name() -> &'static str {
+        "__staticcall"
+    }
     fn isdelegate() -> bool {
         false
     }
@@ -136,6 +136,41 @@ impl FuelTank {
         });
     }
 
+    /// Share of `block_fuel` a transaction of `txsize` is metered out of a block
+    /// with `size` left to run.
+    pub fn metered_fuel(block_fuel: u64, txsize: u64, size: u64) -> u64 {
+        block_fuel * txsize / size
+    }
+
+    /// Fuel the first transaction run from a full tank is given.
+    pub fn allocated_fuel(txsize: u64, size: u64) -> u64 {
+        if size == 0 {
+            return MINIMUM_FUEL;
+        }
+        std::cmp::max(MINIMUM_FUEL, Self::metered_fuel(TOTAL_FUEL, txsize, size))
+    }
+
+    /// Fewest vbytes to add to a transaction of `txsize` in a block of `size`
+    /// for it to be allocated `needed` fuel, or `None` when no amount of padding
+    /// gets there.
+    pub fn padding_for(needed: u64, txsize: u64, size: u64) -> Option<u64> {
+        if Self::allocated_fuel(txsize, size) >= needed {
+            return Some(0);
+        }
+        if needed >= TOTAL_FUEL {
+            return None;
+        }
+        // solve TOTAL_FUEL * (txsize + p) >= needed * (size + p) for p, then
+        // step past the rounding down in metered_fuel
+        let (needed, total) = (needed as u128, TOTAL_FUEL as u128);
+        let shortfall = needed * size as u128 - total * txsize as u128;
+        let mut padding: u64 = shortfall.div_ceil(total - needed).try_into().ok()?;
+        while Self::allocated_fuel(txsize + padding, size + padding) < needed as u64 {
+            padding += 1;
+        }
+        Some(padding)
+    }
+
     pub fn fuel_transaction(txsize: u64, txindex: u32) {
         let mut tank = _FUEL_TANK.write().unwrap();
         let tank = tank.as_mut().unwrap();
@@ -143,7 +178,7 @@ impl FuelTank {
 
         // Calculate fuel allocation based on transaction size
         let _block_fuel_before = tank.block_fuel;
-        tank.block_metered_fuel = tank.block_fuel * txsize / tank.size;
+        tank.block_metered_fuel = Self::metered_fuel(tank.block_fuel, txsize, tank.size);
 
         // Ensure minimum fuel allocation
         tank.transaction_fuel = std::cmp::max(MINIMUM_FUEL, tank.block_metered_fuel);
@@ -258,6 +293,18 @@ pub const FUEL_BALANCE: u64 = 10;
 pub const FUEL_EXTCALL_DEPLOY: u64 = 10_000;
 pub const FUEL_LOAD_BLOCK: u64 = 1000; // Fixed cost for loading a block
 pub const FUEL_LOAD_TRANSACTION: u64 = 500; // Fixed cost for loading a transaction
+pub const FUEL_EMIT: u64 = 100; // Fixed cost for emitting an event
+pub const FUEL_PER_EMIT_BYTE: u64 = 8; // Charged like storage, events are persisted
+pub const FUEL_LOAD_HEADER: u64 = 100; // Fixed cost for loading a block header at any height
+pub const FUEL_BLOCKHASH: u64 = 50; // Fixed cost for loading a block hash at any height
+pub const FUEL_MEDIAN_TIME_PAST: u64 = 11 * FUEL_LOAD_HEADER; // Reads up to eleven headers
+
+// Precompiles are charged up front on top of the extcall itself. Hashing pays
+// per byte of input, signature checks a flat price near their native cost.
+pub const FUEL_PRECOMPILE_HASH: u64 = 100;
+pub const FUEL_PER_PRECOMPILE_HASH_BYTE: u64 = 1;
+pub const FUEL_PRECOMPILE_SIGNATURE: u64 = 5_000;
+pub const FUEL_PER_MERKLE_LEVEL: u64 = 100;
 
 pub trait Fuelable {
     fn consume_fuel(&mut self, n: u64) -> Result<()>;
@@ -281,6 +328,25 @@ pub fn consume_fuel<'a>(caller: &mut Caller<'_, AlkanesState>, n: u64) -> Result
     caller.consume_fuel(n)
 }
 
+/// Notes a call to host function `name` moving `bytes` bytes on the profile
+/// being taken of the running frame, if any.
+pub fn profile_host<'a>(caller: &mut Caller<'_, AlkanesState>, name: &str, bytes: u64, n: u64) {
+    if let Some(profiler) = caller.data().context.lock().unwrap().profiler.as_ref() {
+        profiler.host(name, bytes, n);
+    }
+}
+
+/// `consume_fuel` for a call to host function `name`, noted on the profile.
+pub fn charge<'a>(
+    caller: &mut Caller<'_, AlkanesState>,
+    name: &str,
+    bytes: u64,
+    n: u64,
+) -> Result<()> {
+    profile_host(caller, name, bytes, n);
+    consume_fuel(caller, n)
+}
+
 pub fn compute_extcall_fuel(savecount: u64) -> Result<u64> {
     let save_fuel = overflow_error(FUEL_PER_STORE_BYTE.checked_mul(savecount))?;
     overflow_error::<u64>(FUEL_EXTCALL.checked_add(save_fuel))
@@ -3,15 +3,20 @@ use super::{
     get_memory, read_arraybuffer, send_to_arraybuffer, sequence_pointer, AlkanesState, Extcall,
     Saveable, SaveableExtendedCallResponse,
 };
-use crate::utils::{balance_pointer, pipe_storagemap_to, transfer_from};
+use crate::etl;
+use crate::events::save_event;
+use crate::utils::{balance_pointer, pipe_storagemap_to, storage_writes, transfer_from};
+use crate::vm::precompiles::{is_precompile, run_precompile};
+use crate::vm::revert::revert_data;
 use crate::vm::{run_after_special, run_special_cellpacks};
 use alkanes_support::{
     cellpack::Cellpack,
+    event::AlkaneEvent,
     id::AlkaneId,
     parcel::AlkaneTransferParcel,
     response::CallResponse,
     storage::StorageMap,
-    trace::{TraceContext, TraceEvent, TraceResponse},
+    trace::{StorageRead, TraceContext, TraceEvent, TraceFrame, TraceResponse},
     utils::overflow_error,
 };
 #[allow(unused_imports)]
@@ -25,11 +30,15 @@ use metashrew_core::{
 use metashrew_support::index_pointer::KeyValuePointer;
 
 use crate::vm::fuel::{
-    consume_fuel, Fuelable, FUEL_BALANCE, FUEL_EXTCALL, FUEL_EXTCALL_DEPLOY, FUEL_FUEL,
-    FUEL_HEIGHT, FUEL_LOAD_BLOCK, FUEL_LOAD_TRANSACTION, FUEL_PER_LOAD_BYTE, FUEL_PER_REQUEST_BYTE,
-    FUEL_PER_STORE_BYTE, FUEL_SEQUENCE,
+    charge, consume_fuel, profile_host, Fuelable, FUEL_BALANCE, FUEL_BLOCKHASH, FUEL_EMIT,
+    FUEL_EXTCALL, FUEL_EXTCALL_DEPLOY, FUEL_FUEL, FUEL_HEIGHT, FUEL_LOAD_BLOCK, FUEL_LOAD_HEADER,
+    FUEL_LOAD_TRANSACTION, FUEL_MEDIAN_TIME_PAST, FUEL_PER_EMIT_BYTE, FUEL_PER_LOAD_BYTE,
+    FUEL_PER_REQUEST_BYTE, FUEL_PER_STORE_BYTE, FUEL_SEQUENCE,
 };
-use protorune_support::utils::consensus_encode;
+use bitcoin::OutPoint;
+use protorune::tables::RUNES;
+use protorune::versioned;
+use protorune_support::utils::{consensus_decode, consensus_encode};
 use std::io::Cursor;
 use std::sync::{Arc, Mutex};
 use wasmi::*;
@@ -90,20 +99,21 @@ impl AlkanesHostFunctionsImpl {
                 read_arraybuffer(data, k)?
             };
             let myself = caller.data_mut().context.lock().unwrap().myself.clone();
-            let result: i32 = caller
-                .data_mut()
-                .context
-                .lock()
-                .unwrap()
-                .message
-                .atomic
-                .keyword("/alkanes/")
-                .select(&myself.into())
-                .keyword("/storage/")
-                .select(&key)
-                .get()
-                .len()
-                .try_into()?;
+            let result: i32 = versioned::get(
+                &caller
+                    .data_mut()
+                    .context
+                    .lock()
+                    .unwrap()
+                    .message
+                    .atomic
+                    .keyword("/alkanes/")
+                    .select(&myself.into())
+                    .keyword("/storage/")
+                    .select(&key),
+            )
+            .len()
+            .try_into()?;
             ((result as u64) + (key.len() as u64), result)
         };
 
@@ -119,7 +129,7 @@ impl AlkanesHostFunctionsImpl {
             );
         }
 
-        consume_fuel(caller, fuel_cost)?;
+        charge(caller, "__request_storage", bytes_processed, fuel_cost)?;
         Ok(result)
     }
     pub(super) fn load_storage<'a>(
@@ -127,7 +137,7 @@ impl AlkanesHostFunctionsImpl {
         k: i32,
         v: i32,
     ) -> Result<i32> {
-        let (bytes_processed, value) = {
+        let (bytes_processed, key, value) = {
             let mem = get_memory(caller)?;
             let key = {
                 let data = mem.data(&caller);
@@ -135,15 +145,16 @@ impl AlkanesHostFunctionsImpl {
             };
             let value = {
                 let myself = caller.data_mut().context.lock().unwrap().myself.clone();
-                (&caller.data_mut().context.lock().unwrap().message)
-                    .atomic
-                    .keyword("/alkanes/")
-                    .select(&myself.into())
-                    .keyword("/storage/")
-                    .select(&key)
-                    .get()
+                versioned::get(
+                    &(&caller.data_mut().context.lock().unwrap().message)
+                        .atomic
+                        .keyword("/alkanes/")
+                        .select(&myself.into())
+                        .keyword("/storage/")
+                        .select(&key),
+                )
             };
-            (key.len() + value.len(), value)
+            (key.len() + value.len(), key, value)
         };
 
         let fuel_cost = overflow_error((bytes_processed as u64).checked_mul(FUEL_PER_LOAD_BYTE))?;
@@ -155,7 +166,119 @@ impl AlkanesHostFunctionsImpl {
             );
         }
 
-        consume_fuel(caller, fuel_cost)?;
+        charge(caller, "__load_storage", bytes_processed as u64, fuel_cost)?;
+        caller
+            .data_mut()
+            .context
+            .lock()
+            .unwrap()
+            .frame
+            .storage_reads
+            .push(StorageRead {
+                owner: None,
+                key,
+                value: value.as_ref().clone(),
+            });
+        send_to_arraybuffer(caller, v.try_into()?, value.as_ref())
+    }
+    fn read_foreign_key(
+        caller: &mut Caller<'_, AlkanesState>,
+        who_ptr: i32,
+        k: i32,
+    ) -> Result<(AlkaneId, Vec<u8>)> {
+        let mem = get_memory(caller)?;
+        let data = mem.data(&caller);
+        Ok((
+            AlkaneId::parse(&mut Cursor::new(read_arraybuffer(data, who_ptr)?))?,
+            read_arraybuffer(data, k)?,
+        ))
+    }
+    fn foreign_storage(
+        caller: &mut Caller<'_, AlkanesState>,
+        who: &AlkaneId,
+        key: &Vec<u8>,
+    ) -> Arc<Vec<u8>> {
+        versioned::get(
+            &caller
+                .data_mut()
+                .context
+                .lock()
+                .unwrap()
+                .message
+                .atomic
+                .keyword("/alkanes/")
+                .select(&who.clone().into())
+                .keyword("/storage/")
+                .select(key),
+        )
+    }
+    pub(super) fn request_foreign_storage<'a>(
+        caller: &mut Caller<'_, AlkanesState>,
+        who_ptr: i32,
+        k: i32,
+    ) -> Result<i32> {
+        let (who, key) = Self::read_foreign_key(caller, who_ptr, k)?;
+        let result: i32 = Self::foreign_storage(caller, &who, &key).len().try_into()?;
+        let fuel_cost = overflow_error(
+            ((result as u64) + (key.len() as u64)).checked_mul(FUEL_PER_REQUEST_BYTE),
+        )?;
+
+        #[cfg(feature = "debug-log")]
+        {
+            println!(
+                "request_foreign_storage: who=[{},{}], key_size={} bytes, result_size={} bytes, fuel_cost={}",
+                who.block, who.tx, key.len(), result, fuel_cost
+            );
+        }
+
+        charge(
+            caller,
+            "__request_foreign_storage",
+            (result as u64) + (key.len() as u64),
+            fuel_cost,
+        )?;
+        Ok(result)
+    }
+    /// Reads the storage of another alkane as committed so far in this
+    /// transaction. Frames flush their writes when they return or call out, so
+    /// writes a running frame has made since its last call out are not seen.
+    pub(super) fn load_foreign_storage<'a>(
+        caller: &mut Caller<'_, AlkanesState>,
+        who_ptr: i32,
+        k: i32,
+        v: i32,
+    ) -> Result<i32> {
+        let (who, key) = Self::read_foreign_key(caller, who_ptr, k)?;
+        let value = Self::foreign_storage(caller, &who, &key);
+        let fuel_cost =
+            overflow_error(((key.len() + value.len()) as u64).checked_mul(FUEL_PER_LOAD_BYTE))?;
+
+        #[cfg(feature = "debug-log")]
+        {
+            println!(
+                "load_foreign_storage: who=[{},{}], key_size={} bytes, value_size={} bytes, fuel_cost={}",
+                who.block, who.tx, key.len(), value.len(), fuel_cost
+            );
+        }
+
+        charge(
+            caller,
+            "__load_foreign_storage",
+            (key.len() + value.len()) as u64,
+            fuel_cost,
+        )?;
+        caller
+            .data_mut()
+            .context
+            .lock()
+            .unwrap()
+            .frame
+            .storage_reads
+            .push(StorageRead {
+                owner: Some(who),
+                key,
+                value: value.as_ref().clone(),
+            });
         send_to_arraybuffer(caller, v.try_into()?, value.as_ref())
     }
     pub(super) fn request_context(caller: &mut Caller<'_, AlkanesState>) -> Result<i32> {
@@ -177,7 +300,7 @@ impl AlkanesHostFunctionsImpl {
             );
         }
 
-        consume_fuel(caller, fuel_cost)?;
+        charge(caller, "__request_context", result as u64, fuel_cost)?;
         Ok(result)
     }
     pub(super) fn load_context(caller: &mut Caller<'_, AlkanesState>, v: i32) -> Result<i32> {
@@ -193,7 +316,7 @@ impl AlkanesHostFunctionsImpl {
             );
         }
 
-        consume_fuel(caller, fuel_cost)?;
+        charge(caller, "__load_context", result.len() as u64, fuel_cost)?;
 
         send_to_arraybuffer(caller, v.try_into()?, &result)
     }
@@ -212,7 +335,7 @@ impl AlkanesHostFunctionsImpl {
         // Use a small fixed cost for requesting transaction size
         // This is just getting the size, not loading the full transaction
         let request_fuel = std::cmp::min(50, FUEL_LOAD_TRANSACTION / 10);
-        consume_fuel(caller, request_fuel)?;
+        charge(caller, "__request_transaction", result as u64, request_fuel)?;
 
         #[cfg(feature = "debug-log")]
         {
@@ -271,7 +394,12 @@ impl AlkanesHostFunctionsImpl {
             );
         }
 
-        consume_fuel(caller, fuel_cost)?;
+        charge(
+            caller,
+            "__returndatacopy",
+            returndata.len() as u64,
+            fuel_cost,
+        )?;
 
         send_to_arraybuffer(caller, output.try_into()?, &returndata)?;
         Ok(())
@@ -288,7 +416,12 @@ impl AlkanesHostFunctionsImpl {
         )?;
 
         // Use fixed fuel cost instead of scaling with transaction size
-        consume_fuel(caller, FUEL_LOAD_TRANSACTION)?;
+        charge(
+            caller,
+            "__load_transaction",
+            transaction.len() as u64,
+            FUEL_LOAD_TRANSACTION,
+        )?;
 
         #[cfg(feature = "debug-log")]
         {
@@ -310,7 +443,7 @@ impl AlkanesHostFunctionsImpl {
         // Use a small fixed cost for requesting block size
         // This is just getting the size, not loading the full block
         let request_fuel = std::cmp::min(100, FUEL_LOAD_BLOCK / 10);
-        consume_fuel(caller, request_fuel)?;
+        charge(caller, "__request_block", len as u64, request_fuel)?;
 
         #[cfg(feature = "debug-log")]
         {
@@ -327,7 +460,7 @@ impl AlkanesHostFunctionsImpl {
             consensus_encode(&caller.data_mut().context.lock().unwrap().message.block)?;
 
         // Use fixed fuel cost instead of scaling with block size
-        consume_fuel(caller, FUEL_LOAD_BLOCK)?;
+        charge(caller, "__load_block", block.len() as u64, FUEL_LOAD_BLOCK)?;
 
         #[cfg(feature = "debug-log")]
         {
@@ -340,6 +473,116 @@ impl AlkanesHostFunctionsImpl {
         send_to_arraybuffer(caller, v.try_into()?, &block)?;
         Ok(())
     }
+    /// Heights past the block being indexed fail, as does any height whose
+    /// block was never indexed. The current block is served from the message.
+    fn current_block_at(
+        caller: &mut Caller<'_, AlkanesState>,
+        height: u64,
+    ) -> Result<Option<bitcoin::block::Header>> {
+        let context_guard = caller.data_mut().context.lock().unwrap();
+        let current = context_guard.message.height;
+        if height > current {
+            return Err(anyhow!(
+                "block {} is past the current height {}",
+                height,
+                current
+            ));
+        }
+        Ok(if height == current {
+            Some(context_guard.message.block.header)
+        } else {
+            None
+        })
+    }
+    pub(super) fn load_block_header(
+        caller: &mut Caller<'_, AlkanesState>,
+        height: u64,
+        output: i32,
+    ) -> Result<()> {
+        charge(caller, "__load_block_header", 80, FUEL_LOAD_HEADER)?;
+        let header = match Self::current_block_at(caller, height)? {
+            Some(header) => consensus_encode(&header)?,
+            None => etl::get_header(height.try_into()?)?,
+        };
+
+        #[cfg(feature = "debug-log")]
+        {
+            println!(
+                "load_block_header: height={}, fuel_cost={}",
+                height, FUEL_LOAD_HEADER
+            );
+        }
+
+        send_to_arraybuffer(caller, output.try_into()?, &header)?;
+        Ok(())
+    }
+    /// Median timestamp of the eleven blocks ending at `height`, as BIP113 defines
+    /// it. Near the first indexed block only the headers stored so far count.
+    pub(super) fn load_median_time_past(
+        caller: &mut Caller<'_, AlkanesState>,
+        height: u64,
+        output: i32,
+    ) -> Result<()> {
+        charge(caller, "__load_median_time_past", 4, FUEL_MEDIAN_TIME_PAST)?;
+        let mut times: Vec<u32> = vec![];
+        for h in (height.saturating_sub(10)..=height).rev() {
+            let header = match Self::current_block_at(caller, h)? {
+                Some(header) => header,
+                None => match etl::get_header(h.try_into()?) {
+                    Ok(header) => {
+                        consensus_decode::<bitcoin::block::Header>(&mut Cursor::new(header))?
+                    }
+                    // history starts at the first block the indexer saw
+                    Err(_) if !times.is_empty() => break,
+                    Err(e) => return Err(e),
+                },
+            };
+            times.push(header.time);
+        }
+        times.sort_unstable();
+        let median = times[times.len() / 2];
+
+        #[cfg(feature = "debug-log")]
+        {
+            println!(
+                "load_median_time_past: height={}, headers={}, fuel_cost={}",
+                height,
+                times.len(),
+                FUEL_MEDIAN_TIME_PAST
+            );
+        }
+
+        send_to_arraybuffer(caller, output.try_into()?, &median.to_le_bytes().to_vec())?;
+        Ok(())
+    }
+    pub(super) fn load_blockhash(
+        caller: &mut Caller<'_, AlkanesState>,
+        height: u64,
+        output: i32,
+    ) -> Result<()> {
+        charge(caller, "__load_blockhash", 32, FUEL_BLOCKHASH)?;
+        let hash = match Self::current_block_at(caller, height)? {
+            Some(header) => consensus_encode(&header.block_hash())?,
+            None => {
+                let hash = versioned::get(&RUNES.HEIGHT_TO_BLOCKHASH.select_value::<u64>(height));
+                if hash.len() != 32 {
+                    return Err(anyhow!("no block hash for height {}", height));
+                }
+                hash.as_ref().clone()
+            }
+        };
+
+        #[cfg(feature = "debug-log")]
+        {
+            println!(
+                "load_blockhash: height={}, fuel_cost={}",
+                height, FUEL_BLOCKHASH
+            );
+        }
+
+        send_to_arraybuffer(caller, output.try_into()?, &hash)?;
+        Ok(())
+    }
     pub(super) fn sequence(caller: &mut Caller<'_, AlkanesState>, output: i32) -> Result<()> {
         let buffer: Vec<u8> =
             (&sequence_pointer(&caller.data_mut().context.lock().unwrap().message.atomic)
@@ -352,7 +595,7 @@ impl AlkanesHostFunctionsImpl {
             println!("sequence: fuel_cost={}", FUEL_SEQUENCE);
         }
 
-        consume_fuel(caller, FUEL_SEQUENCE)?;
+        charge(caller, "__sequence", buffer.len() as u64, FUEL_SEQUENCE)?;
 
         send_to_arraybuffer(caller, output.try_into()?, &buffer)?;
         Ok(())
@@ -369,7 +612,7 @@ impl AlkanesHostFunctionsImpl {
             );
         }
 
-        consume_fuel(caller, FUEL_FUEL)?;
+        charge(caller, "__fuel", buffer.len() as u64, FUEL_FUEL)?;
 
         send_to_arraybuffer(caller, output.try_into()?, &buffer)?;
         Ok(())
@@ -386,7 +629,7 @@ impl AlkanesHostFunctionsImpl {
             );
         }
 
-        consume_fuel(caller, FUEL_HEIGHT)?;
+        charge(caller, "__height", height.len() as u64, FUEL_HEIGHT)?;
 
         send_to_arraybuffer(caller, output.try_into()?, &height)?;
         Ok(())
@@ -405,12 +648,11 @@ impl AlkanesHostFunctionsImpl {
                 AlkaneId::parse(&mut Cursor::new(read_arraybuffer(data, what_ptr)?))?,
             )
         };
-        let balance = balance_pointer(
+        let balance = versioned::get(&balance_pointer(
             &mut caller.data_mut().context.lock().unwrap().message.atomic,
             &who.into(),
             &what.into(),
-        )
-        .get()
+        ))
         .as_ref()
         .clone();
 
@@ -427,7 +669,7 @@ impl AlkanesHostFunctionsImpl {
             );
         }
 
-        consume_fuel(caller, FUEL_BALANCE)?;
+        charge(caller, "__balance", balance.len() as u64, FUEL_BALANCE)?;
 
         send_to_arraybuffer(caller, output.try_into()?, &balance)?;
         Ok(())
@@ -436,12 +678,12 @@ impl AlkanesHostFunctionsImpl {
         caller: &mut Caller<'_, AlkanesState>,
         e: anyhow::Error,
         should_rollback: bool,
+        frame: TraceFrame,
     ) -> i32 {
         println!("[[handle_extcall]] Error during extcall: {:?}", e);
-        let mut data: Vec<u8> = vec![0x08, 0xc3, 0x79, 0xa0];
-        data.extend(e.to_string().as_bytes());
+        let data = revert_data(&e);
 
-        let mut revert_context: TraceResponse = TraceResponse::default();
+        let mut revert_context = TraceResponse::new(Default::default(), frame);
         revert_context.inner.data = data.clone();
 
         let mut response = CallResponse::default();
@@ -531,10 +773,12 @@ impl AlkanesHostFunctionsImpl {
                     storage_map_len,
                 ) {
                     Ok(v) => v,
-                    Err(e) => Self::_handle_extcall_abort::<T>(caller, e, true),
+                    Err(e) => {
+                        Self::_handle_extcall_abort::<T>(caller, e, true, TraceFrame::default())
+                    }
                 }
             }
-            Err(e) => Self::_handle_extcall_abort::<T>(caller, e, false),
+            Err(e) => Self::_handle_extcall_abort::<T>(caller, e, false, TraceFrame::default()),
         }
     }
     pub(super) fn extcall<'a, T: Extcall>(
@@ -558,12 +802,11 @@ impl AlkanesHostFunctionsImpl {
             // Re-acquire lock for state updates
             {
                 let mut context_guard = caller.data_mut().context.lock().unwrap();
-                pipe_storagemap_to(
-                    &storage_map,
-                    &mut context_guard.message.atomic.derive(
-                        &IndexPointer::from_keyword("/alkanes/").select(&myself.clone().into()),
-                    ),
+                let mut storage_pointer = context_guard.message.atomic.derive(
+                    &IndexPointer::from_keyword("/alkanes/").select(&myself.clone().into()),
                 );
+                let writes = storage_writes(&storage_map, &storage_pointer);
+                pipe_storagemap_to(&storage_map, &mut storage_pointer);
 
                 transfer_from(
                     &incoming_alkanes,
@@ -574,6 +817,10 @@ impl AlkanesHostFunctionsImpl {
                     &myself,
                     &submyself,
                 )?;
+                context_guard.frame.storage_writes.extend(writes);
+                context_guard
+                    .frame
+                    .transfer(&incoming_alkanes, &myself, &submyself);
             };
             let context_guard = caller.data_mut().context.lock().unwrap();
 
@@ -585,9 +832,11 @@ impl AlkanesHostFunctionsImpl {
                 .derive(&IndexPointer::default());
             (subbed.caller, subbed.myself) =
                 T::change_context(submyself.clone(), caller_id, myself.clone());
+            subbed.code = submyself.clone();
             subbed.returndata = vec![];
             subbed.incoming_alkanes = incoming_alkanes.clone();
             subbed.inputs = cellpack.inputs.clone();
+            subbed.frame = TraceFrame::default();
             (subbed, binary)
         };
 
@@ -602,6 +851,12 @@ impl AlkanesHostFunctionsImpl {
                 cellpack.target.is_deployment());
         }
 
+        let deploy_fuel = if cellpack.target.is_deployment() {
+            FUEL_EXTCALL_DEPLOY
+        } else {
+            0
+        };
+        profile_host(caller, T::name(), storage_map_len, total_fuel + deploy_fuel);
         consume_fuel(caller, total_fuel)?;
 
         let mut trace_context: TraceContext = subcontext.flat().into();
@@ -611,24 +866,41 @@ impl AlkanesHostFunctionsImpl {
         subcontext.trace.clock(event);
 
         // Run the call in a new context
-        let (response, gas_used) = run_after_special(
-            Arc::new(Mutex::new(subcontext.clone())),
-            binary_rc,
-            start_fuel,
-        )?;
+        let subcontext_ref = Arc::new(Mutex::new(subcontext.clone()));
+        let result = if is_precompile(&cellpack.target, subcontext.message.height) {
+            run_precompile(subcontext_ref.clone(), &cellpack.target, start_fuel)
+        } else {
+            run_after_special(subcontext_ref.clone(), binary_rc, start_fuel)
+        };
+        let (response, gas_used) = match result {
+            Ok(v) => v,
+            Err(e) => {
+                let frame = subcontext_ref.lock().unwrap().frame.clone();
+                return Ok(Self::_handle_extcall_abort::<T>(caller, e, true, frame));
+            }
+        };
         let serialized = CallResponse::from(response.clone().into()).serialize();
         {
             caller.set_fuel(overflow_error(start_fuel.checked_sub(gas_used))?)?;
-            let mut return_context: TraceResponse = response.clone().into();
-            return_context.fuel_used = gas_used;
+            let mut frame = subcontext_ref.lock().unwrap().frame.clone();
 
             // Update trace and context state
             let mut context_guard = caller.data_mut().context.lock().unwrap();
-            context_guard
-                .trace
-                .clock(TraceEvent::ReturnContext(return_context));
             let mut saveable: SaveableExtendedCallResponse = response.clone().into();
             saveable.associate(&subcontext);
+            frame.storage_writes.extend(storage_writes(
+                &saveable.storage_map(),
+                &context_guard.message.atomic.derive(
+                    &IndexPointer::from_keyword("/alkanes/").select(&saveable.from().into()),
+                ),
+            ));
+            frame.transfer(&saveable.alkanes(), &saveable.from(), &saveable.to());
+            context_guard
+                .trace
+                .clock(TraceEvent::ReturnContext(TraceResponse::new(
+                    response.clone(),
+                    frame,
+                )));
             saveable.save(&mut context_guard.message.atomic)?;
             context_guard.returndata = serialized.clone();
             T::handle_atomic(&mut context_guard.message.atomic);
@@ -644,6 +916,38 @@ impl AlkanesHostFunctionsImpl {
         print!("{}", String::from_utf8(message)?);
         Ok(())
     }
+    pub(super) fn emit<'a>(caller: &mut Caller<'_, AlkanesState>, v: i32) -> Result<()> {
+        let buffer = {
+            let mem = get_memory(caller)?;
+            let data = mem.data(&caller);
+            read_arraybuffer(data, v)?
+        };
+        let fuel_cost = overflow_error(
+            (buffer.len() as u64)
+                .checked_mul(FUEL_PER_EMIT_BYTE)
+                .and_then(|v| v.checked_add(FUEL_EMIT)),
+        )?;
+        #[cfg(feature = "debug-log")]
+        {
+            println!(
+                "emit: event_size={} bytes, fuel_cost={}",
+                buffer.len(),
+                fuel_cost
+            );
+        }
+        charge(caller, "__emit", buffer.len() as u64, fuel_cost)?;
+        let mut event = AlkaneEvent::parse(&mut Cursor::new(buffer))?;
+        let mut context_guard = caller.data_mut().context.lock().unwrap();
+        event.alkane = context_guard.myself.clone();
+        let outpoint = OutPoint {
+            txid: context_guard.message.transaction.compute_txid(),
+            vout: context_guard.message.vout,
+        };
+        let height = context_guard.message.height;
+        save_event(&mut context_guard.message.atomic, &event, &outpoint, height)?;
+        context_guard.trace.clock(TraceEvent::Emit(event));
+        Ok(())
+    }
 }
 
 // Implementation of the safe wrapper
@@ -697,10 +1001,35 @@ impl SafeAlkanesHostFunctionsImpl {
         Self::with_context_safety(caller, |c| AlkanesHostFunctionsImpl::load_storage(c, k, v))
     }
 
+    pub(super) fn request_foreign_storage<'a>(
+        caller: &mut Caller<'_, AlkanesState>,
+        who: i32,
+        k: i32,
+    ) -> Result<i32> {
+        Self::with_context_safety(caller, |c| {
+            AlkanesHostFunctionsImpl::request_foreign_storage(c, who, k)
+        })
+    }
+
+    pub(super) fn load_foreign_storage<'a>(
+        caller: &mut Caller<'_, AlkanesState>,
+        who: i32,
+        k: i32,
+        v: i32,
+    ) -> Result<i32> {
+        Self::with_context_safety(caller, |c| {
+            AlkanesHostFunctionsImpl::load_foreign_storage(c, who, k, v)
+        })
+    }
+
     pub(super) fn log<'a>(caller: &mut Caller<'_, AlkanesState>, v: i32) -> Result<()> {
         Self::with_context_safety(caller, |c| AlkanesHostFunctionsImpl::log(c, v))
     }
 
+    pub(super) fn emit<'a>(caller: &mut Caller<'_, AlkanesState>, v: i32) -> Result<()> {
+        Self::with_context_safety(caller, |c| AlkanesHostFunctionsImpl::emit(c, v))
+    }
+
     pub(super) fn balance<'a>(
         caller: &mut Caller<'a, AlkanesState>,
         who: i32,
@@ -742,6 +1071,36 @@ impl SafeAlkanesHostFunctionsImpl {
         Self::with_context_safety(caller, |c| AlkanesHostFunctionsImpl::load_block(c, v))
     }
 
+    pub(super) fn load_block_header(
+        caller: &mut Caller<'_, AlkanesState>,
+        height: u64,
+        output: i32,
+    ) -> Result<()> {
+        Self::with_context_safety(caller, |c| {
+            AlkanesHostFunctionsImpl::load_block_header(c, height, output)
+        })
+    }
+
+    pub(super) fn load_median_time_past(
+        caller: &mut Caller<'_, AlkanesState>,
+        height: u64,
+        output: i32,
+    ) -> Result<()> {
+        Self::with_context_safety(caller, |c| {
+            AlkanesHostFunctionsImpl::load_median_time_past(c, height, output)
+        })
+    }
+
+    pub(super) fn load_blockhash(
+        caller: &mut Caller<'_, AlkanesState>,
+        height: u64,
+        output: i32,
+    ) -> Result<()> {
+        Self::with_context_safety(caller, |c| {
+            AlkanesHostFunctionsImpl::load_blockhash(c, height, output)
+        })
+    }
+
     pub(super) fn sequence(caller: &mut Caller<'_, AlkanesState>, output: i32) -> Result<()> {
         Self::with_context_safety(caller, |c| AlkanesHostFunctionsImpl::sequence(c, output))
     }
@@ -1,8 +1,14 @@
 use super::{
-    extcall::*, read_arraybuffer, AlkanesExportsImpl, AlkanesRuntimeContext, AlkanesState,
-    SafeAlkanesHostFunctionsImpl, MEMORY_LIMIT,
+    cache::{compile, ENGINE, LINKER},
+    extcall::*,
+    read_arraybuffer,
+    revert::TypedRevert,
+    AlkanesExportsImpl, AlkanesRuntimeContext, AlkanesState, SafeAlkanesHostFunctionsImpl,
+    MEMORY_LIMIT,
 };
+use crate::network::activation;
 use alkanes_support::{
+    abi::ERROR_STRING_SELECTOR,
     response::{CallResponse, ExtendedCallResponse},
     trace::{TraceEvent, TraceResponse},
 };
@@ -72,19 +78,10 @@ impl AlkanesInstance {
         binary: Arc<Vec<u8>>,
         start_fuel: u64,
     ) -> Result<Self> {
-        /*
-        let binary = context
-            .message
-            .atomic
-            .keyword("/alkanes/")
-            .select(&context.myself.clone().into())
-            .get();
-            */
-        let mut config = Config::default();
-        config.consume_fuel(true);
-        let engine = Engine::new(&config);
+        let module = compile(&binary)?;
+        check_activated_imports(&module, context.lock().unwrap().message.height)?;
         let mut store = Store::<AlkanesState>::new(
-            &engine,
+            &ENGINE,
             AlkanesState {
                 had_failure: false,
                 limiter: StoreLimitsBuilder::new().memory_size(MEMORY_LIMIT).build(),
@@ -93,244 +90,8 @@ impl AlkanesInstance {
         );
         store.limiter(|state| &mut state.limiter);
         Store::<AlkanesState>::set_fuel(&mut store, start_fuel)?; // TODO: implement gas limits
-        let module = Module::new(&engine, &mut &binary[..])?;
-        let mut linker: Linker<AlkanesState> = Linker::<AlkanesState>::new(&engine);
-        linker.func_wrap("env", "abort", SafeAlkanesHostFunctionsImpl::abort)?;
-        linker.func_wrap(
-            "env",
-            "__load_storage",
-            |mut caller: Caller<'_, AlkanesState>, k: i32, v: i32| {
-                match SafeAlkanesHostFunctionsImpl::load_storage(&mut caller, k, v) {
-                    Ok(v) => v,
-                    Err(_e) => {
-                        SafeAlkanesHostFunctionsImpl::_abort(caller);
-                        -1
-                    }
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__request_storage",
-            |mut caller: Caller<'_, AlkanesState>, k: i32| {
-                match SafeAlkanesHostFunctionsImpl::request_storage(&mut caller, k) {
-                    Ok(v) => v,
-                    Err(_e) => {
-                        SafeAlkanesHostFunctionsImpl::_abort(caller);
-                        -1
-                    }
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__log",
-            |mut caller: Caller<'_, AlkanesState>, v: i32| {
-                if let Err(_e) = SafeAlkanesHostFunctionsImpl::log(&mut caller, v) {
-                    SafeAlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__balance",
-            |mut caller: Caller<'_, AlkanesState>, who: i32, what: i32, output: i32| {
-                if let Err(_e) =
-                    SafeAlkanesHostFunctionsImpl::balance(&mut caller, who, what, output)
-                {
-                    SafeAlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__request_context",
-            |mut caller: Caller<'_, AlkanesState>| -> i32 {
-                match SafeAlkanesHostFunctionsImpl::request_context(&mut caller) {
-                    Ok(v) => v,
-                    Err(_e) => {
-                        SafeAlkanesHostFunctionsImpl::_abort(caller);
-                        -1
-                    }
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__load_context",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                match SafeAlkanesHostFunctionsImpl::load_context(&mut caller, output) {
-                    Ok(v) => v,
-                    Err(_e) => {
-                        SafeAlkanesHostFunctionsImpl::_abort(caller);
-                        -1
-                    }
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__sequence",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = SafeAlkanesHostFunctionsImpl::sequence(&mut caller, output) {
-                    SafeAlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__fuel",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = SafeAlkanesHostFunctionsImpl::fuel(&mut caller, output) {
-                    SafeAlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__height",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = SafeAlkanesHostFunctionsImpl::height(&mut caller, output) {
-                    SafeAlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
-        )?;
-
-        linker.func_wrap(
-            "env",
-            "__returndatacopy",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = SafeAlkanesHostFunctionsImpl::returndatacopy(&mut caller, output) {
-                    SafeAlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__request_transaction",
-            |mut caller: Caller<'_, AlkanesState>| -> i32 {
-                match SafeAlkanesHostFunctionsImpl::request_transaction(&mut caller) {
-                    Ok(v) => v,
-                    Err(_e) => {
-                        SafeAlkanesHostFunctionsImpl::_abort(caller);
-                        -1
-                    }
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__load_transaction",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = SafeAlkanesHostFunctionsImpl::load_transaction(&mut caller, output)
-                {
-                    SafeAlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
-        )?;
-        /* removed below to prevent redundancy / requirement for archived chaindata */
-        /*
-        linker.func_wrap(
-            "env",
-            "__request_output",
-            |mut caller: Caller<'_, AlkanesState>, outpoint: i32| -> i32 {
-                match SafeAlkanesHostFunctionsImpl::request_output(&mut caller, outpoint) {
-                  Err(_e) => {
-                    SafeAlkanesHostFunctionsImpl::_abort(caller);
-                    -1
-                  }
-                  Ok(v) => v
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__load_output",
-            |mut caller: Caller<'_, AlkanesState>, outpoint: i32, output: i32| {
-                if let Err(_e) = SafeAlkanesHostFunctionsImpl::load_output(&mut caller, outpoint, output) {
-                    SafeAlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
-        )?;
-        */
-        linker.func_wrap(
-            "env",
-            "__request_block",
-            |mut caller: Caller<'_, AlkanesState>| match SafeAlkanesHostFunctionsImpl::request_block(
-                &mut caller,
-            ) {
-                Ok(v) => v,
-                Err(_e) => {
-                    SafeAlkanesHostFunctionsImpl::_abort(caller);
-                    -1
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__load_block",
-            |mut caller: Caller<'_, AlkanesState>, output: i32| {
-                if let Err(_e) = SafeAlkanesHostFunctionsImpl::load_block(&mut caller, output) {
-                    SafeAlkanesHostFunctionsImpl::_abort(caller);
-                }
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__call",
-            |mut caller: Caller<'_, AlkanesState>,
-             cellpack_ptr: i32,
-             incoming_alkanes_ptr: i32,
-             checkpoint_ptr: i32,
-             start_fuel: u64|
-             -> i32 {
-                SafeAlkanesHostFunctionsImpl::handle_extcall::<Call>(
-                    &mut caller,
-                    cellpack_ptr,
-                    incoming_alkanes_ptr,
-                    checkpoint_ptr,
-                    start_fuel,
-                )
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__delegatecall",
-            |mut caller: Caller<'_, AlkanesState>,
-             cellpack_ptr: i32,
-             incoming_alkanes_ptr: i32,
-             checkpoint_ptr: i32,
-             start_fuel: u64|
-             -> i32 {
-                SafeAlkanesHostFunctionsImpl::handle_extcall::<Delegatecall>(
-                    &mut caller,
-                    cellpack_ptr,
-                    incoming_alkanes_ptr,
-                    checkpoint_ptr,
-                    start_fuel,
-                )
-            },
-        )?;
-        linker.func_wrap(
-            "env",
-            "__staticcall",
-            |mut caller: Caller<'_, AlkanesState>,
-             cellpack_ptr: i32,
-             incoming_alkanes_ptr: i32,
-             checkpoint_ptr: i32,
-             start_fuel: u64|
-             -> i32 {
-                SafeAlkanesHostFunctionsImpl::handle_extcall::<Staticcall>(
-                    &mut caller,
-                    cellpack_ptr,
-                    incoming_alkanes_ptr,
-                    checkpoint_ptr,
-                    start_fuel,
-                )
-            },
-        )?;
         let mut alkanes_instance = AlkanesInstance {
-            instance: linker
+            instance: LINKER
                 .instantiate(&mut store, &module)?
                 .ensure_no_start(&mut store)?,
             store,
@@ -372,14 +133,22 @@ impl AlkanesInstance {
         self.reset();
         if had_failure {
             self.rollback();
-            if call_response.data.len() >= 4
-                && &call_response.data[0..4] == &[0x08, 0xc3, 0x79, 0xa0]
+            if call_response.data.len() >= 4 && &call_response.data[0..4] == &ERROR_STRING_SELECTOR
             {
                 Err(anyhow!(format!(
                     "ALKANES: revert: {}",
                     String::from_utf8((&call_response.data[4..]).to_vec())
                         .unwrap_or_else(|_| hex::encode(&call_response.data[4..]))
                 )))
+            } else if call_response.data.len() >= 4
+                && self.store.data().context.lock().unwrap().message.height
+                    >= activation::TYPED_REVERTS
+            {
+                Err(TypedRevert {
+                    target: self.store.data().context.lock().unwrap().code.clone(),
+                    data: call_response.data,
+                }
+                .into())
             } else if let Some(e) = err {
                 Err(anyhow!(format!("ALKANES: revert: {:?}", e)))
             } else {
@@ -396,3 +165,366 @@ impl AlkanesInstance {
         AlkanesExportsImpl::call_meta(self)
     }
 }
+
+/// Every function `link_host_functions` registers under `env`. Deployments may
+/// import nothing else.
+pub const HOST_FUNCTIONS: &[&str] = &[
+    "abort",
+    "__load_storage",
+    "__request_storage",
+    "__load_foreign_storage",
+    "__request_foreign_storage",
+    "__log",
+    "__emit",
+    "__balance",
+    "__request_context",
+    "__load_context",
+    "__sequence",
+    "__fuel",
+    "__height",
+    "__returndatacopy",
+    "__request_transaction",
+    "__load_transaction",
+    "__request_output",
+    "__load_output",
+    "__request_block",
+    "__load_block",
+    "__load_block_header",
+    "__load_median_time_past",
+    "__load_blockhash",
+    "__call",
+    "__delegatecall",
+    "__staticcall",
+];
+
+/// Host functions added after genesis and the height each is linked from.
+const ACTIVATED_HOST_FUNCTIONS: &[(&str, u64)] = &[
+    ("__emit", activation::EVENTS),
+    ("__load_block_header", activation::BLOCK_HEADERS),
+    ("__load_median_time_past", activation::BLOCK_HEADERS),
+    ("__load_blockhash", activation::BLOCK_HEADERS),
+    ("__request_foreign_storage", activation::FOREIGN_STORAGE),
+    ("__load_foreign_storage", activation::FOREIGN_STORAGE),
+];
+
+// below its activation height a host function is treated as missing from the
+// linker, so a module importing it fails to instantiate as it used to
+fn check_activated_imports(module: &Module, height: u64) -> Result<()> {
+    for import in module.imports() {
+        let inactive = import.module() == "env"
+            && ACTIVATED_HOST_FUNCTIONS
+                .iter()
+                .any(|(name, from)| import.name() == *name && height < *from);
+        if inactive {
+            return Err(anyhow!(
+                "unknown import: `env::{}` has not been defined",
+                import.name()
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn link_host_functions(engine: &Engine) -> Result<Linker<AlkanesState>> {
+    let mut linker: Linker<AlkanesState> = Linker::<AlkanesState>::new(engine);
+    linker.func_wrap("env", "abort", SafeAlkanesHostFunctionsImpl::abort)?;
+    linker.func_wrap(
+        "env",
+        "__load_storage",
+        |mut caller: Caller<'_, AlkanesState>, k: i32, v: i32| {
+            match SafeAlkanesHostFunctionsImpl::load_storage(&mut caller, k, v) {
+                Ok(v) => v,
+                Err(_e) => {
+                    SafeAlkanesHostFunctionsImpl::_abort(caller);
+                    -1
+                }
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__request_storage",
+        |mut caller: Caller<'_, AlkanesState>, k: i32| {
+            match SafeAlkanesHostFunctionsImpl::request_storage(&mut caller, k) {
+                Ok(v) => v,
+                Err(_e) => {
+                    SafeAlkanesHostFunctionsImpl::_abort(caller);
+                    -1
+                }
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__load_foreign_storage",
+        |mut caller: Caller<'_, AlkanesState>, who: i32, k: i32, v: i32| {
+            match SafeAlkanesHostFunctionsImpl::load_foreign_storage(&mut caller, who, k, v) {
+                Ok(v) => v,
+                Err(_e) => {
+                    SafeAlkanesHostFunctionsImpl::_abort(caller);
+                    -1
+                }
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__request_foreign_storage",
+        |mut caller: Caller<'_, AlkanesState>, who: i32, k: i32| {
+            match SafeAlkanesHostFunctionsImpl::request_foreign_storage(&mut caller, who, k) {
+                Ok(v) => v,
+                Err(_e) => {
+                    SafeAlkanesHostFunctionsImpl::_abort(caller);
+                    -1
+                }
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__log",
+        |mut caller: Caller<'_, AlkanesState>, v: i32| {
+            if let Err(_e) = SafeAlkanesHostFunctionsImpl::log(&mut caller, v) {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__emit",
+        |mut caller: Caller<'_, AlkanesState>, v: i32| {
+            if let Err(_e) = SafeAlkanesHostFunctionsImpl::emit(&mut caller, v) {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__balance",
+        |mut caller: Caller<'_, AlkanesState>, who: i32, what: i32, output: i32| {
+            if let Err(_e) = SafeAlkanesHostFunctionsImpl::balance(&mut caller, who, what, output) {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__request_context",
+        |mut caller: Caller<'_, AlkanesState>| -> i32 {
+            match SafeAlkanesHostFunctionsImpl::request_context(&mut caller) {
+                Ok(v) => v,
+                Err(_e) => {
+                    SafeAlkanesHostFunctionsImpl::_abort(caller);
+                    -1
+                }
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__load_context",
+        |mut caller: Caller<'_, AlkanesState>, output: i32| {
+            match SafeAlkanesHostFunctionsImpl::load_context(&mut caller, output) {
+                Ok(v) => v,
+                Err(_e) => {
+                    SafeAlkanesHostFunctionsImpl::_abort(caller);
+                    -1
+                }
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__sequence",
+        |mut caller: Caller<'_, AlkanesState>, output: i32| {
+            if let Err(_e) = SafeAlkanesHostFunctionsImpl::sequence(&mut caller, output) {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__fuel",
+        |mut caller: Caller<'_, AlkanesState>, output: i32| {
+            if let Err(_e) = SafeAlkanesHostFunctionsImpl::fuel(&mut caller, output) {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__height",
+        |mut caller: Caller<'_, AlkanesState>, output: i32| {
+            if let Err(_e) = SafeAlkanesHostFunctionsImpl::height(&mut caller, output) {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+
+    linker.func_wrap(
+        "env",
+        "__returndatacopy",
+        |mut caller: Caller<'_, AlkanesState>, output: i32| {
+            if let Err(_e) = SafeAlkanesHostFunctionsImpl::returndatacopy(&mut caller, output) {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__request_transaction",
+        |mut caller: Caller<'_, AlkanesState>| -> i32 {
+            match SafeAlkanesHostFunctionsImpl::request_transaction(&mut caller) {
+                Ok(v) => v,
+                Err(_e) => {
+                    SafeAlkanesHostFunctionsImpl::_abort(caller);
+                    -1
+                }
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__load_transaction",
+        |mut caller: Caller<'_, AlkanesState>, output: i32| {
+            if let Err(_e) = SafeAlkanesHostFunctionsImpl::load_transaction(&mut caller, output) {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    /* removed below to prevent redundancy / requirement for archived chaindata */
+    /*
+    linker.func_wrap(
+        "env",
+        "__request_output",
+        |mut caller: Caller<'_, AlkanesState>, outpoint: i32| -> i32 {
+            match SafeAlkanesHostFunctionsImpl::request_output(&mut caller, outpoint) {
+              Err(_e) => {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+                -1
+              }
+              Ok(v) => v
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__load_output",
+        |mut caller: Caller<'_, AlkanesState>, outpoint: i32, output: i32| {
+            if let Err(_e) = SafeAlkanesHostFunctionsImpl::load_output(&mut caller, outpoint, output) {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    */
+    linker.func_wrap(
+        "env",
+        "__request_block",
+        |mut caller: Caller<'_, AlkanesState>| match SafeAlkanesHostFunctionsImpl::request_block(
+            &mut caller,
+        ) {
+            Ok(v) => v,
+            Err(_e) => {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+                -1
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__load_block",
+        |mut caller: Caller<'_, AlkanesState>, output: i32| {
+            if let Err(_e) = SafeAlkanesHostFunctionsImpl::load_block(&mut caller, output) {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__load_block_header",
+        |mut caller: Caller<'_, AlkanesState>, height: u64, output: i32| {
+            if let Err(_e) =
+                SafeAlkanesHostFunctionsImpl::load_block_header(&mut caller, height, output)
+            {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__load_median_time_past",
+        |mut caller: Caller<'_, AlkanesState>, height: u64, output: i32| {
+            if let Err(_e) =
+                SafeAlkanesHostFunctionsImpl::load_median_time_past(&mut caller, height, output)
+            {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__load_blockhash",
+        |mut caller: Caller<'_, AlkanesState>, height: u64, output: i32| {
+            if let Err(_e) =
+                SafeAlkanesHostFunctionsImpl::load_blockhash(&mut caller, height, output)
+            {
+                SafeAlkanesHostFunctionsImpl::_abort(caller);
+            }
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__call",
+        |mut caller: Caller<'_, AlkanesState>,
+         cellpack_ptr: i32,
+         incoming_alkanes_ptr: i32,
+         checkpoint_ptr: i32,
+         start_fuel: u64|
+         -> i32 {
+            SafeAlkanesHostFunctionsImpl::handle_extcall::<Call>(
+                &mut caller,
+                cellpack_ptr,
+                incoming_alkanes_ptr,
+                checkpoint_ptr,
+                start_fuel,
+            )
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__delegatecall",
+        |mut caller: Caller<'_, AlkanesState>,
+         cellpack_ptr: i32,
+         incoming_alkanes_ptr: i32,
+         checkpoint_ptr: i32,
+         start_fuel: u64|
+         -> i32 {
+            SafeAlkanesHostFunctionsImpl::handle_extcall::<Delegatecall>(
+                &mut caller,
+                cellpack_ptr,
+                incoming_alkanes_ptr,
+                checkpoint_ptr,
+                start_fuel,
+            )
+        },
+    )?;
+    linker.func_wrap(
+        "env",
+        "__staticcall",
+        |mut caller: Caller<'_, AlkanesState>,
+         cellpack_ptr: i32,
+         incoming_alkanes_ptr: i32,
+         checkpoint_ptr: i32,
+         start_fuel: u64|
+         -> i32 {
+            SafeAlkanesHostFunctionsImpl::handle_extcall::<Staticcall>(
+                &mut caller,
+                cellpack_ptr,
+                incoming_alkanes_ptr,
+                checkpoint_ptr,
+                start_fuel,
+            )
+        },
+    )?;
+    Ok(linker)
+}
@@ -1,12 +1,17 @@
+pub mod cache;
 pub mod constants;
 pub mod exports;
 pub mod extcall;
 pub mod fuel;
 pub mod host_functions;
 pub mod instance;
+pub mod precompiles;
+pub mod profile;
+pub mod revert;
 pub mod runtime;
 pub mod state;
 pub mod utils;
+pub mod validation;
 
 use self::constants::*;
 use self::exports::*;
@@ -0,0 +1,192 @@
+use super::fuel::{
+    FUEL_PER_MERKLE_LEVEL, FUEL_PER_PRECOMPILE_HASH_BYTE, FUEL_PRECOMPILE_HASH,
+    FUEL_PRECOMPILE_SIGNATURE,
+};
+use super::profile::profile_frame;
+use super::AlkanesRuntimeContext;
+use crate::network::activation;
+use alkanes_support::{
+    id::AlkaneId,
+    precompile::{ECDSA_VERIFY, MERKLE_VERIFY, RIPEMD160, SCHNORR_VERIFY, SHA256, TAGGED_HASH},
+    response::ExtendedCallResponse,
+    utils::{overflow_error, shift_bytes_or_err, shift_or_err},
+};
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::{ripemd160, sha256, Hash, HashEngine};
+use bitcoin::secp256k1::{
+    ecdsa, schnorr, Message, PublicKey, Secp256k1, VerifyOnly, XOnlyPublicKey,
+};
+use std::sync::{Arc, LazyLock, Mutex};
+
+/// Whether a call to `target` at `height` is answered by a precompile. Before
+/// activation the precompile block holds no alkanes, so such calls revert.
+pub fn is_precompile(target: &AlkaneId, height: u64) -> bool {
+    target.precompile().is_some() && height >= activation::PRECOMPILES
+}
+
+static SECP: LazyLock<Secp256k1<VerifyOnly>> = LazyLock::new(Secp256k1::verification_only);
+
+fn hash_fuel(bytes: usize) -> Result<u64> {
+    overflow_error(
+        (bytes as u64)
+            .checked_mul(FUEL_PER_PRECOMPILE_HASH_BYTE)
+            .and_then(|v| v.checked_add(FUEL_PRECOMPILE_HASH)),
+    )
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    sha256::Hash::hash(data).to_byte_array()
+}
+
+fn tagged_hash(tag: &[u8], data: &[u8]) -> [u8; 32] {
+    let tag_hash = sha256(tag);
+    let mut engine = sha256::Hash::engine();
+    engine.input(&tag_hash);
+    engine.input(&tag_hash);
+    engine.input(data);
+    sha256::Hash::from_engine(engine).to_byte_array()
+}
+
+fn schnorr_verify(pubkey: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let (Ok(pubkey), Ok(message), Ok(signature)) = (
+        XOnlyPublicKey::from_slice(pubkey),
+        Message::from_digest_slice(message),
+        schnorr::Signature::from_slice(signature),
+    ) else {
+        return false;
+    };
+    SECP.verify_schnorr(&signature, &message, &pubkey).is_ok()
+}
+
+fn ecdsa_verify(pubkey: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let (Ok(pubkey), Ok(message), Ok(signature)) = (
+        PublicKey::from_slice(pubkey),
+        Message::from_digest_slice(message),
+        ecdsa::Signature::from_compact(signature),
+    ) else {
+        return false;
+    };
+    SECP.verify_ecdsa(&message, &signature, &pubkey).is_ok()
+}
+
+fn merkle_verify(root: &[u8], leaf: &[u8], index: u128, leaves: u128, proof: &[u8]) -> bool {
+    if index >= leaves || proof.len() % 32 != 0 {
+        return false;
+    }
+    let mut node = leaf.to_vec();
+    let mut siblings = proof.chunks(32);
+    let (mut index, mut count) = (index, leaves);
+    while count > 1 {
+        // the last node of an odd level has no sibling and moves up as is
+        if index % 2 == 1 || index + 1 < count {
+            let Some(sibling) = siblings.next() else {
+                return false;
+            };
+            let mut pair = Vec::with_capacity(64);
+            if index % 2 == 0 {
+                pair.extend(&node);
+                pair.extend(sibling);
+            } else {
+                pair.extend(sibling);
+                pair.extend(&node);
+            }
+            node = sha256(&pair).to_vec();
+        }
+        index /= 2;
+        count = count.div_ceil(2);
+    }
+    siblings.next().is_none() && node.as_slice() == root
+}
+
+/// Runs the precompile at `target` on `inputs`, returning its output and the
+/// fuel it costs.
+pub fn precompile(target: &AlkaneId, mut inputs: Vec<u128>) -> Result<(Vec<u8>, u64)> {
+    match *target {
+        SHA256 => {
+            let data = shift_bytes_or_err(&mut inputs)?;
+            Ok((sha256(&data).to_vec(), hash_fuel(data.len())?))
+        }
+        RIPEMD160 => {
+            let data = shift_bytes_or_err(&mut inputs)?;
+            Ok((
+                ripemd160::Hash::hash(&data).to_byte_array().to_vec(),
+                hash_fuel(data.len())?,
+            ))
+        }
+        TAGGED_HASH => {
+            let tag = shift_bytes_or_err(&mut inputs)?;
+            let data = shift_bytes_or_err(&mut inputs)?;
+            Ok((
+                tagged_hash(&tag, &data).to_vec(),
+                hash_fuel(tag.len() + data.len())?,
+            ))
+        }
+        SCHNORR_VERIFY | ECDSA_VERIFY => {
+            let pubkey = shift_bytes_or_err(&mut inputs)?;
+            let message = shift_bytes_or_err(&mut inputs)?;
+            let signature = shift_bytes_or_err(&mut inputs)?;
+            let valid = if *target == SCHNORR_VERIFY {
+                schnorr_verify(&pubkey, &message, &signature)
+            } else {
+                ecdsa_verify(&pubkey, &message, &signature)
+            };
+            Ok((vec![valid as u8], FUEL_PRECOMPILE_SIGNATURE))
+        }
+        MERKLE_VERIFY => {
+            let root = shift_bytes_or_err(&mut inputs)?;
+            let leaf = shift_bytes_or_err(&mut inputs)?;
+            let index = shift_or_err(&mut inputs)?;
+            let leaves = shift_or_err(&mut inputs)?;
+            let proof = shift_bytes_or_err(&mut inputs)?;
+            let fuel = overflow_error(
+                ((proof.len() / 32) as u64)
+                    .checked_mul(FUEL_PER_MERKLE_LEVEL)
+                    .and_then(|v| v.checked_add(FUEL_PRECOMPILE_HASH)),
+            )?;
+            Ok((
+                vec![merkle_verify(&root, &leaf, index, leaves, &proof) as u8],
+                fuel,
+            ))
+        }
+        _ => Err(anyhow!("no precompile at [{},{}]", target.block, target.tx)),
+    }
+}
+
+/// Answers a call to a precompile in place of `run_after_special`. Incoming
+/// alkanes are handed back untouched.
+pub fn run_precompile(
+    context: Arc<Mutex<AlkanesRuntimeContext>>,
+    target: &AlkaneId,
+    start_fuel: u64,
+) -> Result<(ExtendedCallResponse, u64)> {
+    profile_frame(
+        &context.clone(),
+        |_| 0,
+        move || execute_precompile(context, target, start_fuel),
+    )
+}
+
+fn execute_precompile(
+    context: Arc<Mutex<AlkanesRuntimeContext>>,
+    target: &AlkaneId,
+    start_fuel: u64,
+) -> Result<(ExtendedCallResponse, u64)> {
+    let (inputs, incoming_alkanes) = {
+        let guard = context.lock().unwrap();
+        (guard.inputs.clone(), guard.incoming_alkanes.clone())
+    };
+    let (data, fuel_used) = precompile(target, inputs)?;
+    if fuel_used > start_fuel {
+        context.lock().unwrap().frame.fuel_used = start_fuel;
+        return Err(anyhow!(
+            "all fuel consumed by precompile: requested {} units, but only {} remaining",
+            fuel_used,
+            start_fuel
+        ));
+    }
+    context.lock().unwrap().frame.fuel_used = fuel_used;
+    let mut response = ExtendedCallResponse::default();
+    response.alkanes = incoming_alkanes;
+    response.data = data;
+    Ok((response, fuel_used))
+}
@@ -0,0 +1,157 @@
+use super::AlkanesRuntimeContext;
+use alkanes_support::id::AlkaneId;
+use alkanes_support::proto;
+use alkanes_support::response::ExtendedCallResponse;
+use anyhow::Result;
+use protobuf::MessageField;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HostFunctionFuel {
+    pub name: String,
+    pub calls: u64,
+    pub bytes: u64,
+    pub fuel: u64,
+}
+
+/// Where the fuel of one frame went. `instructions` is what wasmi metered for
+/// the frame itself, once its returned storage and the children charged to it
+/// are taken out of `total`. Host function fuel is what `src/vm/fuel.rs` prices
+/// each call at; the VM checks it against the fuel left rather than deducting
+/// it, so it is reported beside `total` rather than inside it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FuelProfile {
+    pub target: AlkaneId,
+    pub inputs: Vec<u128>,
+    pub total: u64,
+    pub instructions: u64,
+    pub storage: u64,
+    pub reverted: bool,
+    pub host_functions: Vec<HostFunctionFuel>,
+    pub children: Vec<FuelProfile>,
+}
+
+impl FuelProfile {
+    pub fn host_function(&self, name: &str) -> Option<&HostFunctionFuel> {
+        self.host_functions.iter().find(|v| v.name == name)
+    }
+}
+
+#[derive(Default)]
+struct ProfilerState {
+    frames: Vec<FuelProfile>,
+    root: Option<FuelProfile>,
+}
+
+/// Collects a `FuelProfile` tree as frames are entered and left. Clones share
+/// the tree, so a subcontext cloned from its caller records into the same one.
+#[derive(Clone, Default)]
+pub struct FuelProfiler(Arc<Mutex<ProfilerState>>);
+
+impl FuelProfiler {
+    pub fn enter(&self, target: &AlkaneId, inputs: &[u128]) {
+        self.0.lock().unwrap().frames.push(FuelProfile {
+            target: target.clone(),
+            inputs: inputs.to_vec(),
+            ..Default::default()
+        });
+    }
+    pub fn host(&self, name: &str, bytes: u64, fuel: u64) {
+        let mut state = self.0.lock().unwrap();
+        if let Some(frame) = state.frames.last_mut() {
+            let index = match frame.host_functions.iter().position(|v| v.name == name) {
+                Some(index) => index,
+                None => {
+                    frame.host_functions.push(HostFunctionFuel {
+                        name: name.to_string(),
+                        ..Default::default()
+                    });
+                    frame.host_functions.len() - 1
+                }
+            };
+            let entry = &mut frame.host_functions[index];
+            entry.calls += 1;
+            entry.bytes += bytes;
+            entry.fuel = entry.fuel.saturating_add(fuel);
+        }
+    }
+    pub fn exit(&self, fuel_used: u64, storage: u64, reverted: bool) {
+        let mut state = self.0.lock().unwrap();
+        if let Some(mut frame) = state.frames.pop() {
+            let children: u64 = frame
+                .children
+                .iter()
+                .filter(|v| !v.reverted)
+                .map(|v| v.total)
+                .sum();
+            frame.total = fuel_used;
+            frame.storage = storage;
+            frame.reverted = reverted;
+            frame.instructions = fuel_used.saturating_sub(storage).saturating_sub(children);
+            match state.frames.last_mut() {
+                Some(parent) => parent.children.push(frame),
+                None => state.root = Some(frame),
+            }
+        }
+    }
+    /// The profile of the outermost frame, once it has been left.
+    pub fn finish(&self) -> Option<FuelProfile> {
+        self.0.lock().unwrap().root.take()
+    }
+}
+
+/// Runs the frame `context` sets up through `f`, as a frame of the profile the
+/// context is collecting if there is one. `storage` prices the storage a
+/// successful frame returns.
+pub fn profile_frame<F, S>(
+    context: &Arc<Mutex<AlkanesRuntimeContext>>,
+    storage: S,
+    f: F,
+) -> Result<(ExtendedCallResponse, u64)>
+where
+    F: FnOnce() -> Result<(ExtendedCallResponse, u64)>,
+    S: FnOnce(&ExtendedCallResponse) -> u64,
+{
+    let profiler = match context.lock().unwrap().profiler.clone() {
+        Some(v) => v,
+        None => return f(),
+    };
+    {
+        let guard = context.lock().unwrap();
+        profiler.enter(&guard.myself, &guard.inputs);
+    }
+    let result = f();
+    let storage_fuel = match &result {
+        Ok((response, _)) => storage(response),
+        Err(_) => 0,
+    };
+    let fuel_used = context.lock().unwrap().frame.fuel_used;
+    profiler.exit(fuel_used, storage_fuel, result.is_err());
+    result
+}
+
+impl Into<proto::alkanes::HostFunctionFuel> for HostFunctionFuel {
+    fn into(self) -> proto::alkanes::HostFunctionFuel {
+        let mut result = proto::alkanes::HostFunctionFuel::new();
+        result.name = self.name;
+        result.calls = self.calls;
+        result.bytes = self.bytes;
+        result.fuel = self.fuel;
+        result
+    }
+}
+
+impl Into<proto::alkanes::FuelProfile> for FuelProfile {
+    fn into(self) -> proto::alkanes::FuelProfile {
+        let mut result = proto::alkanes::FuelProfile::new();
+        result.target = MessageField::some(self.target.into());
+        result.inputs = self.inputs.into_iter().map(|v| v.into()).collect();
+        result.total = self.total;
+        result.instructions = self.instructions;
+        result.storage = self.storage;
+        result.reverted = self.reverted;
+        result.host_functions = self.host_functions.into_iter().map(|v| v.into()).collect();
+        result.children = self.children.into_iter().map(|v| v.into()).collect();
+        result
+    }
+}
@@ -0,0 +1,33 @@
+use alkanes_support::abi::ERROR_STRING_SELECTOR;
+use alkanes_support::id::AlkaneId;
+use std::fmt;
+
+/// The code of `target` reverted with a typed error rather than a message, so
+/// its ABI names the error. `data` is the big endian error code followed by the
+/// encoded fields.
+#[derive(Clone, Debug)]
+pub struct TypedRevert {
+    pub target: AlkaneId,
+    pub data: Vec<u8>,
+}
+
+impl fmt::Display for TypedRevert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ALKANES: revert: 0x{}", hex::encode(&self.data))
+    }
+}
+
+impl std::error::Error for TypedRevert {}
+
+/// The revert data recorded for a failed call: the data of a typed error as the
+/// alkane reverted with it, otherwise the message behind the string selector.
+pub fn revert_data(e: &anyhow::Error) -> Vec<u8> {
+    match e.downcast_ref::<TypedRevert>() {
+        Some(revert) => revert.data.clone(),
+        None => {
+            let mut data = ERROR_STRING_SELECTOR.to_vec();
+            data.extend(e.to_string().as_bytes());
+            data
+        }
+    }
+}
@@ -1,8 +1,14 @@
 use std::fmt;
 
 use alkanes_support::{
-    cellpack::Cellpack, context::Context, id::AlkaneId, parcel::AlkaneTransferParcel, trace::Trace,
+    cellpack::Cellpack,
+    context::Context,
+    id::AlkaneId,
+    parcel::AlkaneTransferParcel,
+    trace::{Trace, TraceFrame},
 };
+
+use super::profile::FuelProfiler;
 #[allow(unused_imports)]
 use {
     metashrew_core::{println, stdio::stdout},
@@ -14,12 +20,17 @@ use protorune::message::MessageContextParcel;
 #[derive(Default, Clone)]
 pub struct AlkanesRuntimeContext {
     pub myself: AlkaneId,
+    /// The alkane whose binary runs. It is `myself` except under a
+    /// delegatecall, which runs the callee's code against the caller's storage.
+    pub code: AlkaneId,
     pub caller: AlkaneId,
     pub incoming_alkanes: AlkaneTransferParcel,
     pub returndata: Vec<u8>,
     pub inputs: Vec<u128>,
     pub message: Box<MessageContextParcel>,
     pub trace: Trace,
+    pub frame: TraceFrame,
+    pub profiler: Option<FuelProfiler>,
 }
 
 impl fmt::Debug for AlkanesRuntimeContext {
@@ -46,9 +57,12 @@ impl AlkanesRuntimeContext {
             returndata: vec![],
             incoming_alkanes,
             myself: AlkaneId::default(),
+            code: AlkaneId::default(),
             caller: AlkaneId::default(),
             trace: Trace::default(),
+            frame: TraceFrame::default(),
             inputs: cloned.inputs,
+            profiler: None,
         }
     }
     pub fn flatten(&self) -> Vec<u128> {
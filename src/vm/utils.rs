@@ -1,13 +1,21 @@
+use super::cache::{invalidate, load_binary, store_binary};
+use super::profile::profile_frame;
+use super::validation::validate_deployment;
 use super::{AlkanesInstance, AlkanesRuntimeContext, AlkanesState};
+use crate::abi::record_abi;
+use crate::network::activation;
+use crate::registry::{bytecode_hash, register};
+use crate::tables::CREATED_BY_HEIGHT;
 use crate::utils::{pipe_storagemap_to, transfer_from};
 use crate::vm::fuel::FUEL_PER_STORE_BYTE;
+use alkanes_support::proto::alkanes::{AlkaneDeployKind, AlkaneRegistryEntry, Outpoint};
 use alkanes_support::trace::TraceEvent;
 use alkanes_support::{
-    cellpack::Cellpack, gz::decompress, id::AlkaneId, parcel::AlkaneTransferParcel,
-    response::ExtendedCallResponse, storage::StorageMap, utils::overflow_error,
-    witness::find_witness_payload,
+    cellpack::Cellpack, id::AlkaneId, parcel::AlkaneTransferParcel, response::ExtendedCallResponse,
+    storage::StorageMap, utils::overflow_error, witness::find_witness_payload,
 };
 use anyhow::{anyhow, Result};
+use bitcoin::hashes::Hash;
 use bitcoin::OutPoint;
 use metashrew_core::index_pointer::{AtomicPointer, IndexPointer};
 #[allow(unused_imports)]
@@ -16,6 +24,8 @@ use metashrew_core::{
     stdio::{stdout, Write},
 };
 use metashrew_support::index_pointer::KeyValuePointer;
+use protobuf::MessageField;
+use protorune::versioned;
 use protorune_support::utils::consensus_encode;
 use std::sync::{Arc, Mutex};
 use wasmi::*;
@@ -52,6 +62,9 @@ pub fn sequence_pointer(ptr: &AtomicPointer) -> AtomicPointer {
 fn set_alkane_id_to_tx_id(
     context: Arc<Mutex<AlkanesRuntimeContext>>,
     alkane_id: &AlkaneId,
+    kind: AlkaneDeployKind,
+    factory: Option<AlkaneId>,
+    binary: &Arc<Vec<u8>>,
 ) -> Result<()> {
     // Acquire the mutex once and keep the guard for the duration of the function
     let context_guard = context.lock().unwrap();
@@ -60,14 +73,56 @@ fn set_alkane_id_to_tx_id(
         txid: context_guard.message.transaction.compute_txid(),
         vout: context_guard.message.vout,
     };
-    let outpoint_bytes: Vec<u8> = consensus_encode(&outpoint)?;
+    record_creation(
+        &context_guard.message.atomic,
+        context_guard.message.height,
+        &outpoint,
+        alkane_id,
+        kind,
+        factory,
+        binary,
+    )
+}
 
-    context_guard
-        .message
-        .atomic
-        .keyword("/alkanes_id_to_outpoint/")
-        .select(&alkane_id.clone().into())
-        .set(Arc::new(outpoint_bytes));
+/// Records where `alkane_id` was created, adds it to the registry and records
+/// its ABI. Genesis goes through here too, so 2:0 is indexed like any other
+/// deployment.
+pub fn record_creation(
+    atomic: &AtomicPointer,
+    height: u64,
+    outpoint: &OutPoint,
+    alkane_id: &AlkaneId,
+    kind: AlkaneDeployKind,
+    factory: Option<AlkaneId>,
+    binary: &Arc<Vec<u8>>,
+) -> Result<()> {
+    let outpoint_bytes: Vec<u8> = consensus_encode(outpoint)?;
+
+    versioned::set(
+        &mut atomic
+            .keyword("/alkanes_id_to_outpoint/")
+            .select(&alkane_id.clone().into()),
+        Arc::new(outpoint_bytes),
+    );
+    versioned::append(
+        &atomic.derive(&CREATED_BY_HEIGHT.select_value(height)),
+        Arc::new(alkane_id.clone().into()),
+    );
+    let entry = AlkaneRegistryEntry {
+        id: MessageField::some(alkane_id.clone().into()),
+        height,
+        outpoint: MessageField::some(Outpoint {
+            txid: outpoint.txid.as_byte_array().to_vec(),
+            vout: outpoint.vout,
+            ..Default::default()
+        }),
+        kind: kind.into(),
+        factory: factory.map(|v| v.into()).into(),
+        bytecode_hash: bytecode_hash(binary),
+        ..Default::default()
+    };
+    register(atomic, &entry)?;
+    record_abi(atomic, height, &entry.bytecode_hash, binary)?;
 
     Ok(())
 }
@@ -79,19 +134,21 @@ pub fn run_special_cellpacks(
     let mut payload = cellpack.clone();
     let mut binary = Arc::<Vec<u8>>::new(vec![]);
     let mut next_sequence_pointer = sequence_pointer(&mut context.lock().unwrap().message.atomic);
-    let next_sequence = next_sequence_pointer.get_value::<u128>();
+    let next_sequence = versioned::get_value::<_, u128>(&next_sequence_pointer);
+    let height = context.lock().unwrap().message.height;
     let original_target = cellpack.target.clone();
     if cellpack.target.is_created(next_sequence) {
         // contract already created, load the wasm from the index
-        let wasm_payload = context
-            .lock()
-            .unwrap()
-            .message
-            .atomic
-            .keyword("/alkanes/")
-            .select(&payload.target.clone().into())
-            .get();
-        binary = Arc::new(decompress(wasm_payload.as_ref().clone())?);
+        let wasm_payload = versioned::get(
+            &context
+                .lock()
+                .unwrap()
+                .message
+                .atomic
+                .keyword("/alkanes/")
+                .select(&payload.target.clone().into()),
+        );
+        binary = load_binary(&payload.target, wasm_payload)?;
     } else if cellpack.target.is_create() {
         // contract not created, create it by first loading the wasm from the witness
         // then storing it in the index.
@@ -100,6 +157,12 @@ pub fn run_special_cellpacks(
                 .ok_or("finding witness payload failed for creation of alkane")
                 .map_err(|_| anyhow!("used CREATE cellpack but no binary found in witness"))?,
         );
+        // checked from activation only, so earlier deployments index as they did
+        let validated = if height >= activation::DEPLOY_VALIDATION {
+            Some(validate_deployment(&wasm_payload)?)
+        } else {
+            None
+        };
         payload.target = AlkaneId {
             block: 2,
             tx: next_sequence,
@@ -111,11 +174,21 @@ pub fn run_special_cellpacks(
             .atomic
             .keyword("/alkanes/")
             .select(&payload.target.clone().into());
-        pointer.set(wasm_payload.clone());
-        binary = Arc::new(decompress(wasm_payload.as_ref().clone())?);
-        next_sequence_pointer.set_value(next_sequence + 1);
+        versioned::set(&mut pointer, wasm_payload.clone());
+        invalidate(&payload.target);
+        binary = match validated {
+            Some(validated) => store_binary(&payload.target, wasm_payload, validated),
+            None => load_binary(&payload.target, wasm_payload)?,
+        };
+        versioned::set_value(&mut next_sequence_pointer, next_sequence + 1);
 
-        set_alkane_id_to_tx_id(context.clone(), &payload.target)?;
+        set_alkane_id_to_tx_id(
+            context.clone(),
+            &payload.target,
+            AlkaneDeployKind::CREATE,
+            None,
+            &binary,
+        )?;
     } else if let Some(number) = cellpack.target.reserved() {
         // we have already reserved an alkane id, find the binary and
         // set it in the index
@@ -126,6 +199,11 @@ pub fn run_special_cellpacks(
                     anyhow!("used CREATERESERVED cellpack but no binary found in witness")
                 })?,
         );
+        let validated = if height >= activation::DEPLOY_VALIDATION {
+            Some(validate_deployment(&wasm_payload)?)
+        } else {
+            None
+        };
         payload.target = AlkaneId {
             block: 4,
             tx: number,
@@ -137,41 +215,60 @@ pub fn run_special_cellpacks(
             .atomic
             .keyword("/alkanes/")
             .select(&payload.target.clone().into());
-        if ptr.get().as_ref().len() == 0 {
-            ptr.set(wasm_payload.clone());
-            set_alkane_id_to_tx_id(context.clone(), &payload.target)?;
+        if versioned::get(&ptr).len() == 0 {
+            versioned::set(&mut ptr, wasm_payload.clone());
+            invalidate(&payload.target);
+            binary = match validated {
+                Some(validated) => store_binary(&payload.target, wasm_payload, validated),
+                None => load_binary(&payload.target, wasm_payload)?,
+            };
+            set_alkane_id_to_tx_id(
+                context.clone(),
+                &payload.target,
+                AlkaneDeployKind::CREATERESERVED,
+                None,
+                &binary,
+            )?;
         } else {
             return Err(anyhow!(format!(
                 "used CREATERESERVED cellpack but {} already holds a binary",
                 number
             )));
         }
-        binary = Arc::new(decompress(wasm_payload.clone().as_ref().clone())?);
     } else if let Some(factory) = cellpack.target.factory() {
         // we find the factory alkane wasm and set the current alkane to the factory wasm
         payload.target = AlkaneId::new(2, next_sequence);
-        next_sequence_pointer.set_value(next_sequence + 1);
-        let context_binary: Vec<u8> = context
-            .lock()
-            .unwrap()
-            .message
-            .atomic
-            .keyword("/alkanes/")
-            .select(&factory.clone().into())
-            .get()
-            .as_ref()
-            .clone();
-        let rc = Arc::new(context_binary);
-        context
-            .lock()
-            .unwrap()
-            .message
-            .atomic
-            .keyword("/alkanes/")
-            .select(&payload.target.clone().into())
-            .set(rc.clone()); // TODO: we don't need to store this twice
-        set_alkane_id_to_tx_id(context.clone(), &payload.target)?;
-        binary = Arc::new(decompress(rc.as_ref().clone())?);
+        versioned::set_value(&mut next_sequence_pointer, next_sequence + 1);
+        let rc = versioned::get(
+            &context
+                .lock()
+                .unwrap()
+                .message
+                .atomic
+                .keyword("/alkanes/")
+                .select(&factory.clone().into()),
+        );
+        // TODO: we don't need to store this twice
+        versioned::set(
+            &mut context
+                .lock()
+                .unwrap()
+                .message
+                .atomic
+                .keyword("/alkanes/")
+                .select(&payload.target.clone().into()),
+            rc.clone(),
+        );
+        invalidate(&payload.target);
+        // the clone shares the factory's bytes, so reuse its compiled module
+        binary = load_binary(&factory, rc)?;
+        set_alkane_id_to_tx_id(
+            context.clone(),
+            &payload.target,
+            AlkaneDeployKind::FACTORY,
+            Some(factory),
+            &binary,
+        )?;
     }
     if &original_target != &payload.target {
         context
@@ -249,6 +346,18 @@ pub fn run_after_special(
     context: Arc<Mutex<AlkanesRuntimeContext>>,
     binary: Arc<Vec<u8>>,
     start_fuel: u64,
+) -> Result<(ExtendedCallResponse, u64)> {
+    profile_frame(
+        &context.clone(),
+        |response| FUEL_PER_STORE_BYTE.saturating_mul(response.storage.serialize().len() as u64),
+        move || execute_after_special(context, binary, start_fuel),
+    )
+}
+
+fn execute_after_special(
+    context: Arc<Mutex<AlkanesRuntimeContext>>,
+    binary: Arc<Vec<u8>>,
+    start_fuel: u64,
 ) -> Result<(ExtendedCallResponse, u64)> {
     #[cfg(feature = "debug-log")]
     {
@@ -260,7 +369,12 @@ pub fn run_after_special(
     }
 
     let mut instance = AlkanesInstance::from_alkane(context.clone(), binary.clone(), start_fuel)?;
-    let response = instance.execute()?;
+    let response = instance.execute().map_err(|e| {
+        // a reverted frame still reports what it burned before failing
+        context.lock().unwrap().frame.fuel_used =
+            start_fuel.saturating_sub(instance.store.get_fuel().unwrap_or(0));
+        e
+    })?;
 
     let remaining_fuel = instance.store.get_fuel()?;
     let storage_len = response.storage.serialize().len() as u64;
@@ -295,6 +409,7 @@ pub fn run_after_special(
             opt
         },
     ))?;
+    context.lock().unwrap().frame.fuel_used = fuel_used;
 
     Ok((response, fuel_used))
 }
@@ -304,8 +419,9 @@ pub fn prepare_context(
     myself: &AlkaneId,
     delegate: bool,
 ) {
+    let mut inner = context.lock().unwrap();
+    inner.code = myself.clone();
     if !delegate {
-        let mut inner = context.lock().unwrap();
         inner.caller = caller.clone();
         inner.myself = myself.clone();
     }
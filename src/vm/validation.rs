@@ -0,0 +1,156 @@
+use super::cache::ENGINE;
+use super::{
+    HOST_FUNCTIONS, MAX_BINARY_SIZE, MAX_MEMORY_PAGES, MAX_TABLE_ELEMENTS, MIN_MEMORY_PAGES,
+};
+use alkanes_support::gz::decompress_bounded;
+use std::fmt;
+use wasmparser::{ExternalKind, Parser, Payload, TypeRef, Validator, WasmFeatures};
+
+/// Why a CREATE or CREATERESERVED payload was refused. The `Display` output is
+/// the revert reason recorded in the trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeployRejection {
+    InvalidPayload(String),
+    BinaryTooLarge { limit: usize },
+    InvalidModule(String),
+    FloatingPoint,
+    UnknownImport { module: String, name: String },
+    MissingExport(&'static str),
+    MemoryTooLarge { pages: u64, limit: u64 },
+    MemoryCannotGrow { maximum: u64 },
+    TableTooLarge { elements: u64, limit: u64 },
+}
+
+impl fmt::Display for DeployRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "deployment rejected: ")?;
+        match self {
+            Self::InvalidPayload(e) => write!(f, "payload is not gzip: {}", e),
+            Self::BinaryTooLarge { limit } => {
+                write!(f, "binary exceeds {} bytes once decompressed", limit)
+            }
+            Self::InvalidModule(e) => write!(f, "invalid module: {}", e),
+            Self::FloatingPoint => write!(f, "module uses floating point"),
+            Self::UnknownImport { module, name } => {
+                write!(f, "unknown import {}::{}", module, name)
+            }
+            Self::MissingExport(name) => write!(f, "missing export {}", name),
+            Self::MemoryTooLarge { pages, limit } => {
+                write!(f, "memory of {} pages exceeds {} pages", pages, limit)
+            }
+            Self::MemoryCannotGrow { maximum } => write!(
+                f,
+                "memory maximum of {} pages is below the {} pages every alkane runs with",
+                maximum, MIN_MEMORY_PAGES
+            ),
+            Self::TableTooLarge { elements, limit } => {
+                write!(
+                    f,
+                    "table of {} elements exceeds {} elements",
+                    elements, limit
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeployRejection {}
+
+/// Decompresses a deployment payload and checks the module against the
+/// bytecode policy, returning the decompressed binary.
+pub fn validate_deployment(payload: &[u8]) -> Result<Vec<u8>, DeployRejection> {
+    let binary = decompress_bounded(payload, MAX_BINARY_SIZE)
+        .map_err(|e| DeployRejection::InvalidPayload(e.to_string()))?
+        .ok_or(DeployRejection::BinaryTooLarge {
+            limit: MAX_BINARY_SIZE,
+        })?;
+    validate_binary(&binary)?;
+    Ok(binary)
+}
+
+pub fn validate_binary(binary: &[u8]) -> Result<(), DeployRejection> {
+    // anything the runtime itself cannot compile is rejected as is
+    wasmi::Module::new(&ENGINE, &mut &binary[..])
+        .map_err(|e| DeployRejection::InvalidModule(e.to_string()))?;
+    Validator::new_with_features(WasmFeatures::default())
+        .validate_all(binary)
+        .map_err(|e| DeployRejection::InvalidModule(e.to_string()))?;
+    // the module is otherwise valid, so rejecting it with floats disabled can
+    // only be down to floats
+    Validator::new_with_features(WasmFeatures::default() - WasmFeatures::FLOATS)
+        .validate_all(binary)
+        .map_err(|_| DeployRejection::FloatingPoint)?;
+    let mut execute = false;
+    let mut memory = false;
+    for payload in Parser::new(0).parse_all(binary) {
+        let payload = payload.map_err(|e| DeployRejection::InvalidModule(e.to_string()))?;
+        match payload {
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    let import =
+                        import.map_err(|e| DeployRejection::InvalidModule(e.to_string()))?;
+                    let known = import.module == "env"
+                        && matches!(import.ty, TypeRef::Func(_))
+                        && HOST_FUNCTIONS.contains(&import.name);
+                    if !known {
+                        return Err(DeployRejection::UnknownImport {
+                            module: import.module.to_string(),
+                            name: import.name.to_string(),
+                        });
+                    }
+                }
+            }
+            Payload::MemorySection(reader) => {
+                for ty in reader {
+                    let ty = ty.map_err(|e| DeployRejection::InvalidModule(e.to_string()))?;
+                    for pages in std::iter::once(ty.initial).chain(ty.maximum) {
+                        if pages > MAX_MEMORY_PAGES {
+                            return Err(DeployRejection::MemoryTooLarge {
+                                pages,
+                                limit: MAX_MEMORY_PAGES,
+                            });
+                        }
+                    }
+                    if let Some(maximum) = ty.maximum {
+                        if maximum < MIN_MEMORY_PAGES {
+                            return Err(DeployRejection::MemoryCannotGrow { maximum });
+                        }
+                    }
+                }
+            }
+            Payload::TableSection(reader) => {
+                for table in reader {
+                    let table = table.map_err(|e| DeployRejection::InvalidModule(e.to_string()))?;
+                    let maximum = table.ty.maximum.map(u64::from);
+                    for elements in std::iter::once(u64::from(table.ty.initial)).chain(maximum) {
+                        if elements > MAX_TABLE_ELEMENTS {
+                            return Err(DeployRejection::TableTooLarge {
+                                elements,
+                                limit: MAX_TABLE_ELEMENTS,
+                            });
+                        }
+                    }
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    let export =
+                        export.map_err(|e| DeployRejection::InvalidModule(e.to_string()))?;
+                    match (export.name, export.kind) {
+                        ("__execute", ExternalKind::Func) => execute = true,
+                        ("memory", ExternalKind::Memory) => memory = true,
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    if !execute {
+        return Err(DeployRejection::MissingExport("__execute"));
+    }
+    if !memory {
+        return Err(DeployRejection::MissingExport("memory"));
+    }
+    Ok(())
+}